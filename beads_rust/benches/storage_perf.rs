@@ -81,6 +81,9 @@ fn create_test_issue(i: usize) -> Issue {
         labels: vec![format!("label-{}", i % 5)],
         dependencies: vec![],
         comments: vec![],
+        time_entries: vec![],
+        recurrence: None,
+        attachments: vec![],
     }
 }
 
@@ -0,0 +1,425 @@
+//! Content-addressed attachment blobs in `.beads/blobs/`.
+//!
+//! `br attach` copies a file into the store under its SHA-256
+//! (`blobs/<first two hex digits>/<digest>`), so the same content attached
+//! twice is stored once. The `attachments` table and the JSONL export only
+//! carry metadata (name, digest, size).
+//!
+//! Size policy:
+//! - `attachments.max_size` (default 50 MiB) rejects larger files.
+//! - `attachments.git_max_size` (default 1 MiB) keeps larger blobs out of
+//!   git by listing them in `blobs/.gitignore`. Their records are still
+//!   exported, so other clones know the attachment exists but lack the
+//!   content; `br doctor` reports those as missing.
+//!
+//! Sizes accept a plain byte count or a unit (`512K`, `10MB`, `1GiB`;
+//! units are binary).
+
+use crate::config::ConfigLayer;
+use crate::error::{BeadsError, Result};
+use crate::util::{is_sha256_hex, sha256_reader};
+use std::collections::BTreeSet;
+use std::fs::{self, File};
+use std::io::{self, BufReader};
+use std::path::{Path, PathBuf};
+
+/// Blob directory inside `.beads/`.
+pub const BLOBS_DIR: &str = "blobs";
+
+/// Largest file `br attach` accepts by default.
+pub const DEFAULT_MAX_SIZE: u64 = 50 * 1024 * 1024;
+
+/// Largest blob committed to git by default.
+pub const DEFAULT_GIT_MAX_SIZE: u64 = 1024 * 1024;
+
+const GITIGNORE_HEADER: &str =
+    "# Attachment blobs over attachments.git_max_size (managed by br attach)\n";
+
+/// A blob written to the store.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredBlob {
+    pub sha256: String,
+    pub size: u64,
+    /// The blob is listed in `blobs/.gitignore`.
+    pub local_only: bool,
+}
+
+/// The blob store of one workspace.
+#[derive(Debug, Clone)]
+pub struct BlobStore {
+    dir: PathBuf,
+    max_size: u64,
+    git_max_size: u64,
+}
+
+impl BlobStore {
+    /// Store for the workspace at `beads_dir` with the default size policy.
+    #[must_use]
+    pub fn new(beads_dir: &Path) -> Self {
+        Self {
+            dir: beads_dir.join(BLOBS_DIR),
+            max_size: DEFAULT_MAX_SIZE,
+            git_max_size: DEFAULT_GIT_MAX_SIZE,
+        }
+    }
+
+    /// Store for the workspace at `beads_dir`, with the size policy from
+    /// config.
+    ///
+    /// # Errors
+    ///
+    /// Returns a config error if `attachments.max_size` or
+    /// `attachments.git_max_size` is not a valid size.
+    pub fn load(beads_dir: &Path, layer: &ConfigLayer) -> Result<Self> {
+        let value = |key: &str| {
+            layer
+                .runtime
+                .get(key)
+                .map(|value| value.trim())
+                .filter(|value| !value.is_empty())
+        };
+        let mut store = Self::new(beads_dir);
+        if let Some(raw) = value("attachments.max_size") {
+            store.max_size = parse_size(raw, "attachments.max_size")?;
+            if store.max_size == 0 {
+                return Err(BeadsError::Config(
+                    "attachments.max_size must be greater than zero".to_string(),
+                ));
+            }
+        }
+        if let Some(raw) = value("attachments.git_max_size") {
+            store.git_max_size = parse_size(raw, "attachments.git_max_size")?;
+        }
+        Ok(store)
+    }
+
+    /// The `blobs/` directory.
+    #[must_use]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    #[must_use]
+    pub const fn max_size(&self) -> u64 {
+        self.max_size
+    }
+
+    #[must_use]
+    pub const fn git_max_size(&self) -> u64 {
+        self.git_max_size
+    }
+
+    /// Where the blob with `sha256` is (or would be) stored.
+    #[must_use]
+    pub fn path_for(&self, sha256: &str) -> PathBuf {
+        let shard = sha256.get(..2).unwrap_or("00");
+        self.dir.join(shard).join(sha256)
+    }
+
+    /// Whether the blob with `sha256` is present.
+    #[must_use]
+    pub fn contains(&self, sha256: &str) -> bool {
+        self.path_for(sha256).is_file()
+    }
+
+    /// Copy `source` into the store.
+    ///
+    /// Blobs over `attachments.git_max_size`, or any blob when `local_only`
+    /// is set, are listed in `blobs/.gitignore`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if `source` is not a file or exceeds
+    /// `attachments.max_size`, or an I/O error if it cannot be copied.
+    pub fn store(&self, source: &Path, local_only: bool) -> Result<StoredBlob> {
+        let metadata = fs::metadata(source)?;
+        if !metadata.is_file() {
+            return Err(BeadsError::validation(
+                "file",
+                format!("{} is not a regular file", source.display()),
+            ));
+        }
+        if metadata.len() > self.max_size {
+            return Err(self.too_large(source, metadata.len()));
+        }
+
+        let (sha256, size) = sha256_reader(BufReader::new(File::open(source)?))?;
+        if size > self.max_size {
+            return Err(self.too_large(source, size));
+        }
+
+        let target = self.path_for(&sha256);
+        if !target.is_file() {
+            let shard_dir = target.parent().unwrap_or(&self.dir);
+            fs::create_dir_all(shard_dir)?;
+            let temp = shard_dir.join(format!(".{sha256}.tmp"));
+            fs::copy(source, &temp)?;
+            // The file may have changed since it was hashed.
+            let (copied, _) = sha256_reader(BufReader::new(File::open(&temp)?))?;
+            if copied != sha256 {
+                let _ = fs::remove_file(&temp);
+                return Err(BeadsError::validation(
+                    "file",
+                    format!("{} changed while it was being attached", source.display()),
+                ));
+            }
+            fs::rename(&temp, &target)?;
+        }
+
+        let local_only = local_only || size > self.git_max_size || self.is_ignored(&sha256)?;
+        if local_only {
+            self.set_ignored(&sha256, true)?;
+        }
+        Ok(StoredBlob {
+            sha256,
+            size,
+            local_only,
+        })
+    }
+
+    /// Digests of all blobs present on disk.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the blob directory cannot be read.
+    pub fn list(&self) -> Result<BTreeSet<String>> {
+        let mut blobs = BTreeSet::new();
+        let shards = match fs::read_dir(&self.dir) {
+            Ok(shards) => shards,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(blobs),
+            Err(err) => return Err(err.into()),
+        };
+        for shard in shards {
+            let shard = shard?;
+            if !shard.file_type()?.is_dir() {
+                continue;
+            }
+            for entry in fs::read_dir(shard.path())? {
+                let name = entry?.file_name();
+                if let Some(name) = name.to_str().filter(|name| is_sha256_hex(name)) {
+                    blobs.insert(name.to_string());
+                }
+            }
+        }
+        Ok(blobs)
+    }
+
+    /// Delete the blob with `sha256` and drop it from `blobs/.gitignore`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the blob exists but cannot be removed.
+    pub fn remove(&self, sha256: &str) -> Result<()> {
+        match fs::remove_file(self.path_for(sha256)) {
+            Ok(()) => {}
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err.into()),
+        }
+        if let Some(shard_dir) = self.path_for(sha256).parent() {
+            let _ = fs::remove_dir(shard_dir); // only succeeds once empty
+        }
+        self.set_ignored(sha256, false)
+    }
+
+    fn too_large(&self, source: &Path, size: u64) -> BeadsError {
+        BeadsError::validation(
+            "file",
+            format!(
+                "{} is {size} bytes, over attachments.max_size ({} bytes)",
+                source.display(),
+                self.max_size
+            ),
+        )
+    }
+
+    fn gitignore_path(&self) -> PathBuf {
+        self.dir.join(".gitignore")
+    }
+
+    fn gitignore_entry(sha256: &str) -> String {
+        format!("/{}/{sha256}", sha256.get(..2).unwrap_or("00"))
+    }
+
+    fn is_ignored(&self, sha256: &str) -> Result<bool> {
+        let entry = Self::gitignore_entry(sha256);
+        match fs::read_to_string(self.gitignore_path()) {
+            Ok(content) => Ok(content.lines().any(|line| line.trim() == entry)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(false),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn set_ignored(&self, sha256: &str, ignored: bool) -> Result<()> {
+        let path = self.gitignore_path();
+        let entry = Self::gitignore_entry(sha256);
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                if !ignored {
+                    return Ok(());
+                }
+                GITIGNORE_HEADER.to_string()
+            }
+            Err(err) => return Err(err.into()),
+        };
+        let present = content.lines().any(|line| line.trim() == entry);
+        if present == ignored {
+            return Ok(());
+        }
+        let updated = if ignored {
+            let mut updated = content;
+            if !updated.is_empty() && !updated.ends_with('\n') {
+                updated.push('\n');
+            }
+            updated.push_str(&entry);
+            updated.push('\n');
+            updated
+        } else {
+            let mut updated = String::with_capacity(content.len());
+            for line in content.lines().filter(|line| line.trim() != entry) {
+                updated.push_str(line);
+                updated.push('\n');
+            }
+            updated
+        };
+        fs::create_dir_all(&self.dir)?;
+        fs::write(path, updated)?;
+        Ok(())
+    }
+}
+
+/// Parse a size such as `1048576`, `512K`, `10MB` or `1GiB` (binary units).
+///
+/// # Errors
+///
+/// Returns a config error naming `key` if the value is not a size.
+pub fn parse_size(value: &str, key: &str) -> Result<u64> {
+    let value = value.trim();
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (digits, unit) = value.split_at(split);
+    let multiplier: u64 = match unit.trim().to_ascii_lowercase().as_str() {
+        "" | "b" => 1,
+        "k" | "kb" | "kib" => 1024,
+        "m" | "mb" | "mib" => 1024 * 1024,
+        "g" | "gb" | "gib" => 1024 * 1024 * 1024,
+        _ => 0,
+    };
+    digits
+        .parse::<u64>()
+        .ok()
+        .filter(|_| multiplier > 0)
+        .and_then(|number| number.checked_mul(multiplier))
+        .ok_or_else(|| {
+            BeadsError::Config(format!(
+                "{key} must be a size like 1048576, 512K or 10MB, got '{value}'"
+            ))
+        })
+}
+
+/// Media type for common attachment file extensions.
+#[must_use]
+pub fn guess_media_type(name: &str) -> Option<&'static str> {
+    let extension = Path::new(name).extension()?.to_str()?.to_ascii_lowercase();
+    let media_type = match extension.as_str() {
+        "txt" | "log" | "out" => "text/plain",
+        "md" | "markdown" => "text/markdown",
+        "patch" | "diff" => "text/x-diff",
+        "csv" => "text/csv",
+        "html" | "htm" => "text/html",
+        "json" | "jsonl" => "application/json",
+        "yaml" | "yml" => "application/yaml",
+        "xml" => "application/xml",
+        "pdf" => "application/pdf",
+        "zip" => "application/zip",
+        "gz" | "tgz" => "application/gzip",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "svg" => "image/svg+xml",
+        "webp" => "image/webp",
+        _ => return None,
+    };
+    Some(media_type)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write(dir: &TempDir, name: &str, content: &[u8]) -> PathBuf {
+        let path = dir.path().join(name);
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn store_is_content_addressed() {
+        let dir = TempDir::new().unwrap();
+        let store = BlobStore::new(&dir.path().join(".beads"));
+        let first = write(&dir, "a.log", b"hello");
+        let second = write(&dir, "b.log", b"hello");
+
+        let a = store.store(&first, false).unwrap();
+        let b = store.store(&second, false).unwrap();
+        assert_eq!(a, b);
+        assert_eq!(a.size, 5);
+        assert!(!a.local_only);
+        assert!(
+            store
+                .path_for(&a.sha256)
+                .ends_with(format!("2c/{}", a.sha256))
+        );
+        assert_eq!(fs::read(store.path_for(&a.sha256)).unwrap(), b"hello");
+        assert_eq!(store.list().unwrap().len(), 1);
+
+        store.remove(&a.sha256).unwrap();
+        assert!(!store.contains(&a.sha256));
+        assert!(store.list().unwrap().is_empty());
+    }
+
+    #[test]
+    fn size_policy_limits_and_ignores_large_blobs() {
+        let dir = TempDir::new().unwrap();
+        let mut store = BlobStore::new(&dir.path().join(".beads"));
+        store.max_size = 8;
+        store.git_max_size = 4;
+
+        let small = store.store(&write(&dir, "s", b"abc"), false).unwrap();
+        let large = store.store(&write(&dir, "l", b"abcdef"), false).unwrap();
+        assert!(!small.local_only);
+        assert!(large.local_only);
+        let gitignore = fs::read_to_string(store.dir().join(".gitignore")).unwrap();
+        assert!(gitignore.contains(&BlobStore::gitignore_entry(&large.sha256)));
+        assert!(!gitignore.contains(&small.sha256));
+
+        let err = store
+            .store(&write(&dir, "x", b"0123456789"), false)
+            .unwrap_err();
+        assert!(err.to_string().contains("attachments.max_size"), "{err}");
+
+        store.remove(&large.sha256).unwrap();
+        let gitignore = fs::read_to_string(store.dir().join(".gitignore")).unwrap();
+        assert!(!gitignore.contains(&large.sha256));
+    }
+
+    #[test]
+    fn parse_size_units() {
+        assert_eq!(parse_size("1048576", "k").unwrap(), 1_048_576);
+        assert_eq!(parse_size("512K", "k").unwrap(), 512 * 1024);
+        assert_eq!(parse_size("10 MB", "k").unwrap(), 10 * 1024 * 1024);
+        assert_eq!(parse_size("1GiB", "k").unwrap(), 1024 * 1024 * 1024);
+        assert!(parse_size("ten", "k").is_err());
+        assert!(parse_size("5XB", "k").is_err());
+    }
+
+    #[test]
+    fn media_type_by_extension() {
+        assert_eq!(guess_media_type("build.LOG"), Some("text/plain"));
+        assert_eq!(guess_media_type("fix.patch"), Some("text/x-diff"));
+        assert_eq!(guess_media_type("shot.png"), Some("image/png"));
+        assert_eq!(guess_media_type("README"), None);
+    }
+}
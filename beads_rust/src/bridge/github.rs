@@ -0,0 +1,301 @@
+//! GitHub REST adapter (`/repos/{owner}/{name}/issues`).
+
+use super::http::{HttpRequest, Transport};
+use super::{BridgeConfig, Provider, RemoteComment, RemoteDraft, RemoteIssue, Tracker};
+use crate::error::Result;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::json;
+
+const PER_PAGE: usize = 100;
+
+#[derive(Debug, Deserialize)]
+struct GitHubIssue {
+    number: u64,
+    title: String,
+    body: Option<String>,
+    state: String,
+    #[serde(default)]
+    labels: Vec<GitHubLabel>,
+    #[serde(default)]
+    comments: u64,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    html_url: Option<String>,
+    /// Present when the item is a pull request.
+    pull_request: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubLabel {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubComment {
+    user: Option<GitHubUser>,
+    body: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubUser {
+    login: String,
+}
+
+impl From<GitHubIssue> for RemoteIssue {
+    fn from(issue: GitHubIssue) -> Self {
+        Self {
+            number: issue.number,
+            title: issue.title,
+            body: issue.body,
+            closed: issue.state == "closed",
+            labels: issue.labels.into_iter().map(|label| label.name).collect(),
+            comments: issue.comments,
+            created_at: issue.created_at,
+            updated_at: issue.updated_at,
+            url: issue.html_url,
+        }
+    }
+}
+
+/// Tracker backed by the GitHub REST API.
+pub struct GitHubTracker {
+    api_url: String,
+    repo: String,
+    token: Option<String>,
+    transport: Box<dyn Transport>,
+}
+
+impl GitHubTracker {
+    #[must_use]
+    pub fn new(config: &BridgeConfig, transport: Box<dyn Transport>) -> Self {
+        Self {
+            api_url: config.api_url.clone(),
+            repo: config.repo.clone(),
+            token: config.token(),
+            transport,
+        }
+    }
+
+    fn request(&self, method: &'static str, path: &str, body: Option<String>) -> HttpRequest {
+        let mut headers = vec![
+            (
+                "Accept".to_string(),
+                "application/vnd.github+json".to_string(),
+            ),
+            ("X-GitHub-Api-Version".to_string(), "2022-11-28".to_string()),
+            ("User-Agent".to_string(), "br-bridge".to_string()),
+        ];
+        if let Some(token) = &self.token {
+            headers.push(("Authorization".to_string(), format!("Bearer {token}")));
+        }
+        HttpRequest {
+            method,
+            url: format!("{}/repos/{}{path}", self.api_url, self.repo),
+            headers,
+            body,
+        }
+    }
+
+    fn send(&self, request: &HttpRequest) -> Result<super::http::HttpResponse> {
+        self.transport.send(request)?.require_success(request)
+    }
+}
+
+fn draft_body(draft: &RemoteDraft) -> serde_json::Value {
+    json!({
+        "title": draft.title,
+        "body": draft.body.clone().unwrap_or_default(),
+        "state": if draft.closed { "closed" } else { "open" },
+        "labels": draft.labels,
+    })
+}
+
+impl Tracker for GitHubTracker {
+    fn provider(&self) -> Provider {
+        Provider::GitHub
+    }
+
+    fn repo(&self) -> &str {
+        &self.repo
+    }
+
+    fn list_updated_since(&self, since: Option<DateTime<Utc>>) -> Result<Vec<RemoteIssue>> {
+        let mut issues = Vec::new();
+        for page in 1.. {
+            let mut path = format!(
+                "/issues?state=all&sort=updated&direction=asc&per_page={PER_PAGE}&page={page}"
+            );
+            if let Some(since) = since {
+                path.push_str(&format!("&since={}", since.format("%Y-%m-%dT%H:%M:%SZ")));
+            }
+            let request = self.request("GET", &path, None);
+            let batch: Vec<GitHubIssue> = self.send(&request)?.json()?;
+            let done = batch.len() < PER_PAGE;
+            issues.extend(
+                batch
+                    .into_iter()
+                    .filter(|issue| issue.pull_request.is_none())
+                    .map(RemoteIssue::from),
+            );
+            if done {
+                break;
+            }
+        }
+        Ok(issues)
+    }
+
+    fn get_issue(&self, number: u64) -> Result<RemoteIssue> {
+        let request = self.request("GET", &format!("/issues/{number}"), None);
+        Ok(self.send(&request)?.json::<GitHubIssue>()?.into())
+    }
+
+    fn create_issue(&self, draft: &RemoteDraft) -> Result<RemoteIssue> {
+        let body = json!({
+            "title": draft.title,
+            "body": draft.body.clone().unwrap_or_default(),
+            "labels": draft.labels,
+        });
+        let request = self.request("POST", "/issues", Some(body.to_string()));
+        let created: RemoteIssue = self.send(&request)?.json::<GitHubIssue>()?.into();
+        // Issues are always created open; close afterwards if needed.
+        if draft.closed {
+            return self.update_issue(created.number, draft);
+        }
+        Ok(created)
+    }
+
+    fn update_issue(&self, number: u64, draft: &RemoteDraft) -> Result<RemoteIssue> {
+        let request = self.request(
+            "PATCH",
+            &format!("/issues/{number}"),
+            Some(draft_body(draft).to_string()),
+        );
+        Ok(self.send(&request)?.json::<GitHubIssue>()?.into())
+    }
+
+    fn list_comments(&self, number: u64) -> Result<Vec<RemoteComment>> {
+        let mut comments = Vec::new();
+        for page in 1.. {
+            let path = format!("/issues/{number}/comments?per_page={PER_PAGE}&page={page}");
+            let request = self.request("GET", &path, None);
+            let batch: Vec<GitHubComment> = self.send(&request)?.json()?;
+            let done = batch.len() < PER_PAGE;
+            comments.extend(batch.into_iter().map(|comment| RemoteComment {
+                author: comment.user.map(|user| user.login).unwrap_or_default(),
+                body: comment.body.unwrap_or_default(),
+            }));
+            if done {
+                break;
+            }
+        }
+        Ok(comments)
+    }
+
+    fn add_comment(&self, number: u64, body: &str) -> Result<()> {
+        let request = self.request(
+            "POST",
+            &format!("/issues/{number}/comments"),
+            Some(json!({ "body": body }).to_string()),
+        );
+        self.send(&request)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bridge::http::HttpResponse;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// Transport replaying canned responses and recording requests.
+    struct Replay {
+        responses: RefCell<Vec<HttpResponse>>,
+        sent: Rc<RefCell<Vec<HttpRequest>>>,
+    }
+
+    impl Transport for Replay {
+        fn send(&self, request: &HttpRequest) -> Result<HttpResponse> {
+            self.sent.borrow_mut().push(request.clone());
+            Ok(self.responses.borrow_mut().remove(0))
+        }
+    }
+
+    fn tracker(bodies: &[&str]) -> (GitHubTracker, Rc<RefCell<Vec<HttpRequest>>>) {
+        let sent = Rc::new(RefCell::new(Vec::new()));
+        let transport = Replay {
+            responses: RefCell::new(
+                bodies
+                    .iter()
+                    .map(|body| HttpResponse {
+                        status: 200,
+                        body: (*body).to_string(),
+                    })
+                    .collect(),
+            ),
+            sent: Rc::clone(&sent),
+        };
+        let config = BridgeConfig {
+            provider: Provider::GitHub,
+            repo: "acme/app".to_string(),
+            api_url: "http://127.0.0.1:9".to_string(),
+            token_env: "BR_TEST_UNSET_TOKEN".to_string(),
+        };
+        (GitHubTracker::new(&config, Box::new(transport)), sent)
+    }
+
+    #[test]
+    fn test_list_skips_pull_requests_and_passes_since() {
+        let page = r#"[
+            {"number": 1, "title": "Bug", "body": null, "state": "closed",
+             "labels": [{"name": "bug"}], "comments": 2,
+             "created_at": "2026-10-01T10:00:00Z", "updated_at": "2026-10-02T10:00:00Z"},
+            {"number": 2, "title": "PR", "body": "", "state": "open",
+             "created_at": "2026-10-01T10:00:00Z", "updated_at": "2026-10-02T11:00:00Z",
+             "pull_request": {"url": "x"}}
+        ]"#;
+        let (tracker, sent) = tracker(&[page]);
+        let since = DateTime::parse_from_rfc3339("2026-10-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let issues = tracker.list_updated_since(Some(since)).unwrap();
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].closed);
+        assert_eq!(issues[0].labels, ["bug"]);
+        assert_eq!(issues[0].comments, 2);
+        let url = &sent.borrow()[0].url;
+        assert!(
+            url.starts_with("http://127.0.0.1:9/repos/acme/app/issues?"),
+            "{url}"
+        );
+        assert!(url.ends_with("&since=2026-10-01T00:00:00Z"), "{url}");
+    }
+
+    #[test]
+    fn test_create_closed_issue_patches_state() {
+        let created = r#"{"number": 5, "title": "T", "body": "B", "state": "open",
+            "created_at": "2026-10-01T10:00:00Z", "updated_at": "2026-10-01T10:00:00Z"}"#;
+        let closed = created.replace("\"open\"", "\"closed\"");
+        let (tracker, sent) = tracker(&[created, &closed]);
+        let draft = RemoteDraft {
+            title: "T".to_string(),
+            body: Some("B".to_string()),
+            closed: true,
+            labels: vec![],
+        };
+        let issue = tracker.create_issue(&draft).unwrap();
+        assert!(issue.closed);
+        let sent = sent.borrow();
+        assert_eq!(sent[0].method, "POST");
+        assert_eq!(sent[1].method, "PATCH");
+        assert!(
+            sent[1]
+                .body
+                .as_deref()
+                .unwrap()
+                .contains("\"state\":\"closed\"")
+        );
+    }
+}
@@ -0,0 +1,242 @@
+//! GitLab REST adapter (`/projects/{id}/issues`, keyed by issue `iid`).
+
+use super::http::{HttpRequest, HttpResponse, Transport};
+use super::{BridgeConfig, Provider, RemoteComment, RemoteDraft, RemoteIssue, Tracker};
+use crate::error::Result;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::json;
+
+const PER_PAGE: usize = 100;
+
+#[derive(Debug, Deserialize)]
+struct GitLabIssue {
+    iid: u64,
+    title: String,
+    description: Option<String>,
+    state: String,
+    #[serde(default)]
+    labels: Vec<String>,
+    #[serde(default)]
+    user_notes_count: u64,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    web_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabNote {
+    author: Option<GitLabUser>,
+    body: Option<String>,
+    /// System notes record events ("changed the description"), not comments.
+    #[serde(default)]
+    system: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabUser {
+    username: String,
+}
+
+impl From<GitLabIssue> for RemoteIssue {
+    fn from(issue: GitLabIssue) -> Self {
+        Self {
+            number: issue.iid,
+            title: issue.title,
+            body: issue.description,
+            closed: issue.state == "closed",
+            labels: issue.labels,
+            comments: issue.user_notes_count,
+            created_at: issue.created_at,
+            updated_at: issue.updated_at,
+            url: issue.web_url,
+        }
+    }
+}
+
+/// Tracker backed by the GitLab REST API (v4).
+pub struct GitLabTracker {
+    api_url: String,
+    repo: String,
+    token: Option<String>,
+    transport: Box<dyn Transport>,
+}
+
+impl GitLabTracker {
+    #[must_use]
+    pub fn new(config: &BridgeConfig, transport: Box<dyn Transport>) -> Self {
+        Self {
+            api_url: config.api_url.clone(),
+            repo: config.repo.clone(),
+            token: config.token(),
+            transport,
+        }
+    }
+
+    fn request(&self, method: &'static str, path: &str, body: Option<String>) -> HttpRequest {
+        let mut headers = Vec::new();
+        if let Some(token) = &self.token {
+            headers.push(("PRIVATE-TOKEN".to_string(), token.clone()));
+        }
+        HttpRequest {
+            method,
+            url: format!(
+                "{}/projects/{}{path}",
+                self.api_url,
+                self.repo.replace('/', "%2F")
+            ),
+            headers,
+            body,
+        }
+    }
+
+    fn send(&self, request: &HttpRequest) -> Result<HttpResponse> {
+        self.transport.send(request)?.require_success(request)
+    }
+}
+
+impl Tracker for GitLabTracker {
+    fn provider(&self) -> Provider {
+        Provider::GitLab
+    }
+
+    fn repo(&self) -> &str {
+        &self.repo
+    }
+
+    fn list_updated_since(&self, since: Option<DateTime<Utc>>) -> Result<Vec<RemoteIssue>> {
+        let mut issues = Vec::new();
+        for page in 1.. {
+            let mut path = format!(
+                "/issues?scope=all&order_by=updated_at&sort=asc&per_page={PER_PAGE}&page={page}"
+            );
+            if let Some(since) = since {
+                path.push_str(&format!(
+                    "&updated_after={}",
+                    since.format("%Y-%m-%dT%H:%M:%SZ")
+                ));
+            }
+            let request = self.request("GET", &path, None);
+            let batch: Vec<GitLabIssue> = self.send(&request)?.json()?;
+            let done = batch.len() < PER_PAGE;
+            issues.extend(batch.into_iter().map(RemoteIssue::from));
+            if done {
+                break;
+            }
+        }
+        Ok(issues)
+    }
+
+    fn get_issue(&self, number: u64) -> Result<RemoteIssue> {
+        let request = self.request("GET", &format!("/issues/{number}"), None);
+        Ok(self.send(&request)?.json::<GitLabIssue>()?.into())
+    }
+
+    fn create_issue(&self, draft: &RemoteDraft) -> Result<RemoteIssue> {
+        let body = json!({
+            "title": draft.title,
+            "description": draft.body.clone().unwrap_or_default(),
+            "labels": draft.labels.join(","),
+        });
+        let request = self.request("POST", "/issues", Some(body.to_string()));
+        let created: RemoteIssue = self.send(&request)?.json::<GitLabIssue>()?.into();
+        if draft.closed {
+            return self.update_issue(created.number, draft);
+        }
+        Ok(created)
+    }
+
+    fn update_issue(&self, number: u64, draft: &RemoteDraft) -> Result<RemoteIssue> {
+        let body = json!({
+            "title": draft.title,
+            "description": draft.body.clone().unwrap_or_default(),
+            "labels": draft.labels.join(","),
+            "state_event": if draft.closed { "close" } else { "reopen" },
+        });
+        let request = self.request("PUT", &format!("/issues/{number}"), Some(body.to_string()));
+        Ok(self.send(&request)?.json::<GitLabIssue>()?.into())
+    }
+
+    fn list_comments(&self, number: u64) -> Result<Vec<RemoteComment>> {
+        let mut comments = Vec::new();
+        for page in 1.. {
+            let path = format!("/issues/{number}/notes?sort=asc&per_page={PER_PAGE}&page={page}");
+            let request = self.request("GET", &path, None);
+            let batch: Vec<GitLabNote> = self.send(&request)?.json()?;
+            let done = batch.len() < PER_PAGE;
+            comments.extend(batch.into_iter().filter(|note| !note.system).map(|note| {
+                RemoteComment {
+                    author: note.author.map(|user| user.username).unwrap_or_default(),
+                    body: note.body.unwrap_or_default(),
+                }
+            }));
+            if done {
+                break;
+            }
+        }
+        Ok(comments)
+    }
+
+    fn add_comment(&self, number: u64, body: &str) -> Result<()> {
+        let request = self.request(
+            "POST",
+            &format!("/issues/{number}/notes"),
+            Some(json!({ "body": body }).to_string()),
+        );
+        self.send(&request)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    struct Replay {
+        body: String,
+        sent: Rc<RefCell<Vec<HttpRequest>>>,
+    }
+
+    impl Transport for Replay {
+        fn send(&self, request: &HttpRequest) -> Result<HttpResponse> {
+            self.sent.borrow_mut().push(request.clone());
+            Ok(HttpResponse {
+                status: 200,
+                body: self.body.clone(),
+            })
+        }
+    }
+
+    #[test]
+    fn test_notes_skip_system_and_project_path_is_encoded() {
+        let sent = Rc::new(RefCell::new(Vec::new()));
+        let transport = Replay {
+            body: r#"[
+                {"author": {"username": "dev"}, "body": "Looks good", "system": false},
+                {"author": {"username": "dev"}, "body": "changed the description", "system": true}
+            ]"#
+            .to_string(),
+            sent: Rc::clone(&sent),
+        };
+        let config = BridgeConfig {
+            provider: Provider::GitLab,
+            repo: "group/sub/project".to_string(),
+            api_url: "https://gitlab.example.com/api/v4".to_string(),
+            token_env: "BR_TEST_UNSET_TOKEN".to_string(),
+        };
+        let tracker = GitLabTracker::new(&config, Box::new(transport));
+        let comments = tracker.list_comments(4).unwrap();
+        assert_eq!(
+            comments,
+            [RemoteComment {
+                author: "dev".to_string(),
+                body: "Looks good".to_string(),
+            }]
+        );
+        assert!(sent.borrow()[0].url.starts_with(
+            "https://gitlab.example.com/api/v4/projects/group%2Fsub%2Fproject/issues/4/notes?"
+        ));
+    }
+}
@@ -0,0 +1,193 @@
+//! Minimal HTTP transport for tracker adapters.
+//!
+//! Adapters build [`HttpRequest`]s and hand them to a [`Transport`]. The
+//! default [`CurlTransport`] runs the system `curl` and passes the whole
+//! request (including the auth header) on stdin, so tokens never appear in
+//! the process list. Tests substitute their own transport or point the API
+//! URL at a local mock server.
+
+use crate::error::{BeadsError, Result};
+use serde::de::DeserializeOwned;
+use std::fmt::Write as _;
+use std::io::{ErrorKind, Write};
+use std::process::{Command, Stdio};
+
+/// Seconds before an API request is abandoned.
+const REQUEST_TIMEOUT_SECS: u32 = 60;
+
+/// An HTTP request against a tracker API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpRequest {
+    pub method: &'static str,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    /// JSON body, if any.
+    pub body: Option<String>,
+}
+
+/// Status code and body of an HTTP response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+impl HttpResponse {
+    /// Fail unless the status is 2xx, including the start of the body.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for non-success statuses.
+    pub fn require_success(self, request: &HttpRequest) -> Result<Self> {
+        if (200..300).contains(&self.status) {
+            return Ok(self);
+        }
+        let detail: String = self.body.chars().take(200).collect();
+        Err(BeadsError::Config(format!(
+            "{} {} failed with HTTP {}: {}",
+            request.method,
+            request.url,
+            self.status,
+            detail.trim()
+        )))
+    }
+
+    /// Parse the body as JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the body does not match `T`.
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T> {
+        Ok(serde_json::from_str(&self.body)?)
+    }
+}
+
+/// Sends HTTP requests; implemented by curl in production and by fakes in tests.
+pub trait Transport {
+    /// Send a request and return the response, whatever its status.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request could not be sent at all.
+    fn send(&self, request: &HttpRequest) -> Result<HttpResponse>;
+}
+
+/// Transport backed by the system `curl` binary.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CurlTransport;
+
+impl Transport for CurlTransport {
+    fn send(&self, request: &HttpRequest) -> Result<HttpResponse> {
+        let config = curl_config(request);
+        let mut child = Command::new("curl")
+            .args(["-q", "--config", "-"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|err| {
+                if err.kind() == ErrorKind::NotFound {
+                    BeadsError::Config("br bridge requires `curl` on PATH".to_string())
+                } else {
+                    BeadsError::Io(err)
+                }
+            })?;
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(config.as_bytes())?;
+        }
+        let output = child.wait_with_output()?;
+        if !output.status.success() {
+            return Err(BeadsError::Config(format!(
+                "{} {} failed: {}",
+                request.method,
+                request.url,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        parse_curl_output(&String::from_utf8_lossy(&output.stdout))
+    }
+}
+
+/// Render a request as a curl config file (read from stdin with `--config -`;
+/// `-q` keeps a user `.curlrc` out of the request).
+fn curl_config(request: &HttpRequest) -> String {
+    let mut config = String::new();
+    let _ = writeln!(config, "url = {}", quote(&request.url));
+    let _ = writeln!(config, "request = {}", quote(request.method));
+    for (name, value) in &request.headers {
+        let _ = writeln!(config, "header = {}", quote(&format!("{name}: {value}")));
+    }
+    if let Some(body) = &request.body {
+        let _ = writeln!(config, "header = \"Content-Type: application/json\"");
+        let _ = writeln!(config, "data-binary = {}", quote(body));
+    }
+    let _ = writeln!(config, "max-time = {REQUEST_TIMEOUT_SECS}");
+    let _ = writeln!(config, "silent");
+    let _ = writeln!(config, "show-error");
+    let _ = writeln!(config, "write-out = \"\\n%{{http_code}}\"");
+    config
+}
+
+/// Quote a value for a curl config file.
+fn quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for ch in value.chars() {
+        match ch {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            _ => quoted.push(ch),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Split curl's stdout into the body and the trailing status code.
+fn parse_curl_output(stdout: &str) -> Result<HttpResponse> {
+    let (body, code) = stdout.rsplit_once('\n').unwrap_or(("", stdout));
+    let status = code.trim().parse::<u16>().map_err(|_| {
+        BeadsError::Config(format!("Unexpected curl output (status '{}')", code.trim()))
+    })?;
+    Ok(HttpResponse {
+        status,
+        body: body.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_curl_config_quotes_body_and_headers() {
+        let request = HttpRequest {
+            method: "PATCH",
+            url: "https://api.example.com/issues/1".to_string(),
+            headers: vec![("Authorization".to_string(), "Bearer t0k\"en".to_string())],
+            body: Some("{\"body\":\"line one\\nline \\\"two\\\"\"}\n".to_string()),
+        };
+        let config = curl_config(&request);
+        assert!(config.contains("request = \"PATCH\"\n"));
+        assert!(config.contains("header = \"Authorization: Bearer t0k\\\"en\"\n"));
+        assert!(
+            config.contains(r#"data-binary = "{\"body\":\"line one\\nline \\\"two\\\"\"}\n""#),
+            "{config}"
+        );
+    }
+
+    #[test]
+    fn test_parse_curl_output_splits_status() {
+        let response = parse_curl_output("{\"a\":1}\n201").unwrap();
+        assert_eq!(response.status, 201);
+        assert_eq!(response.body, "{\"a\":1}");
+
+        let empty = parse_curl_output("\n204").unwrap();
+        assert_eq!(empty.status, 204);
+        assert!(empty.body.is_empty());
+        assert!(parse_curl_output("garbage").is_err());
+    }
+}
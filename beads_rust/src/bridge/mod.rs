@@ -0,0 +1,1067 @@
+//! Bidirectional sync with an external issue tracker (GitHub or GitLab).
+//!
+//! A beads issue is linked to a tracker issue through `external_ref`
+//! (`gh-<number>` or `gl-<iid>`). Each `br bridge sync` run:
+//!
+//! 1. pulls tracker issues updated since the stored cursor, creating local
+//!    issues for unknown ones;
+//! 2. projects both sides of every linked issue onto the shared fields
+//!    (title, description, open/closed, labels, comment bodies);
+//! 3. merges the projections against the last synced snapshot with
+//!    [`three_way_merge`], so edits to different fields combine and a field
+//!    changed on both sides goes to the newer side;
+//! 4. applies the merged result locally and pushes it to the tracker.
+//!
+//! The snapshot lives in `.beads/bridge.base.jsonl` and the cursor in the
+//! database metadata; both are per clone. Tracker access goes through the
+//! [`Tracker`] trait, whose REST adapters send requests via a pluggable
+//! [`http::Transport`].
+
+pub mod github;
+pub mod gitlab;
+pub mod http;
+
+use crate::config::ConfigLayer;
+use crate::error::{BeadsError, Result};
+use crate::model::{Comment, Issue, Status};
+use crate::storage::{IssueUpdate, SqliteStorage};
+use crate::sync::{
+    ConflictResolution, FieldConflict, MergeContext, read_issues_from_jsonl, three_way_merge,
+    write_issues_jsonl,
+};
+use crate::util::id::compute_id_hash;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Snapshot of the last synced state of every linked issue.
+pub const BRIDGE_BASE_FILE: &str = "bridge.base.jsonl";
+
+/// Metadata key: newest tracker `updated_at` seen by a sync.
+pub const METADATA_BRIDGE_CURSOR: &str = "bridge_cursor";
+
+/// Metadata key: when the last sync finished.
+pub const METADATA_BRIDGE_LAST_SYNC: &str = "bridge_last_sync";
+
+/// Hash length of IDs for issues pulled from the tracker.
+const PULLED_HASH_LENGTH: usize = 8;
+
+/// Supported trackers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Provider {
+    GitHub,
+    GitLab,
+}
+
+impl Provider {
+    /// Parse a provider name from config.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for unknown providers.
+    pub fn parse(name: &str) -> Result<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "github" | "gh" => Ok(Self::GitHub),
+            "gitlab" | "gl" => Ok(Self::GitLab),
+            other => Err(BeadsError::Config(format!(
+                "Unknown bridge.provider '{other}' (expected github or gitlab)"
+            ))),
+        }
+    }
+
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::GitHub => "github",
+            Self::GitLab => "gitlab",
+        }
+    }
+
+    /// Prefix of `external_ref` values for this tracker.
+    #[must_use]
+    pub const fn ref_prefix(self) -> &'static str {
+        match self {
+            Self::GitHub => "gh",
+            Self::GitLab => "gl",
+        }
+    }
+
+    const fn default_api_url(self) -> &'static str {
+        match self {
+            Self::GitHub => "https://api.github.com",
+            Self::GitLab => "https://gitlab.com/api/v4",
+        }
+    }
+
+    const fn default_token_env(self) -> &'static str {
+        match self {
+            Self::GitHub => "GITHUB_TOKEN",
+            Self::GitLab => "GITLAB_TOKEN",
+        }
+    }
+
+    /// `external_ref` for a tracker issue number, e.g. `gh-42`.
+    #[must_use]
+    pub fn external_ref(self, number: u64) -> String {
+        format!("{}-{number}", self.ref_prefix())
+    }
+
+    /// Tracker issue number of an `external_ref` belonging to this tracker.
+    #[must_use]
+    pub fn parse_ref(self, external_ref: &str) -> Option<u64> {
+        external_ref
+            .strip_prefix(self.ref_prefix())?
+            .strip_prefix('-')?
+            .parse()
+            .ok()
+    }
+}
+
+impl std::fmt::Display for Provider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Bridge settings from the `bridge.*` config keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BridgeConfig {
+    pub provider: Provider,
+    /// `owner/name` on GitHub, `group/project` (or numeric ID) on GitLab.
+    pub repo: String,
+    pub api_url: String,
+    /// Environment variable holding the API token.
+    pub token_env: String,
+}
+
+impl BridgeConfig {
+    /// Read `bridge.provider`, `bridge.repo`, `bridge.api-url` and
+    /// `bridge.token-env`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the provider or repository is missing or invalid.
+    pub fn from_layer(layer: &ConfigLayer) -> Result<Self> {
+        let value = |names: &[&str]| {
+            names
+                .iter()
+                .find_map(|name| layer.runtime.get(*name))
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+        let provider = value(&["bridge.provider"]).ok_or_else(|| {
+            BeadsError::Config("No bridge configured: set bridge.provider and bridge.repo".into())
+        })?;
+        let provider = Provider::parse(&provider)?;
+        let repo = value(&["bridge.repo"])
+            .ok_or_else(|| BeadsError::Config("bridge.repo is not set".to_string()))?;
+        let api_url = value(&["bridge.api-url", "bridge.api_url"])
+            .unwrap_or_else(|| provider.default_api_url().to_string());
+        let token_env = value(&["bridge.token-env", "bridge.token_env"])
+            .unwrap_or_else(|| provider.default_token_env().to_string());
+        Ok(Self {
+            provider,
+            repo,
+            api_url: api_url.trim_end_matches('/').to_string(),
+            token_env,
+        })
+    }
+
+    /// API token from the configured environment variable, if set.
+    #[must_use]
+    pub fn token(&self) -> Option<String> {
+        std::env::var(&self.token_env)
+            .ok()
+            .filter(|token| !token.trim().is_empty())
+    }
+}
+
+/// Build the REST adapter for a configured tracker.
+#[must_use]
+pub fn connect(config: &BridgeConfig, transport: Box<dyn http::Transport>) -> Box<dyn Tracker> {
+    match config.provider {
+        Provider::GitHub => Box::new(github::GitHubTracker::new(config, transport)),
+        Provider::GitLab => Box::new(gitlab::GitLabTracker::new(config, transport)),
+    }
+}
+
+/// An issue as the tracker reports it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteIssue {
+    pub number: u64,
+    pub title: String,
+    pub body: Option<String>,
+    pub closed: bool,
+    pub labels: Vec<String>,
+    /// Number of user comments, used to skip fetching empty threads.
+    pub comments: u64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub url: Option<String>,
+}
+
+/// A user comment on a tracker issue.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteComment {
+    pub author: String,
+    pub body: String,
+}
+
+/// The shared fields written to the tracker.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteDraft {
+    pub title: String,
+    pub body: Option<String>,
+    pub closed: bool,
+    pub labels: Vec<String>,
+}
+
+/// Operations the bridge needs from an external tracker.
+pub trait Tracker {
+    fn provider(&self) -> Provider;
+
+    /// Repository or project the tracker is bound to.
+    fn repo(&self) -> &str;
+
+    /// Issues (not pull or merge requests) updated at or after `since`;
+    /// all issues when `since` is `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    fn list_updated_since(&self, since: Option<DateTime<Utc>>) -> Result<Vec<RemoteIssue>>;
+
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the issue does not exist.
+    fn get_issue(&self, number: u64) -> Result<RemoteIssue>;
+
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    fn create_issue(&self, draft: &RemoteDraft) -> Result<RemoteIssue>;
+
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    fn update_issue(&self, number: u64, draft: &RemoteDraft) -> Result<RemoteIssue>;
+
+    /// User comments, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    fn list_comments(&self, number: u64) -> Result<Vec<RemoteComment>>;
+
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    fn add_comment(&self, number: u64, body: &str) -> Result<()>;
+}
+
+/// Inputs shared by the bridge operations.
+#[derive(Debug, Clone)]
+pub struct BridgeOptions {
+    pub beads_dir: PathBuf,
+    pub actor: String,
+    /// Issue ID prefix for issues pulled from the tracker.
+    pub prefix: String,
+    pub dry_run: bool,
+    pub now: DateTime<Utc>,
+}
+
+/// A linked issue touched by a bridge operation.
+#[derive(Debug, Clone, Serialize)]
+pub struct BridgeChange {
+    pub id: String,
+    pub external_ref: String,
+    pub title: String,
+}
+
+/// Outcome of `br bridge sync`.
+#[derive(Debug, Default, Serialize)]
+pub struct BridgeReport {
+    /// Tracker issues imported as new local issues.
+    pub pulled: Vec<BridgeChange>,
+    /// Linked issues changed locally from tracker edits.
+    pub updated_local: Vec<BridgeChange>,
+    /// Linked issues changed on the tracker from local edits.
+    pub updated_remote: Vec<BridgeChange>,
+    pub comments_pulled: usize,
+    pub comments_pushed: usize,
+    /// Fields edited on both sides; `local` is beads, `external` the tracker.
+    pub field_conflicts: Vec<FieldConflict>,
+    pub cursor: Option<DateTime<Utc>>,
+    pub dry_run: bool,
+}
+
+/// Sync every linked issue with the tracker and pull new tracker issues.
+///
+/// # Errors
+///
+/// Returns an error if a tracker request fails or the database or snapshot
+/// cannot be read or written. Changes applied before the failure are kept;
+/// the cursor only advances on success, so the next run retries.
+#[allow(clippy::too_many_lines)]
+pub fn sync(
+    storage: &mut SqliteStorage,
+    tracker: &dyn Tracker,
+    options: &BridgeOptions,
+) -> Result<BridgeReport> {
+    let provider = tracker.provider();
+    let cursor = read_timestamp(storage, METADATA_BRIDGE_CURSOR)?;
+    let last_sync = read_timestamp(storage, METADATA_BRIDGE_LAST_SYNC)?;
+    let base_path = options.beads_dir.join(BRIDGE_BASE_FILE);
+    let mut base = load_base(&base_path)?;
+    let linked = linked_issues(storage, provider)?;
+
+    let mut report = BridgeReport {
+        dry_run: options.dry_run,
+        cursor,
+        ..BridgeReport::default()
+    };
+    let mut left = HashMap::new();
+    let mut right = HashMap::new();
+    let mut numbers = HashMap::new();
+    let mut remote_authors: HashMap<String, String> = HashMap::new();
+
+    // 1. Pull tracker issues changed since the cursor.
+    for remote in tracker.list_updated_since(cursor)? {
+        report.cursor = report.cursor.max(Some(remote.updated_at));
+        let external_ref = provider.external_ref(remote.number);
+        let comments = fetch_comments(tracker, &remote)?;
+        for comment in &comments {
+            remote_authors
+                .entry(normalize_text(&comment.body))
+                .or_insert_with(|| comment.author.clone());
+        }
+
+        if let Some(local) = linked.get(&external_ref) {
+            numbers.insert(local.id.clone(), remote.number);
+            right.insert(
+                local.id.clone(),
+                remote_projection(&local.id, &remote, &comments),
+            );
+            continue;
+        }
+
+        let issue = issue_from_remote(tracker, &remote, options);
+        if !options.dry_run {
+            storage.create_issue(&issue, &options.actor)?;
+            for comment in &comments {
+                storage.add_comment(&issue.id, &comment.author, &comment.body)?;
+            }
+        }
+        report.comments_pulled += comments.len();
+        base.insert(
+            issue.id.clone(),
+            remote_projection(&issue.id, &remote, &comments),
+        );
+        report.pulled.push(BridgeChange {
+            id: issue.id,
+            external_ref,
+            title: issue.title,
+        });
+    }
+
+    // 2. Project local issues; unchanged issues outside the pull are skipped.
+    for (external_ref, local) in &linked {
+        let Some(number) = provider.parse_ref(external_ref) else {
+            continue;
+        };
+        if !right.contains_key(&local.id) {
+            let changed_locally = last_sync.is_none_or(|at| local.updated_at > at);
+            match base.get(&local.id) {
+                Some(_) if !changed_locally => continue,
+                // Not in the pull, so the tracker side is unchanged since the snapshot.
+                Some(snapshot) => {
+                    right.insert(local.id.clone(), snapshot.clone());
+                }
+                None => {
+                    let remote = tracker.get_issue(number)?;
+                    let comments = fetch_comments(tracker, &remote)?;
+                    report.cursor = report.cursor.max(Some(remote.updated_at));
+                    right.insert(
+                        local.id.clone(),
+                        remote_projection(&local.id, &remote, &comments),
+                    );
+                }
+            }
+        }
+        numbers.insert(local.id.clone(), number);
+        left.insert(local.id.clone(), local_projection(storage, local)?);
+    }
+
+    // 3. Merge each linked issue against its snapshot.
+    let snapshot: HashMap<String, Issue> = left
+        .keys()
+        .filter_map(|id| base.get(id).map(|issue| (id.clone(), issue.clone())))
+        .collect();
+    let context = MergeContext::new(snapshot, left.clone(), right.clone());
+    let merge = three_way_merge(&context, ConflictResolution::PreferNewer, None);
+    report.field_conflicts = merge.field_conflicts;
+
+    // 4. Apply the merged state to whichever side differs.
+    let mut kept = merge.kept;
+    kept.sort_by(|a, b| a.id.cmp(&b.id));
+    for merged in kept {
+        let (Some(local), Some(remote), Some(&number)) = (
+            left.get(&merged.id),
+            right.get(&merged.id),
+            numbers.get(&merged.id),
+        ) else {
+            continue;
+        };
+        let change = BridgeChange {
+            id: merged.id.clone(),
+            external_ref: provider.external_ref(number),
+            title: merged.title.clone(),
+        };
+
+        if !same_fields(&merged, local) || !new_comments(&merged, local).is_empty() {
+            report.comments_pulled += new_comments(&merged, local).len();
+            if !options.dry_run {
+                apply_local(storage, &merged, local, &remote_authors, options, provider)?;
+            }
+            report.updated_local.push(change.clone());
+        }
+        if !same_fields(&merged, remote) || !new_comments(&merged, remote).is_empty() {
+            let comments = new_comments(&merged, remote);
+            report.comments_pushed += comments.len();
+            if !options.dry_run {
+                if !same_fields(&merged, remote) {
+                    let updated = tracker.update_issue(number, &draft_from(&merged))?;
+                    report.cursor = report.cursor.max(Some(updated.updated_at));
+                }
+                for body in comments {
+                    tracker.add_comment(number, &body)?;
+                }
+            }
+            report.updated_remote.push(change);
+        }
+        base.insert(merged.id.clone(), merged);
+    }
+
+    if !options.dry_run {
+        save_base(&base_path, &base)?;
+        if let Some(at) = report.cursor {
+            storage.set_metadata(METADATA_BRIDGE_CURSOR, &at.to_rfc3339())?;
+        }
+        storage.set_metadata(METADATA_BRIDGE_LAST_SYNC, &options.now.to_rfc3339())?;
+    }
+    Ok(report)
+}
+
+/// Link a local issue to a tracker issue, creating one when `number` is `None`.
+///
+/// A newly created tracker issue starts from the local fields; comments follow
+/// on the next sync. Linking an existing tracker issue leaves reconciling the
+/// two to the next sync, where the newer side wins.
+///
+/// # Errors
+///
+/// Returns an error if the issue does not exist, either side is already
+/// linked, or the tracker request fails.
+pub fn link(
+    storage: &mut SqliteStorage,
+    tracker: &dyn Tracker,
+    options: &BridgeOptions,
+    id: &str,
+    number: Option<u64>,
+) -> Result<BridgeChange> {
+    let provider = tracker.provider();
+    let issue = storage
+        .get_issue(id)?
+        .ok_or_else(|| BeadsError::IssueNotFound { id: id.to_string() })?;
+    if let Some(existing) = issue.external_ref.as_deref() {
+        return Err(BeadsError::Config(format!(
+            "{id} is already linked to {existing}"
+        )));
+    }
+    if let Some(number) = number {
+        let external_ref = provider.external_ref(number);
+        if let Some(other) = storage.find_by_external_ref(&external_ref)? {
+            return Err(BeadsError::Config(format!(
+                "{external_ref} is already linked to {}",
+                other.id
+            )));
+        }
+    }
+
+    let remote = if let Some(number) = number {
+        tracker.get_issue(number)?
+    } else {
+        let mut projection = local_projection(storage, &issue)?;
+        projection.comments.clear();
+        if options.dry_run {
+            return Ok(BridgeChange {
+                id: id.to_string(),
+                external_ref: format!("{}-?", provider.ref_prefix()),
+                title: issue.title,
+            });
+        }
+        tracker.create_issue(&draft_from(&projection))?
+    };
+    let external_ref = provider.external_ref(remote.number);
+    if !options.dry_run {
+        let update = IssueUpdate {
+            external_ref: Some(Some(external_ref.clone())),
+            ..IssueUpdate::default()
+        };
+        storage.update_issue(id, &update, &options.actor)?;
+        if number.is_none() {
+            let base_path = options.beads_dir.join(BRIDGE_BASE_FILE);
+            let mut base = load_base(&base_path)?;
+            base.insert(id.to_string(), remote_projection(id, &remote, &[]));
+            save_base(&base_path, &base)?;
+        }
+    }
+    Ok(BridgeChange {
+        id: id.to_string(),
+        external_ref,
+        title: issue.title,
+    })
+}
+
+/// Remove the link between a local issue and its tracker issue.
+///
+/// # Errors
+///
+/// Returns an error if the issue does not exist or the snapshot cannot be
+/// rewritten.
+pub fn unlink(
+    storage: &mut SqliteStorage,
+    options: &BridgeOptions,
+    id: &str,
+) -> Result<Option<String>> {
+    let issue = storage
+        .get_issue(id)?
+        .ok_or_else(|| BeadsError::IssueNotFound { id: id.to_string() })?;
+    let Some(external_ref) = issue.external_ref else {
+        return Ok(None);
+    };
+    if !options.dry_run {
+        let update = IssueUpdate {
+            external_ref: Some(None),
+            ..IssueUpdate::default()
+        };
+        storage.update_issue(id, &update, &options.actor)?;
+        let base_path = options.beads_dir.join(BRIDGE_BASE_FILE);
+        let mut base = load_base(&base_path)?;
+        if base.remove(id).is_some() {
+            save_base(&base_path, &base)?;
+        }
+    }
+    Ok(Some(external_ref))
+}
+
+/// Local issues linked to this tracker, keyed by `external_ref`.
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+pub fn linked_issues(
+    storage: &SqliteStorage,
+    provider: Provider,
+) -> Result<HashMap<String, Issue>> {
+    Ok(storage
+        .get_all_issues_for_export()?
+        .into_iter()
+        .filter(|issue| issue.status != Status::Tombstone)
+        .filter_map(|issue| {
+            let external_ref = issue.external_ref.clone()?;
+            provider.parse_ref(&external_ref)?;
+            Some((external_ref, issue))
+        })
+        .collect())
+}
+
+/// Read a timestamp stored in the metadata table.
+///
+/// # Errors
+///
+/// Returns an error if the metadata lookup fails.
+pub fn read_timestamp(storage: &SqliteStorage, key: &str) -> Result<Option<DateTime<Utc>>> {
+    Ok(storage
+        .get_metadata(key)?
+        .and_then(|value| DateTime::parse_from_rfc3339(&value).ok())
+        .map(|at| at.with_timezone(&Utc)))
+}
+
+fn fetch_comments(tracker: &dyn Tracker, remote: &RemoteIssue) -> Result<Vec<RemoteComment>> {
+    if remote.comments == 0 {
+        return Ok(Vec::new());
+    }
+    tracker.list_comments(remote.number)
+}
+
+fn load_base(path: &Path) -> Result<HashMap<String, Issue>> {
+    if !path.exists() {
+        return Ok(HashMap::new());
+    }
+    Ok(read_issues_from_jsonl(path)?
+        .into_iter()
+        .map(|issue| (issue.id.clone(), issue))
+        .collect())
+}
+
+fn save_base(path: &Path, base: &HashMap<String, Issue>) -> Result<()> {
+    let mut issues: Vec<Issue> = base.values().cloned().collect();
+    issues.sort_by(|a, b| a.id.cmp(&b.id));
+    write_issues_jsonl(path, &issues)
+}
+
+/// Deterministic ID for a pulled tracker issue, so clones pulling the same
+/// tracker create the same local issue.
+fn pulled_id(tracker: &dyn Tracker, prefix: &str, number: u64) -> String {
+    let seed = format!("bridge|{}|{}|{number}", tracker.provider(), tracker.repo());
+    format!("{prefix}-{}", compute_id_hash(&seed, PULLED_HASH_LENGTH))
+}
+
+fn issue_from_remote(
+    tracker: &dyn Tracker,
+    remote: &RemoteIssue,
+    options: &BridgeOptions,
+) -> Issue {
+    let provider = tracker.provider();
+    let mut issue = Issue {
+        id: pulled_id(tracker, &options.prefix, remote.number),
+        title: remote.title.clone(),
+        description: remote.body.as_deref().and_then(normalize_body),
+        status: if remote.closed {
+            Status::Closed
+        } else {
+            Status::Open
+        },
+        closed_at: remote.closed.then_some(remote.updated_at),
+        created_at: remote.created_at,
+        created_by: Some(options.actor.clone()),
+        updated_at: options.now,
+        external_ref: Some(provider.external_ref(remote.number)),
+        source_system: Some(provider.as_str().to_string()),
+        labels: normalize_labels(remote.labels.clone()),
+        ..Issue::default()
+    };
+    issue.content_hash = Some(issue.compute_content_hash());
+    issue
+}
+
+/// The bridged fields of a local issue.
+fn local_projection(storage: &SqliteStorage, issue: &Issue) -> Result<Issue> {
+    let labels = storage.get_labels(&issue.id)?;
+    let comments = storage.get_comments(&issue.id)?;
+    Ok(projection(
+        &issue.id,
+        &issue.title,
+        issue.description.as_deref(),
+        issue.status == Status::Closed,
+        labels,
+        comments.iter().map(|comment| comment.body.as_str()),
+        issue.updated_at,
+    ))
+}
+
+/// The bridged fields of a tracker issue, under the local ID.
+fn remote_projection(id: &str, remote: &RemoteIssue, comments: &[RemoteComment]) -> Issue {
+    projection(
+        id,
+        &remote.title,
+        remote.body.as_deref(),
+        remote.closed,
+        remote.labels.clone(),
+        comments.iter().map(|comment| comment.body.as_str()),
+        remote.updated_at,
+    )
+}
+
+/// Reduce an issue to the fields both sides share. Comments are keyed by
+/// their normalized body alone, since authors and timestamps differ between
+/// the two systems.
+fn projection<'a>(
+    id: &str,
+    title: &str,
+    description: Option<&str>,
+    closed: bool,
+    labels: Vec<String>,
+    comment_bodies: impl Iterator<Item = &'a str>,
+    updated_at: DateTime<Utc>,
+) -> Issue {
+    let mut comments: Vec<Comment> = Vec::new();
+    for body in comment_bodies {
+        let body = normalize_text(body);
+        if body.is_empty() || comments.iter().any(|comment| comment.body == body) {
+            continue;
+        }
+        comments.push(Comment {
+            id: 0,
+            issue_id: id.to_string(),
+            author: String::new(),
+            body,
+            created_at: DateTime::UNIX_EPOCH,
+        });
+    }
+    Issue {
+        id: id.to_string(),
+        title: title.trim().to_string(),
+        description: description.and_then(normalize_body),
+        status: if closed { Status::Closed } else { Status::Open },
+        labels: normalize_labels(labels),
+        comments,
+        created_at: DateTime::UNIX_EPOCH,
+        updated_at,
+        ..Issue::default()
+    }
+}
+
+fn normalize_text(text: &str) -> String {
+    text.replace("\r\n", "\n").trim().to_string()
+}
+
+fn normalize_body(body: &str) -> Option<String> {
+    Some(normalize_text(body)).filter(|body| !body.is_empty())
+}
+
+fn normalize_labels(mut labels: Vec<String>) -> Vec<String> {
+    labels.sort();
+    labels.dedup();
+    labels
+}
+
+/// Whether two projections agree on every field except comments.
+fn same_fields(a: &Issue, b: &Issue) -> bool {
+    a.title == b.title
+        && a.description == b.description
+        && a.status == b.status
+        && a.labels == b.labels
+}
+
+/// Comment bodies in `merged` that `side` does not have yet.
+fn new_comments(merged: &Issue, side: &Issue) -> Vec<String> {
+    merged
+        .comments
+        .iter()
+        .filter(|comment| !side.comments.iter().any(|other| other.body == comment.body))
+        .map(|comment| comment.body.clone())
+        .collect()
+}
+
+fn draft_from(projection: &Issue) -> RemoteDraft {
+    RemoteDraft {
+        title: projection.title.clone(),
+        body: projection.description.clone(),
+        closed: projection.status == Status::Closed,
+        labels: projection.labels.clone(),
+    }
+}
+
+fn apply_local(
+    storage: &mut SqliteStorage,
+    merged: &Issue,
+    local: &Issue,
+    remote_authors: &HashMap<String, String>,
+    options: &BridgeOptions,
+    provider: Provider,
+) -> Result<()> {
+    let mut update = IssueUpdate::default();
+    if merged.title != local.title {
+        update.title = Some(merged.title.clone());
+    }
+    if merged.description != local.description {
+        update.description = Some(merged.description.clone());
+    }
+    if merged.status != local.status {
+        let closed = merged.status == Status::Closed;
+        update.status = Some(if closed { Status::Closed } else { Status::Open });
+        update.close_reason = Some(closed.then(|| format!("Closed on {provider}")));
+    }
+    if !update.is_empty() {
+        storage.update_issue(&merged.id, &update, &options.actor)?;
+    }
+    if merged.labels != local.labels {
+        storage.set_labels(&merged.id, &merged.labels, &options.actor)?;
+    }
+    for body in new_comments(merged, local) {
+        let author = remote_authors.get(&body).unwrap_or(&options.actor);
+        storage.add_comment(&merged.id, author, &body)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use std::cell::RefCell;
+    use tempfile::TempDir;
+
+    fn at(minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, 1, 12, minute, 0).unwrap()
+    }
+
+    /// In-memory tracker recording what the bridge pushes.
+    #[derive(Default)]
+    struct FakeTracker {
+        issues: RefCell<Vec<RemoteIssue>>,
+        comments: RefCell<HashMap<u64, Vec<RemoteComment>>>,
+        updates: RefCell<Vec<(u64, RemoteDraft)>>,
+    }
+
+    impl FakeTracker {
+        fn with_issue(number: u64, title: &str, updated_at: DateTime<Utc>) -> Self {
+            let tracker = Self::default();
+            tracker.issues.borrow_mut().push(RemoteIssue {
+                number,
+                title: title.to_string(),
+                body: Some("Steps to reproduce".to_string()),
+                closed: false,
+                labels: vec!["bug".to_string()],
+                comments: 0,
+                created_at: updated_at,
+                updated_at,
+                url: None,
+            });
+            tracker
+        }
+
+        fn edit(
+            &self,
+            number: u64,
+            updated_at: DateTime<Utc>,
+            change: impl FnOnce(&mut RemoteIssue),
+        ) {
+            let mut issues = self.issues.borrow_mut();
+            let issue = issues
+                .iter_mut()
+                .find(|issue| issue.number == number)
+                .unwrap();
+            change(issue);
+            issue.updated_at = updated_at;
+        }
+    }
+
+    impl Tracker for FakeTracker {
+        fn provider(&self) -> Provider {
+            Provider::GitHub
+        }
+
+        fn repo(&self) -> &'static str {
+            "acme/app"
+        }
+
+        fn list_updated_since(&self, since: Option<DateTime<Utc>>) -> Result<Vec<RemoteIssue>> {
+            Ok(self
+                .issues
+                .borrow()
+                .iter()
+                .filter(|issue| since.is_none_or(|since| issue.updated_at >= since))
+                .cloned()
+                .collect())
+        }
+
+        fn get_issue(&self, number: u64) -> Result<RemoteIssue> {
+            self.issues
+                .borrow()
+                .iter()
+                .find(|issue| issue.number == number)
+                .cloned()
+                .ok_or_else(|| BeadsError::Config(format!("no issue {number}")))
+        }
+
+        fn create_issue(&self, draft: &RemoteDraft) -> Result<RemoteIssue> {
+            let number = self.issues.borrow().len() as u64 + 1;
+            let issue = RemoteIssue {
+                number,
+                title: draft.title.clone(),
+                body: draft.body.clone(),
+                closed: draft.closed,
+                labels: draft.labels.clone(),
+                comments: 0,
+                created_at: at(30),
+                updated_at: at(30),
+                url: None,
+            };
+            self.issues.borrow_mut().push(issue.clone());
+            Ok(issue)
+        }
+
+        fn update_issue(&self, number: u64, draft: &RemoteDraft) -> Result<RemoteIssue> {
+            self.updates.borrow_mut().push((number, draft.clone()));
+            self.edit(number, at(40), |issue| {
+                issue.title.clone_from(&draft.title);
+                issue.body.clone_from(&draft.body);
+                issue.closed = draft.closed;
+                issue.labels.clone_from(&draft.labels);
+            });
+            self.get_issue(number)
+        }
+
+        fn list_comments(&self, number: u64) -> Result<Vec<RemoteComment>> {
+            Ok(self
+                .comments
+                .borrow()
+                .get(&number)
+                .cloned()
+                .unwrap_or_default())
+        }
+
+        fn add_comment(&self, number: u64, body: &str) -> Result<()> {
+            self.comments
+                .borrow_mut()
+                .entry(number)
+                .or_default()
+                .push(RemoteComment {
+                    author: "bot".to_string(),
+                    body: body.to_string(),
+                });
+            self.edit(number, at(41), |issue| issue.comments += 1);
+            Ok(())
+        }
+    }
+
+    fn options(temp: &TempDir, now: DateTime<Utc>) -> BridgeOptions {
+        BridgeOptions {
+            beads_dir: temp.path().to_path_buf(),
+            actor: "agent".to_string(),
+            prefix: "bd".to_string(),
+            dry_run: false,
+            now,
+        }
+    }
+
+    #[test]
+    fn test_provider_refs_round_trip() {
+        assert_eq!(Provider::GitHub.external_ref(42), "gh-42");
+        assert_eq!(Provider::GitHub.parse_ref("gh-42"), Some(42));
+        assert_eq!(Provider::GitHub.parse_ref("gl-42"), None);
+        assert_eq!(Provider::GitLab.parse_ref("gl-7"), Some(7));
+        assert_eq!(Provider::GitLab.parse_ref("gl-x"), None);
+        assert!(Provider::parse("bitbucket").is_err());
+    }
+
+    #[test]
+    fn test_sync_pulls_new_issues_with_stable_ids() {
+        let temp = TempDir::new().unwrap();
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let tracker = FakeTracker::with_issue(7, "Login fails", at(0));
+        tracker.comments.borrow_mut().insert(
+            7,
+            vec![RemoteComment {
+                author: "octocat".to_string(),
+                body: "Repro attached".to_string(),
+            }],
+        );
+        tracker.edit(7, at(0), |issue| issue.comments = 1);
+
+        let report = sync(&mut storage, &tracker, &options(&temp, at(5))).unwrap();
+        assert_eq!(report.pulled.len(), 1);
+        let id = &report.pulled[0].id;
+        assert_eq!(id, &pulled_id(&tracker, "bd", 7));
+
+        let issue = storage.find_by_external_ref("gh-7").unwrap().unwrap();
+        assert_eq!(&issue.id, id);
+        assert_eq!(issue.title, "Login fails");
+        assert_eq!(storage.get_labels(id).unwrap(), ["bug"]);
+        let comments = storage.get_comments(id).unwrap();
+        assert_eq!(comments[0].author, "octocat");
+
+        // Nothing changed on either side: a second run is a no-op.
+        let again = sync(&mut storage, &tracker, &options(&temp, at(6))).unwrap();
+        assert!(again.pulled.is_empty());
+        assert!(again.updated_local.is_empty());
+        assert!(again.updated_remote.is_empty());
+        assert!(tracker.updates.borrow().is_empty());
+    }
+
+    #[test]
+    fn test_sync_merges_edits_from_both_sides() {
+        let temp = TempDir::new().unwrap();
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let tracker = FakeTracker::with_issue(3, "Crash on save", at(0));
+        let report = sync(&mut storage, &tracker, &options(&temp, at(5))).unwrap();
+        let id = report.pulled[0].id.clone();
+
+        // Local: close and comment. Tracker: add a label and retitle, later.
+        let update = IssueUpdate {
+            status: Some(Status::Closed),
+            title: Some("Crash on save (local)".to_string()),
+            ..IssueUpdate::default()
+        };
+        storage.update_issue(&id, &update, "agent").unwrap();
+        storage
+            .add_comment(&id, "agent", "Fixed in abc123")
+            .unwrap();
+        tracker.edit(3, Utc::now() + chrono::Duration::hours(1), |issue| {
+            issue.labels.push("p1".to_string());
+            issue.title = "Crash on save (tracker)".to_string();
+        });
+
+        let report = sync(&mut storage, &tracker, &options(&temp, Utc::now())).unwrap();
+        assert_eq!(report.updated_local.len(), 1);
+        assert_eq!(report.updated_remote.len(), 1);
+        assert_eq!(report.comments_pushed, 1);
+        assert_eq!(report.field_conflicts.len(), 1);
+        assert_eq!(report.field_conflicts[0].field, "title");
+
+        let local = storage.get_issue(&id).unwrap().unwrap();
+        assert_eq!(local.status, Status::Closed);
+        assert_eq!(local.title, "Crash on save (tracker)");
+        assert_eq!(storage.get_labels(&id).unwrap(), ["bug", "p1"]);
+
+        let remote = tracker.get_issue(3).unwrap();
+        assert!(remote.closed);
+        assert_eq!(remote.labels, ["bug", "p1"]);
+        assert_eq!(tracker.comments.borrow()[&3][0].body, "Fixed in abc123");
+    }
+
+    #[test]
+    fn test_link_creates_tracker_issue_and_dry_run_writes_nothing() {
+        let temp = TempDir::new().unwrap();
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let issue = Issue {
+            id: "bd-local".to_string(),
+            title: "Agent task".to_string(),
+            labels: vec!["agent".to_string()],
+            ..Issue::default()
+        };
+        storage.create_issue(&issue, "agent").unwrap();
+        let tracker = FakeTracker::default();
+
+        let mut dry = options(&temp, at(1));
+        dry.dry_run = true;
+        link(&mut storage, &tracker, &dry, "bd-local", None).unwrap();
+        assert!(tracker.issues.borrow().is_empty());
+
+        let change = link(
+            &mut storage,
+            &tracker,
+            &options(&temp, at(1)),
+            "bd-local",
+            None,
+        )
+        .unwrap();
+        assert_eq!(change.external_ref, "gh-1");
+        assert_eq!(tracker.get_issue(1).unwrap().labels, ["agent"]);
+        let linked = storage.get_issue("bd-local").unwrap().unwrap();
+        assert_eq!(linked.external_ref.as_deref(), Some("gh-1"));
+        assert!(
+            link(
+                &mut storage,
+                &tracker,
+                &options(&temp, at(1)),
+                "bd-local",
+                None
+            )
+            .is_err()
+        );
+
+        let removed = unlink(&mut storage, &options(&temp, at(2)), "bd-local").unwrap();
+        assert_eq!(removed.as_deref(), Some("gh-1"));
+        assert!(
+            load_base(&temp.path().join(BRIDGE_BASE_FILE))
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
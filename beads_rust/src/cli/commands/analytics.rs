@@ -0,0 +1,933 @@
+//! Analytics command implementation.
+//!
+//! Derives historical metrics from the local `events` table and `closed_at`:
+//! daily burndown, weekly throughput, lead/cycle time distributions and aging
+//! work in progress. Issues without recorded status changes (e.g. imported
+//! from JSONL) fall back to `created_at`/`closed_at`.
+
+use crate::cli::{AnalyticsArgs, AnalyticsReport, OutputFormat, resolve_output_format};
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::format::csv::escape_field;
+use crate::format::truncate_title;
+use crate::model::{DependencyType, Event, EventType, Issue, Status};
+use crate::output::{OutputContext, OutputMode};
+use crate::storage::{ListFilters, SqliteStorage};
+use crate::util::id::{IdResolver, ResolverConfig};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use rich_rust::prelude::*;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write as _;
+
+const SPARK_CHARS: [char; 8] = [
+    '\u{2581}', '\u{2582}', '\u{2583}', '\u{2584}', '\u{2585}', '\u{2586}', '\u{2587}', '\u{2588}',
+];
+
+/// Histogram buckets for lead/cycle time, as (label, upper bound in days).
+const BUCKETS: [(&str, f64); 7] = [
+    ("<1d", 1.0),
+    ("1-2d", 2.0),
+    ("2-4d", 4.0),
+    ("4-7d", 7.0),
+    ("1-2w", 14.0),
+    ("2-4w", 28.0),
+    (">4w", f64::INFINITY),
+];
+
+/// JSON output for `br analytics`.
+#[derive(Debug, Serialize)]
+struct Analytics {
+    generated_at: DateTime<Utc>,
+    scope: Scope,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    burndown: Option<Vec<BurndownDay>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    throughput: Option<Vec<ThroughputWeek>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lead_time: Option<Distribution>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cycle_time: Option<Distribution>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    flow: Option<Vec<FlowRecord>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    aging: Option<Vec<AgingItem>>,
+}
+
+#[derive(Debug, Serialize)]
+struct Scope {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    epic: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+    issues: usize,
+}
+
+#[derive(Debug, Serialize, PartialEq, Eq)]
+struct BurndownDay {
+    date: NaiveDate,
+    total: usize,
+    closed: usize,
+    remaining: usize,
+}
+
+#[derive(Debug, Serialize, PartialEq, Eq)]
+struct ThroughputWeek {
+    week_start: NaiveDate,
+    closed: usize,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+struct Distribution {
+    count: usize,
+    min_days: Option<f64>,
+    p50_days: Option<f64>,
+    p85_days: Option<f64>,
+    p95_days: Option<f64>,
+    max_days: Option<f64>,
+    mean_days: Option<f64>,
+    histogram: Vec<Bucket>,
+}
+
+#[derive(Debug, Serialize, PartialEq, Eq)]
+struct Bucket {
+    label: &'static str,
+    count: usize,
+}
+
+/// Per-issue lead/cycle time for an issue closed within the window.
+#[derive(Debug, Serialize)]
+struct FlowRecord {
+    id: String,
+    created_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    started_at: Option<DateTime<Utc>>,
+    closed_at: DateTime<Utc>,
+    lead_days: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cycle_days: Option<f64>,
+}
+
+#[derive(Debug, Serialize)]
+struct AgingItem {
+    id: String,
+    title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    assignee: Option<String>,
+    status_since: DateTime<Utc>,
+    age_days: f64,
+}
+
+/// Status history of one issue, reconstructed from `status_changed` events.
+#[derive(Debug)]
+struct Timeline<'a> {
+    issue: &'a Issue,
+    /// Status entered at each point in time, oldest first.
+    changes: Vec<(DateTime<Utc>, Status)>,
+}
+
+impl<'a> Timeline<'a> {
+    fn new(issue: &'a Issue, events: &[&Event]) -> Self {
+        let initial = events.first().map_or_else(
+            || {
+                if issue.closed_at.is_some() {
+                    Status::Open
+                } else {
+                    issue.status.clone()
+                }
+            },
+            |event| parse_status(event.old_value.as_deref()),
+        );
+        let mut changes = vec![(issue.created_at, initial)];
+        changes.extend(
+            events
+                .iter()
+                .map(|event| (event.created_at, parse_status(event.new_value.as_deref()))),
+        );
+        if events.is_empty()
+            && let Some(closed_at) = issue.closed_at
+        {
+            changes.push((closed_at, Status::Closed));
+        }
+        Self { issue, changes }
+    }
+
+    /// Status at `at`, or `None` if the issue did not exist yet.
+    fn status_at(&self, at: DateTime<Utc>) -> Option<&Status> {
+        if at < self.issue.created_at {
+            return None;
+        }
+        self.changes
+            .iter()
+            .take_while(|(changed_at, _)| *changed_at <= at)
+            .last()
+            .map(|(_, status)| status)
+    }
+
+    /// When work first started.
+    fn started_at(&self) -> Option<DateTime<Utc>> {
+        self.changes
+            .iter()
+            .find(|(_, status)| *status == Status::InProgress)
+            .map(|(at, _)| *at)
+    }
+
+    /// When the issue last entered its current status.
+    fn current_since(&self) -> DateTime<Utc> {
+        self.changes
+            .iter()
+            .rev()
+            .find(|(_, status)| *status == self.issue.status)
+            .map_or(self.issue.updated_at, |(at, _)| *at)
+    }
+
+    /// Close time, if the issue is currently closed.
+    fn closed_at(&self) -> Option<DateTime<Utc>> {
+        if self.issue.status == Status::Closed {
+            self.issue.closed_at
+        } else {
+            None
+        }
+    }
+}
+
+fn parse_status(value: Option<&str>) -> Status {
+    value.map_or_else(Status::default, |value| {
+        value
+            .parse()
+            .unwrap_or_else(|_| Status::Custom(value.to_string()))
+    })
+}
+
+/// Execute the analytics command.
+///
+/// # Errors
+///
+/// Returns an error if the database cannot be opened, the epic is unknown, or
+/// CSV output is requested for more than one report.
+pub fn execute(
+    args: &AnalyticsArgs,
+    json: bool,
+    cli: &config::CliOverrides,
+    _ctx: &OutputContext,
+) -> Result<()> {
+    let output_format = resolve_output_format(args.format, json, args.robot);
+    if output_format == OutputFormat::Csv && args.report == AnalyticsReport::All {
+        return Err(BeadsError::validation(
+            "format",
+            "csv renders one report; pick burndown, throughput, cycle-time or aging",
+        ));
+    }
+
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let storage = &storage_ctx.storage;
+    let config_layer = config::load_config(&beads_dir, Some(storage), cli)?;
+    let use_color = config::should_use_color(&config_layer);
+    let quiet = cli.quiet.unwrap_or(false);
+    let ctx = OutputContext::from_output_format(output_format, quiet, !use_color);
+
+    let epic = args
+        .epic
+        .as_deref()
+        .map(|input| {
+            let id_config = config::id_config_from_layer(&config_layer);
+            let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
+            resolver
+                .resolve(
+                    input,
+                    |id| storage.id_exists(id).unwrap_or(false),
+                    |hash| storage.find_ids_by_hash(hash).unwrap_or_default(),
+                )
+                .map(|resolved| resolved.id)
+        })
+        .transpose()?;
+
+    let issues = scoped_issues(storage, epic.as_deref(), args.label.as_deref())?;
+    let mut events_by_issue: HashMap<&str, Vec<&Event>> = HashMap::new();
+    let events = storage.get_events_of_type(&EventType::StatusChanged)?;
+    for event in &events {
+        events_by_issue
+            .entry(event.issue_id.as_str())
+            .or_default()
+            .push(event);
+    }
+    let timelines: Vec<Timeline> = issues
+        .iter()
+        .map(|issue| {
+            let events = events_by_issue
+                .get(issue.id.as_str())
+                .map_or(&[][..], Vec::as_slice);
+            Timeline::new(issue, events)
+        })
+        .collect();
+
+    let now = Utc::now();
+    let wants =
+        |report: AnalyticsReport| args.report == AnalyticsReport::All || args.report == report;
+    let flow = wants(AnalyticsReport::CycleTime).then(|| flow_records(&timelines, args.weeks, now));
+    let analytics = Analytics {
+        generated_at: now,
+        scope: Scope {
+            epic,
+            label: args.label.clone(),
+            issues: issues.len(),
+        },
+        burndown: wants(AnalyticsReport::Burndown).then(|| burndown(&timelines, args.days, now)),
+        throughput: wants(AnalyticsReport::Throughput)
+            .then(|| throughput(&timelines, args.weeks, now)),
+        lead_time: flow
+            .as_ref()
+            .map(|flow| distribution(flow.iter().map(|record| record.lead_days))),
+        cycle_time: flow
+            .as_ref()
+            .map(|flow| distribution(flow.iter().filter_map(|record| record.cycle_days))),
+        flow,
+        aging: wants(AnalyticsReport::Aging).then(|| aging(&timelines, now)),
+    };
+
+    if matches!(ctx.mode(), OutputMode::Quiet) {
+        return Ok(());
+    }
+
+    match output_format {
+        OutputFormat::Json => ctx.json_pretty(&analytics),
+        OutputFormat::Toon => ctx.toon(&analytics),
+        OutputFormat::Csv => print!("{}", render_csv(&analytics)),
+        OutputFormat::Text => {
+            if matches!(ctx.mode(), OutputMode::Rich) {
+                render_rich(&analytics, args, &ctx);
+            } else {
+                print!("{}", render_text(&analytics, args));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Issues in scope: live, non-template issues, optionally limited to a label
+/// and to the (recursive) children of an epic.
+fn scoped_issues(
+    storage: &SqliteStorage,
+    epic: Option<&str>,
+    label: Option<&str>,
+) -> Result<Vec<Issue>> {
+    let filters = ListFilters {
+        include_closed: true,
+        include_deferred: true,
+        labels: label.map(|label| vec![label.to_string()]),
+        ..Default::default()
+    };
+    let mut issues = storage.list_issues(&filters)?;
+    issues.retain(|issue| issue.status != Status::Tombstone);
+
+    if let Some(epic) = epic {
+        let descendants = descendants(storage, epic)?;
+        issues.retain(|issue| descendants.contains(&issue.id));
+    }
+    Ok(issues)
+}
+
+/// All issues below `epic` via parent-child dependencies.
+fn descendants(storage: &SqliteStorage, epic: &str) -> Result<HashSet<String>> {
+    let mut children: HashMap<String, Vec<String>> = HashMap::new();
+    for deps in storage.get_all_dependency_records()?.into_values() {
+        for dep in deps {
+            if dep.dep_type == DependencyType::ParentChild {
+                children
+                    .entry(dep.depends_on_id)
+                    .or_default()
+                    .push(dep.issue_id);
+            }
+        }
+    }
+
+    let mut found = HashSet::new();
+    let mut stack = vec![epic.to_string()];
+    while let Some(parent) = stack.pop() {
+        for child in children.remove(&parent).unwrap_or_default() {
+            if child != epic && found.insert(child.clone()) {
+                stack.push(child);
+            }
+        }
+    }
+    Ok(found)
+}
+
+/// Open/closed counts at the end of each of the last `days` UTC days.
+fn burndown(timelines: &[Timeline], days: u32, now: DateTime<Utc>) -> Vec<BurndownDay> {
+    let today = now.date_naive();
+    (0..i64::from(days.max(1)))
+        .rev()
+        .map(|back| {
+            let date = today - Duration::days(back);
+            let end_of_day = date
+                .succ_opt()
+                .and_then(|next| next.and_hms_opt(0, 0, 0))
+                .map_or(now, |next| next.and_utc() - Duration::nanoseconds(1))
+                .min(now);
+            let mut total = 0;
+            let mut closed = 0;
+            for status in timelines.iter().filter_map(|t| t.status_at(end_of_day)) {
+                total += 1;
+                if *status == Status::Closed {
+                    closed += 1;
+                }
+            }
+            BurndownDay {
+                date,
+                total,
+                closed,
+                remaining: total - closed,
+            }
+        })
+        .collect()
+}
+
+/// Monday of the week `weeks - 1` weeks before the current one.
+fn window_start(weeks: u32, now: DateTime<Utc>) -> NaiveDate {
+    let today = now.date_naive();
+    let monday = today - Duration::days(i64::from(today.weekday().num_days_from_monday()));
+    monday - Duration::weeks(i64::from(weeks.max(1)) - 1)
+}
+
+/// Issues closed per Monday-start week over the last `weeks` weeks.
+fn throughput(timelines: &[Timeline], weeks: u32, now: DateTime<Utc>) -> Vec<ThroughputWeek> {
+    let start = window_start(weeks, now);
+    let mut counts = vec![0usize; weeks.max(1) as usize];
+    for closed_at in timelines.iter().filter_map(Timeline::closed_at) {
+        let offset = (closed_at.date_naive() - start).num_days();
+        if let Ok(offset) = usize::try_from(offset)
+            && let Some(count) = counts.get_mut(offset / 7)
+        {
+            *count += 1;
+        }
+    }
+    (0..)
+        .zip(counts)
+        .map(|(index, closed)| ThroughputWeek {
+            week_start: start + Duration::weeks(index),
+            closed,
+        })
+        .collect()
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn days_between(from: DateTime<Utc>, to: DateTime<Utc>) -> f64 {
+    round2((to - from).num_seconds().max(0) as f64 / 86_400.0)
+}
+
+fn round2(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+/// Lead/cycle time of issues closed within the throughput window.
+fn flow_records(timelines: &[Timeline], weeks: u32, now: DateTime<Utc>) -> Vec<FlowRecord> {
+    let start = window_start(weeks, now);
+    let mut records: Vec<FlowRecord> = timelines
+        .iter()
+        .filter_map(|timeline| {
+            let closed_at = timeline.closed_at()?;
+            if closed_at.date_naive() < start {
+                return None;
+            }
+            let started_at = timeline.started_at().filter(|at| *at <= closed_at);
+            Some(FlowRecord {
+                id: timeline.issue.id.clone(),
+                created_at: timeline.issue.created_at,
+                started_at,
+                closed_at,
+                lead_days: days_between(timeline.issue.created_at, closed_at),
+                cycle_days: started_at.map(|at| days_between(at, closed_at)),
+            })
+        })
+        .collect();
+    records.sort_by(|a, b| a.closed_at.cmp(&b.closed_at).then_with(|| a.id.cmp(&b.id)));
+    records
+}
+
+/// Nearest-rank percentile of sorted values.
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+fn percentile(sorted: &[f64], pct: f64) -> Option<f64> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let rank = ((pct / 100.0) * sorted.len() as f64).ceil() as usize;
+    Some(sorted[rank.clamp(1, sorted.len()) - 1])
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn distribution(values: impl Iterator<Item = f64>) -> Distribution {
+    let mut sorted: Vec<f64> = values.collect();
+    sorted.sort_by(f64::total_cmp);
+    let mut histogram: Vec<Bucket> = BUCKETS
+        .iter()
+        .map(|(label, _)| Bucket { label, count: 0 })
+        .collect();
+    for value in &sorted {
+        let index = BUCKETS
+            .iter()
+            .position(|(_, upper)| value < upper)
+            .unwrap_or(BUCKETS.len() - 1);
+        histogram[index].count += 1;
+    }
+    let mean =
+        (!sorted.is_empty()).then(|| round2(sorted.iter().sum::<f64>() / sorted.len() as f64));
+    Distribution {
+        count: sorted.len(),
+        min_days: sorted.first().copied(),
+        p50_days: percentile(&sorted, 50.0),
+        p85_days: percentile(&sorted, 85.0),
+        p95_days: percentile(&sorted, 95.0),
+        max_days: sorted.last().copied(),
+        mean_days: mean,
+        histogram,
+    }
+}
+
+/// In-progress issues, oldest first.
+fn aging(timelines: &[Timeline], now: DateTime<Utc>) -> Vec<AgingItem> {
+    let mut items: Vec<AgingItem> = timelines
+        .iter()
+        .filter(|timeline| timeline.issue.status == Status::InProgress)
+        .map(|timeline| {
+            let since = timeline.current_since();
+            AgingItem {
+                id: timeline.issue.id.clone(),
+                title: timeline.issue.title.clone(),
+                assignee: timeline.issue.assignee.clone(),
+                status_since: since,
+                age_days: days_between(since, now),
+            }
+        })
+        .collect();
+    items.sort_by(|a, b| {
+        a.status_since
+            .cmp(&b.status_since)
+            .then_with(|| a.id.cmp(&b.id))
+    });
+    items
+}
+
+/// One block character per value, scaled to the largest.
+fn sparkline(values: &[usize]) -> String {
+    let max = values.iter().copied().max().unwrap_or(0);
+    values
+        .iter()
+        .map(|&value| {
+            (value * (SPARK_CHARS.len() - 1))
+                .checked_div(max)
+                .map_or(SPARK_CHARS[0], |index| SPARK_CHARS[index])
+        })
+        .collect()
+}
+
+fn format_days(value: Option<f64>) -> String {
+    value.map_or_else(|| "-".to_string(), |days| format!("{days:.1}d"))
+}
+
+fn render_csv(analytics: &Analytics) -> String {
+    let mut out = String::new();
+    if let Some(days) = &analytics.burndown {
+        out.push_str("date,total,closed,remaining\n");
+        for day in days {
+            let _ = writeln!(
+                out,
+                "{},{},{},{}",
+                day.date, day.total, day.closed, day.remaining
+            );
+        }
+    } else if let Some(weeks) = &analytics.throughput {
+        out.push_str("week_start,closed\n");
+        for week in weeks {
+            let _ = writeln!(out, "{},{}", week.week_start, week.closed);
+        }
+    } else if let Some(flow) = &analytics.flow {
+        out.push_str("id,created_at,started_at,closed_at,lead_days,cycle_days\n");
+        for record in flow {
+            let _ = writeln!(
+                out,
+                "{},{},{},{},{},{}",
+                escape_field(&record.id),
+                record.created_at.to_rfc3339(),
+                record
+                    .started_at
+                    .map(|at| at.to_rfc3339())
+                    .unwrap_or_default(),
+                record.closed_at.to_rfc3339(),
+                record.lead_days,
+                record
+                    .cycle_days
+                    .map(|days| days.to_string())
+                    .unwrap_or_default()
+            );
+        }
+    } else if let Some(items) = &analytics.aging {
+        out.push_str("id,title,assignee,status_since,age_days\n");
+        for item in items {
+            let _ = writeln!(
+                out,
+                "{},{},{},{},{}",
+                escape_field(&item.id),
+                escape_field(&item.title),
+                escape_field(item.assignee.as_deref().unwrap_or("")),
+                item.status_since.to_rfc3339(),
+                item.age_days
+            );
+        }
+    }
+    out
+}
+
+fn scope_line(analytics: &Analytics) -> String {
+    let scope = &analytics.scope;
+    let mut line = format!(
+        "{} issue{}",
+        scope.issues,
+        if scope.issues == 1 { "" } else { "s" }
+    );
+    if let Some(epic) = &scope.epic {
+        let _ = write!(line, " under {epic}");
+    }
+    if let Some(label) = &scope.label {
+        let _ = write!(line, " labelled {label}");
+    }
+    line
+}
+
+fn histogram_bar(count: usize, max: usize) -> String {
+    const WIDTH: usize = 20;
+    let len = if max == 0 {
+        0
+    } else {
+        (count * WIDTH).div_ceil(max)
+    };
+    "\u{2588}".repeat(len)
+}
+
+fn distribution_summary(dist: &Distribution) -> String {
+    format!(
+        "p50 {}  p85 {}  p95 {}  max {}  mean {}",
+        format_days(dist.p50_days),
+        format_days(dist.p85_days),
+        format_days(dist.p95_days),
+        format_days(dist.max_days),
+        format_days(dist.mean_days)
+    )
+}
+
+fn render_text(analytics: &Analytics, args: &AnalyticsArgs) -> String {
+    let mut out = format!("Analytics: {}\n", scope_line(analytics));
+
+    if let Some(days) = &analytics.burndown {
+        let _ = writeln!(out, "\nBurndown (last {} days)", days.len());
+        let remaining: Vec<usize> = days.iter().map(|day| day.remaining).collect();
+        let closed: Vec<usize> = days.iter().map(|day| day.closed).collect();
+        let last = days.last();
+        let _ = writeln!(
+            out,
+            "  remaining {}  {}",
+            sparkline(&remaining),
+            last.map_or(0, |day| day.remaining)
+        );
+        let _ = writeln!(
+            out,
+            "  closed    {}  {}",
+            sparkline(&closed),
+            last.map_or(0, |day| day.closed)
+        );
+    }
+
+    if let Some(weeks) = &analytics.throughput {
+        let counts: Vec<usize> = weeks.iter().map(|week| week.closed).collect();
+        let total: usize = counts.iter().sum();
+        let _ = writeln!(out, "\nThroughput (last {} weeks)", weeks.len());
+        #[allow(clippy::cast_precision_loss)]
+        let average = total as f64 / counts.len().max(1) as f64;
+        let _ = writeln!(
+            out,
+            "  {}  {total} closed, {average:.1}/week",
+            sparkline(&counts)
+        );
+    }
+
+    for (name, dist) in [
+        ("Lead time (created -> closed)", &analytics.lead_time),
+        ("Cycle time (in_progress -> closed)", &analytics.cycle_time),
+    ] {
+        let Some(dist) = dist else { continue };
+        let _ = writeln!(
+            out,
+            "\n{name}, {} issue{} closed in the last {} weeks",
+            dist.count,
+            if dist.count == 1 { "" } else { "s" },
+            args.weeks
+        );
+        if dist.count == 0 {
+            continue;
+        }
+        let _ = writeln!(out, "  {}", distribution_summary(dist));
+        let max = dist.histogram.iter().map(|b| b.count).max().unwrap_or(0);
+        for bucket in &dist.histogram {
+            let _ = writeln!(
+                out,
+                "  {:<5} {:<20} {}",
+                bucket.label,
+                histogram_bar(bucket.count, max),
+                bucket.count
+            );
+        }
+    }
+
+    if let Some(items) = &analytics.aging {
+        let _ = writeln!(out, "\nAging WIP ({} in progress)", items.len());
+        for item in items {
+            let _ = writeln!(
+                out,
+                "  {:>6.1}d  {}  {}{}",
+                item.age_days,
+                item.id,
+                truncate_title(&item.title, 50),
+                item.assignee
+                    .as_deref()
+                    .map(|assignee| format!(" ({assignee})"))
+                    .unwrap_or_default()
+            );
+        }
+    }
+    out
+}
+
+#[allow(clippy::too_many_lines)]
+fn render_rich(analytics: &Analytics, args: &AnalyticsArgs, ctx: &OutputContext) {
+    let console = Console::default();
+    let theme = ctx.theme();
+    let mut content = Text::new("");
+
+    content.append_styled(&scope_line(analytics), theme.dimmed.clone());
+    content.append("\n");
+
+    if let Some(days) = &analytics.burndown {
+        content.append_styled(
+            &format!("\n\u{1f4c9} Burndown (last {} days)\n", days.len()),
+            theme.section.clone(),
+        );
+        let remaining: Vec<usize> = days.iter().map(|day| day.remaining).collect();
+        let closed: Vec<usize> = days.iter().map(|day| day.closed).collect();
+        let last = days.last();
+        content.append_styled("   Remaining ", theme.dimmed.clone());
+        content.append_styled(&sparkline(&remaining), theme.warning.clone());
+        content.append(&format!("  {}\n", last.map_or(0, |day| day.remaining)));
+        content.append_styled("   Closed    ", theme.dimmed.clone());
+        content.append_styled(&sparkline(&closed), theme.success.clone());
+        content.append(&format!("  {}\n", last.map_or(0, |day| day.closed)));
+    }
+
+    if let Some(weeks) = &analytics.throughput {
+        let counts: Vec<usize> = weeks.iter().map(|week| week.closed).collect();
+        let total: usize = counts.iter().sum();
+        content.append_styled(
+            &format!("\n\u{1f4e6} Throughput (last {} weeks)\n", weeks.len()),
+            theme.section.clone(),
+        );
+        content.append("   ");
+        content.append_styled(&sparkline(&counts), theme.success.clone());
+        #[allow(clippy::cast_precision_loss)]
+        let average = total as f64 / counts.len().max(1) as f64;
+        content.append_styled(
+            &format!("  {total} closed, {average:.1}/week\n"),
+            theme.dimmed.clone(),
+        );
+    }
+
+    for (name, dist) in [
+        ("Lead time", &analytics.lead_time),
+        ("Cycle time", &analytics.cycle_time),
+    ] {
+        let Some(dist) = dist else { continue };
+        content.append_styled(
+            &format!(
+                "\n\u{23f1} {name} ({} closed in {} weeks)\n",
+                dist.count, args.weeks
+            ),
+            theme.section.clone(),
+        );
+        if dist.count == 0 {
+            content.append_styled("   No closed issues\n", theme.dimmed.clone());
+            continue;
+        }
+        content.append("   ");
+        content.append(&distribution_summary(dist));
+        content.append("\n");
+        let max = dist.histogram.iter().map(|b| b.count).max().unwrap_or(0);
+        for bucket in &dist.histogram {
+            content.append_styled(&format!("   {:<5} ", bucket.label), theme.dimmed.clone());
+            content.append_styled(
+                &format!("{:<20}", histogram_bar(bucket.count, max)),
+                theme.accent.clone(),
+            );
+            content.append(&format!(" {}\n", bucket.count));
+        }
+    }
+
+    if let Some(items) = &analytics.aging {
+        content.append_styled(
+            &format!("\n\u{1f6a7} Aging WIP ({} in progress)\n", items.len()),
+            theme.section.clone(),
+        );
+        for item in items {
+            content.append_styled(
+                &format!("   {:>6.1}d  ", item.age_days),
+                theme.warning.clone(),
+            );
+            content.append_styled(&item.id, theme.issue_id.clone());
+            content.append(&format!("  {}", truncate_title(&item.title, 50)));
+            if let Some(assignee) = &item.assignee {
+                content.append_styled(&format!(" ({assignee})"), theme.dimmed.clone());
+            }
+            content.append("\n");
+        }
+    }
+
+    let panel = Panel::from_rich_text(&content, ctx.width())
+        .title(Text::styled("Analytics", theme.panel_title.clone()))
+        .box_style(theme.box_style);
+    console.print_renderable(&panel);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(day: u32, hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, day, hour, 0, 0).unwrap()
+    }
+
+    fn issue(
+        id: &str,
+        created: DateTime<Utc>,
+        status: Status,
+        closed: Option<DateTime<Utc>>,
+    ) -> Issue {
+        Issue {
+            id: id.to_string(),
+            title: id.to_string(),
+            status,
+            created_at: created,
+            updated_at: created,
+            closed_at: closed,
+            ..Issue::default()
+        }
+    }
+
+    fn change(id: &str, when: DateTime<Utc>, from: &str, to: &str) -> Event {
+        Event {
+            id: 0,
+            issue_id: id.to_string(),
+            event_type: EventType::StatusChanged,
+            actor: "alice".to_string(),
+            old_value: Some(from.to_string()),
+            new_value: Some(to.to_string()),
+            comment: None,
+            created_at: when,
+        }
+    }
+
+    #[test]
+    fn test_timeline_from_events() {
+        let item = issue("bd-1", at(2, 9), Status::Closed, Some(at(5, 9)));
+        let events = [
+            change("bd-1", at(3, 9), "open", "in_progress"),
+            change("bd-1", at(5, 9), "in_progress", "closed"),
+        ];
+        let refs: Vec<&Event> = events.iter().collect();
+        let timeline = Timeline::new(&item, &refs);
+        assert_eq!(timeline.status_at(at(1, 9)), None);
+        assert_eq!(timeline.status_at(at(2, 12)), Some(&Status::Open));
+        assert_eq!(timeline.status_at(at(4, 0)), Some(&Status::InProgress));
+        assert_eq!(timeline.status_at(at(6, 0)), Some(&Status::Closed));
+        assert_eq!(timeline.started_at(), Some(at(3, 9)));
+    }
+
+    #[test]
+    fn test_timeline_without_events_uses_closed_at() {
+        let item = issue("bd-1", at(2, 9), Status::Closed, Some(at(4, 9)));
+        let timeline = Timeline::new(&item, &[]);
+        assert_eq!(timeline.status_at(at(3, 0)), Some(&Status::Open));
+        assert_eq!(timeline.status_at(at(5, 0)), Some(&Status::Closed));
+        assert_eq!(timeline.started_at(), None);
+    }
+
+    #[test]
+    fn test_burndown_and_throughput() {
+        let issues = [
+            issue("bd-1", at(2, 9), Status::Closed, Some(at(3, 9))),
+            issue("bd-2", at(3, 9), Status::Open, None),
+            issue("bd-3", at(4, 9), Status::Closed, Some(at(10, 9))),
+        ];
+        let timelines: Vec<Timeline> = issues.iter().map(|i| Timeline::new(i, &[])).collect();
+
+        let days = burndown(&timelines, 3, at(4, 12));
+        let remaining: Vec<(usize, usize)> = days.iter().map(|d| (d.total, d.remaining)).collect();
+        assert_eq!(remaining, [(1, 1), (2, 1), (3, 2)]);
+        assert_eq!(days[2].date, at(4, 0).date_naive());
+
+        // 2026-03-10 is a Tuesday; weeks start on Monday.
+        let weeks = throughput(&timelines, 2, at(10, 12));
+        assert_eq!(
+            weeks,
+            [
+                ThroughputWeek {
+                    week_start: NaiveDate::from_ymd_opt(2026, 3, 2).unwrap(),
+                    closed: 1,
+                },
+                ThroughputWeek {
+                    week_start: NaiveDate::from_ymd_opt(2026, 3, 9).unwrap(),
+                    closed: 1,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_distribution_percentiles_and_buckets() {
+        let dist = distribution([0.5, 1.5, 3.0, 10.0].into_iter());
+        assert_eq!(dist.count, 4);
+        assert_eq!(dist.p50_days, Some(1.5));
+        assert_eq!(dist.p95_days, Some(10.0));
+        assert_eq!(dist.mean_days, Some(3.75));
+        let counts: Vec<usize> = dist.histogram.iter().map(|b| b.count).collect();
+        assert_eq!(counts, [1, 1, 1, 0, 1, 0, 0]);
+
+        let empty = distribution(std::iter::empty());
+        assert_eq!(empty.p50_days, None);
+    }
+
+    #[test]
+    fn test_aging_uses_last_entry_into_progress() {
+        let mut item = issue("bd-1", at(1, 9), Status::InProgress, None);
+        item.assignee = Some("alice".to_string());
+        let events = [
+            change("bd-1", at(2, 9), "open", "in_progress"),
+            change("bd-1", at(3, 9), "in_progress", "open"),
+            change("bd-1", at(4, 9), "open", "in_progress"),
+        ];
+        let refs: Vec<&Event> = events.iter().collect();
+        let timelines = [Timeline::new(&item, &refs)];
+        let items = aging(&timelines, at(6, 9));
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].status_since, at(4, 9));
+        assert!((items[0].age_days - 2.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_sparkline_scales_to_max() {
+        assert_eq!(sparkline(&[0, 4, 8]), "\u{2581}\u{2584}\u{2588}");
+        assert_eq!(sparkline(&[0, 0]), "\u{2581}\u{2581}");
+    }
+}
@@ -0,0 +1,326 @@
+//! Attach command implementation.
+//!
+//! `br attach <id> <file>...` copies files into the content-addressed blob
+//! store (`.beads/blobs/`) and records them on the issue. Without files it
+//! lists the issue's attachments; `--get` writes one back out and `--remove`
+//! drops the record. Removed blobs stay on disk until `br doctor --fix`
+//! finds them unreferenced.
+
+use crate::attachments::{BlobStore, guess_media_type};
+use crate::cli::AttachArgs;
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::format::format_bytes;
+use crate::model::Attachment;
+use crate::output::OutputContext;
+use crate::storage::SqliteStorage;
+use crate::util::id::{IdResolver, ResolverConfig};
+use crate::util::sha256_reader;
+use chrono::Utc;
+use serde::Serialize;
+use std::fs::{self, File};
+use std::io::{self, BufReader};
+use std::path::{Path, PathBuf};
+
+/// An attachment with where its blob lives in this clone.
+#[derive(Debug, Serialize)]
+struct AttachmentListing<'a> {
+    #[serde(flatten)]
+    attachment: &'a Attachment,
+    path: PathBuf,
+    /// Whether the blob is in this clone's store.
+    present: bool,
+}
+
+/// Execute the attach command.
+///
+/// # Errors
+///
+/// Returns an error if the database cannot be opened, the issue cannot be
+/// resolved, a file is rejected by the size policy, or the named attachment
+/// does not exist.
+pub fn execute(
+    args: &AttachArgs,
+    json: bool,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let config_layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+    let id_config = config::id_config_from_layer(&config_layer);
+    let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
+    let actor = config::resolve_actor(&config_layer);
+    let store = BlobStore::load(&beads_dir, &config_layer)?;
+
+    let storage = &mut storage_ctx.storage;
+    let id = resolver
+        .resolve(
+            &args.id,
+            |id| storage.id_exists(id).unwrap_or(false),
+            |hash| storage.find_ids_by_hash(hash).unwrap_or_default(),
+        )?
+        .id;
+
+    if let Some(name) = &args.remove {
+        let Some(removed) = storage.remove_attachment(&id, name, &actor)? else {
+            return Err(unknown_attachment(
+                &id,
+                name,
+                &storage.get_attachments(&id)?,
+            ));
+        };
+        crate::util::set_last_touched_id(&beads_dir, &id);
+        if json {
+            println!("{}", serde_json::to_string_pretty(&removed)?);
+        } else {
+            ctx.success(&format!("Removed {} from {id}", removed.name));
+        }
+    } else if let Some(name) = &args.get {
+        get_attachment(
+            &store,
+            &storage.get_attachments(&id)?,
+            &id,
+            name,
+            args,
+            json,
+            ctx,
+        )?;
+    } else if args.files.is_empty() {
+        let attachments = storage.get_attachments(&id)?;
+        let listings: Vec<AttachmentListing> = attachments
+            .iter()
+            .map(|attachment| AttachmentListing {
+                attachment,
+                path: store.path_for(&attachment.sha256),
+                present: store.contains(&attachment.sha256),
+            })
+            .collect();
+        if json {
+            println!("{}", serde_json::to_string_pretty(&listings)?);
+        } else {
+            print_listings(&id, &listings);
+        }
+    } else {
+        let added = add_files(storage, &store, &id, &actor, args, json, ctx)?;
+        crate::util::set_last_touched_id(&beads_dir, &id);
+        if json {
+            println!("{}", serde_json::to_string_pretty(&added)?);
+        }
+    }
+
+    storage_ctx.flush_no_db_if_dirty()?;
+    Ok(())
+}
+
+/// Store each file and record it on `id`, replacing same-named attachments.
+fn add_files(
+    storage: &mut SqliteStorage,
+    store: &BlobStore,
+    id: &str,
+    actor: &str,
+    args: &AttachArgs,
+    json: bool,
+    ctx: &OutputContext,
+) -> Result<Vec<Attachment>> {
+    if args.name.is_some() && args.files.len() > 1 {
+        return Err(BeadsError::validation(
+            "name",
+            "--name can only be used when attaching a single file",
+        ));
+    }
+    let mut added = Vec::with_capacity(args.files.len());
+    for file in &args.files {
+        let name = attachment_name(file, args.name.as_deref())?;
+        let blob = store.store(file, args.local)?;
+        let attachment = Attachment {
+            media_type: guess_media_type(&name).map(str::to_string),
+            name,
+            sha256: blob.sha256,
+            size: blob.size,
+            created_at: Utc::now(),
+            created_by: Some(actor.to_string()),
+            local_only: blob.local_only,
+        };
+        let replaced = storage.add_attachment(id, &attachment, actor)?;
+        if !json {
+            ctx.success(&format!(
+                "{} {} on {id} ({}, sha256 {})",
+                if replaced.is_some() {
+                    "Replaced"
+                } else {
+                    "Attached"
+                },
+                attachment.name,
+                format_bytes(attachment.size),
+                short_digest(&attachment.sha256)
+            ));
+            if attachment.local_only && !args.local {
+                ctx.info(&format!(
+                    "Kept out of git: larger than attachments.git_max_size ({})",
+                    format_bytes(store.git_max_size())
+                ));
+            }
+        }
+        added.push(attachment);
+    }
+    Ok(added)
+}
+
+/// Write the named attachment to `--output`, or to stdout.
+fn get_attachment(
+    store: &BlobStore,
+    attachments: &[Attachment],
+    id: &str,
+    name: &str,
+    args: &AttachArgs,
+    json: bool,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let attachment = attachments
+        .iter()
+        .find(|attachment| attachment.name == name)
+        .ok_or_else(|| unknown_attachment(id, name, attachments))?;
+    let blob = verified_blob(store, attachment)?;
+    let Some(output) = &args.output else {
+        io::copy(&mut File::open(&blob)?, &mut io::stdout().lock())?;
+        return Ok(());
+    };
+    fs::copy(&blob, output)?;
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&AttachmentListing {
+                attachment,
+                path: output.clone(),
+                present: true,
+            })?
+        );
+    } else {
+        ctx.success(&format!(
+            "Wrote {} to {}",
+            attachment.name,
+            output.display()
+        ));
+    }
+    Ok(())
+}
+
+/// The attachment name: `--name`, or the file name.
+fn attachment_name(file: &Path, name: Option<&str>) -> Result<String> {
+    let name = match name {
+        Some(name) => name.trim().to_string(),
+        None => file
+            .file_name()
+            .and_then(|name| name.to_str())
+            .map(str::to_string)
+            .ok_or_else(|| {
+                BeadsError::validation(
+                    "file",
+                    format!("cannot derive a name from {}; use --name", file.display()),
+                )
+            })?,
+    };
+    if name.is_empty() || name.contains(['/', '\\']) || name.chars().any(char::is_control) {
+        return Err(BeadsError::validation(
+            "name",
+            format!("invalid attachment name '{name}'"),
+        ));
+    }
+    Ok(name)
+}
+
+/// Path to the attachment's blob after checking it is present and intact.
+fn verified_blob(store: &BlobStore, attachment: &Attachment) -> Result<PathBuf> {
+    let path = store.path_for(&attachment.sha256);
+    if !path.is_file() {
+        let hint = if attachment.local_only {
+            " (it was kept out of git, so only the clone that attached it has it)"
+        } else {
+            ""
+        };
+        return Err(BeadsError::validation(
+            "attachment",
+            format!(
+                "blob for '{}' is missing from {}{hint}",
+                attachment.name,
+                store.dir().display()
+            ),
+        ));
+    }
+    let (digest, _) = sha256_reader(BufReader::new(File::open(&path)?))?;
+    if digest != attachment.sha256 {
+        return Err(BeadsError::validation(
+            "attachment",
+            format!(
+                "blob for '{}' is corrupt (sha256 {}); run `br doctor`",
+                attachment.name,
+                short_digest(&digest)
+            ),
+        ));
+    }
+    Ok(path)
+}
+
+fn unknown_attachment(id: &str, name: &str, attachments: &[Attachment]) -> BeadsError {
+    let names: Vec<&str> = attachments.iter().map(|a| a.name.as_str()).collect();
+    BeadsError::validation(
+        "attachment",
+        if names.is_empty() {
+            format!("{id} has no attachment named '{name}' (it has none)")
+        } else {
+            format!(
+                "{id} has no attachment named '{name}' (have: {})",
+                names.join(", ")
+            )
+        },
+    )
+}
+
+fn short_digest(sha256: &str) -> &str {
+    &sha256[..sha256.len().min(12)]
+}
+
+fn print_listings(id: &str, listings: &[AttachmentListing]) {
+    if listings.is_empty() {
+        println!("No attachments on {id}");
+        return;
+    }
+    println!("Attachments on {id}:");
+    for listing in listings {
+        let attachment = listing.attachment;
+        let mut notes = Vec::new();
+        if attachment.local_only {
+            notes.push("local only");
+        }
+        if !listing.present {
+            notes.push("missing");
+        }
+        println!(
+            "  {}  {}  {}  {}{}",
+            attachment.name,
+            format_bytes(attachment.size),
+            short_digest(&attachment.sha256),
+            attachment.created_at.format("%Y-%m-%d %H:%M"),
+            if notes.is_empty() {
+                String::new()
+            } else {
+                format!("  [{}]", notes.join(", "))
+            }
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn attachment_name_defaults_to_file_name() {
+        let path = Path::new("/tmp/out/build.log");
+        assert_eq!(attachment_name(path, None).unwrap(), "build.log");
+        assert_eq!(attachment_name(path, Some(" ci.log ")).unwrap(), "ci.log");
+        assert!(attachment_name(path, Some("a/b")).is_err());
+        assert!(attachment_name(path, Some("  ")).is_err());
+    }
+}
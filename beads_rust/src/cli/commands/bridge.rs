@@ -0,0 +1,215 @@
+//! Bridge command implementation.
+//!
+//! Thin CLI layer over [`crate::bridge`]: resolves the configured tracker,
+//! runs the operation and reports what changed on each side.
+
+use crate::bridge::{
+    self, BridgeConfig, BridgeOptions, BridgeReport, Provider, http::CurlTransport,
+};
+use crate::cli::BridgeCommands;
+use crate::cli::commands::sync::describe_field_conflict;
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::output::OutputContext;
+use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// JSON output for `br bridge status`.
+#[derive(Debug, Serialize)]
+struct StatusReport {
+    provider: Provider,
+    repo: String,
+    api_url: String,
+    token_env: String,
+    token_set: bool,
+    linked: usize,
+    last_sync: Option<DateTime<Utc>>,
+    cursor: Option<DateTime<Utc>>,
+}
+
+/// Execute the bridge command.
+///
+/// # Errors
+///
+/// Returns an error if the bridge is not configured, an ID cannot be
+/// resolved, or a tracker request or database write fails.
+#[allow(clippy::too_many_lines)]
+pub fn execute(
+    command: &BridgeCommands,
+    json: bool,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+
+    let config_layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+    let bridge_config = BridgeConfig::from_layer(&config_layer)?;
+    let id_config = config::id_config_from_layer(&config_layer);
+    let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix.clone()));
+    let all_ids = storage_ctx.storage.get_all_ids()?;
+    let resolve = |input: &str| -> Result<String> {
+        let resolution = resolver.resolve(
+            input,
+            |id| all_ids.iter().any(|existing| existing == id),
+            |hash| find_matching_ids(&all_ids, hash),
+        )?;
+        Ok(resolution.id)
+    };
+    let options = |dry_run: bool| BridgeOptions {
+        beads_dir: beads_dir.clone(),
+        actor: config::resolve_actor(&config_layer),
+        prefix: id_config.prefix.clone(),
+        dry_run,
+        now: Utc::now(),
+    };
+    let tracker = bridge::connect(&bridge_config, Box::new(CurlTransport));
+    let storage = &mut storage_ctx.storage;
+
+    match command {
+        BridgeCommands::Sync(args) => {
+            let report = bridge::sync(storage, tracker.as_ref(), &options(args.dry_run))?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else {
+                print_sync_report(&report, bridge_config.provider);
+            }
+        }
+        BridgeCommands::Link(args) => {
+            let id = resolve(&args.id)?;
+            let number = args
+                .reference
+                .as_deref()
+                .map(|reference| parse_reference(bridge_config.provider, reference))
+                .transpose()?;
+            let change = bridge::link(
+                storage,
+                tracker.as_ref(),
+                &options(args.dry_run),
+                &id,
+                number,
+            )?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&change)?);
+            } else if args.dry_run {
+                println!(
+                    "Would link {id} to {} issue {}",
+                    bridge_config.provider,
+                    number.map_or_else(|| "(new)".to_string(), |n| format!("#{n}"))
+                );
+            } else {
+                crate::util::set_last_touched_id(&beads_dir, &id);
+                ctx.success(&format!("Linked {id} to {}", change.external_ref));
+            }
+        }
+        BridgeCommands::Unlink(args) => {
+            let id = resolve(&args.id)?;
+            let removed = bridge::unlink(storage, &options(false), &id)?;
+            if json {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&serde_json::json!({
+                        "id": id,
+                        "external_ref": removed,
+                    }))?
+                );
+            } else if let Some(external_ref) = removed {
+                ctx.success(&format!("Unlinked {id} from {external_ref}"));
+            } else {
+                println!("{id} is not linked.");
+            }
+        }
+        BridgeCommands::Status => {
+            let report = StatusReport {
+                provider: bridge_config.provider,
+                repo: bridge_config.repo.clone(),
+                api_url: bridge_config.api_url.clone(),
+                token_env: bridge_config.token_env.clone(),
+                token_set: bridge_config.token().is_some(),
+                linked: bridge::linked_issues(storage, bridge_config.provider)?.len(),
+                last_sync: bridge::read_timestamp(storage, bridge::METADATA_BRIDGE_LAST_SYNC)?,
+                cursor: bridge::read_timestamp(storage, bridge::METADATA_BRIDGE_CURSOR)?,
+            };
+            if json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else {
+                let token = if report.token_set { "set" } else { "not set" };
+                println!("Provider:  {} ({})", report.provider, report.repo);
+                println!("API:       {}", report.api_url);
+                println!("Token:     ${} {token}", report.token_env);
+                println!("Linked:    {}", report.linked);
+                println!(
+                    "Last sync: {}",
+                    report
+                        .last_sync
+                        .map_or_else(|| "never".to_string(), |at| at.to_rfc3339())
+                );
+            }
+        }
+    }
+
+    storage_ctx.flush_no_db_if_dirty()?;
+    Ok(())
+}
+
+/// Accept `42`, `#42`, or this provider's `gh-42` / `gl-42`.
+fn parse_reference(provider: Provider, reference: &str) -> Result<u64> {
+    let reference = reference.trim();
+    provider
+        .parse_ref(reference)
+        .or_else(|| reference.trim_start_matches('#').parse().ok())
+        .ok_or_else(|| {
+            BeadsError::validation(
+                "ref",
+                format!(
+                    "'{reference}' is not a {provider} issue number or {}-<number>",
+                    provider.ref_prefix()
+                ),
+            )
+        })
+}
+
+fn print_sync_report(report: &BridgeReport, provider: Provider) {
+    let would = if report.dry_run { "Would " } else { "" };
+    for change in &report.pulled {
+        println!(
+            "{would}pull {} -> {}: {}",
+            change.external_ref, change.id, change.title
+        );
+    }
+    for change in &report.updated_local {
+        println!(
+            "{would}update {} from {}: {}",
+            change.id, change.external_ref, change.title
+        );
+    }
+    for change in &report.updated_remote {
+        println!(
+            "{would}push {} -> {}: {}",
+            change.id, change.external_ref, change.title
+        );
+    }
+    for conflict in &report.field_conflicts {
+        println!(
+            "Conflict on {}: {}",
+            conflict.issue_id,
+            describe_field_conflict(conflict)
+        );
+    }
+    if report.pulled.is_empty()
+        && report.updated_local.is_empty()
+        && report.updated_remote.is_empty()
+    {
+        println!("Already in sync with {provider}.");
+    } else {
+        println!(
+            "{} pulled, {} updated locally, {} pushed ({} comments pulled, {} pushed)",
+            report.pulled.len(),
+            report.updated_local.len(),
+            report.updated_remote.len(),
+            report.comments_pulled,
+            report.comments_pushed
+        );
+    }
+}
@@ -0,0 +1,286 @@
+//! Claim command implementation.
+//!
+//! `br claim` hands out work to agents without races: picking the next ready
+//! issue, assigning it and attaching a lease all happen in one IMMEDIATE
+//! transaction. Leases expire after a TTL, at which point the issue returns
+//! to the ready pool.
+
+use crate::cli::ClaimArgs;
+use crate::cli::commands::ready::{parse_priorities, parse_types};
+use crate::config;
+use crate::error::Result;
+use crate::format::ReadyIssue;
+use crate::model::{ClaimLease, Issue};
+use crate::output::OutputContext;
+use crate::storage::{ClaimOptions, ReadyFilters};
+use crate::sync::auto_flush;
+use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
+use crate::util::time::parse_duration;
+use chrono::{Duration, Utc};
+use serde::Serialize;
+use std::collections::HashSet;
+use tracing::{debug, warn};
+
+/// Lease length used when neither `--ttl` nor `claim.lease-ttl` is set.
+pub const DEFAULT_LEASE_TTL_MINUTES: i64 = 30;
+
+/// JSON result of a claim attempt.
+#[derive(Debug, Serialize)]
+pub struct ClaimResult {
+    /// The claimed issue, or `null` when nothing was ready.
+    pub issue: Option<ReadyIssue>,
+    pub lease: Option<ClaimLease>,
+}
+
+/// Execute the claim command.
+///
+/// # Errors
+///
+/// Returns an error if the database cannot be opened, the ID cannot be
+/// resolved, or the claim/renew/release is rejected.
+#[allow(clippy::too_many_lines)]
+pub fn execute(
+    args: &ClaimArgs,
+    json: bool,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+
+    let config_layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+    let actor = config::resolve_actor(&config_layer);
+
+    let resolved_id = match &args.id {
+        Some(input) => {
+            let id_config = config::id_config_from_layer(&config_layer);
+            let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
+            let all_ids = storage_ctx.storage.get_all_ids()?;
+            let resolution = resolver.resolve(
+                input,
+                |id| all_ids.iter().any(|existing| existing == id),
+                |hash| find_matching_ids(&all_ids, hash),
+            )?;
+            Some(resolution.id)
+        }
+        None => None,
+    };
+
+    if args.list {
+        let expired = storage_ctx.storage.expire_leases(&actor)?;
+        let leases = storage_ctx.storage.list_leases()?;
+        if json {
+            println!("{}", serde_json::to_string_pretty(&leases)?);
+        } else {
+            for lease in &expired {
+                ctx.warning(&format!(
+                    "Lease on {} held by {} expired; returned to ready",
+                    lease.issue_id, lease.actor
+                ));
+            }
+            render_leases(&leases, ctx);
+        }
+        storage_ctx.flush_no_db_if_dirty()?;
+        return Ok(());
+    }
+
+    if args.release {
+        let id = resolved_id.as_deref().unwrap_or_default();
+        let lease = storage_ctx.storage.release_lease(id, &actor, args.force)?;
+        crate::util::set_last_touched_id(&beads_dir, id);
+        if json {
+            println!("{}", serde_json::to_string_pretty(&lease)?);
+        } else {
+            ctx.success(&format!("Released {id} (lease held by {})", lease.actor));
+        }
+        storage_ctx.flush_no_db_if_dirty()?;
+        return Ok(());
+    }
+
+    let ttl = resolve_ttl(args.ttl.as_deref(), &config_layer)?;
+
+    if args.renew {
+        let id = resolved_id.as_deref().unwrap_or_default();
+        let lease = storage_ctx.storage.renew_lease(id, &actor, ttl)?;
+        crate::util::set_last_touched_id(&beads_dir, id);
+        if json {
+            println!("{}", serde_json::to_string_pretty(&lease)?);
+        } else {
+            ctx.success(&format!(
+                "Renewed lease on {id} until {}",
+                lease.expires_at.to_rfc3339()
+            ));
+        }
+        storage_ctx.flush_no_db_if_dirty()?;
+        return Ok(());
+    }
+
+    // Honor cross-project blockers the same way `br ready` does.
+    let exclude_ids: HashSet<String> = if resolved_id.is_none() {
+        let external_db_paths = config::external_project_db_paths(&config_layer, &beads_dir);
+        let external_statuses = storage_ctx
+            .storage
+            .resolve_external_dependency_statuses(&external_db_paths, true)?;
+        storage_ctx
+            .storage
+            .external_blockers(&external_statuses)?
+            .into_keys()
+            .collect()
+    } else {
+        HashSet::new()
+    };
+
+    let options = ClaimOptions {
+        issue_id: resolved_id,
+        filters: ReadyFilters {
+            labels_and: args.label.clone(),
+            types: parse_types(&args.type_)?,
+            priorities: parse_priorities(&args.priority)?,
+            parent: args.parent.clone(),
+            ..Default::default()
+        },
+        exclude_ids,
+        ttl,
+        exclusive: config::claim_exclusive_from_layer(&config_layer),
+    };
+
+    tracing::info!(id = ?options.issue_id, ttl_secs = ttl.num_seconds(), "Claiming issue");
+    let claimed = storage_ctx.storage.claim_issue(&options, &actor)?;
+
+    if let Some((issue, _)) = &claimed {
+        crate::util::set_last_touched_id(&beads_dir, &issue.id);
+    }
+
+    if json {
+        let result = ClaimResult {
+            issue: claimed.as_ref().map(|(issue, _)| ReadyIssue::from(issue)),
+            lease: claimed.as_ref().map(|(_, lease)| lease.clone()),
+        };
+        println!("{}", serde_json::to_string_pretty(&result)?);
+    } else {
+        render_claim(claimed.as_ref(), ctx);
+    }
+
+    storage_ctx.flush_no_db_if_dirty()?;
+    Ok(())
+}
+
+/// Return issues whose claim lease lapsed to the ready pool and persist it.
+///
+/// Read commands call this before querying so they never report an issue as
+/// `in_progress` under a lease that has already expired. The reaped state is
+/// flushed to JSONL right away, since read commands skip the auto-flush.
+///
+/// # Errors
+///
+/// Returns an error if the leases cannot be reaped or, in no-db mode, the
+/// JSONL export fails.
+pub(crate) fn reap_expired_leases(
+    storage_ctx: &mut config::OpenStorageResult,
+    cli: &config::CliOverrides,
+    actor: &str,
+) -> Result<Vec<ClaimLease>> {
+    let expired = storage_ctx.storage.expire_leases(actor)?;
+    if expired.is_empty() {
+        return Ok(expired);
+    }
+    debug!(count = expired.len(), "Reaped expired claim leases");
+
+    if storage_ctx.no_db {
+        storage_ctx.flush_no_db_if_dirty()?;
+    } else if !cli.no_auto_flush.unwrap_or(false) {
+        if let Err(e) = auto_flush(&mut storage_ctx.storage, &storage_ctx.paths.beads_dir) {
+            warn!(?e, "Auto-flush after lease expiry failed (non-fatal)");
+        }
+    }
+    Ok(expired)
+}
+
+/// Resolve the lease TTL from `--ttl`, then `claim.lease-ttl`, then the default.
+fn resolve_ttl(flag: Option<&str>, layer: &config::ConfigLayer) -> Result<Duration> {
+    if let Some(value) = flag {
+        return parse_duration(value, "ttl");
+    }
+    if let Some(value) = config::claim_lease_ttl_from_layer(layer) {
+        return parse_duration(&value, "claim.lease-ttl");
+    }
+    Ok(Duration::minutes(DEFAULT_LEASE_TTL_MINUTES))
+}
+
+fn render_claim(claimed: Option<&(Issue, ClaimLease)>, ctx: &OutputContext) {
+    let Some((issue, lease)) = claimed else {
+        ctx.info("No ready issues to claim.");
+        return;
+    };
+    ctx.success(&format!(
+        "Claimed {}: {} [{}]",
+        issue.id,
+        issue.title,
+        crate::format::format_priority(&issue.priority)
+    ));
+    ctx.print(&format!(
+        "  Lease held by {} until {} (renew: br claim {} --renew)",
+        lease.actor,
+        lease.expires_at.to_rfc3339(),
+        issue.id
+    ));
+}
+
+fn render_leases(leases: &[ClaimLease], ctx: &OutputContext) {
+    if leases.is_empty() {
+        ctx.info("No active leases.");
+        return;
+    }
+    let now = Utc::now();
+    for lease in leases {
+        let remaining = lease.expires_at - now;
+        ctx.print(&format!(
+            "{}  {}  expires {} ({}m left)",
+            lease.issue_id,
+            lease.actor,
+            lease.expires_at.to_rfc3339(),
+            remaining.num_minutes().max(0)
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logging::init_test_logging;
+    use tracing::info;
+
+    #[test]
+    fn test_resolve_ttl_prefers_flag() {
+        init_test_logging();
+        info!("test_resolve_ttl_prefers_flag: starting");
+        let mut layer = config::ConfigLayer::default();
+        layer
+            .runtime
+            .insert("claim.lease-ttl".to_string(), "2h".to_string());
+
+        assert_eq!(
+            resolve_ttl(Some("10m"), &layer).unwrap(),
+            Duration::minutes(10)
+        );
+        assert_eq!(resolve_ttl(None, &layer).unwrap(), Duration::hours(2));
+        info!("test_resolve_ttl_prefers_flag: assertions passed");
+    }
+
+    #[test]
+    fn test_resolve_ttl_default() {
+        init_test_logging();
+        let layer = config::ConfigLayer::default();
+        assert_eq!(
+            resolve_ttl(None, &layer).unwrap(),
+            Duration::minutes(DEFAULT_LEASE_TTL_MINUTES)
+        );
+    }
+
+    #[test]
+    fn test_resolve_ttl_rejects_garbage() {
+        init_test_logging();
+        let layer = config::ConfigLayer::default();
+        assert!(resolve_ttl(Some("soon"), &layer).is_err());
+    }
+}
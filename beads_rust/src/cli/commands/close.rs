@@ -2,10 +2,12 @@
 
 use crate::cli::CloseArgs as CliCloseArgs;
 use crate::config;
+use crate::config::workflow::Workflow;
 use crate::error::{BeadsError, Result};
+use crate::hooks::{self, Hooks};
 use crate::model::Status;
 use crate::output::OutputContext;
-use crate::storage::IssueUpdate;
+use crate::storage::{IssueUpdate, SqliteStorage};
 use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
@@ -136,6 +138,8 @@ pub fn execute_with_args(
     let actor = config::resolve_actor(&config_layer);
     let id_config = config::id_config_from_layer(&config_layer);
     let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
+    let workflow = Workflow::load(&config_layer)?;
+    let hooks = Hooks::load(&beads_dir, &config_layer)?;
     let all_ids = storage_ctx.storage.get_all_ids()?;
     let storage = &mut storage_ctx.storage;
 
@@ -185,73 +189,16 @@ pub fn execute_with_args(
         let id = &resolved.id;
         tracing::info!(id = %id, "Closing issue");
 
-        // Get current issue
-        let Some(issue) = storage.get_issue(id)? else {
-            skipped_issues.push(SkippedIssue {
-                id: id.clone(),
-                reason: "issue not found".to_string(),
-            });
-            continue;
-        };
-
-        // Check if already closed
-        if issue.status.is_terminal() {
-            skipped_issues.push(SkippedIssue {
-                id: id.clone(),
-                reason: format!("already {}", issue.status.as_str()),
-            });
-            continue;
-        }
+        match close_issue(storage, id, args, &actor, &workflow, &hooks)? {
+            CloseOutcome::Closed(closed) => {
+                tracing::info!(id = %id, reason = ?args.reason, "Issue closed");
 
-        // Check if blocked (unless --force)
-        if !args.force && storage.is_blocked(id)? {
-            let mut blocker_ids = storage
-                .get_blocked_issues()?
-                .into_iter()
-                .find(|(issue, _)| issue.id == *id)
-                .map(|(_, blockers)| blockers)
-                .unwrap_or_default();
-            if blocker_ids.is_empty() {
-                blocker_ids = storage.get_dependencies(id)?;
+                // Update last touched
+                crate::util::set_last_touched_id(&beads_dir, id);
+                closed_issues.push(closed);
             }
-            tracing::debug!(blocked_by = ?blocker_ids, "Issue is blocked");
-            let reason = if blocker_ids.is_empty() {
-                "blocked by dependencies".to_string()
-            } else {
-                format!("blocked by: {}", blocker_ids.join(", "))
-            };
-            skipped_issues.push(SkippedIssue {
-                id: id.clone(),
-                reason,
-            });
-            continue;
+            CloseOutcome::Skipped(skipped) => skipped_issues.push(skipped),
         }
-
-        // Build update
-        let now = Utc::now();
-        let close_reason = args.reason.clone().unwrap_or_else(|| "done".to_string());
-        let update = IssueUpdate {
-            status: Some(Status::Closed),
-            closed_at: Some(Some(now)),
-            close_reason: Some(Some(close_reason.clone())),
-            closed_by_session: args.session.clone().map(Some),
-            ..Default::default()
-        };
-
-        // Apply update
-        storage.update_issue(id, &update, &actor)?;
-        tracing::info!(id = %id, reason = ?args.reason, "Issue closed");
-
-        // Update last touched
-        crate::util::set_last_touched_id(&beads_dir, id);
-
-        closed_issues.push(ClosedIssue {
-            id: id.clone(),
-            title: issue.title.clone(),
-            status: "closed".to_string(),
-            closed_at: now.to_rfc3339(),
-            close_reason: Some(close_reason),
-        });
     }
 
     // Handle suggest-next: find issues that became unblocked
@@ -333,6 +280,126 @@ pub fn execute_with_args(
     Ok(())
 }
 
+/// Outcome of attempting to close a single issue.
+pub(crate) enum CloseOutcome {
+    Closed(ClosedIssue),
+    Skipped(SkippedIssue),
+}
+
+/// Close one resolved issue, skipping it if it is missing, already terminal,
+/// blocked (unless `args.force`), its workflow does not allow closing, or a
+/// pre-hook vetoes it.
+///
+/// # Errors
+///
+/// Returns an error if a storage read or write fails or a hook cannot run.
+pub(crate) fn close_issue(
+    storage: &mut SqliteStorage,
+    id: &str,
+    args: &CloseArgs,
+    actor: &str,
+    workflow: &Workflow,
+    hooks: &Hooks,
+) -> Result<CloseOutcome> {
+    let Some(mut issue) = storage.get_issue(id)? else {
+        return Ok(CloseOutcome::Skipped(SkippedIssue {
+            id: id.to_string(),
+            reason: "issue not found".to_string(),
+        }));
+    };
+
+    // Check if already closed
+    if issue.status.is_terminal() {
+        return Ok(CloseOutcome::Skipped(SkippedIssue {
+            id: id.to_string(),
+            reason: format!("already {}", issue.status.as_str()),
+        }));
+    }
+
+    // Check if blocked (unless --force)
+    if !args.force && storage.is_blocked(id)? {
+        let mut blocker_ids = storage
+            .get_blocked_issues()?
+            .into_iter()
+            .find(|(issue, _)| issue.id == id)
+            .map(|(_, blockers)| blockers)
+            .unwrap_or_default();
+        if blocker_ids.is_empty() {
+            blocker_ids = storage.get_dependencies(id)?;
+        }
+        tracing::debug!(blocked_by = ?blocker_ids, "Issue is blocked");
+        let reason = if blocker_ids.is_empty() {
+            "blocked by dependencies".to_string()
+        } else {
+            format!("blocked by: {}", blocker_ids.join(", "))
+        };
+        return Ok(CloseOutcome::Skipped(SkippedIssue {
+            id: id.to_string(),
+            reason,
+        }));
+    }
+
+    // Check the workflow (not bypassed by --force, which only skips blockers)
+    if !workflow.is_empty() || hooks.is_active() {
+        issue.labels = storage.get_labels(id)?;
+    }
+    if !workflow.is_empty() {
+        if let Some(reason) = workflow.violation(&issue, &Status::Closed) {
+            return Ok(CloseOutcome::Skipped(SkippedIssue {
+                id: id.to_string(),
+                reason,
+            }));
+        }
+    }
+
+    // Build update
+    let now = Utc::now();
+    let close_reason = args.reason.clone().unwrap_or_else(|| "done".to_string());
+    let from = issue.status.clone();
+    let events = hooks::status_events(&from, &Status::Closed, Some(&close_reason));
+
+    // Pre-hooks may veto the close; a hook that cannot run is an error.
+    for event in &events {
+        match hooks.pre(event, actor, &issue) {
+            Ok(()) => {}
+            Err(err @ BeadsError::HookRejected { .. }) => {
+                return Ok(CloseOutcome::Skipped(SkippedIssue {
+                    id: id.to_string(),
+                    reason: err.to_string(),
+                }));
+            }
+            Err(err) => return Err(err),
+        }
+    }
+    let update = IssueUpdate {
+        status: Some(Status::Closed),
+        closed_at: Some(Some(now)),
+        close_reason: Some(Some(close_reason.clone())),
+        closed_by_session: args.session.clone().map(Some),
+        ..Default::default()
+    };
+
+    // Apply update
+    storage.update_issue(id, &update, actor)?;
+
+    if hooks.is_active() {
+        if let Some(mut closed) = storage.get_issue(id)? {
+            closed.labels = std::mem::take(&mut issue.labels);
+            for event in &events {
+                hooks.post(event, actor, &closed);
+            }
+        }
+    }
+
+    Ok(CloseOutcome::Closed(ClosedIssue {
+        id: id.to_string(),
+        title: issue.title,
+        status: "closed".to_string(),
+        closed_at: now.to_rfc3339(),
+        close_reason: Some(close_reason),
+    }))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
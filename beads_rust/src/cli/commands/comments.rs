@@ -315,7 +315,7 @@ fn read_comment_text(args: &CommentAddArgs) -> Result<String> {
     Err(BeadsError::validation("text", "comment text required"))
 }
 
-fn resolve_author(author_override: Option<&str>, actor: Option<&str>) -> String {
+pub(crate) fn resolve_author(author_override: Option<&str>, actor: Option<&str>) -> String {
     if let Some(author) = author_override {
         if !author.trim().is_empty() {
             return author.to_string();
@@ -303,6 +303,9 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            time_entries: vec![],
+            recurrence: None,
+            attachments: vec![],
             content_hash: None,
         }
     }
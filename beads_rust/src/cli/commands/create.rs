@@ -1,6 +1,8 @@
 use crate::cli::CreateArgs;
 use crate::config;
+use crate::config::templates::{IssueTemplate, TemplateSet};
 use crate::error::{BeadsError, Result};
+use crate::hooks::{HookEvent, Hooks};
 use crate::model::{Dependency, DependencyType, Issue, IssueType, Priority, Status};
 use crate::output::OutputContext;
 use crate::storage::SqliteStorage;
@@ -18,6 +20,10 @@ pub struct CreateConfig {
     pub default_priority: Priority,
     pub default_issue_type: IssueType,
     pub actor: String,
+    /// Template applied by `--template`.
+    pub template: Option<IssueTemplate>,
+    /// Hooks run around the write.
+    pub hooks: Hooks,
 }
 
 /// Execute the create command.
@@ -40,6 +46,12 @@ pub fn execute(args: &CreateArgs, cli: &config::CliOverrides, ctx: &OutputContex
                 "--dry-run is not supported with --file",
             ));
         }
+        if args.template.is_some() {
+            return Err(BeadsError::validation(
+                "template",
+                "--template is not supported with --file",
+            ));
+        }
         return execute_import(file_path, args, cli, ctx);
     }
 
@@ -49,12 +61,18 @@ pub fn execute(args: &CreateArgs, cli: &config::CliOverrides, ctx: &OutputContex
     // We open storage even for dry-run to check ID collisions.
     let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
     let layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+    let template = match &args.template {
+        Some(name) => Some(TemplateSet::load(&beads_dir, &layer)?.get(name)?.clone()),
+        None => None,
+    };
 
     let config = CreateConfig {
         id_config: config::id_config_from_layer(&layer),
         default_priority: config::default_priority_from_layer(&layer)?,
         default_issue_type: config::default_issue_type_from_layer(&layer)?,
         actor: config::resolve_actor(&layer),
+        template,
+        hooks: Hooks::load(&beads_dir, &layer)?,
     };
 
     let issue = create_issue_impl(&mut storage_ctx.storage, args, &config)?;
@@ -176,19 +194,29 @@ pub fn create_issue_impl(
         )
     };
 
-    // 3. Parse fields
+    // 3. Parse fields (explicit args, then template, then config defaults)
+    let template = config.template.as_ref();
     let priority = if let Some(p) = &args.priority {
         Priority::from_str(p)?
     } else {
-        config.default_priority
+        template
+            .and_then(|t| t.priority)
+            .unwrap_or(config.default_priority)
     };
 
     let issue_type = if let Some(t) = &args.type_ {
         IssueType::from_str(t)?
     } else {
-        config.default_issue_type.clone()
+        template
+            .and_then(|t| t.issue_type.clone())
+            .unwrap_or_else(|| config.default_issue_type.clone())
     };
 
+    let description = template.map_or_else(
+        || args.description.clone(),
+        |t| t.scaffold(args.description.as_deref()),
+    );
+
     let due_at = parse_optional_date(args.due.as_deref())?;
     let defer_until = parse_optional_date(args.defer.as_deref())?;
 
@@ -210,7 +238,7 @@ pub fn create_issue_impl(
     let mut issue = Issue {
         id: id.clone(),
         title: title.clone(),
-        description: args.description.clone(),
+        description,
         status,
         priority,
         issue_type,
@@ -248,6 +276,9 @@ pub fn create_issue_impl(
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        time_entries: vec![],
+        recurrence: None,
+        attachments: vec![],
     };
 
     // Compute content hash
@@ -262,13 +293,38 @@ pub fn create_issue_impl(
     // 6. Populate Relations (labels & dependencies)
     populate_relations(&mut issue, args, &config.actor, now);
 
+    // 6b. Apply template labels and check its required fields
+    if let Some(template) = template {
+        for label in &template.labels {
+            if !issue.labels.contains(label) {
+                issue.labels.push(label.clone());
+            }
+        }
+        let missing = template.missing_fields(&issue);
+        if !missing.is_empty() {
+            let fields: Vec<&str> = missing.iter().map(|f| f.as_str()).collect();
+            return Err(BeadsError::validation(
+                "template",
+                format!(
+                    "template '{}' requires: {}",
+                    template.name,
+                    fields.join(", ")
+                ),
+            ));
+        }
+    }
+
     // 7. Dry Run check - return early
     if args.dry_run {
         return Ok(issue);
     }
 
-    // 8. Create (atomic)
+    // 8. Create (atomic), letting pre-create hooks veto it
+    config
+        .hooks
+        .pre(&HookEvent::Create, &config.actor, &issue)?;
     storage.create_issue(&issue, &config.actor)?;
+    config.hooks.post(&HookEvent::Create, &config.actor, &issue);
 
     Ok(issue)
 }
@@ -407,6 +463,7 @@ fn execute_import(
     let default_priority = config::default_priority_from_layer(&layer)?;
     let default_issue_type = config::default_issue_type_from_layer(&layer)?;
     let actor = config::resolve_actor(&layer);
+    let hooks = Hooks::load(&beads_dir, &layer)?;
     let now = Utc::now();
     let _json_mode = cli.json.unwrap_or(false);
     let due_at = parse_optional_date(args.due.as_deref())?;
@@ -514,6 +571,9 @@ fn execute_import(
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            time_entries: vec![],
+            recurrence: None,
+            attachments: vec![],
         };
 
         issue.content_hash = Some(issue.compute_content_hash());
@@ -574,10 +634,14 @@ fn execute_import(
             });
         }
 
-        if let Err(err) = storage.create_issue(&issue, &actor) {
+        if let Err(err) = hooks
+            .pre(&HookEvent::Create, &actor, &issue)
+            .and_then(|()| storage.create_issue(&issue, &actor))
+        {
             eprintln!("✗ Failed to create {title}: {err}");
             continue;
         }
+        hooks.post(&HookEvent::Create, &actor, &issue);
 
         if ctx.is_json() {
             if let Some(full_issue) = storage.get_issue_for_export(&id)? {
@@ -644,6 +708,7 @@ mod tests {
             dry_run: false,
             silent: false,
             file: None,
+            template: None,
         }
     }
 
@@ -658,6 +723,8 @@ mod tests {
             default_priority: Priority::MEDIUM,
             default_issue_type: IssueType::Task,
             actor: "test_user".to_string(),
+            template: None,
+            hooks: Hooks::default(),
         }
     }
 
@@ -1046,4 +1113,38 @@ mod tests {
         assert_eq!(labels, vec!["trimmed"]);
         info!("test_create_issue_trims_labels: assertions passed");
     }
+
+    #[test]
+    fn test_create_issue_applies_template() {
+        init_test_logging();
+        info!("test_create_issue_applies_template: starting");
+        let mut storage = setup_memory_storage();
+        let mut config = default_config();
+        config.template = Some(IssueTemplate {
+            name: "incident".to_string(),
+            issue_type: Some(IssueType::Bug),
+            priority: Some(Priority::HIGH),
+            labels: vec!["incident".to_string()],
+            required: vec![crate::config::templates::RequiredField::Assignee],
+            sections: vec!["## Impact".to_string()],
+            body: None,
+        });
+
+        let args = default_args();
+        let err = create_issue_impl(&mut storage, &args, &config).unwrap_err();
+        assert!(err.to_string().contains("requires: assignee"), "{err}");
+
+        let mut args = default_args();
+        args.assignee = Some("alice".to_string());
+        args.labels = vec!["db".to_string()];
+        args.priority = Some("0".to_string());
+        let issue = create_issue_impl(&mut storage, &args, &config).expect("create failed");
+
+        assert_eq!(issue.issue_type, IssueType::Bug);
+        assert_eq!(issue.priority, Priority::CRITICAL);
+        assert_eq!(issue.description.as_deref(), Some("## Impact\n"));
+        let labels = storage.get_labels(&issue.id).expect("get labels");
+        assert_eq!(labels, vec!["db", "incident"]);
+        info!("test_create_issue_applies_template: assertions passed");
+    }
 }
@@ -481,6 +481,9 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            time_entries: vec![],
+            recurrence: None,
+            attachments: vec![],
         }
     }
 
@@ -585,7 +588,7 @@ mod tests {
         let _lock = TEST_DIR_LOCK.lock().expect("dir lock");
         let temp = TempDir::new().expect("tempdir");
         let ctx = OutputContext::from_flags(false, false, true);
-        commands::init::execute(None, false, Some(temp.path()), &ctx).expect("init");
+        commands::init::execute(None, false, false, Some(temp.path()), &ctx).expect("init");
 
         let beads_dir = temp.path().join(".beads");
         let mut storage = SqliteStorage::open(&beads_dir.join("beads.db")).expect("storage");
@@ -610,7 +613,7 @@ mod tests {
         let _lock = TEST_DIR_LOCK.lock().expect("dir lock");
         let temp = TempDir::new().expect("tempdir");
         let ctx = OutputContext::from_flags(false, false, true);
-        commands::init::execute(None, false, Some(temp.path()), &ctx).expect("init");
+        commands::init::execute(None, false, false, Some(temp.path()), &ctx).expect("init");
 
         let beads_dir = temp.path().join(".beads");
         let mut storage = SqliteStorage::open(&beads_dir.join("beads.db")).expect("storage");
@@ -635,7 +638,7 @@ mod tests {
         let _lock = TEST_DIR_LOCK.lock().expect("dir lock");
         let temp = TempDir::new().expect("tempdir");
         let ctx = OutputContext::from_flags(false, false, true);
-        commands::init::execute(None, false, Some(temp.path()), &ctx).expect("init");
+        commands::init::execute(None, false, false, Some(temp.path()), &ctx).expect("init");
 
         let beads_dir = temp.path().join(".beads");
         let mut storage = SqliteStorage::open(&beads_dir.join("beads.db")).expect("storage");
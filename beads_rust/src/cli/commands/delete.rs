@@ -517,6 +517,9 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            time_entries: vec![],
+            recurrence: None,
+            attachments: vec![],
         }
     }
 
@@ -7,6 +7,7 @@ use crate::cli::{
 use crate::config;
 use crate::error::{BeadsError, Result};
 use crate::format::truncate_title;
+use crate::hooks::{HookEvent, Hooks};
 use crate::model::DependencyType;
 use crate::output::{OutputContext, OutputMode};
 use crate::storage::SqliteStorage;
@@ -43,7 +44,20 @@ pub fn execute(
     let external_db_paths = config::external_project_db_paths(&config_layer, &beads_dir);
 
     match command {
-        DepCommands::Add(args) => dep_add(args, storage, &resolver, &all_ids, &actor, json, ctx),
+        DepCommands::Add(args) => {
+            let hooks = Hooks::load(&beads_dir, &config_layer)?;
+            dep_add(
+                args,
+                storage,
+                &resolver,
+                &all_ids,
+                &external_db_paths,
+                &actor,
+                &hooks,
+                json,
+                ctx,
+            )
+        }
         DepCommands::Remove(args) => {
             dep_remove(args, storage, &resolver, &all_ids, &actor, json, ctx)
         }
@@ -75,13 +89,13 @@ pub fn execute(
 
 /// JSON output for dep add/remove operations
 #[derive(Serialize)]
-struct DepActionResult {
-    status: String,
-    issue_id: String,
-    depends_on_id: String,
+pub(crate) struct DepActionResult {
+    pub(crate) status: String,
+    pub(crate) issue_id: String,
+    pub(crate) depends_on_id: String,
     #[serde(rename = "type")]
-    dep_type: String,
-    action: String,
+    pub(crate) dep_type: String,
+    pub(crate) action: String,
 }
 
 /// JSON output for dep list
@@ -115,12 +129,15 @@ struct CyclesResult {
     count: usize,
 }
 
+#[allow(clippy::too_many_arguments)]
 fn dep_add(
     args: &DepAddArgs,
     storage: &mut SqliteStorage,
     resolver: &IdResolver,
     all_ids: &[String],
+    external_db_paths: &HashMap<String, PathBuf>,
     actor: &str,
+    hooks: &Hooks,
     _json: bool,
     ctx: &OutputContext,
 ) -> Result<()> {
@@ -130,48 +147,24 @@ fn dep_add(
     let depends_on_id = if args.depends_on.starts_with("external:") {
         args.depends_on.clone()
     } else {
-        resolve_issue_id(storage, resolver, all_ids, &args.depends_on)?
+        match resolve_issue_id(storage, resolver, all_ids, &args.depends_on) {
+            Ok(id) => id,
+            // An issue in another project becomes `external:<project>:<id>`,
+            // resolved against that project's database at query time.
+            Err(err) => SqliteStorage::locate_external_issue(external_db_paths, &args.depends_on)
+                .map(|project| format!("external:{project}:{}", args.depends_on))
+                .ok_or(err)?,
+        }
     };
 
-    // Parse and validate dependency type
-    let dep_type_str = &args.dep_type;
-    let dep_type: DependencyType = dep_type_str.parse().map_err(|_| BeadsError::Validation {
-        field: "type".to_string(),
-        reason: format!("Invalid dependency type: {dep_type_str}"),
-    })?;
-
-    // Disallow accidental custom types from typos
-    if let DependencyType::Custom(_) = dep_type {
-        // We enforce standard types for reliability unless it looks like a deliberate custom type
-        // For now, let's strictly enforce known types to prevent typos like "parent_child"
-        // which would otherwise be accepted as a non-blocking custom type.
-        return Err(BeadsError::Validation {
-            field: "type".to_string(),
-            reason: format!(
-                "Unknown dependency type: '{dep_type_str}'. \
-                 Allowed types: blocks, parent-child, conditional-blocks, waits-for, \
-                 related, discovered-from, replies-to, relates-to, duplicates, \
-                 supersedes, caused-by"
-            ),
-        });
-    }
-
-    // Self-dependency check
-    if issue_id == depends_on_id {
-        return Err(BeadsError::SelfDependency { id: issue_id });
-    }
-
-    // Cycle check for blocking types only
-    if dep_type.is_blocking()
-        && !depends_on_id.starts_with("external:")
-        && storage.would_create_cycle(&issue_id, &depends_on_id, true)?
-    {
-        return Err(BeadsError::DependencyCycle {
-            path: format!("{issue_id} -> {depends_on_id}"),
-        });
-    }
-
-    let added = storage.add_dependency(&issue_id, &depends_on_id, dep_type.as_str(), actor)?;
+    let (dep_type, added) = add_dependency_checked(
+        storage,
+        &issue_id,
+        &depends_on_id,
+        &args.dep_type,
+        actor,
+        hooks,
+    )?;
 
     if ctx.is_json() || ctx.is_toon() {
         let result = DepActionResult {
@@ -221,6 +214,86 @@ fn dep_add(
     Ok(())
 }
 
+/// Validate and add a dependency between two resolved issue IDs.
+///
+/// Rejects unknown types, self-dependencies and blocking cycles, and runs the
+/// `dep_added` hooks around a new edge. Returns the parsed type and whether a
+/// new edge was written. Shared by `br dep add` and `br serve`.
+///
+/// # Errors
+///
+/// Returns an error if validation fails, a pre-hook vetoes the edge, or the
+/// storage write fails.
+pub(crate) fn add_dependency_checked(
+    storage: &mut SqliteStorage,
+    issue_id: &str,
+    depends_on_id: &str,
+    dep_type_str: &str,
+    actor: &str,
+    hooks: &Hooks,
+) -> Result<(DependencyType, bool)> {
+    // Parse and validate dependency type
+    let dep_type: DependencyType = dep_type_str.parse().map_err(|_| BeadsError::Validation {
+        field: "type".to_string(),
+        reason: format!("Invalid dependency type: {dep_type_str}"),
+    })?;
+
+    // Disallow accidental custom types from typos
+    if let DependencyType::Custom(_) = dep_type {
+        // We enforce standard types for reliability unless it looks like a deliberate custom type
+        // For now, let's strictly enforce known types to prevent typos like "parent_child"
+        // which would otherwise be accepted as a non-blocking custom type.
+        return Err(BeadsError::Validation {
+            field: "type".to_string(),
+            reason: format!(
+                "Unknown dependency type: '{dep_type_str}'. \
+                 Allowed types: blocks, parent-child, conditional-blocks, waits-for, \
+                 related, discovered-from, replies-to, relates-to, duplicates, \
+                 supersedes, caused-by"
+            ),
+        });
+    }
+
+    // Self-dependency check
+    if issue_id == depends_on_id {
+        return Err(BeadsError::SelfDependency {
+            id: issue_id.to_string(),
+        });
+    }
+
+    // Cycle check for blocking types only
+    if dep_type.is_blocking()
+        && !depends_on_id.starts_with("external:")
+        && storage.would_create_cycle(issue_id, depends_on_id, true)?
+    {
+        return Err(BeadsError::DependencyCycle {
+            path: format!("{issue_id} -> {depends_on_id}"),
+        });
+    }
+
+    // Hooks only see new edges; re-adding an existing one is a no-op.
+    let mut hooked =
+        if hooks.is_active() && !storage.dependency_exists_between(issue_id, depends_on_id)? {
+            storage.get_issue(issue_id)?
+        } else {
+            None
+        };
+    let event = HookEvent::DepAdded {
+        depends_on_id,
+        dep_type: dep_type.as_str(),
+    };
+    if let Some(issue) = &mut hooked {
+        issue.labels = storage.get_labels(issue_id)?;
+        hooks.pre(&event, actor, issue)?;
+    }
+
+    let added = storage.add_dependency(issue_id, depends_on_id, dep_type.as_str(), actor)?;
+    if let (true, Some(issue)) = (added, &hooked) {
+        hooks.post(&event, actor, issue);
+    }
+    Ok((dep_type, added))
+}
+
 fn dep_remove(
     args: &DepRemoveArgs,
     storage: &mut SqliteStorage,
@@ -912,6 +985,9 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            time_entries: vec![],
+            recurrence: None,
+            attachments: vec![],
         }
     }
 
@@ -2,15 +2,19 @@
 
 #![allow(clippy::option_if_let_else)]
 
+use crate::attachments::BlobStore;
+use crate::cli::DoctorArgs;
 use crate::config;
 use crate::error::Result;
 use crate::output::OutputContext;
+use crate::storage::SqliteStorage;
 use crate::sync::{
     PathValidation, scan_conflict_markers, validate_no_git_path, validate_sync_path,
 };
 use rich_rust::prelude::*;
 use rusqlite::{Connection, OpenFlags};
 use serde::Serialize;
+use std::collections::HashSet;
 use std::fs::{self, File};
 use std::io::{BufRead, BufReader};
 use std::path::{Path, PathBuf};
@@ -302,6 +306,184 @@ fn check_integrity(conn: &Connection, checks: &mut Vec<CheckResult>) -> Result<(
     Ok(())
 }
 
+fn check_search_index(conn: &Connection, checks: &mut Vec<CheckResult>) -> Result<()> {
+    let has_index = conn
+        .prepare("SELECT 1 FROM sqlite_master WHERE type='table' AND name='issues_fts'")?
+        .exists([])?;
+    if !has_index {
+        push_check(
+            checks,
+            "search.fts_index",
+            CheckStatus::Warn,
+            Some("Full-text search index missing (run `br doctor --fix`)".to_string()),
+            None,
+        );
+        return Ok(());
+    }
+
+    // Index rows share their rowid with issues; VACUUM or manual edits can break that.
+    let missing: i64 = conn.query_row(
+        "SELECT count(*) FROM issues i LEFT JOIN issues_fts f ON f.rowid = i.rowid
+         WHERE f.rowid IS NULL OR f.id IS NOT i.id",
+        [],
+        |row| row.get(0),
+    )?;
+    let stale: i64 = conn.query_row(
+        "SELECT count(*) FROM issues_fts f
+         WHERE NOT EXISTS (SELECT 1 FROM issues i WHERE i.rowid = f.rowid)",
+        [],
+        |row| row.get(0),
+    )?;
+
+    if missing == 0 && stale == 0 {
+        push_check(checks, "search.fts_index", CheckStatus::Ok, None, None);
+    } else {
+        push_check(
+            checks,
+            "search.fts_index",
+            CheckStatus::Warn,
+            Some(format!(
+                "Full-text search index out of sync ({missing} missing, {stale} stale); run `br doctor --fix`"
+            )),
+            Some(serde_json::json!({ "missing": missing, "stale": stale })),
+        );
+    }
+    Ok(())
+}
+
+/// Compare attachment records with the blob store. With `fix`, delete blobs
+/// that no attachment refers to.
+fn check_attachments(
+    conn: &Connection,
+    beads_dir: &Path,
+    fix: bool,
+    checks: &mut Vec<CheckResult>,
+) -> Result<()> {
+    let has_table = conn
+        .prepare("SELECT 1 FROM sqlite_master WHERE type='table' AND name='attachments'")?
+        .exists([])?;
+    if !has_table {
+        return Ok(());
+    }
+
+    let store = BlobStore::new(beads_dir);
+    let mut stmt =
+        conn.prepare("SELECT issue_id, name, sha256, local_only FROM attachments ORDER BY 1, 2")?;
+    let rows = stmt.query_map([], |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            row.get::<_, String>(1)?,
+            row.get::<_, String>(2)?,
+            row.get::<_, bool>(3)?,
+        ))
+    })?;
+    let mut referenced = HashSet::new();
+    let mut missing = Vec::new();
+    for row in rows {
+        let (issue_id, name, sha256, local_only) = row?;
+        if !store.contains(&sha256) {
+            missing.push(serde_json::json!({
+                "issue_id": issue_id,
+                "name": name,
+                "sha256": sha256,
+                "local_only": local_only,
+            }));
+        }
+        referenced.insert(sha256);
+    }
+
+    if missing.is_empty() {
+        push_check(
+            checks,
+            "attachments.missing_blobs",
+            CheckStatus::Ok,
+            None,
+            None,
+        );
+    } else {
+        push_check(
+            checks,
+            "attachments.missing_blobs",
+            CheckStatus::Warn,
+            Some(format!(
+                "{} attachment(s) have no blob in {} (local-only blobs exist only in the clone that attached them)",
+                missing.len(),
+                store.dir().display()
+            )),
+            Some(serde_json::json!({ "missing": missing })),
+        );
+    }
+
+    let orphaned: Vec<String> = store
+        .list()?
+        .into_iter()
+        .filter(|sha256| !referenced.contains(sha256))
+        .collect();
+    if orphaned.is_empty() {
+        push_check(
+            checks,
+            "attachments.orphaned_blobs",
+            CheckStatus::Ok,
+            None,
+            None,
+        );
+    } else if fix {
+        let mut removed = 0usize;
+        for sha256 in &orphaned {
+            match store.remove(sha256) {
+                Ok(()) => removed += 1,
+                Err(err) => push_check(
+                    checks,
+                    "attachments.orphan_cleanup",
+                    CheckStatus::Error,
+                    Some(format!("Failed to delete blob {sha256}: {err}")),
+                    None,
+                ),
+            }
+        }
+        push_check(
+            checks,
+            "attachments.orphaned_blobs",
+            CheckStatus::Ok,
+            Some(format!("Deleted {removed} orphaned blob(s)")),
+            Some(serde_json::json!({ "deleted": orphaned })),
+        );
+    } else {
+        push_check(
+            checks,
+            "attachments.orphaned_blobs",
+            CheckStatus::Warn,
+            Some(format!(
+                "{} blob(s) not referenced by any attachment (run `br doctor --fix` to delete)",
+                orphaned.len()
+            )),
+            Some(serde_json::json!({ "orphaned": orphaned })),
+        );
+    }
+    Ok(())
+}
+
+fn rebuild_search_index(db_path: &Path, checks: &mut Vec<CheckResult>) {
+    let result =
+        SqliteStorage::open(db_path).and_then(|mut storage| storage.rebuild_search_index());
+    match result {
+        Ok(indexed) => push_check(
+            checks,
+            "search.fts_rebuild",
+            CheckStatus::Ok,
+            Some(format!("Rebuilt full-text search index ({indexed} issues)")),
+            None,
+        ),
+        Err(err) => push_check(
+            checks,
+            "search.fts_rebuild",
+            CheckStatus::Error,
+            Some(format!("Failed to rebuild full-text search index: {err}")),
+            None,
+        ),
+    }
+}
+
 fn check_merge_artifacts(beads_dir: &Path, checks: &mut Vec<CheckResult>) -> Result<()> {
     let mut artifacts = Vec::new();
     for entry in beads_dir.read_dir()? {
@@ -791,7 +973,7 @@ fn check_sync_metadata(
 ///
 /// Returns an error if report serialization fails or if IO operations fail.
 #[allow(clippy::too_many_lines)]
-pub fn execute(cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
+pub fn execute(args: &DoctorArgs, cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
     let mut checks = Vec::new();
     let Ok(beads_dir) = config::discover_beads_dir(None) else {
         push_check(
@@ -868,11 +1050,16 @@ pub fn execute(cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
     };
 
     let db_path = paths.db_path;
+    if args.fix && db_path.exists() {
+        rebuild_search_index(&db_path, &mut checks);
+    }
     if db_path.exists() {
         match Connection::open_with_flags(&db_path, OpenFlags::SQLITE_OPEN_READ_ONLY) {
             Ok(conn) => {
                 required_schema_checks(&conn, &mut checks)?;
                 check_integrity(&conn, &mut checks)?;
+                check_search_index(&conn, &mut checks)?;
+                check_attachments(&conn, &beads_dir, args.fix, &mut checks)?;
                 check_db_count(&conn, jsonl_count, &mut checks)?;
 
                 // SYNC SAFETY CHECK: metadata consistency (beads_rust-0v1.2.6)
@@ -937,6 +1124,69 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_check_search_index_detects_drift() {
+        let conn = Connection::open_in_memory().unwrap();
+        crate::storage::schema::apply_schema(&conn).unwrap();
+        conn.execute(
+            "INSERT INTO issues (id, title) VALUES ('bd-1', 'Indexed')",
+            [],
+        )
+        .unwrap();
+
+        let mut checks = Vec::new();
+        check_search_index(&conn, &mut checks).unwrap();
+        let check = find_check(&checks, "search.fts_index").expect("fts check");
+        assert!(matches!(check.status, CheckStatus::Ok));
+
+        conn.execute("DELETE FROM issues_fts", []).unwrap();
+        let mut checks = Vec::new();
+        check_search_index(&conn, &mut checks).unwrap();
+        let check = find_check(&checks, "search.fts_index").expect("fts check");
+        assert!(matches!(check.status, CheckStatus::Warn));
+
+        crate::storage::schema::rebuild_search_index(&conn).unwrap();
+        let mut checks = Vec::new();
+        check_search_index(&conn, &mut checks).unwrap();
+        let check = find_check(&checks, "search.fts_index").expect("fts check");
+        assert!(matches!(check.status, CheckStatus::Ok));
+    }
+
+    #[test]
+    fn test_check_attachments_missing_and_orphaned() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let beads_dir = dir.path().join(".beads");
+        let store = BlobStore::new(&beads_dir);
+        let file = dir.path().join("orphan.log");
+        fs::write(&file, b"orphan").unwrap();
+        let orphan = store.store(&file, false).unwrap();
+
+        let conn = Connection::open_in_memory().unwrap();
+        crate::storage::schema::apply_schema(&conn).unwrap();
+        conn.execute("INSERT INTO issues (id, title) VALUES ('bd-1', 'A')", [])
+            .unwrap();
+        conn.execute(
+            "INSERT INTO attachments (issue_id, name, sha256, size)
+             VALUES ('bd-1', 'gone.log', ?, 3)",
+            ["ab".repeat(32)],
+        )
+        .unwrap();
+
+        let mut checks = Vec::new();
+        check_attachments(&conn, &beads_dir, false, &mut checks).unwrap();
+        let missing = find_check(&checks, "attachments.missing_blobs").expect("missing check");
+        assert!(matches!(missing.status, CheckStatus::Warn));
+        let orphaned = find_check(&checks, "attachments.orphaned_blobs").expect("orphan check");
+        assert!(matches!(orphaned.status, CheckStatus::Warn));
+        assert!(store.contains(&orphan.sha256));
+
+        let mut checks = Vec::new();
+        check_attachments(&conn, &beads_dir, true, &mut checks).unwrap();
+        let orphaned = find_check(&checks, "attachments.orphaned_blobs").expect("orphan check");
+        assert!(matches!(orphaned.status, CheckStatus::Ok));
+        assert!(!store.contains(&orphan.sha256));
+    }
+
     #[test]
     fn test_required_schema_checks_missing_tables() {
         let conn = Connection::open_in_memory().unwrap();
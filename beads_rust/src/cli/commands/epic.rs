@@ -3,7 +3,7 @@
 use crate::cli::{EpicCloseEligibleArgs, EpicCommands, EpicStatusArgs};
 use crate::config;
 use crate::error::Result;
-use crate::model::{EpicStatus, IssueType, Status};
+use crate::model::{EpicStatus, Issue, IssueType, Status};
 use crate::output::{OutputContext, OutputMode};
 use crate::storage::{IssueUpdate, ListFilters, SqliteStorage};
 use chrono::Utc;
@@ -166,24 +166,7 @@ fn load_epic_statuses(storage: &SqliteStorage) -> Result<Vec<EpicStatus>> {
 
     let mut statuses = Vec::new();
     for epic in epics {
-        let children = storage.get_dependents_with_metadata(&epic.id)?;
-        let parent_children: Vec<_> = children
-            .into_iter()
-            .filter(|c| c.dep_type == "parent-child")
-            .collect();
-        let total_children = parent_children.len();
-        let closed_children = parent_children
-            .iter()
-            .filter(|c| matches!(c.status, Status::Closed | Status::Tombstone))
-            .count();
-        let eligible_for_close = total_children > 0 && closed_children == total_children;
-
-        statuses.push(EpicStatus {
-            epic,
-            total_children,
-            closed_children,
-            eligible_for_close,
-        });
+        statuses.push(epic_status(storage, epic)?);
     }
 
     statuses.sort_by(|a, b| {
@@ -198,6 +181,32 @@ fn load_epic_statuses(storage: &SqliteStorage) -> Result<Vec<EpicStatus>> {
     Ok(statuses)
 }
 
+/// Progress of one epic over its parent-child children.
+///
+/// # Errors
+///
+/// Returns an error if the dependents query fails.
+pub(crate) fn epic_status(storage: &SqliteStorage, epic: Issue) -> Result<EpicStatus> {
+    let children = storage.get_dependents_with_metadata(&epic.id)?;
+    let parent_children: Vec<_> = children
+        .into_iter()
+        .filter(|c| c.dep_type == "parent-child")
+        .collect();
+    let total_children = parent_children.len();
+    let closed_children = parent_children
+        .iter()
+        .filter(|c| matches!(c.status, Status::Closed | Status::Tombstone))
+        .count();
+    let eligible_for_close = total_children > 0 && closed_children == total_children;
+
+    Ok(EpicStatus {
+        epic,
+        total_children,
+        closed_children,
+        eligible_for_close,
+    })
+}
+
 fn render_epic_status(epic_status: &EpicStatus, use_color: bool) {
     let total = epic_status.total_children;
     let closed = epic_status.closed_children;
@@ -478,6 +487,9 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            time_entries: vec![],
+            recurrence: None,
+            attachments: vec![],
         }
     }
 
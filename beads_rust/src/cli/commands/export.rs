@@ -0,0 +1,149 @@
+//! Export command implementation.
+//!
+//! Writes the issue database as a static HTML site: a filterable index, one
+//! page per issue with comments and history, epic progress, and the open
+//! dependency graph. Pages are rendered by [`crate::format::html`].
+
+use crate::cli::ExportArgs;
+use crate::cli::commands::{epic, graph};
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::format::html;
+use crate::model::{IssueType, Status};
+use crate::output::OutputContext;
+use crate::storage::SqliteStorage;
+use chrono::Utc;
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// History entries shown per issue page.
+const EVENT_LIMIT: usize = 200;
+
+/// JSON output for `br export`.
+#[derive(Debug, Serialize)]
+struct ExportReport {
+    format: String,
+    out: PathBuf,
+    issues: usize,
+    epics: usize,
+    files: usize,
+}
+
+/// Execute the export command.
+///
+/// # Errors
+///
+/// Returns an error if the format is unknown, the database cannot be read,
+/// or the output directory cannot be written.
+pub fn execute(
+    args: &ExportArgs,
+    json: bool,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    if args.format != "html" {
+        return Err(BeadsError::validation(
+            "format",
+            format!("unknown export format '{}' (expected: html)", args.format),
+        ));
+    }
+
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let config_layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+    let site = args
+        .title
+        .clone()
+        .unwrap_or_else(|| config::id_config_from_layer(&config_layer).prefix);
+
+    let report = write_site(&storage_ctx.storage, &args.out, &site)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        ctx.success(&format!(
+            "Exported {} issues ({} epics) to {}",
+            report.issues,
+            report.epics,
+            report.out.join("index.html").display()
+        ));
+    }
+    Ok(())
+}
+
+fn write_site(storage: &SqliteStorage, out: &Path, site: &str) -> Result<ExportReport> {
+    let mut issues: Vec<_> = storage
+        .get_all_issues_for_export()?
+        .into_iter()
+        .filter(|issue| issue.status != Status::Tombstone)
+        .collect();
+    let mut labels = storage.get_all_labels()?;
+    for issue in &mut issues {
+        issue.labels = labels.remove(&issue.id).unwrap_or_default();
+    }
+
+    let issues_dir = out.join("issues");
+    fs::create_dir_all(&issues_dir)?;
+    remove_stale_pages(&issues_dir)?;
+
+    let mut files = 0;
+    let mut write = |path: PathBuf, contents: String| -> Result<()> {
+        fs::write(path, contents)?;
+        files += 1;
+        Ok(())
+    };
+
+    write(
+        out.join("index.html"),
+        html::render_index(site, &issues, Utc::now()),
+    )?;
+
+    let mut epics = Vec::new();
+    for issue in &issues {
+        let Some(details) = storage.get_issue_details(&issue.id, true, true, EVENT_LIMIT)? else {
+            continue;
+        };
+        let status = if issue.issue_type == IssueType::Epic {
+            Some(epic::epic_status(storage, issue.clone())?)
+        } else {
+            None
+        };
+        write(
+            out.join(html::issue_path(&issue.id)),
+            html::render_issue(site, &details, status.as_ref()),
+        )?;
+        if let Some(status) = status {
+            let children = details
+                .dependents
+                .into_iter()
+                .filter(|child| child.dep_type == "parent-child")
+                .collect();
+            epics.push((status, children));
+        }
+    }
+    write(out.join("epics.html"), html::render_epics(site, &epics))?;
+
+    let dependency_graph = graph::collect_export(storage, None, None)?;
+    let svg = graph::render_svg(&dependency_graph, html::issue_path);
+    write(out.join("graph.html"), html::render_graph(site, &svg))?;
+
+    Ok(ExportReport {
+        format: "html".to_string(),
+        out: out.to_path_buf(),
+        issues: issues.len(),
+        epics: epics.len(),
+        files,
+    })
+}
+
+/// Drop pages for issues that no longer exist so re-exports stay in sync.
+fn remove_stale_pages(issues_dir: &Path) -> Result<()> {
+    for entry in fs::read_dir(issues_dir)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "html") {
+            fs::remove_file(path)?;
+        }
+    }
+    Ok(())
+}
@@ -4,17 +4,20 @@
 //!
 //! - `br graph <issue-id>`: Show all dependents of an issue (what depends on it)
 //! - `br graph --all`: Show connected components for `open`/`in_progress`/`blocked` issues
+//! - `br graph --format dot|mermaid|graphml|cytoscape-json`: Export the graph with every
+//!   dependency type, epics as clusters, and nodes colored by status and priority
 
 use crate::cli::GraphArgs;
 use crate::config;
 use crate::error::{BeadsError, Result};
-use crate::model::{DependencyType, Issue, Status};
+use crate::model::{Dependency, DependencyType, Issue, IssueType, Status};
 use crate::output::{OutputContext, OutputMode};
 use crate::storage::{ListFilters, SqliteStorage};
 use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
 use rich_rust::prelude::*;
 use serde::Serialize;
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::fmt::Write as _;
 use tracing::debug;
 
 /// JSON output for a single node in the graph.
@@ -66,15 +69,31 @@ pub fn execute(args: &GraphArgs, cli: &config::CliOverrides, ctx: &OutputContext
     let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
     let all_ids = storage_ctx.storage.get_all_ids()?;
 
-    if args.all {
-        graph_all(&storage_ctx.storage, args.compact, ctx)
+    let format = ExportFormat::parse(&args.format)?;
+    let focus = if args.all {
+        None
     } else {
         let issue_id = args.issue.as_ref().ok_or_else(|| {
             BeadsError::validation("issue", "Issue ID required unless --all is specified")
         })?;
+        Some(resolve_issue_id(
+            &storage_ctx.storage,
+            &resolver,
+            &all_ids,
+            issue_id,
+        )?)
+    };
 
-        let resolved_id = resolve_issue_id(&storage_ctx.storage, &resolver, &all_ids, issue_id)?;
-        graph_single(&storage_ctx.storage, &resolved_id, args.compact, ctx)
+    match (format, focus) {
+        (Some(format), focus) => {
+            let graph = collect_export(&storage_ctx.storage, focus.as_deref(), args.depth)?;
+            print!("{}", format.render(&graph)?);
+            Ok(())
+        }
+        (None, None) => graph_all(&storage_ctx.storage, args.compact, ctx),
+        (None, Some(root)) => {
+            graph_single(&storage_ctx.storage, &root, args.compact, args.depth, ctx)
+        }
     }
 }
 
@@ -83,6 +102,7 @@ fn graph_single(
     storage: &SqliteStorage,
     root_id: &str,
     compact: bool,
+    max_depth: Option<usize>,
     ctx: &OutputContext,
 ) -> Result<()> {
     // Verify the root issue exists
@@ -122,6 +142,10 @@ fn graph_single(
             depth,
         });
 
+        if max_depth.is_some_and(|max| depth >= max) {
+            continue;
+        }
+
         // Get dependents (issues that depend on current_id)
         let mut dependents = storage.get_dependents_with_metadata(&current_id)?;
 
@@ -492,6 +516,699 @@ fn resolve_issue_id(
         .map(|resolved| resolved.id)
 }
 
+// ─────────────────────────────────────────────────────────────
+// Graph Export (DOT, Mermaid, GraphML, Cytoscape JSON)
+// ─────────────────────────────────────────────────────────────
+
+/// Export formats for `--format`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ExportFormat {
+    Dot,
+    Mermaid,
+    GraphMl,
+    CytoscapeJson,
+}
+
+impl ExportFormat {
+    /// Parse `--format`; `None` means the regular text/JSON output.
+    fn parse(value: &str) -> Result<Option<Self>> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "" | "text" => Ok(None),
+            "dot" => Ok(Some(Self::Dot)),
+            "mermaid" => Ok(Some(Self::Mermaid)),
+            "graphml" => Ok(Some(Self::GraphMl)),
+            "cytoscape-json" | "cytoscape" => Ok(Some(Self::CytoscapeJson)),
+            _ => Err(BeadsError::validation(
+                "format",
+                format!(
+                    "unknown format '{value}' (expected text, dot, mermaid, graphml, cytoscape-json)"
+                ),
+            )),
+        }
+    }
+
+    fn render(self, graph: &ExportGraph) -> Result<String> {
+        Ok(match self {
+            Self::Dot => render_dot(graph),
+            Self::Mermaid => render_mermaid(graph),
+            Self::GraphMl => render_graphml(graph),
+            Self::CytoscapeJson => {
+                let mut json = serde_json::to_string_pretty(&cytoscape_elements(graph))?;
+                json.push('\n');
+                json
+            }
+        })
+    }
+}
+
+/// Issues and dependencies selected for export.
+#[derive(Debug, Default)]
+pub(crate) struct ExportGraph {
+    /// Sorted by ID.
+    nodes: Vec<Issue>,
+    /// Every dependency (of any type) between two exported issues.
+    edges: Vec<Dependency>,
+    /// Epic an issue is clustered under (via parent-child).
+    epic_of: HashMap<String, String>,
+}
+
+impl ExportGraph {
+    fn new(mut nodes: Vec<Issue>, all_dependencies: &HashMap<String, Vec<Dependency>>) -> Self {
+        nodes.sort_by(|a, b| a.id.cmp(&b.id));
+        let types: HashMap<&str, &IssueType> = nodes
+            .iter()
+            .map(|issue| (issue.id.as_str(), &issue.issue_type))
+            .collect();
+
+        let mut edges = Vec::new();
+        let mut epic_of = HashMap::new();
+        for issue in &nodes {
+            for dep in all_dependencies.get(&issue.id).into_iter().flatten() {
+                let Some(target_type) = types.get(dep.depends_on_id.as_str()) else {
+                    continue;
+                };
+                if dep.dep_type == DependencyType::ParentChild
+                    && **target_type == IssueType::Epic
+                    && dep.depends_on_id != issue.id
+                {
+                    epic_of
+                        .entry(issue.id.clone())
+                        .or_insert_with(|| dep.depends_on_id.clone());
+                }
+                edges.push(dep.clone());
+            }
+        }
+        edges.sort_by(|a, b| {
+            (&a.issue_id, &a.depends_on_id, a.dep_type.as_str()).cmp(&(
+                &b.issue_id,
+                &b.depends_on_id,
+                b.dep_type.as_str(),
+            ))
+        });
+
+        Self {
+            nodes,
+            edges,
+            epic_of,
+        }
+    }
+
+    /// Members of each epic cluster, in node order.
+    fn clusters(&self) -> BTreeMap<&str, Vec<&Issue>> {
+        let mut clusters: BTreeMap<&str, Vec<&Issue>> = BTreeMap::new();
+        for issue in &self.nodes {
+            if let Some(epic) = self.epic_of.get(&issue.id) {
+                clusters.entry(epic.as_str()).or_default().push(issue);
+            }
+        }
+        clusters
+    }
+
+    /// Nodes in drawing order, with epic clusters opened and closed around
+    /// their members, so each format only decides how to print them.
+    fn layout(&self) -> Vec<LayoutItem<'_>> {
+        let clusters = self.clusters();
+        let mut visited: HashSet<&str> = HashSet::new();
+        let mut items = Vec::new();
+        for issue in self
+            .nodes
+            .iter()
+            .filter(|i| !self.epic_of.contains_key(&i.id))
+        {
+            layout_issue(issue, 1, &clusters, &mut visited, &mut items);
+        }
+        // Epic parent-child cycles leave members unreachable from the top.
+        for issue in &self.nodes {
+            layout_issue(issue, 1, &clusters, &mut visited, &mut items);
+        }
+        items
+    }
+}
+
+/// One step of [`ExportGraph::layout`]; `usize` is the nesting level.
+enum LayoutItem<'a> {
+    Enter(&'a Issue, usize),
+    Node(&'a Issue, usize),
+    Leave(usize),
+}
+
+fn layout_issue<'a>(
+    issue: &'a Issue,
+    level: usize,
+    clusters: &BTreeMap<&str, Vec<&'a Issue>>,
+    visited: &mut HashSet<&'a str>,
+    items: &mut Vec<LayoutItem<'a>>,
+) {
+    if !visited.insert(issue.id.as_str()) {
+        return;
+    }
+    match clusters.get(issue.id.as_str()) {
+        Some(members) => {
+            items.push(LayoutItem::Enter(issue, level));
+            items.push(LayoutItem::Node(issue, level + 1));
+            for member in members {
+                layout_issue(member, level + 1, clusters, visited, items);
+            }
+            items.push(LayoutItem::Leave(level));
+        }
+        None => items.push(LayoutItem::Node(issue, level)),
+    }
+}
+
+/// Pick the issues to export: every open/in-progress/blocked issue with
+/// `--all`, otherwise everything reachable from `focus` over dependencies of
+/// any type, in either direction, up to `depth` hops.
+pub(crate) fn collect_export(
+    storage: &SqliteStorage,
+    focus: Option<&str>,
+    depth: Option<usize>,
+) -> Result<ExportGraph> {
+    let all_dependencies = storage.get_all_dependency_records()?;
+
+    let Some(root) = focus else {
+        let filters = ListFilters {
+            statuses: Some(vec![Status::Open, Status::InProgress, Status::Blocked]),
+            include_closed: false,
+            include_templates: false,
+            ..Default::default()
+        };
+        return Ok(ExportGraph::new(
+            storage.list_issues(&filters)?,
+            &all_dependencies,
+        ));
+    };
+
+    let mut neighbors: HashMap<&str, Vec<&str>> = HashMap::new();
+    for dep in all_dependencies.values().flatten() {
+        neighbors
+            .entry(dep.issue_id.as_str())
+            .or_default()
+            .push(dep.depends_on_id.as_str());
+        neighbors
+            .entry(dep.depends_on_id.as_str())
+            .or_default()
+            .push(dep.issue_id.as_str());
+    }
+
+    let mut seen: HashSet<&str> = HashSet::from([root]);
+    let mut queue: VecDeque<(&str, usize)> = VecDeque::from([(root, 0)]);
+    while let Some((current, hops)) = queue.pop_front() {
+        if depth.is_some_and(|max| hops >= max) {
+            continue;
+        }
+        for &next in neighbors.get(current).into_iter().flatten() {
+            if seen.insert(next) {
+                queue.push_back((next, hops + 1));
+            }
+        }
+    }
+
+    let ids: Vec<String> = seen.into_iter().map(str::to_string).collect();
+    // External references (`external:...`) have no issue row and are skipped.
+    Ok(ExportGraph::new(
+        storage.get_issues_by_ids(&ids)?,
+        &all_dependencies,
+    ))
+}
+
+/// Fill color for a status.
+fn status_color(status: &Status) -> &'static str {
+    match status {
+        Status::Open => "#e3f2fd",
+        Status::InProgress => "#fff3cd",
+        Status::Blocked => "#f8d7da",
+        Status::Deferred => "#ede7f6",
+        Status::Closed | Status::Tombstone => "#d4edda",
+        Status::Pinned => "#fce4ec",
+        Status::Custom(_) => "#ffffff",
+    }
+}
+
+/// Border color for a priority (P0 red … P4 light gray).
+const fn priority_color(priority: i32) -> &'static str {
+    match priority {
+        0 => "#d62728",
+        1 => "#ff7f0e",
+        2 => "#1f77b4",
+        3 => "#7f7f7f",
+        _ => "#c7c7c7",
+    }
+}
+
+/// How an edge of a given dependency type is drawn.
+struct EdgeStyle {
+    /// DOT `style`.
+    dot_style: &'static str,
+    color: &'static str,
+    /// Mermaid link (the type name is added as its label).
+    mermaid: &'static str,
+    /// Drawn without an arrowhead (symmetric relations).
+    undirected: bool,
+}
+
+const fn edge_style(dep_type: &DependencyType) -> EdgeStyle {
+    let (dot_style, color, mermaid, undirected) = match dep_type {
+        DependencyType::Blocks => ("bold", "#d62728", "==>", false),
+        DependencyType::ConditionalBlocks => ("dashed", "#d62728", "-.->", false),
+        DependencyType::WaitsFor => ("dashed", "#ff7f0e", "-.->", false),
+        DependencyType::ParentChild => ("solid", "#6a3d9a", "-->", false),
+        DependencyType::Related | DependencyType::RelatesTo => ("dotted", "#7f7f7f", "-.-", true),
+        DependencyType::DiscoveredFrom => ("dotted", "#2ca02c", "-.->", false),
+        DependencyType::RepliesTo => ("dotted", "#17becf", "-.->", false),
+        DependencyType::Duplicates => ("dashed", "#8c564b", "--o", false),
+        DependencyType::Supersedes => ("solid", "#8c564b", "--x", false),
+        DependencyType::CausedBy => ("solid", "#e377c2", "-->", false),
+        DependencyType::Custom(_) => ("solid", "#bcbd22", "-->", false),
+    };
+    EdgeStyle {
+        dot_style,
+        color,
+        mermaid,
+        undirected,
+    }
+}
+
+fn node_label(issue: &Issue) -> String {
+    format!(
+        "{}: {} [P{}] [{}]",
+        issue.id,
+        issue.title,
+        issue.priority.0,
+        issue.status.as_str()
+    )
+}
+
+fn dot_escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn render_dot(graph: &ExportGraph) -> String {
+    let mut out = String::from("digraph beads {\n    rankdir=LR;\n    compound=true;\n");
+    out.push_str("    node [shape=box, style=\"rounded,filled\", penwidth=2];\n");
+
+    for item in graph.layout() {
+        match item {
+            LayoutItem::Enter(epic, level) => {
+                let indent = "    ".repeat(level);
+                let _ = writeln!(
+                    out,
+                    "{indent}subgraph \"cluster_{}\" {{\n{indent}    label=\"{}\";\n{indent}    style=dashed;",
+                    dot_escape(&epic.id),
+                    dot_escape(&format!("{}: {}", epic.id, epic.title))
+                );
+            }
+            LayoutItem::Node(issue, level) => {
+                let _ = writeln!(
+                    out,
+                    "{}\"{}\" [label=\"{}\", fillcolor=\"{}\", color=\"{}\"];",
+                    "    ".repeat(level),
+                    dot_escape(&issue.id),
+                    dot_escape(&node_label(issue)),
+                    status_color(&issue.status),
+                    priority_color(issue.priority.0)
+                );
+            }
+            LayoutItem::Leave(level) => {
+                let _ = writeln!(out, "{}}}", "    ".repeat(level));
+            }
+        }
+    }
+
+    for dep in &graph.edges {
+        let style = edge_style(&dep.dep_type);
+        let arrowhead = if style.undirected { ", dir=none" } else { "" };
+        let _ = writeln!(
+            out,
+            "    \"{}\" -> \"{}\" [label=\"{}\", style={}, color=\"{}\"{arrowhead}];",
+            dot_escape(&dep.issue_id),
+            dot_escape(&dep.depends_on_id),
+            dot_escape(dep.dep_type.as_str()),
+            style.dot_style,
+            style.color
+        );
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// Mermaid node IDs may not contain `-` or `.`, so map them to safe names.
+fn mermaid_id(id: &str) -> String {
+    let safe: String = id
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    format!("n_{safe}")
+}
+
+fn mermaid_escape(value: &str) -> String {
+    value.replace('"', "#quot;")
+}
+
+fn render_mermaid(graph: &ExportGraph) -> String {
+    let mut out = String::from("graph LR\n");
+
+    for item in graph.layout() {
+        match item {
+            LayoutItem::Enter(epic, level) => {
+                let _ = writeln!(
+                    out,
+                    "{}subgraph cluster_{}[\"{}\"]",
+                    "    ".repeat(level),
+                    mermaid_id(&epic.id),
+                    mermaid_escape(&format!("{}: {}", epic.id, epic.title))
+                );
+            }
+            LayoutItem::Node(issue, level) => {
+                let _ = writeln!(
+                    out,
+                    "{}{}[\"{}\"]",
+                    "    ".repeat(level),
+                    mermaid_id(&issue.id),
+                    mermaid_escape(&node_label(issue))
+                );
+            }
+            LayoutItem::Leave(level) => {
+                let _ = writeln!(out, "{}end", "    ".repeat(level));
+            }
+        }
+    }
+
+    for dep in &graph.edges {
+        let _ = writeln!(
+            out,
+            "    {} {}|{}| {}",
+            mermaid_id(&dep.issue_id),
+            edge_style(&dep.dep_type).mermaid,
+            dep.dep_type.as_str(),
+            mermaid_id(&dep.depends_on_id)
+        );
+    }
+    for (i, dep) in graph.edges.iter().enumerate() {
+        let _ = writeln!(
+            out,
+            "    linkStyle {i} stroke:{}",
+            edge_style(&dep.dep_type).color
+        );
+    }
+    for issue in &graph.nodes {
+        let _ = writeln!(
+            out,
+            "    style {} fill:{},stroke:{},stroke-width:2px",
+            mermaid_id(&issue.id),
+            status_color(&issue.status),
+            priority_color(issue.priority.0)
+        );
+    }
+    out
+}
+
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn render_graphml(graph: &ExportGraph) -> String {
+    let mut out = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n",
+    );
+    for (id, target, name) in [
+        ("title", "node", "title"),
+        ("status", "node", "status"),
+        ("priority", "node", "priority"),
+        ("type", "node", "issue_type"),
+        ("epic", "node", "epic"),
+        ("color", "node", "color"),
+        ("border", "node", "border_color"),
+        ("dep_type", "edge", "dep_type"),
+        ("edge_color", "edge", "color"),
+        ("edge_style", "edge", "style"),
+    ] {
+        let kind = if id == "priority" { "int" } else { "string" };
+        let _ = writeln!(
+            out,
+            "  <key id=\"{id}\" for=\"{target}\" attr.name=\"{name}\" attr.type=\"{kind}\"/>"
+        );
+    }
+    out.push_str("  <graph id=\"beads\" edgedefault=\"directed\">\n");
+
+    for issue in &graph.nodes {
+        let _ = writeln!(out, "    <node id=\"{}\">", xml_escape(&issue.id));
+        let mut data = vec![
+            ("title", issue.title.clone()),
+            ("status", issue.status.as_str().to_string()),
+            ("priority", issue.priority.0.to_string()),
+            ("type", issue.issue_type.as_str().to_string()),
+        ];
+        if let Some(epic) = graph.epic_of.get(&issue.id) {
+            data.push(("epic", epic.clone()));
+        }
+        data.push(("color", status_color(&issue.status).to_string()));
+        data.push(("border", priority_color(issue.priority.0).to_string()));
+        for (key, value) in data {
+            let _ = writeln!(
+                out,
+                "      <data key=\"{key}\">{}</data>",
+                xml_escape(&value)
+            );
+        }
+        out.push_str("    </node>\n");
+    }
+
+    for (i, dep) in graph.edges.iter().enumerate() {
+        let style = edge_style(&dep.dep_type);
+        let _ = writeln!(
+            out,
+            "    <edge id=\"e{i}\" source=\"{}\" target=\"{}\">\n      \
+             <data key=\"dep_type\">{}</data>\n      \
+             <data key=\"edge_color\">{}</data>\n      \
+             <data key=\"edge_style\">{}</data>\n    </edge>",
+            xml_escape(&dep.issue_id),
+            xml_escape(&dep.depends_on_id),
+            xml_escape(dep.dep_type.as_str()),
+            style.color,
+            style.dot_style
+        );
+    }
+    out.push_str("  </graph>\n</graphml>\n");
+    out
+}
+
+/// Inline SVG for the static site. Each issue sits one column right of
+/// everything it depends on; `href` maps an issue ID to its page.
+#[allow(clippy::too_many_lines)]
+pub(crate) fn render_svg(graph: &ExportGraph, href: impl Fn(&str) -> String) -> String {
+    const NODE_WIDTH: usize = 220;
+    const NODE_HEIGHT: usize = 44;
+    const COLUMN_GAP: usize = 70;
+    const ROW_GAP: usize = 16;
+    const MARGIN: usize = 20;
+
+    if graph.nodes.is_empty() {
+        return "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"320\" height=\"60\">\
+                <text x=\"20\" y=\"35\">No open issues</text></svg>\n"
+            .to_string();
+    }
+
+    let index: HashMap<&str, usize> = graph
+        .nodes
+        .iter()
+        .enumerate()
+        .map(|(i, issue)| (issue.id.as_str(), i))
+        .collect();
+    let directed: Vec<(usize, usize)> = graph
+        .edges
+        .iter()
+        .filter(|dep| !edge_style(&dep.dep_type).undirected)
+        .filter_map(|dep| {
+            Some((
+                *index.get(dep.depends_on_id.as_str())?,
+                *index.get(dep.issue_id.as_str())?,
+            ))
+        })
+        .filter(|(from, to)| from != to)
+        .collect();
+
+    // Longest-path layering; the column cap stops cycles from growing forever.
+    let mut column = vec![0usize; graph.nodes.len()];
+    for _ in 0..graph.nodes.len() {
+        let mut changed = false;
+        for &(from, to) in &directed {
+            if column[to] <= column[from] && column[from] + 1 < graph.nodes.len() {
+                column[to] = column[from] + 1;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+    let mut row = vec![0usize; graph.nodes.len()];
+    let mut rows_in_column: BTreeMap<usize, usize> = BTreeMap::new();
+    for (i, &col) in column.iter().enumerate() {
+        let next = rows_in_column.entry(col).or_default();
+        row[i] = *next;
+        *next += 1;
+    }
+    let columns = column.iter().max().map_or(1, |max| max + 1);
+    let rows = rows_in_column.values().max().copied().unwrap_or(1);
+    let position = |i: usize| {
+        (
+            MARGIN + column[i] * (NODE_WIDTH + COLUMN_GAP),
+            MARGIN + row[i] * (NODE_HEIGHT + ROW_GAP),
+        )
+    };
+
+    let width = 2 * MARGIN + columns * NODE_WIDTH + (columns - 1) * COLUMN_GAP;
+    let height = 2 * MARGIN + rows * NODE_HEIGHT + (rows - 1) * ROW_GAP;
+    let mut out = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" class=\"graph\" width=\"{width}\" \
+         height=\"{height}\" viewBox=\"0 0 {width} {height}\" font-family=\"sans-serif\" \
+         font-size=\"12\">\n<defs>\n"
+    );
+    let colors: BTreeSet<&str> = graph
+        .edges
+        .iter()
+        .map(|dep| edge_style(&dep.dep_type).color)
+        .collect();
+    for color in colors {
+        let _ = writeln!(
+            out,
+            "<marker id=\"arrow-{}\" viewBox=\"0 0 10 10\" refX=\"10\" refY=\"5\" \
+             markerWidth=\"8\" markerHeight=\"8\" orient=\"auto-start-reverse\">\
+             <path d=\"M 0 0 L 10 5 L 0 10 z\" fill=\"{color}\"/></marker>",
+            color.trim_start_matches('#')
+        );
+    }
+    out.push_str("</defs>\n");
+
+    for dep in &graph.edges {
+        let (Some(&source), Some(&target)) = (
+            index.get(dep.depends_on_id.as_str()),
+            index.get(dep.issue_id.as_str()),
+        ) else {
+            continue;
+        };
+        let style = edge_style(&dep.dep_type);
+        let ((sx, sy), (tx, ty)) = (position(source), position(target));
+        // Forward edges run between facing sides; others between centers.
+        let (x1, y1, x2, y2) = if column[source] < column[target] {
+            (
+                sx + NODE_WIDTH,
+                sy + NODE_HEIGHT / 2,
+                tx,
+                ty + NODE_HEIGHT / 2,
+            )
+        } else {
+            (
+                sx + NODE_WIDTH / 2,
+                sy + NODE_HEIGHT / 2,
+                tx + NODE_WIDTH / 2,
+                ty + NODE_HEIGHT / 2,
+            )
+        };
+        let dash = match style.dot_style {
+            "dashed" => " stroke-dasharray=\"6 4\"",
+            "dotted" => " stroke-dasharray=\"2 3\"",
+            _ => "",
+        };
+        let stroke_width = if style.dot_style == "bold" {
+            "2.5"
+        } else {
+            "1.5"
+        };
+        let arrow = if style.undirected {
+            String::new()
+        } else {
+            format!(
+                " marker-end=\"url(#arrow-{})\"",
+                style.color.trim_start_matches('#')
+            )
+        };
+        let _ = writeln!(
+            out,
+            "<line x1=\"{x1}\" y1=\"{y1}\" x2=\"{x2}\" y2=\"{y2}\" stroke=\"{}\" \
+             stroke-width=\"{stroke_width}\"{dash}{arrow}><title>{} {} {}</title></line>",
+            style.color,
+            xml_escape(&dep.issue_id),
+            xml_escape(dep.dep_type.as_str()),
+            xml_escape(&dep.depends_on_id)
+        );
+    }
+
+    for (i, issue) in graph.nodes.iter().enumerate() {
+        let (x, y) = position(i);
+        let _ = writeln!(
+            out,
+            "<a href=\"{}\"><title>{}</title>\
+             <rect x=\"{x}\" y=\"{y}\" width=\"{NODE_WIDTH}\" height=\"{NODE_HEIGHT}\" rx=\"6\" \
+             fill=\"{}\" stroke=\"{}\" stroke-width=\"2\"/>\
+             <text x=\"{}\" y=\"{}\" font-weight=\"bold\">{}</text>\
+             <text x=\"{}\" y=\"{}\">{}</text></a>",
+            xml_escape(&href(&issue.id)),
+            xml_escape(&node_label(issue)),
+            status_color(&issue.status),
+            priority_color(issue.priority.0),
+            x + 8,
+            y + 18,
+            xml_escape(&issue.id),
+            x + 8,
+            y + 35,
+            xml_escape(&crate::format::truncate_title(&issue.title, 32))
+        );
+    }
+    out.push_str("</svg>\n");
+    out
+}
+
+/// Cytoscape.js `elements` JSON; epics become compound parent nodes.
+fn cytoscape_elements(graph: &ExportGraph) -> serde_json::Value {
+    let nodes: Vec<serde_json::Value> = graph
+        .nodes
+        .iter()
+        .map(|issue| {
+            let mut data = serde_json::json!({
+                "id": issue.id,
+                "label": node_label(issue),
+                "title": issue.title,
+                "status": issue.status.as_str(),
+                "priority": issue.priority.0,
+                "issue_type": issue.issue_type.as_str(),
+                "color": status_color(&issue.status),
+                "border_color": priority_color(issue.priority.0),
+            });
+            if let Some(epic) = graph.epic_of.get(&issue.id) {
+                data["parent"] = serde_json::Value::from(epic.as_str());
+            }
+            serde_json::json!({ "data": data })
+        })
+        .collect();
+
+    let edges: Vec<serde_json::Value> = graph
+        .edges
+        .iter()
+        .map(|dep| {
+            let style = edge_style(&dep.dep_type);
+            serde_json::json!({
+                "data": {
+                    "id": format!("{}->{}:{}", dep.issue_id, dep.depends_on_id, dep.dep_type.as_str()),
+                    "source": dep.issue_id,
+                    "target": dep.depends_on_id,
+                    "dep_type": dep.dep_type.as_str(),
+                    "color": style.color,
+                    "line_style": style.dot_style,
+                    "directed": !style.undirected,
+                }
+            })
+        })
+        .collect();
+
+    serde_json::json!({ "elements": { "nodes": nodes, "edges": edges } })
+}
+
 // ─────────────────────────────────────────────────────────────
 // Rich Output Rendering
 // ─────────────────────────────────────────────────────────────
@@ -1133,4 +1850,148 @@ mod tests {
         let result = graph_all(&storage, false, &ctx);
         assert!(result.is_ok());
     }
+
+    fn export_issue(id: &str, issue_type: IssueType, status: Status, priority: i32) -> Issue {
+        let now = chrono::Utc::now();
+        Issue {
+            id: id.to_string(),
+            title: format!("Issue {id}"),
+            status,
+            priority: crate::model::Priority(priority),
+            issue_type,
+            created_at: now,
+            updated_at: now,
+            ..Default::default()
+        }
+    }
+
+    /// epic <- story (parent-child), story <- task (blocks), task ~ note (related).
+    fn export_storage() -> SqliteStorage {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        for issue in [
+            export_issue("bd-epic", IssueType::Epic, Status::Open, 1),
+            export_issue("bd-story", IssueType::Feature, Status::InProgress, 0),
+            export_issue("bd-task", IssueType::Task, Status::Blocked, 2),
+            export_issue("bd-note", IssueType::Task, Status::Open, 4),
+        ] {
+            storage.create_issue(&issue, "test").unwrap();
+        }
+        storage
+            .add_dependency("bd-story", "bd-epic", "parent-child", "test")
+            .unwrap();
+        storage
+            .add_dependency("bd-task", "bd-story", "blocks", "test")
+            .unwrap();
+        storage
+            .add_dependency("bd-note", "bd-task", "related", "test")
+            .unwrap();
+        storage
+    }
+
+    #[test]
+    fn test_export_format_parse() {
+        assert_eq!(ExportFormat::parse("text").unwrap(), None);
+        assert_eq!(ExportFormat::parse("").unwrap(), None);
+        assert_eq!(ExportFormat::parse("DOT").unwrap(), Some(ExportFormat::Dot));
+        assert_eq!(
+            ExportFormat::parse("cytoscape-json").unwrap(),
+            Some(ExportFormat::CytoscapeJson)
+        );
+        assert!(ExportFormat::parse("svg").is_err());
+    }
+
+    #[test]
+    fn test_collect_export_depth_and_clusters() {
+        let storage = export_storage();
+
+        let all = collect_export(&storage, None, None).unwrap();
+        assert_eq!(all.nodes.len(), 4);
+        assert_eq!(all.edges.len(), 3);
+        assert_eq!(
+            all.epic_of.get("bd-story").map(String::as_str),
+            Some("bd-epic")
+        );
+
+        // Depth counts hops in either direction from the focus issue.
+        let near = collect_export(&storage, Some("bd-story"), Some(1)).unwrap();
+        let ids: Vec<&str> = near.nodes.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, vec!["bd-epic", "bd-story", "bd-task"]);
+        assert_eq!(near.edges.len(), 2);
+
+        let far = collect_export(&storage, Some("bd-story"), None).unwrap();
+        assert_eq!(far.nodes.len(), 4);
+    }
+
+    #[test]
+    fn test_render_dot_clusters_and_styles() {
+        let graph = collect_export(&export_storage(), None, None).unwrap();
+        let dot = render_dot(&graph);
+        assert!(dot.starts_with("digraph beads {"));
+        assert!(dot.contains("subgraph \"cluster_bd-epic\""));
+        assert!(dot.contains(
+            "\"bd-task\" -> \"bd-story\" [label=\"blocks\", style=bold, color=\"#d62728\"]"
+        ));
+        assert!(dot.contains("dir=none"));
+        // P0 border and in_progress fill on the story.
+        assert!(dot.contains("fillcolor=\"#fff3cd\", color=\"#d62728\""));
+        assert!(dot.trim_end().ends_with('}'));
+    }
+
+    #[test]
+    fn test_render_mermaid_sanitizes_ids() {
+        let graph = collect_export(&export_storage(), None, None).unwrap();
+        let mermaid = render_mermaid(&graph);
+        assert!(mermaid.starts_with("graph LR\n"));
+        assert!(mermaid.contains("subgraph cluster_n_bd_epic"));
+        assert!(mermaid.contains("n_bd_task ==>|blocks| n_bd_story"));
+        assert!(mermaid.contains("n_bd_note -.-|related| n_bd_task"));
+        assert_eq!(
+            mermaid.matches("subgraph").count(),
+            mermaid.lines().filter(|l| l.trim() == "end").count()
+        );
+    }
+
+    #[test]
+    fn test_render_graphml_and_cytoscape() {
+        let mut graph = collect_export(&export_storage(), None, None).unwrap();
+        graph.nodes[0].title = "A <b> & \"c\"".to_string();
+
+        let graphml = render_graphml(&graph);
+        assert!(graphml.contains("A &lt;b&gt; &amp; &quot;c&quot;"));
+        assert!(graphml.contains("<data key=\"epic\">bd-epic</data>"));
+        assert!(graphml.contains("<data key=\"dep_type\">parent-child</data>"));
+
+        let elements = cytoscape_elements(&graph);
+        let nodes = elements["elements"]["nodes"].as_array().unwrap();
+        let story = nodes
+            .iter()
+            .find(|n| n["data"]["id"] == "bd-story")
+            .unwrap();
+        assert_eq!(story["data"]["parent"], "bd-epic");
+        assert_eq!(story["data"]["border_color"], "#d62728");
+        assert_eq!(elements["elements"]["edges"].as_array().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_render_svg_places_dependents_to_the_right() {
+        let graph = collect_export(&export_storage(), None, None).unwrap();
+        let svg = render_svg(&graph, |id| format!("issues/{id}.html"));
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert!(svg.contains("<a href=\"issues/bd-task.html\">"));
+        assert_eq!(svg.matches("<line ").count(), 3);
+
+        // epic (column 0) <- story (1) <- task (2); related adds no column.
+        let x_of = |id: &str| {
+            let anchor = svg.find(&format!("issues/{id}.html")).unwrap();
+            let rect = &svg[anchor..];
+            let start = rect.find("<rect x=\"").unwrap() + 9;
+            rect[start..start + rect[start..].find('"').unwrap()]
+                .parse::<usize>()
+                .unwrap()
+        };
+        assert!(x_of("bd-epic") < x_of("bd-story"));
+        assert!(x_of("bd-story") < x_of("bd-task"));
+        assert_eq!(x_of("bd-note"), x_of("bd-epic"));
+    }
 }
@@ -3,6 +3,7 @@
 use crate::cli::InfoArgs;
 use crate::config;
 use crate::error::Result;
+use crate::format::format_bytes;
 use crate::output::OutputContext;
 use crate::storage::SqliteStorage;
 use crate::storage::schema::CURRENT_SCHEMA_VERSION;
@@ -317,51 +318,6 @@ fn render_info_rich(info: &InfoOutput, ctx: &OutputContext) {
     console.print_renderable(&panel);
 }
 
-/// Format bytes as human-readable size.
-fn format_bytes(bytes: u64) -> String {
-    const KB: u64 = 1024;
-    const MB: u64 = KB * 1024;
-    const GB: u64 = MB * 1024;
-
-    if bytes >= GB {
-        format!("{:.1} GB", bytes as f64 / GB as f64)
-    } else if bytes >= MB {
-        format!("{:.1} MB", bytes as f64 / MB as f64)
-    } else if bytes >= KB {
-        format!("{:.1} KB", bytes as f64 / KB as f64)
-    } else {
-        format!("{bytes} B")
-    }
-}
-
 fn canonicalize_lossy(path: &Path) -> PathBuf {
     path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_format_bytes_small() {
-        assert_eq!(format_bytes(500), "500 B");
-        assert_eq!(format_bytes(0), "0 B");
-    }
-
-    #[test]
-    fn test_format_bytes_kb() {
-        assert_eq!(format_bytes(1024), "1.0 KB");
-        assert_eq!(format_bytes(1536), "1.5 KB");
-    }
-
-    #[test]
-    fn test_format_bytes_mb() {
-        assert_eq!(format_bytes(1024 * 1024), "1.0 MB");
-        assert_eq!(format_bytes(2_500_000), "2.4 MB");
-    }
-
-    #[test]
-    fn test_format_bytes_gb() {
-        assert_eq!(format_bytes(1024 * 1024 * 1024), "1.0 GB");
-    }
-}
@@ -4,27 +4,54 @@ use crate::storage::SqliteStorage;
 use crate::util::db_path;
 use rich_rust::prelude::*;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Name of the merge driver in `.gitattributes` and git config.
+const MERGE_DRIVER_NAME: &str = "beads";
+
+/// Command git runs for the merge driver (ancestor, ours, theirs).
+const MERGE_DRIVER_COMMAND: &str = "br merge-driver %O %A %B";
 
 /// Execute the init command.
 ///
 /// # Errors
 ///
-/// Returns an error if the directory or database cannot be created.
+/// Returns an error if the directory or database cannot be created, or if
+/// `merge_driver` is set outside a git repository or git cannot be configured.
+#[allow(clippy::too_many_lines)]
 pub fn execute(
     prefix: Option<String>,
     force: bool,
+    merge_driver: bool,
     root_dir: Option<&Path>,
     ctx: &OutputContext,
 ) -> Result<()> {
     let base_dir = root_dir.unwrap_or_else(|| Path::new("."));
     let beads_dir = base_dir.join(".beads");
 
+    // Locate the repository first so a failed install leaves nothing behind.
+    let git_root = find_git_root(base_dir);
+    if merge_driver && git_root.is_none() {
+        return Err(BeadsError::Config(
+            "--merge-driver requires a git repository (run `git init` first)".to_string(),
+        ));
+    }
+    let driver_root = git_root.as_deref().filter(|_| merge_driver);
+
     let mut created_dir = false;
     if beads_dir.exists() {
         // Check if DB exists (in cache dir if BEADS_CACHE_DIR is set)
         let effective_db_path = db_path(&beads_dir);
         if effective_db_path.exists() && !force {
+            // An existing workspace can still opt into the merge driver.
+            if let Some(root) = driver_root {
+                let changed = install_merge_driver(root, &beads_dir)?;
+                if !matches!(ctx.mode(), OutputMode::Quiet) {
+                    println!("{}", merge_driver_message(changed));
+                }
+                return Ok(());
+            }
             return Err(BeadsError::AlreadyInitialized {
                 path: effective_db_path,
             });
@@ -92,6 +119,9 @@ pub fn execute(
 # Temporary
 last-touched
 *.tmp
+
+# Bridge sync state (per clone)
+bridge.base.jsonl
 ";
         fs::write(gitignore_path, gitignore)?;
     }
@@ -104,12 +134,17 @@ last-touched
         fs::write(&jsonl_path, "")?;
     }
 
+    let driver_changed = driver_root
+        .map(|root| install_merge_driver(root, &beads_dir))
+        .transpose()?;
+    let suggest_driver = git_root.is_some() && driver_changed.is_none();
+
     if matches!(ctx.mode(), OutputMode::Quiet) {
         return Ok(());
     }
 
     if matches!(ctx.mode(), OutputMode::Rich) {
-        let steps = build_init_steps(
+        let mut steps = build_init_steps(
             created_dir,
             db_existed,
             metadata_existed,
@@ -119,17 +154,120 @@ last-touched
             jsonl_existed,
             prefix_set.as_deref(),
         );
-        render_init_rich(&beads_dir, &steps, prefix_set.as_deref(), ctx);
+        if let Some(changed) = driver_changed {
+            steps.push(InitStep {
+                label: "git merge driver for issues.jsonl".to_string(),
+                status: if changed {
+                    InitStepStatus::Created
+                } else {
+                    InitStepStatus::Existing
+                },
+            });
+        }
+        render_init_rich(
+            &beads_dir,
+            &steps,
+            prefix_set.as_deref(),
+            suggest_driver,
+            ctx,
+        );
     } else {
         if let Some(p) = prefix_set.as_deref() {
             println!("Prefix set to: {p}");
         }
         println!("Initialized beads workspace in .beads/");
+        if let Some(changed) = driver_changed {
+            println!("{}", merge_driver_message(changed));
+        } else if suggest_driver {
+            println!("Tip: run `br init --merge-driver` so git merges issues.jsonl issue by issue");
+        }
     }
 
     Ok(())
 }
 
+fn merge_driver_message(changed: bool) -> &'static str {
+    if changed {
+        "Installed git merge driver for issues.jsonl"
+    } else {
+        "Git merge driver for issues.jsonl already installed"
+    }
+}
+
+/// Find the top of the git work tree containing `dir`, if any.
+fn find_git_root(dir: &Path) -> Option<PathBuf> {
+    let dir = dir.canonicalize().ok()?;
+    dir.ancestors()
+        .find(|candidate| candidate.join(".git").exists())
+        .map(Path::to_path_buf)
+}
+
+/// Map `issues.jsonl` to the merge driver in `.gitattributes` and register the
+/// driver command in the repository's git config.
+///
+/// Returns whether anything changed; running it again is a no-op.
+fn install_merge_driver(git_root: &Path, beads_dir: &Path) -> Result<bool> {
+    let beads_dir = beads_dir.canonicalize()?;
+    let relative = beads_dir.strip_prefix(git_root).map_err(|_| {
+        BeadsError::Config(format!(
+            "{} is outside the git repository at {}",
+            beads_dir.display(),
+            git_root.display()
+        ))
+    })?;
+    let pattern = relative
+        .join("issues.jsonl")
+        .components()
+        .map(|part| part.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/");
+    let attribute = format!("{pattern} merge={MERGE_DRIVER_NAME}");
+
+    let mut changed = false;
+    let attributes_path = git_root.join(".gitattributes");
+    let existing = fs::read_to_string(&attributes_path).unwrap_or_default();
+    if !existing.lines().any(|line| line.trim() == attribute) {
+        let mut content = existing;
+        if !content.is_empty() && !content.ends_with('\n') {
+            content.push('\n');
+        }
+        content.push_str(&attribute);
+        content.push('\n');
+        fs::write(&attributes_path, content)?;
+        changed = true;
+    }
+
+    let settings = [
+        ("name", "beads issues.jsonl merge"),
+        ("driver", MERGE_DRIVER_COMMAND),
+    ];
+    for (key, value) in settings {
+        let key = format!("merge.{MERGE_DRIVER_NAME}.{key}");
+        let current = Command::new("git")
+            .arg("-C")
+            .arg(git_root)
+            .args(["config", "--get", &key])
+            .output()?;
+        if current.status.success() && String::from_utf8_lossy(&current.stdout).trim() == value {
+            continue;
+        }
+        let output = Command::new("git")
+            .arg("-C")
+            .arg(git_root)
+            .args(["config", &key, value])
+            .output()?;
+        if !output.status.success() {
+            return Err(BeadsError::Config(format!(
+                "git config {key} failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        changed = true;
+    }
+
+    Ok(changed)
+}
+
 #[derive(Clone, Copy)]
 enum InitStepStatus {
     Created,
@@ -226,6 +364,7 @@ fn render_init_rich(
     beads_dir: &Path,
     steps: &[InitStep],
     prefix: Option<&str>,
+    suggest_driver: bool,
     ctx: &OutputContext,
 ) {
     let theme = ctx.theme();
@@ -264,6 +403,12 @@ fn render_init_rich(
             theme.dimmed.clone(),
         );
     }
+    if suggest_driver {
+        content.append_styled(
+            "Tip: Let git merge issues.jsonl with `br init --merge-driver`\n",
+            theme.dimmed.clone(),
+        );
+    }
 
     let panel = Panel::from_rich_text(&content, ctx.width())
         .title(Text::new("Beads Initialized"))
@@ -300,7 +445,7 @@ mod tests {
         info!("test_init_creates_beads_directory: starting");
         let temp_dir = TempDir::new().unwrap();
         let ctx = OutputContext::from_flags(false, false, true);
-        let result = execute(None, false, Some(temp_dir.path()), &ctx);
+        let result = execute(None, false, false, Some(temp_dir.path()), &ctx);
 
         assert!(result.is_ok());
         assert!(temp_dir.path().join(".beads").exists());
@@ -318,7 +463,13 @@ mod tests {
         info!("test_init_with_prefix: starting");
         let temp_dir = TempDir::new().unwrap();
         let ctx = OutputContext::from_flags(false, false, true);
-        let result = execute(Some("test".to_string()), false, Some(temp_dir.path()), &ctx);
+        let result = execute(
+            Some("test".to_string()),
+            false,
+            false,
+            Some(temp_dir.path()),
+            &ctx,
+        );
 
         assert!(result.is_ok());
 
@@ -338,11 +489,11 @@ mod tests {
         let ctx = OutputContext::from_flags(false, false, true);
 
         // First init should succeed
-        let result1 = execute(None, false, Some(temp_dir.path()), &ctx);
+        let result1 = execute(None, false, false, Some(temp_dir.path()), &ctx);
         assert!(result1.is_ok());
 
         // Second init without force should fail
-        let result2 = execute(None, false, Some(temp_dir.path()), &ctx);
+        let result2 = execute(None, false, false, Some(temp_dir.path()), &ctx);
 
         assert!(result2.is_err());
         assert!(matches!(
@@ -363,6 +514,7 @@ mod tests {
         execute(
             Some("first".to_string()),
             false,
+            false,
             Some(temp_dir.path()),
             &ctx,
         )
@@ -372,6 +524,7 @@ mod tests {
         let result = execute(
             Some("second".to_string()),
             true,
+            false,
             Some(temp_dir.path()),
             &ctx,
         );
@@ -392,7 +545,7 @@ mod tests {
         info!("test_metadata_json_content: starting");
         let temp_dir = TempDir::new().unwrap();
         let ctx = OutputContext::from_flags(false, false, true);
-        execute(None, false, Some(temp_dir.path()), &ctx).unwrap();
+        execute(None, false, false, Some(temp_dir.path()), &ctx).unwrap();
 
         let metadata_path = temp_dir.path().join(".beads/metadata.json");
         let content = fs::read_to_string(metadata_path).unwrap();
@@ -409,7 +562,7 @@ mod tests {
         info!("test_gitignore_excludes_db_files: starting");
         let temp_dir = TempDir::new().unwrap();
         let ctx = OutputContext::from_flags(false, false, true);
-        execute(None, false, Some(temp_dir.path()), &ctx).unwrap();
+        execute(None, false, false, Some(temp_dir.path()), &ctx).unwrap();
 
         let gitignore_path = temp_dir.path().join(".beads/.gitignore");
         let content = fs::read_to_string(gitignore_path).unwrap();
@@ -1,9 +1,12 @@
 //! Lint command implementation.
 //!
-//! Checks issues for missing recommended template sections based on issue type.
+//! Checks issues against the template named after their type (see
+//! [`crate::config::templates`]): missing description sections and empty
+//! required fields.
 
 use crate::cli::LintArgs;
 use crate::config;
+use crate::config::templates::{RequiredField, TemplateSet};
 use crate::error::{BeadsError, Result};
 use crate::model::{Issue, IssueType, Status};
 use crate::output::OutputContext;
@@ -12,7 +15,6 @@ use crate::util::id::{IdResolver, ResolverConfig};
 use rich_rust::prelude::*;
 use serde::Serialize;
 use std::collections::BTreeMap;
-use std::path::Path;
 
 #[derive(Debug, Serialize)]
 struct LintResult {
@@ -22,6 +24,8 @@ struct LintResult {
     issue_type: String,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     missing: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    missing_fields: Vec<String>,
     warnings: usize,
 }
 
@@ -45,34 +49,6 @@ impl LintSummary {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-struct RequiredSection {
-    heading: &'static str,
-    #[allow(dead_code)] // Kept for future use in suggestions
-    hint: &'static str,
-}
-
-const BUG_SECTIONS: [RequiredSection; 2] = [
-    RequiredSection {
-        heading: "## Steps to Reproduce",
-        hint: "Describe how to reproduce the bug",
-    },
-    RequiredSection {
-        heading: "## Acceptance Criteria",
-        hint: "Define criteria to verify the fix",
-    },
-];
-
-const TASK_SECTIONS: [RequiredSection; 1] = [RequiredSection {
-    heading: "## Acceptance Criteria",
-    hint: "Define criteria to verify completion",
-}];
-
-const EPIC_SECTIONS: [RequiredSection; 1] = [RequiredSection {
-    heading: "## Success Criteria",
-    hint: "Define high-level success criteria",
-}];
-
 /// Execute the lint command.
 ///
 /// # Errors
@@ -88,14 +64,24 @@ pub fn execute(
     let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
     let storage = &storage_ctx.storage;
 
-    let issues = if args.ids.is_empty() {
+    let layer = config::load_config(&beads_dir, Some(storage), cli)?;
+    let templates = TemplateSet::load(&beads_dir, &layer)?;
+
+    let mut issues = if args.ids.is_empty() {
         let filters = build_filters(args)?;
         storage.list_issues(&filters)?
     } else {
-        resolve_issues(storage, &beads_dir, args, cli)?
+        resolve_issues(storage, &layer, args)?
     };
+    if templates.requires(RequiredField::Labels) {
+        let ids: Vec<String> = issues.iter().map(|issue| issue.id.clone()).collect();
+        let mut labels = storage.get_labels_for_issues(&ids)?;
+        for issue in &mut issues {
+            issue.labels = labels.remove(&issue.id).unwrap_or_default();
+        }
+    }
 
-    let summary = lint_issues(&issues);
+    let summary = lint_issues(&issues, &templates);
 
     if ctx.is_json() {
         let output = LintOutput {
@@ -135,6 +121,9 @@ pub fn execute(
             for missing in &result.missing {
                 println!("  ⚠ Missing: {missing}");
             }
+            for field in &result.missing_fields {
+                println!("  ⚠ Missing field: {field}");
+            }
             println!();
         }
     }
@@ -197,6 +186,11 @@ fn render_lint_rich(summary: &LintSummary, ctx: &OutputContext) {
                     content.append_styled(missing, theme.warning.clone());
                     content.append("\n");
                 }
+                for field in &result.missing_fields {
+                    content.append_styled("    missing field: ", theme.dimmed.clone());
+                    content.append_styled(field, theme.warning.clone());
+                    content.append("\n");
+                }
             }
             content.append("\n");
         }
@@ -261,12 +255,10 @@ fn build_filters(args: &LintArgs) -> Result<ListFilters> {
 
 fn resolve_issues(
     storage: &SqliteStorage,
-    beads_dir: &Path,
+    config_layer: &config::ConfigLayer,
     args: &LintArgs,
-    cli: &config::CliOverrides,
 ) -> Result<Vec<Issue>> {
-    let config_layer = config::load_config(beads_dir, Some(storage), cli)?;
-    let id_config = config::id_config_from_layer(&config_layer);
+    let id_config = config::id_config_from_layer(config_layer);
     let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
 
     let mut issues = Vec::new();
@@ -286,12 +278,12 @@ fn resolve_issues(
     Ok(issues)
 }
 
-fn lint_issues(issues: &[Issue]) -> LintSummary {
+fn lint_issues(issues: &[Issue], templates: &TemplateSet) -> LintSummary {
     let mut warnings = 0;
     let mut results = Vec::new();
 
     for issue in issues {
-        if let Some(result) = lint_issue(issue) {
+        if let Some(result) = lint_issue(issue, templates) {
             warnings += result.warnings;
             results.push(result);
         }
@@ -304,15 +296,21 @@ fn lint_issues(issues: &[Issue]) -> LintSummary {
     }
 }
 
-fn lint_issue(issue: &Issue) -> Option<LintResult> {
-    let required = required_sections(&issue.issue_type);
-    if required.is_empty() {
-        return None;
-    }
+fn lint_issue(issue: &Issue, templates: &TemplateSet) -> Option<LintResult> {
+    let template = templates.for_type(&issue.issue_type)?;
 
     let description = issue.description.as_deref().unwrap_or("");
-    let missing = missing_sections(description, required);
-    if missing.is_empty() {
+    let missing: Vec<String> = template
+        .missing_sections(description)
+        .into_iter()
+        .map(str::to_string)
+        .collect();
+    let missing_fields: Vec<String> = template
+        .missing_fields(issue)
+        .into_iter()
+        .map(|field| field.as_str().to_string())
+        .collect();
+    if missing.is_empty() && missing_fields.is_empty() {
         return None;
     }
 
@@ -320,43 +318,12 @@ fn lint_issue(issue: &Issue) -> Option<LintResult> {
         id: issue.id.clone(),
         title: issue.title.clone(),
         issue_type: issue.issue_type.as_str().to_string(),
-        warnings: missing.len(),
-        missing: missing.into_iter().map(|m| m.heading.to_string()).collect(),
+        warnings: missing.len() + missing_fields.len(),
+        missing,
+        missing_fields,
     })
 }
 
-const fn required_sections(issue_type: &IssueType) -> &'static [RequiredSection] {
-    match issue_type {
-        IssueType::Bug => &BUG_SECTIONS,
-        IssueType::Task | IssueType::Feature => &TASK_SECTIONS,
-        IssueType::Epic => &EPIC_SECTIONS,
-        _ => &[],
-    }
-}
-
-fn missing_sections(description: &str, required: &[RequiredSection]) -> Vec<RequiredSection> {
-    let desc_lower = description.to_lowercase();
-    let mut missing = Vec::new();
-
-    for section in required {
-        let heading_text = strip_heading_prefix(section.heading);
-        let heading_lower = heading_text.to_lowercase();
-        if !desc_lower.contains(&heading_lower) {
-            missing.push(*section);
-        }
-    }
-
-    missing
-}
-
-fn strip_heading_prefix(heading: &str) -> &str {
-    let trimmed = heading.trim();
-    trimmed
-        .strip_prefix("## ")
-        .or_else(|| trimmed.strip_prefix("# "))
-        .unwrap_or(trimmed)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -403,13 +370,16 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            time_entries: vec![],
+            recurrence: None,
+            attachments: vec![],
         }
     }
 
     #[test]
     fn test_missing_sections_for_bug() {
         let issue = make_issue(IssueType::Bug, Some("Bug report"));
-        let result = lint_issue(&issue).expect("lint result");
+        let result = lint_issue(&issue, &TemplateSet::builtin()).expect("lint result");
         assert_eq!(result.warnings, 2);
         assert!(
             result
@@ -427,14 +397,37 @@ mod tests {
     fn test_required_sections_present_case_insensitive() {
         let description = "## steps to reproduce\n- foo\n# acceptance criteria\n- bar";
         let issue = make_issue(IssueType::Bug, Some(description));
-        assert!(lint_issue(&issue).is_none());
+        assert!(lint_issue(&issue, &TemplateSet::builtin()).is_none());
     }
 
     #[test]
     fn test_exit_code_behavior() {
         let issue = make_issue(IssueType::Task, Some("No criteria"));
-        let summary = lint_issues(&[issue]);
+        let summary = lint_issues(&[issue], &TemplateSet::builtin());
         assert_eq!(summary.exit_code(true), 0);
         assert_eq!(summary.exit_code(false), 1);
     }
+
+    #[test]
+    fn test_lint_uses_template_required_fields() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let dir = temp.path().join(crate::config::templates::TEMPLATES_DIR);
+        std::fs::create_dir(&dir).unwrap();
+        std::fs::write(
+            dir.join("chore.md"),
+            "---\nrequired: [assignee, estimate]\n---\n## Checklist\n",
+        )
+        .unwrap();
+        let templates = TemplateSet::load(temp.path(), &config::ConfigLayer::default()).unwrap();
+
+        let mut issue = make_issue(IssueType::Chore, Some("## Checklist\n- [ ] rotate keys"));
+        let result = lint_issue(&issue, &templates).expect("lint result");
+        assert!(result.missing.is_empty());
+        assert_eq!(result.missing_fields, vec!["assignee", "estimate"]);
+        assert_eq!(result.warnings, 2);
+
+        issue.assignee = Some("alice".to_string());
+        issue.estimated_minutes = Some(30);
+        assert!(lint_issue(&issue, &templates).is_none());
+    }
 }
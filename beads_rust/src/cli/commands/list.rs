@@ -3,14 +3,19 @@
 //! Primary discovery interface with classic filter semantics and
 //! `IssueWithCounts` JSON output. Supports text, JSON, and CSV formats.
 
+use crate::cli::commands::claim;
 use crate::cli::{ListArgs, OutputFormat, resolve_output_format};
 use crate::config;
+use crate::config::federation::{self, Project};
 use crate::error::{BeadsError, Result};
 use crate::format::csv;
-use crate::format::{IssueWithCounts, TextFormatOptions, format_issue_line_with, terminal_width};
-use crate::model::{IssueType, Priority, Status};
+use crate::format::{
+    IssueWithCounts, ProjectItem, TextFormatOptions, format_issue_line_with, terminal_width,
+};
+use crate::model::{Issue, IssueType, Priority, Status};
 use crate::output::{IssueTable, IssueTableColumns, OutputContext, OutputMode};
-use crate::storage::{ListFilters, SqliteStorage};
+use crate::storage::filter_expr::Field;
+use crate::storage::{FilterExpr, ListFilters, SqliteStorage};
 use chrono::Utc;
 use std::collections::HashSet;
 use std::io::IsTerminal;
@@ -29,9 +34,10 @@ pub fn execute(
 ) -> Result<()> {
     // Open storage (--db flag allows working from any directory)
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
-    let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let config_layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+    claim::reap_expired_leases(&mut storage_ctx, cli, &config::resolve_actor(&config_layer))?;
     let storage = &storage_ctx.storage;
-    let config_layer = config::load_config(&beads_dir, Some(storage), cli)?;
     let use_color = config::should_use_color(&config_layer);
     let max_width = if std::io::stdout().is_terminal() {
         Some(terminal_width())
@@ -44,36 +50,34 @@ pub fn execute(
         wrap: args.wrap,
     };
 
-    // Build filter from args
-    let mut filters = build_filters(args)?;
-    let client_filters = needs_client_filters(args);
-    let limit = if client_filters {
-        filters.limit.take()
-    } else {
-        None
-    };
-
     // Validate sort key before query
     validate_sort_key(args.sort.as_deref())?;
 
-    // Query issues
-    let issues = storage.list_issues(&filters)?;
-    let mut issues = if client_filters {
-        apply_client_filters(storage, issues, args)?
-    } else {
-        issues
-    };
-
-    if let Some(limit) = limit {
-        if limit > 0 && issues.len() > limit {
-            issues.truncate(limit);
-        }
-    }
-
     // Determine output format: --json flag overrides --format
     let output_format = resolve_output_format(args.format, outer_ctx.is_json(), false);
     let quiet = cli.quiet.unwrap_or(false);
     let ctx = OutputContext::from_output_format(output_format, quiet, !use_color);
+
+    if args.federation.is_federated() {
+        let projects = federation::select_projects(
+            &config_layer,
+            &beads_dir,
+            &storage_ctx.paths.db_path,
+            args.federation.all_projects,
+            &args.federation.projects,
+        )?;
+        return execute_federated(
+            args,
+            storage,
+            &projects,
+            output_format,
+            &ctx,
+            format_options,
+        );
+    }
+
+    // Query issues
+    let issues = query_issues(storage, args)?;
     if matches!(ctx.mode(), OutputMode::Quiet) {
         return Ok(());
     }
@@ -81,32 +85,7 @@ pub fn execute(
     // Output
     match output_format {
         OutputFormat::Json | OutputFormat::Toon => {
-            // Fetch relations for all issues
-            let issue_ids: Vec<String> = issues.iter().map(|i| i.id.clone()).collect();
-            let mut labels_map = storage.get_labels_for_issues(&issue_ids)?;
-
-            // Use batch counting
-            let dependency_counts = storage.count_dependencies_for_issues(&issue_ids)?;
-            let dependent_counts = storage.count_dependents_for_issues(&issue_ids)?;
-
-            // Convert to IssueWithCounts
-            let issues_with_counts: Vec<IssueWithCounts> = issues
-                .into_iter()
-                .map(|mut issue| {
-                    if let Some(labels) = labels_map.remove(&issue.id) {
-                        issue.labels = labels;
-                    }
-
-                    let dependency_count = *dependency_counts.get(&issue.id).unwrap_or(&0);
-                    let dependent_count = *dependent_counts.get(&issue.id).unwrap_or(&0);
-
-                    IssueWithCounts {
-                        issue,
-                        dependency_count,
-                        dependent_count,
-                    }
-                })
-                .collect();
+            let issues_with_counts = with_counts(storage, issues)?;
 
             if matches!(output_format, OutputFormat::Toon) {
                 ctx.toon_with_stats(&issues_with_counts, args.stats);
@@ -165,6 +144,139 @@ pub fn execute(
     Ok(())
 }
 
+/// Run the list query against one database.
+fn query_issues(storage: &SqliteStorage, args: &ListArgs) -> Result<Vec<Issue>> {
+    let mut filters = build_filters(args)?;
+    let client_filters = needs_client_filters(args);
+    let limit = if client_filters {
+        filters.limit.take()
+    } else {
+        None
+    };
+
+    let issues = storage.list_issues(&filters)?;
+    let mut issues = if client_filters {
+        apply_client_filters(storage, issues, args)?
+    } else {
+        issues
+    };
+
+    if let Some(limit) = limit {
+        if limit > 0 && issues.len() > limit {
+            issues.truncate(limit);
+        }
+    }
+    Ok(issues)
+}
+
+/// Attach labels and dependency counts for JSON/TOON output.
+fn with_counts(storage: &SqliteStorage, issues: Vec<Issue>) -> Result<Vec<IssueWithCounts>> {
+    // Fetch relations for all issues
+    let issue_ids: Vec<String> = issues.iter().map(|i| i.id.clone()).collect();
+    let mut labels_map = storage.get_labels_for_issues(&issue_ids)?;
+
+    // Use batch counting
+    let dependency_counts = storage.count_dependencies_for_issues(&issue_ids)?;
+    let dependent_counts = storage.count_dependents_for_issues(&issue_ids)?;
+
+    Ok(issues
+        .into_iter()
+        .map(|mut issue| {
+            if let Some(labels) = labels_map.remove(&issue.id) {
+                issue.labels = labels;
+            }
+
+            let dependency_count = *dependency_counts.get(&issue.id).unwrap_or(&0);
+            let dependent_count = *dependent_counts.get(&issue.id).unwrap_or(&0);
+
+            IssueWithCounts {
+                issue,
+                dependency_count,
+                dependent_count,
+            }
+        })
+        .collect())
+}
+
+/// `--all-projects`/`--projects`: run the query in each project and tag the
+/// results with their project. Limits apply per project.
+fn execute_federated(
+    args: &ListArgs,
+    local: &SqliteStorage,
+    projects: &[Project],
+    output_format: OutputFormat,
+    ctx: &OutputContext,
+    format_options: TextFormatOptions,
+) -> Result<()> {
+    let results = federation::for_each_project(projects, local, |_, storage| {
+        with_counts(storage, query_issues(storage, args)?)
+    })?;
+    if matches!(ctx.mode(), OutputMode::Quiet) {
+        return Ok(());
+    }
+
+    if matches!(output_format, OutputFormat::Json | OutputFormat::Toon) {
+        let items: Vec<ProjectItem<IssueWithCounts>> = results
+            .into_iter()
+            .flat_map(|(project, issues)| {
+                issues.into_iter().map(|item| ProjectItem {
+                    project: project.name.clone(),
+                    item,
+                })
+            })
+            .collect();
+        if output_format == OutputFormat::Toon {
+            ctx.toon_with_stats(&items, args.stats);
+        } else {
+            ctx.json_pretty(&items);
+        }
+        return Ok(());
+    }
+
+    if output_format == OutputFormat::Csv {
+        let fields = csv::parse_fields(args.fields.as_deref());
+        let mut out = format!("project,{}\n", fields.join(","));
+        for (project, issues) in &results {
+            for iwc in issues {
+                out.push_str(&csv::escape_field(&project.name));
+                out.push(',');
+                out.push_str(&csv::format_issue_row(&iwc.issue, &fields));
+                out.push('\n');
+            }
+        }
+        print!("{out}");
+        return Ok(());
+    }
+
+    for (project, issues) in results {
+        if issues.is_empty() {
+            continue;
+        }
+        let issues: Vec<Issue> = issues.into_iter().map(|iwc| iwc.issue).collect();
+        if matches!(ctx.mode(), OutputMode::Rich) {
+            let table = IssueTable::new(&issues, ctx.theme())
+                .columns(IssueTableColumns {
+                    id: true,
+                    priority: true,
+                    status: true,
+                    issue_type: true,
+                    title: true,
+                    ..Default::default()
+                })
+                .title(format!("{}: Issues ({})", project.name, issues.len()))
+                .wrap(args.wrap)
+                .build();
+            ctx.render(&table);
+        } else {
+            println!("{} ({}):", project.name, issues.len());
+            for issue in &issues {
+                println!("  {}", format_issue_line_with(issue, format_options));
+            }
+        }
+    }
+    Ok(())
+}
+
 /// Convert CLI args to storage filter.
 fn build_filters(args: &ListArgs) -> Result<ListFilters> {
     // Parse status strings to Status enums
@@ -203,7 +315,14 @@ fn build_filters(args: &ListArgs) -> Result<ListFilters> {
         )
     };
 
+    let expr = args.where_.as_deref().map(FilterExpr::parse).transpose()?;
+    // A status condition in the expression takes over status scoping.
+    let expr_scopes_status = expr
+        .as_ref()
+        .is_some_and(|expr| expr.references(Field::Status));
+
     let include_closed = args.all
+        || expr_scopes_status
         || statuses
             .as_ref()
             .is_some_and(|parsed| parsed.iter().any(Status::is_terminal));
@@ -212,6 +331,7 @@ fn build_filters(args: &ListArgs) -> Result<ListFilters> {
     // They are only excluded when explicitly filtering by status that doesn't include deferred.
     let include_deferred = args.deferred
         || args.all
+        || expr_scopes_status
         || statuses.is_none()
         || statuses
             .as_ref()
@@ -242,6 +362,7 @@ fn build_filters(args: &ListArgs) -> Result<ListFilters> {
         },
         updated_before: None,
         updated_after: None,
+        expr,
     })
 }
 
@@ -0,0 +1,64 @@
+//! Merge driver command implementation.
+//!
+//! Git calls `br merge-driver %O %A %B` for `.beads/issues.jsonl` when
+//! `.gitattributes` maps the file to the `beads` driver. The three versions
+//! are merged issue by issue (and field by field) instead of line by line, so
+//! concurrent branches no longer leave conflict markers in the JSONL. No
+//! database is opened; the command only reads and writes the given files.
+
+use crate::cli::MergeDriverArgs;
+use crate::cli::commands::sync::describe_field_conflict;
+use crate::error::{BeadsError, Result};
+use crate::output::OutputContext;
+use crate::sync::{ConflictResolution, merge_jsonl_versions, write_issues_jsonl};
+
+/// Execute the merge-driver command.
+///
+/// # Errors
+///
+/// Returns an error, leaving `ours` untouched, if a version cannot be parsed
+/// or the merge has conflicts it cannot resolve.
+pub fn execute(args: &MergeDriverArgs, json: bool, ctx: &OutputContext) -> Result<()> {
+    let report = merge_jsonl_versions(
+        &args.base,
+        &args.ours,
+        &args.theirs,
+        ConflictResolution::PreferNewer,
+    )?;
+
+    if report.has_conflicts() {
+        let conflicts: Vec<String> = report
+            .conflicts
+            .iter()
+            .map(|(id, kind)| format!("{id}: {kind:?}"))
+            .collect();
+        return Err(BeadsError::Config(format!(
+            "Unresolved merge conflicts in {}: {}",
+            args.ours.display(),
+            conflicts.join(", ")
+        )));
+    }
+
+    write_issues_jsonl(&args.ours, &report.kept)?;
+
+    if json {
+        ctx.json_pretty(&serde_json::json!({
+            "merged_issues": report.kept.len(),
+            "deleted_issues": report.deleted,
+            "field_conflicts": report.field_conflicts,
+            "notes": report.notes,
+        }));
+    } else if !ctx.is_quiet() {
+        // Git shows driver output inline, so stay silent unless a field
+        // changed on both branches and one side's value was dropped.
+        for conflict in &report.field_conflicts {
+            println!(
+                "br merge-driver: {} {}",
+                conflict.issue_id,
+                describe_field_conflict(conflict)
+            );
+        }
+    }
+
+    Ok(())
+}
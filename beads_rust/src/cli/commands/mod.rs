@@ -1,7 +1,11 @@
 pub mod agents;
+pub mod analytics;
+pub mod attach;
 pub mod audit;
 pub mod blocked;
+pub mod bridge;
 pub mod changelog;
+pub mod claim;
 pub mod close;
 pub mod comments;
 pub mod completions;
@@ -13,6 +17,7 @@ pub mod delete;
 pub mod dep;
 pub mod doctor;
 pub mod epic;
+pub mod export;
 pub mod graph;
 pub mod history;
 pub mod info;
@@ -20,20 +25,27 @@ pub mod init;
 pub mod label;
 pub mod lint;
 pub mod list;
+pub mod merge_driver;
 pub mod orphans;
+pub mod plan;
 pub mod q;
 pub mod query;
 pub mod ready;
+pub mod recur;
 pub mod reopen;
 pub mod schema;
 pub mod search;
+pub mod serve;
 pub mod show;
 pub mod stale;
 pub mod stats;
 pub mod sync;
+pub mod time;
 pub mod update;
 pub mod version;
+pub mod watch;
 pub mod r#where;
+pub mod workflow;
 
 #[cfg(feature = "self_update")]
 pub mod upgrade;
@@ -0,0 +1,788 @@
+//! Plan command implementation.
+//!
+//! `br plan` schedules open work over the `blocks`/`waits-for` dependency
+//! graph using each issue's `estimated_minutes`:
+//!
+//! - The critical path and per-issue slack assume unlimited workers (classic
+//!   critical path method). Slack is how long an issue can slip without
+//!   pushing out the end of the plan.
+//! - Earliest start/finish come from a list schedule over `--workers`
+//!   parallel workers, picking the ready issue with the least slack first.
+//! - An issue is at risk when its scheduled finish is after its `due_at`; the
+//!   due date is unreachable when even unlimited workers would miss it.
+//!
+//! Times are working minutes from now. Calendar timestamps assume
+//! `--hours-per-day` working hours per calendar day. Estimates are treated as
+//! remaining work, including for issues already in progress.
+
+use crate::cli::PlanArgs;
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::model::{DependencyType, Issue, Status};
+use crate::output::OutputContext;
+use crate::storage::ListFilters;
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt::Write as _;
+
+/// Output formats besides `--json`.
+const PLAN_FORMATS: &[&str] = &["text", "mermaid", "dot"];
+
+/// One issue in the plan.
+#[derive(Debug, Clone, Serialize)]
+struct PlanEntry {
+    id: String,
+    title: String,
+    status: Status,
+    priority: i32,
+    /// Estimate used for scheduling (after `--default-estimate`).
+    estimate_minutes: i64,
+    /// Working minutes from now, given `workers` workers.
+    earliest_start: i64,
+    earliest_finish: i64,
+    start_at: Option<DateTime<Utc>>,
+    finish_at: Option<DateTime<Utc>>,
+    /// 1-based worker the issue is scheduled on.
+    worker: usize,
+    slack_minutes: i64,
+    critical: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    due_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    late_by_minutes: Option<i64>,
+    /// The due date would be missed even with unlimited workers.
+    due_unreachable: bool,
+}
+
+/// JSON output for the plan command.
+#[derive(Debug, Serialize)]
+struct PlanOutput {
+    generated_at: DateTime<Utc>,
+    workers: usize,
+    hours_per_day: u32,
+    /// Working minutes until all open issues are done with `workers` workers.
+    total_minutes: i64,
+    finish_at: Option<DateTime<Utc>>,
+    critical_path: Vec<String>,
+    critical_path_minutes: i64,
+    issues: Vec<PlanEntry>,
+    /// `(prerequisite, dependent)` pairs.
+    edges: Vec<(String, String)>,
+    at_risk: Vec<String>,
+    unestimated: Vec<String>,
+}
+
+/// Scheduling input for one issue.
+#[derive(Debug, Clone)]
+struct Task {
+    duration: i64,
+    priority: i32,
+    in_progress: bool,
+    id: String,
+    /// Indices of prerequisites.
+    preds: Vec<usize>,
+    /// Indices of dependents.
+    succs: Vec<usize>,
+}
+
+/// Critical path method result for one task.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct Cpm {
+    earliest_start: i64,
+    earliest_finish: i64,
+    latest_start: i64,
+    slack: i64,
+}
+
+/// Placement of one task in the worker schedule.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct Slot {
+    start: i64,
+    finish: i64,
+    worker: usize,
+}
+
+/// Execute the plan command.
+///
+/// # Errors
+///
+/// Returns an error if arguments are invalid, the blocking graph has a cycle,
+/// or database operations fail.
+pub fn execute(args: &PlanArgs, cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
+    let format = args.format.to_ascii_lowercase();
+    if !PLAN_FORMATS.contains(&format.as_str()) {
+        return Err(BeadsError::validation(
+            "format",
+            format!(
+                "unknown format '{}' (expected {})",
+                args.format,
+                PLAN_FORMATS.join(", ")
+            ),
+        ));
+    }
+    if args.workers == 0 {
+        return Err(BeadsError::validation("workers", "must be at least 1"));
+    }
+    if !(1..=24).contains(&args.hours_per_day) {
+        return Err(BeadsError::validation(
+            "hours-per-day",
+            "must be between 1 and 24",
+        ));
+    }
+
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let storage = &storage_ctx.storage;
+
+    let filters = ListFilters {
+        include_closed: false,
+        include_deferred: true,
+        include_templates: false,
+        ..Default::default()
+    };
+    let mut issues = storage.list_issues(&filters)?;
+    issues.sort_by(|a, b| a.id.cmp(&b.id));
+    let dependencies = storage.get_all_dependency_records()?;
+
+    let tasks = build_tasks(&issues, &dependencies, args.default_estimate);
+    let order = topo_order(&tasks)?;
+    let cpm = critical_path_method(&tasks, &order);
+    let slots = schedule(&tasks, &cpm, args.workers);
+    let output = build_output(
+        &issues,
+        &tasks,
+        &cpm,
+        &slots,
+        args,
+        Utc::now(),
+        &critical_path(&tasks, &cpm),
+    );
+
+    if ctx.is_json() {
+        ctx.json_pretty(&output);
+        return Ok(());
+    }
+
+    // Use println! directly to avoid rich_rust markup interpretation
+    let rendered = match format.as_str() {
+        "mermaid" => render_mermaid(&output),
+        "dot" => render_dot(&output),
+        _ => render_text(&output),
+    };
+    print!("{rendered}");
+    Ok(())
+}
+
+/// Only hard ordering constraints take part in the schedule.
+const fn is_schedule_edge(dep_type: &DependencyType) -> bool {
+    matches!(dep_type, DependencyType::Blocks | DependencyType::WaitsFor)
+}
+
+fn build_tasks(
+    issues: &[Issue],
+    dependencies: &HashMap<String, Vec<crate::model::Dependency>>,
+    default_estimate: u32,
+) -> Vec<Task> {
+    let index: HashMap<&str, usize> = issues
+        .iter()
+        .enumerate()
+        .map(|(i, issue)| (issue.id.as_str(), i))
+        .collect();
+
+    let mut tasks: Vec<Task> = issues
+        .iter()
+        .map(|issue| Task {
+            duration: issue
+                .estimated_minutes
+                .map_or_else(|| i64::from(default_estimate), |m| i64::from(m.max(0))),
+            priority: issue.priority.0,
+            in_progress: issue.status == Status::InProgress,
+            id: issue.id.clone(),
+            preds: Vec::new(),
+            succs: Vec::new(),
+        })
+        .collect();
+
+    for (i, issue) in issues.iter().enumerate() {
+        let Some(deps) = dependencies.get(&issue.id) else {
+            continue;
+        };
+        for dep in deps.iter().filter(|d| is_schedule_edge(&d.dep_type)) {
+            // Closed prerequisites are already satisfied.
+            let Some(&p) = index.get(dep.depends_on_id.as_str()) else {
+                continue;
+            };
+            if p != i && !tasks[i].preds.contains(&p) {
+                tasks[i].preds.push(p);
+                tasks[p].succs.push(i);
+            }
+        }
+    }
+    tasks
+}
+
+/// Topologically sort tasks (prerequisites first).
+///
+/// # Errors
+///
+/// Returns `DependencyCycle` naming one cycle if the graph is not a DAG.
+fn topo_order(tasks: &[Task]) -> Result<Vec<usize>> {
+    let mut indegree: Vec<usize> = tasks.iter().map(|t| t.preds.len()).collect();
+    let mut queue: Vec<usize> = (0..tasks.len()).filter(|&i| indegree[i] == 0).collect();
+    let mut order = Vec::with_capacity(tasks.len());
+
+    while let Some(i) = queue.pop() {
+        order.push(i);
+        for &s in &tasks[i].succs {
+            indegree[s] -= 1;
+            if indegree[s] == 0 {
+                queue.push(s);
+            }
+        }
+    }
+
+    if order.len() == tasks.len() {
+        return Ok(order);
+    }
+
+    // Every unsorted task has an unsorted prerequisite, so walking
+    // prerequisites from any of them must revisit a task.
+    let start = (0..tasks.len()).find(|&i| indegree[i] > 0).unwrap_or(0);
+    let mut seen: Vec<usize> = vec![start];
+    let mut current = start;
+    loop {
+        current = tasks[current]
+            .preds
+            .iter()
+            .copied()
+            .find(|&p| indegree[p] > 0)
+            .unwrap_or(current);
+        if let Some(pos) = seen.iter().position(|&i| i == current) {
+            let mut cycle: Vec<&str> = seen[pos..]
+                .iter()
+                .rev()
+                .map(|&i| tasks[i].id.as_str())
+                .collect();
+            cycle.push(cycle[0]);
+            return Err(BeadsError::DependencyCycle {
+                path: cycle.join(" -> "),
+            });
+        }
+        seen.push(current);
+    }
+}
+
+/// Forward/backward pass with unlimited workers.
+fn critical_path_method(tasks: &[Task], order: &[usize]) -> Vec<Cpm> {
+    let mut cpm = vec![Cpm::default(); tasks.len()];
+
+    for &i in order {
+        let start = tasks[i]
+            .preds
+            .iter()
+            .map(|&p| cpm[p].earliest_finish)
+            .max()
+            .unwrap_or(0);
+        cpm[i].earliest_start = start;
+        cpm[i].earliest_finish = start + tasks[i].duration;
+    }
+
+    let length = cpm.iter().map(|c| c.earliest_finish).max().unwrap_or(0);
+    for &i in order.iter().rev() {
+        let latest_finish = tasks[i]
+            .succs
+            .iter()
+            .map(|&s| cpm[s].latest_start)
+            .min()
+            .unwrap_or(length);
+        cpm[i].latest_start = latest_finish - tasks[i].duration;
+        cpm[i].slack = cpm[i].latest_start - cpm[i].earliest_start;
+    }
+    cpm
+}
+
+/// The zero-slack chain from the start of the plan to its end.
+///
+/// A zero-slack task that ends before the plan does always has a zero-slack
+/// dependent starting right when it finishes, so the walk reaches the end.
+fn critical_path(tasks: &[Task], cpm: &[Cpm]) -> Vec<usize> {
+    let length = cpm.iter().map(|c| c.earliest_finish).max().unwrap_or(0);
+    if length == 0 {
+        return Vec::new();
+    }
+    let by_rank = |&a: &usize, &b: &usize| {
+        tasks[a]
+            .priority
+            .cmp(&tasks[b].priority)
+            .then_with(|| tasks[a].id.cmp(&tasks[b].id))
+    };
+
+    let mut path = Vec::new();
+    let mut current = (0..tasks.len())
+        .filter(|&i| cpm[i].slack == 0 && cpm[i].earliest_start == 0)
+        .min_by(by_rank);
+    while let Some(i) = current {
+        path.push(i);
+        current = tasks[i]
+            .succs
+            .iter()
+            .copied()
+            .filter(|&s| cpm[s].slack == 0 && cpm[s].earliest_start == cpm[i].earliest_finish)
+            .min_by(by_rank);
+    }
+    path
+}
+
+/// List-schedule tasks on `workers` workers.
+///
+/// The earliest-free worker takes the released task with the least slack
+/// (then in-progress work, priority and ID). If nothing is released yet, it
+/// waits for the task that is released first.
+fn schedule(tasks: &[Task], cpm: &[Cpm], workers: usize) -> Vec<Slot> {
+    let mut slots = vec![Slot::default(); tasks.len()];
+    let mut scheduled = vec![false; tasks.len()];
+    let mut waiting: Vec<usize> = tasks.iter().map(|t| t.preds.len()).collect();
+    let mut release = vec![0_i64; tasks.len()];
+    let mut free_at = vec![0_i64; workers.clamp(1, tasks.len().max(1))];
+
+    let key = |i: usize| {
+        (
+            cpm[i].latest_start,
+            !tasks[i].in_progress,
+            tasks[i].priority,
+            tasks[i].id.as_str(),
+        )
+    };
+
+    for _ in 0..tasks.len() {
+        let (worker, &free) = free_at
+            .iter()
+            .enumerate()
+            .min_by_key(|&(w, &t)| (t, w))
+            .expect("at least one worker");
+        let ready = (0..tasks.len()).filter(|&i| !scheduled[i] && waiting[i] == 0);
+        let next = ready
+            .clone()
+            .filter(|&i| release[i] <= free)
+            .min_by_key(|&i| key(i))
+            .or_else(|| ready.min_by_key(|&i| (release[i], key(i))));
+        // The graph is acyclic, so some unscheduled task is always ready.
+        let Some(i) = next else { break };
+
+        let start = free.max(release[i]);
+        let finish = start + tasks[i].duration;
+        slots[i] = Slot {
+            start,
+            finish,
+            worker,
+        };
+        scheduled[i] = true;
+        free_at[worker] = finish;
+        for &s in &tasks[i].succs {
+            waiting[s] -= 1;
+            release[s] = release[s].max(finish);
+        }
+    }
+    slots
+}
+
+/// Convert working minutes from `now` into a calendar time.
+fn work_time(now: DateTime<Utc>, minutes: i64, hours_per_day: u32) -> Option<DateTime<Utc>> {
+    let per_day = i64::from(hours_per_day) * 60;
+    let offset = Duration::try_days(minutes / per_day)?
+        .checked_add(&Duration::try_minutes(minutes % per_day)?)?;
+    now.checked_add_signed(offset)
+}
+
+fn build_output(
+    issues: &[Issue],
+    tasks: &[Task],
+    cpm: &[Cpm],
+    slots: &[Slot],
+    args: &PlanArgs,
+    now: DateTime<Utc>,
+    path: &[usize],
+) -> PlanOutput {
+    let hours = args.hours_per_day;
+    let length = cpm.iter().map(|c| c.earliest_finish).max().unwrap_or(0);
+    let total = slots.iter().map(|s| s.finish).max().unwrap_or(0);
+
+    let mut entries: Vec<PlanEntry> = issues
+        .iter()
+        .enumerate()
+        .map(|(i, issue)| {
+            let finish_at = work_time(now, slots[i].finish, hours);
+            let late_by_minutes = match (issue.due_at, finish_at) {
+                (Some(due), Some(finish)) if finish > due => Some((finish - due).num_minutes()),
+                (Some(_), None) => Some(i64::MAX),
+                _ => None,
+            };
+            let due_unreachable = issue.due_at.is_some_and(|due| {
+                work_time(now, cpm[i].earliest_finish, hours).is_none_or(|f| f > due)
+            });
+            PlanEntry {
+                id: issue.id.clone(),
+                title: issue.title.clone(),
+                status: issue.status.clone(),
+                priority: issue.priority.0,
+                estimate_minutes: tasks[i].duration,
+                earliest_start: slots[i].start,
+                earliest_finish: slots[i].finish,
+                start_at: work_time(now, slots[i].start, hours),
+                finish_at,
+                worker: slots[i].worker + 1,
+                slack_minutes: cpm[i].slack,
+                critical: length > 0 && cpm[i].slack == 0,
+                due_at: issue.due_at,
+                late_by_minutes,
+                due_unreachable,
+            }
+        })
+        .collect();
+
+    let mut edges: Vec<(String, String)> = tasks
+        .iter()
+        .flat_map(|t| t.preds.iter().map(|&p| (tasks[p].id.clone(), t.id.clone())))
+        .collect();
+    edges.sort();
+
+    let unestimated = issues
+        .iter()
+        .filter(|issue| issue.estimated_minutes.is_none())
+        .map(|issue| issue.id.clone())
+        .collect();
+
+    entries.sort_by(|a, b| {
+        a.earliest_start
+            .cmp(&b.earliest_start)
+            .then(a.worker.cmp(&b.worker))
+            .then_with(|| a.id.cmp(&b.id))
+    });
+    let at_risk = entries
+        .iter()
+        .filter(|e| e.late_by_minutes.is_some())
+        .map(|e| e.id.clone())
+        .collect();
+
+    PlanOutput {
+        generated_at: now,
+        workers: args.workers,
+        hours_per_day: hours,
+        total_minutes: total,
+        finish_at: work_time(now, total, hours),
+        critical_path: path.iter().map(|&i| tasks[i].id.clone()).collect(),
+        critical_path_minutes: length,
+        issues: entries,
+        edges,
+        at_risk,
+        unestimated,
+    }
+}
+
+/// Format working minutes as e.g. `45m`, `2h`, `3h15m`.
+pub(crate) fn format_minutes(minutes: i64) -> String {
+    let (hours, mins) = (minutes / 60, minutes % 60);
+    match (hours, mins) {
+        (0, m) => format!("{m}m"),
+        (h, 0) => format!("{h}h"),
+        (h, m) => format!("{h}h{m}m"),
+    }
+}
+
+fn format_time(at: Option<DateTime<Utc>>) -> String {
+    at.map_or_else(
+        || "never".to_string(),
+        |t| t.format("%Y-%m-%d %H:%M UTC").to_string(),
+    )
+}
+
+fn render_text(plan: &PlanOutput) -> String {
+    let mut out = String::new();
+    if plan.issues.is_empty() {
+        out.push_str("No open issues to plan\n");
+        return out;
+    }
+
+    let _ = writeln!(
+        out,
+        "Plan: {} open issue(s), {} worker(s), done in {} ({})",
+        plan.issues.len(),
+        plan.workers,
+        format_minutes(plan.total_minutes),
+        format_time(plan.finish_at)
+    );
+    if !plan.critical_path.is_empty() {
+        let _ = writeln!(
+            out,
+            "Critical path ({}): {}",
+            format_minutes(plan.critical_path_minutes),
+            plan.critical_path.join(" -> ")
+        );
+    }
+    out.push('\n');
+
+    let id_width = plan.issues.iter().map(|e| e.id.len()).max().unwrap_or(2);
+    let _ = writeln!(
+        out,
+        "  {:<8} {:<8} {:<7} {:<6}   {:<id_width$}  TITLE",
+        "START", "FINISH", "SLACK", "WORKER", "ID"
+    );
+    for entry in &plan.issues {
+        let marker = if entry.critical { '*' } else { ' ' };
+        let _ = writeln!(
+            out,
+            "  {:<8} {:<8} {:<7} {:<6} {marker} {:<id_width$}  {}",
+            format_minutes(entry.earliest_start),
+            format_minutes(entry.earliest_finish),
+            format_minutes(entry.slack_minutes),
+            entry.worker,
+            entry.id,
+            entry.title
+        );
+    }
+
+    let late: Vec<&PlanEntry> = plan
+        .issues
+        .iter()
+        .filter(|e| e.late_by_minutes.is_some())
+        .collect();
+    if !late.is_empty() {
+        let _ = writeln!(out, "\nAt risk ({}):", late.len());
+        for entry in late {
+            let unreachable = if entry.due_unreachable {
+                " (cannot be met)"
+            } else {
+                ""
+            };
+            let _ = writeln!(
+                out,
+                "  {}: due {}, finishes {}{unreachable}",
+                entry.id,
+                format_time(entry.due_at),
+                format_time(entry.finish_at)
+            );
+        }
+    }
+
+    if !plan.unestimated.is_empty() {
+        let _ = writeln!(
+            out,
+            "\nNo estimate ({}): {}",
+            plan.unestimated.len(),
+            plan.unestimated.join(", ")
+        );
+    }
+    out
+}
+
+/// Whether `from -> to` lies on the critical path.
+fn is_critical_edge(plan: &PlanOutput, from: &str, to: &str) -> bool {
+    plan.critical_path
+        .windows(2)
+        .any(|pair| pair[0] == from && pair[1] == to)
+}
+
+fn render_mermaid(plan: &PlanOutput) -> String {
+    let mut out = String::from("graph LR\n");
+    for entry in &plan.issues {
+        // Escape quotes in title for mermaid
+        let title = entry.title.replace('"', "'");
+        let _ = writeln!(
+            out,
+            "    {}[\"{}: {} ({}, slack {})\"]",
+            entry.id,
+            entry.id,
+            title,
+            format_minutes(entry.estimate_minutes),
+            format_minutes(entry.slack_minutes)
+        );
+    }
+    for (from, to) in &plan.edges {
+        let arrow = if is_critical_edge(plan, from, to) {
+            "==>"
+        } else {
+            "-->"
+        };
+        let _ = writeln!(out, "    {from} {arrow} {to}");
+    }
+
+    let critical: Vec<&str> = plan
+        .issues
+        .iter()
+        .filter(|e| e.critical)
+        .map(|e| e.id.as_str())
+        .collect();
+    if !critical.is_empty() {
+        out.push_str("    classDef critical stroke:#d62728,stroke-width:3px\n");
+        let _ = writeln!(out, "    class {} critical", critical.join(","));
+    }
+    if !plan.at_risk.is_empty() {
+        out.push_str("    classDef late fill:#f8d7da\n");
+        let _ = writeln!(out, "    class {} late", plan.at_risk.join(","));
+    }
+    out
+}
+
+fn dot_escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn render_dot(plan: &PlanOutput) -> String {
+    let mut out = String::from("digraph plan {\n    rankdir=LR;\n    node [shape=box];\n");
+    for entry in &plan.issues {
+        let mut attrs = format!(
+            "label=\"{}: {}\\n{}, slack {}\"",
+            dot_escape(&entry.id),
+            dot_escape(&entry.title),
+            format_minutes(entry.estimate_minutes),
+            format_minutes(entry.slack_minutes)
+        );
+        if entry.critical {
+            attrs.push_str(", color=red, penwidth=2");
+        }
+        if entry.late_by_minutes.is_some() {
+            attrs.push_str(", style=filled, fillcolor=\"#f8d7da\"");
+        }
+        let _ = writeln!(out, "    \"{}\" [{attrs}];", dot_escape(&entry.id));
+    }
+    for (from, to) in &plan.edges {
+        let attrs = if is_critical_edge(plan, from, to) {
+            " [color=red, penwidth=2]"
+        } else {
+            ""
+        };
+        let _ = writeln!(
+            out,
+            "    \"{}\" -> \"{}\"{attrs};",
+            dot_escape(from),
+            dot_escape(to)
+        );
+    }
+    out.push_str("}\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build tasks from `(id, minutes)` and `(dependent, prerequisite)` pairs.
+    fn tasks(specs: &[(&str, i64)], deps: &[(&str, &str)]) -> Vec<Task> {
+        let mut tasks: Vec<Task> = specs
+            .iter()
+            .map(|&(id, duration)| Task {
+                duration,
+                priority: 2,
+                in_progress: false,
+                id: id.to_string(),
+                preds: Vec::new(),
+                succs: Vec::new(),
+            })
+            .collect();
+        let pos = |id: &str| specs.iter().position(|s| s.0 == id).unwrap();
+        for &(dependent, prerequisite) in deps {
+            let (d, p) = (pos(dependent), pos(prerequisite));
+            tasks[d].preds.push(p);
+            tasks[p].succs.push(d);
+        }
+        tasks
+    }
+
+    fn ids(tasks: &[Task], indices: &[usize]) -> Vec<String> {
+        indices.iter().map(|&i| tasks[i].id.clone()).collect()
+    }
+
+    #[test]
+    fn test_critical_path_and_slack() {
+        // a(60) -> c(30); b(20) -> c; c -> d(10)
+        let tasks = tasks(
+            &[("a", 60), ("b", 20), ("c", 30), ("d", 10)],
+            &[("c", "a"), ("c", "b"), ("d", "c")],
+        );
+        let order = topo_order(&tasks).unwrap();
+        let cpm = critical_path_method(&tasks, &order);
+
+        assert_eq!(cpm[2].earliest_start, 60);
+        assert_eq!(cpm[3].earliest_finish, 100);
+        assert_eq!(cpm[0].slack, 0);
+        assert_eq!(cpm[1].slack, 40);
+        assert_eq!(
+            ids(&tasks, &critical_path(&tasks, &cpm)),
+            vec!["a", "c", "d"]
+        );
+    }
+
+    #[test]
+    fn test_schedule_respects_worker_count() {
+        let tasks = tasks(&[("a", 60), ("b", 60), ("c", 60), ("d", 30)], &[]);
+        let order = topo_order(&tasks).unwrap();
+        let cpm = critical_path_method(&tasks, &order);
+
+        let one = schedule(&tasks, &cpm, 1);
+        assert_eq!(one.iter().map(|s| s.finish).max(), Some(210));
+
+        let two = schedule(&tasks, &cpm, 2);
+        assert_eq!(two.iter().map(|s| s.finish).max(), Some(120));
+        assert!(two.iter().all(|s| s.worker < 2));
+
+        // More workers than tasks behaves like unlimited workers.
+        let many = schedule(&tasks, &cpm, 10);
+        assert!(many.iter().all(|s| s.start == 0));
+    }
+
+    #[test]
+    fn test_schedule_prefers_least_slack() {
+        // Two ready tasks, one worker: the long chain's head goes first.
+        let tasks = tasks(
+            &[("short", 10), ("head", 10), ("tail", 100)],
+            &[("tail", "head")],
+        );
+        let order = topo_order(&tasks).unwrap();
+        let cpm = critical_path_method(&tasks, &order);
+        let slots = schedule(&tasks, &cpm, 2);
+
+        assert_eq!(slots[1].start, 0);
+        assert_eq!(slots[2].start, 10);
+        assert_eq!(slots[2].finish, 110);
+
+        let single = schedule(&tasks, &cpm, 1);
+        assert_eq!(single[1].start, 0);
+        assert_eq!(single[2].start, 10);
+        assert_eq!(single[0].start, 110);
+    }
+
+    #[test]
+    fn test_topo_order_reports_cycle() {
+        let tasks = tasks(
+            &[("a", 1), ("b", 1), ("c", 1), ("x", 1)],
+            &[("b", "a"), ("c", "b"), ("a", "c"), ("x", "a")],
+        );
+        let err = topo_order(&tasks).unwrap_err();
+        let BeadsError::DependencyCycle { path } = err else {
+            panic!("expected cycle error, got {err:?}");
+        };
+        let nodes: Vec<&str> = path.split(" -> ").collect();
+        assert_eq!(nodes.len(), 4);
+        assert_eq!(nodes.first(), nodes.last());
+        assert!(!path.contains('x'), "{path}");
+    }
+
+    #[test]
+    fn test_work_time_uses_working_hours() {
+        let now = DateTime::parse_from_rfc3339("2026-01-05T09:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let at = work_time(now, 8 * 60 + 30, 8).unwrap();
+        assert_eq!(at.to_rfc3339(), "2026-01-06T09:30:00+00:00");
+        assert!(work_time(now, i64::MAX, 8).is_none());
+    }
+
+    #[test]
+    fn test_format_minutes() {
+        assert_eq!(format_minutes(0), "0m");
+        assert_eq!(format_minutes(45), "45m");
+        assert_eq!(format_minutes(120), "2h");
+        assert_eq!(format_minutes(195), "3h15m");
+    }
+}
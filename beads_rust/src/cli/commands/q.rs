@@ -1,6 +1,7 @@
 use crate::cli::QuickArgs;
 use crate::config;
 use crate::error::{BeadsError, Result};
+use crate::hooks::{HookEvent, Hooks};
 use crate::model::{Issue, IssueType, Priority, Status};
 use crate::output::{OutputContext, OutputMode};
 use crate::util::id::IdGenerator;
@@ -27,6 +28,7 @@ fn split_labels(values: &[String]) -> Vec<String> {
 /// # Errors
 ///
 /// Returns an error if validation fails, the database cannot be opened, or creation fails.
+#[allow(clippy::too_many_lines)]
 pub fn execute(args: QuickArgs, cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
     let title = args.title.join(" ").trim().to_string();
     if title.is_empty() {
@@ -39,6 +41,7 @@ pub fn execute(args: QuickArgs, cli: &config::CliOverrides, ctx: &OutputContext)
     let id_config = config::id_config_from_layer(&layer);
     let default_priority = config::default_priority_from_layer(&layer)?;
     let default_issue_type = config::default_issue_type_from_layer(&layer)?;
+    let hooks = Hooks::load(&beads_dir, &layer)?;
     let storage = &mut storage_ctx.storage;
 
     let priority = if let Some(p) = args.priority {
@@ -101,6 +104,9 @@ pub fn execute(args: QuickArgs, cli: &config::CliOverrides, ctx: &OutputContext)
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        time_entries: vec![],
+        recurrence: None,
+        attachments: vec![],
     };
 
     // Resolve actor and set created_by
@@ -110,6 +116,7 @@ pub fn execute(args: QuickArgs, cli: &config::CliOverrides, ctx: &OutputContext)
     // Compute content hash
     issue.content_hash = Some(issue.compute_content_hash());
 
+    hooks.pre(&HookEvent::Create, &actor, &issue)?;
     storage.create_issue(&issue, &actor)?;
 
     let labels = split_labels(&args.labels);
@@ -124,6 +131,11 @@ pub fn execute(args: QuickArgs, cli: &config::CliOverrides, ctx: &OutputContext)
         }
     }
 
+    if hooks.is_active() {
+        issue.labels = storage.get_labels(&issue.id)?;
+        hooks.post(&HookEvent::Create, &actor, &issue);
+    }
+
     // Output
     if ctx.is_json() {
         let output = serde_json::json!({
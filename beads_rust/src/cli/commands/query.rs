@@ -2,10 +2,13 @@
 //!
 //! Provides named, reusable filters for issue listing.
 
-use crate::cli::{ListArgs, QueryCommands, QueryDeleteArgs, QueryRunArgs, QuerySaveArgs};
+use crate::cli::{
+    ListArgs, ProjectsArgs, QueryCommands, QueryDeleteArgs, QueryRunArgs, QuerySaveArgs,
+};
 use crate::config;
 use crate::error::{BeadsError, Result};
 use crate::output::{OutputContext, OutputMode};
+use crate::storage::FilterExpr;
 use chrono::{DateTime, Utc};
 use rich_rust::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -60,6 +63,9 @@ pub struct SavedFilters {
     pub desc_contains: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub notes_contains: Option<String>,
+    /// `--where` filter expression, stored as text and re-parsed on each run
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub where_: Option<String>,
     #[serde(default, skip_serializing_if = "is_false")]
     pub all: bool,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -96,6 +102,7 @@ impl From<&ListArgs> for SavedFilters {
             title_contains: args.title_contains.clone(),
             desc_contains: args.desc_contains.clone(),
             notes_contains: args.notes_contains.clone(),
+            where_: args.where_.clone(),
             all: args.all,
             limit: args.limit,
             sort: args.sort.clone(),
@@ -124,12 +131,14 @@ impl SavedFilters {
             title_contains: self.title_contains.clone(),
             desc_contains: self.desc_contains.clone(),
             notes_contains: self.notes_contains.clone(),
+            where_: self.where_.clone(),
             all: self.all,
             limit: self.limit,
             sort: self.sort.clone(),
             reverse: self.reverse,
             deferred: self.deferred,
             overdue: self.overdue,
+            federation: ProjectsArgs::default(),
             // Output-related fields use defaults
             long: false,
             pretty: false,
@@ -183,6 +192,11 @@ impl SavedFilters {
             title_contains: cli.title_contains.clone().or(base.title_contains),
             desc_contains: cli.desc_contains.clone().or(base.desc_contains),
             notes_contains: cli.notes_contains.clone().or(base.notes_contains),
+            // Expressions narrow rather than replace: saved AND CLI
+            where_: match (base.where_, &cli.where_) {
+                (Some(saved), Some(extra)) => Some(format!("({saved}) AND ({extra})")),
+                (saved, extra) => extra.clone().or(saved),
+            },
             limit: cli.limit.or(base.limit),
             sort: cli.sort.clone().or(base.sort),
             // Bool fields: CLI true overrides saved
@@ -191,7 +205,8 @@ impl SavedFilters {
             reverse: cli.reverse || base.reverse,
             deferred: cli.deferred || base.deferred,
             overdue: cli.overdue || base.overdue,
-            // Output fields from CLI only
+            // Project selection and output fields from CLI only
+            federation: cli.federation.clone(),
             long: cli.long,
             pretty: cli.pretty,
             wrap: cli.wrap,
@@ -266,6 +281,10 @@ fn query_save(
         ));
     }
 
+    if let Some(expr) = &args.filters.where_ {
+        FilterExpr::parse(expr)?;
+    }
+
     let key = format!("{QUERY_KEY_PREFIX}{name}");
 
     // Check if query already exists
@@ -788,6 +807,31 @@ mod tests {
         assert_eq!(merged2.sort, Some("updated".to_string()));
     }
 
+    #[test]
+    fn test_merge_where_expressions_are_anded() {
+        let saved = SavedFilters {
+            where_: Some("status:open OR status:blocked".to_string()),
+            ..Default::default()
+        };
+
+        let merged = saved.merge_with_cli(&ListArgs::default());
+        assert_eq!(
+            merged.where_.as_deref(),
+            Some("status:open OR status:blocked")
+        );
+
+        let cli = ListArgs {
+            where_: Some("label:backend".to_string()),
+            ..Default::default()
+        };
+        let merged = saved.merge_with_cli(&cli);
+        assert_eq!(
+            merged.where_.as_deref(),
+            Some("(status:open OR status:blocked) AND (label:backend)")
+        );
+        assert!(FilterExpr::parse(merged.where_.as_deref().unwrap()).is_ok());
+    }
+
     #[test]
     fn test_saved_query_without_description() {
         let query = SavedQuery {
@@ -839,6 +883,7 @@ mod tests {
             title_contains: Some("search term".to_string()),
             desc_contains: Some("description search".to_string()),
             notes_contains: Some("notes search".to_string()),
+            where_: Some("label:backend OR priority<=1".to_string()),
             all: true,
             limit: Some(25),
             sort: Some("created".to_string()),
@@ -863,6 +908,7 @@ mod tests {
         assert_eq!(parsed.title_contains, filters.title_contains);
         assert_eq!(parsed.desc_contains, filters.desc_contains);
         assert_eq!(parsed.notes_contains, filters.notes_contains);
+        assert_eq!(parsed.where_, filters.where_);
         assert_eq!(parsed.all, filters.all);
         assert_eq!(parsed.limit, filters.limit);
         assert_eq!(parsed.sort, filters.sort);
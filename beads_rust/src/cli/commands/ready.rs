@@ -2,14 +2,20 @@
 //!
 //! Shows issues ready to work on: unblocked, not deferred, not pinned, not ephemeral.
 
+use crate::cli::commands::claim;
 use crate::cli::{OutputFormat, ReadyArgs, SortPolicy, resolve_output_format_basic};
 use crate::config;
+use crate::config::federation::{self, Project};
 use crate::error::Result;
-use crate::format::{ReadyIssue, format_priority_badge, terminal_width, truncate_title};
-use crate::model::{IssueType, Priority};
+use crate::format::{
+    ProjectItem, ReadyIssue, format_priority_badge, terminal_width, truncate_title,
+};
+use crate::model::{Issue, IssueType, Priority};
 use crate::output::{IssueTable, IssueTableColumns, OutputContext, OutputMode};
-use crate::storage::{ReadyFilters, ReadySortPolicy};
+use crate::storage::{ReadyFilters, ReadySortPolicy, SqliteStorage};
+use std::collections::HashMap;
 use std::io::IsTerminal;
+use std::path::PathBuf;
 use std::str::FromStr;
 use tracing::{debug, info, trace};
 use unicode_width::UnicodeWidthStr;
@@ -19,6 +25,7 @@ use unicode_width::UnicodeWidthStr;
 /// # Errors
 ///
 /// Returns an error if the database cannot be opened or the query fails.
+#[allow(clippy::too_many_lines)]
 pub fn execute(
     args: &ReadyArgs,
     _json: bool,
@@ -27,10 +34,14 @@ pub fn execute(
 ) -> Result<()> {
     // Open storage
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
-    let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+
+    let config_layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+
+    // Issues whose claim lease lapsed go back to the ready pool before listing.
+    claim::reap_expired_leases(&mut storage_ctx, cli, &config::resolve_actor(&config_layer))?;
     let storage = &storage_ctx.storage;
 
-    let config_layer = config::load_config(&beads_dir, Some(storage), cli)?;
     let external_db_paths = config::external_project_db_paths(&config_layer, &beads_dir);
     let use_color = config::should_use_color(&config_layer);
     let max_width = if std::io::stdout().is_terminal() {
@@ -65,20 +76,41 @@ pub fn execute(
     info!("Fetching ready issues");
     debug!(filters = ?filters, sort = ?sort_policy, "Applied ready filters");
 
-    // Get ready issues from storage (blocked cache only)
-    let mut ready_issues = storage.get_ready_issues(&filters, sort_policy)?;
-
-    let external_statuses =
-        storage.resolve_external_dependency_statuses(&external_db_paths, true)?;
-    let external_blockers = storage.external_blockers(&external_statuses)?;
-    if !external_blockers.is_empty() {
-        ready_issues.retain(|issue| !external_blockers.contains_key(&issue.id));
+    if args.federation.is_federated() {
+        let projects = federation::select_projects(
+            &config_layer,
+            &beads_dir,
+            &storage_ctx.paths.db_path,
+            args.federation.all_projects,
+            &args.federation.projects,
+        )?;
+        let results = federation::for_each_project(&projects, storage, |project, storage| {
+            // Each project resolves its own external dependencies.
+            let external_db_paths = if project.is_local {
+                external_db_paths.clone()
+            } else {
+                let layer = federation::project_config(project, storage)?;
+                config::external_project_db_paths(&layer, &project.beads_dir)
+            };
+            query_ready(
+                storage,
+                &filters,
+                sort_policy,
+                &external_db_paths,
+                args.limit,
+            )
+        })?;
+        render_federated(&results, args, output_format, &ctx, use_color, max_width);
+        return Ok(());
     }
 
-    // Apply limit after external filtering
-    if args.limit > 0 && ready_issues.len() > args.limit {
-        ready_issues.truncate(args.limit);
-    }
+    let ready_issues = query_ready(
+        storage,
+        &filters,
+        sort_policy,
+        &external_db_paths,
+        args.limit,
+    )?;
 
     info!(count = ready_issues.len(), "Found ready issues");
     for issue in ready_issues.iter().take(5) {
@@ -142,6 +174,104 @@ pub fn execute(
     Ok(())
 }
 
+/// Ready issues in one database, dropping those blocked by unsatisfied
+/// external (including cross-project) dependencies.
+fn query_ready(
+    storage: &SqliteStorage,
+    filters: &ReadyFilters,
+    sort_policy: ReadySortPolicy,
+    external_db_paths: &HashMap<String, PathBuf>,
+    limit: usize,
+) -> Result<Vec<Issue>> {
+    // Get ready issues from storage (blocked cache only)
+    let mut ready_issues = storage.get_ready_issues(filters, sort_policy)?;
+
+    let external_statuses =
+        storage.resolve_external_dependency_statuses(external_db_paths, true)?;
+    let external_blockers = storage.external_blockers(&external_statuses)?;
+    if !external_blockers.is_empty() {
+        ready_issues.retain(|issue| !external_blockers.contains_key(&issue.id));
+    }
+
+    // Apply limit after external filtering
+    if limit > 0 && ready_issues.len() > limit {
+        ready_issues.truncate(limit);
+    }
+    Ok(ready_issues)
+}
+
+/// `--all-projects`/`--projects`: ready work grouped by project, each issue
+/// tagged with its project in JSON/TOON. Limits apply per project.
+fn render_federated(
+    results: &[(&Project, Vec<Issue>)],
+    args: &ReadyArgs,
+    output_format: OutputFormat,
+    ctx: &OutputContext,
+    use_color: bool,
+    max_width: Option<usize>,
+) {
+    if matches!(ctx.mode(), OutputMode::Quiet) {
+        return;
+    }
+    if matches!(output_format, OutputFormat::Json | OutputFormat::Toon) {
+        let items: Vec<ProjectItem<ReadyIssue>> = results
+            .iter()
+            .flat_map(|(project, issues)| {
+                issues.iter().map(|issue| ProjectItem {
+                    project: project.name.clone(),
+                    item: ReadyIssue::from(issue),
+                })
+            })
+            .collect();
+        if output_format == OutputFormat::Toon {
+            ctx.toon_with_stats(&items, args.stats);
+        } else {
+            ctx.json_pretty(&items);
+        }
+        return;
+    }
+
+    let total: usize = results.iter().map(|(_, issues)| issues.len()).sum();
+    if total == 0 {
+        println!("✨ No open issues");
+        return;
+    }
+    if !matches!(ctx.mode(), OutputMode::Rich) {
+        println!(
+            "📋 Ready work ({total} issue{} across {} project{}):",
+            if total == 1 { "" } else { "s" },
+            results.len(),
+            if results.len() == 1 { "" } else { "s" }
+        );
+    }
+    for (project, issues) in results {
+        if issues.is_empty() {
+            continue;
+        }
+        if matches!(ctx.mode(), OutputMode::Rich) {
+            let table = IssueTable::new(issues, ctx.theme())
+                .columns(IssueTableColumns {
+                    id: true,
+                    priority: true,
+                    status: true,
+                    issue_type: true,
+                    title: true,
+                    ..Default::default()
+                })
+                .title(format!("{}: Ready work ({})", project.name, issues.len()))
+                .wrap(args.wrap)
+                .build();
+            ctx.render(&table);
+        } else {
+            println!("\n{} ({}):", project.name, issues.len());
+            for (i, issue) in issues.iter().enumerate() {
+                let line = format_ready_line(i + 1, issue, use_color, max_width, args.wrap);
+                println!("  {line}");
+            }
+        }
+    }
+}
+
 fn format_ready_line(
     index: usize,
     issue: &crate::model::Issue,
@@ -177,7 +307,7 @@ fn format_ready_line(
 }
 
 /// Parse type filter strings to `IssueType` enums.
-fn parse_types(types: &[String]) -> Result<Option<Vec<IssueType>>> {
+pub(crate) fn parse_types(types: &[String]) -> Result<Option<Vec<IssueType>>> {
     if types.is_empty() {
         return Ok(None);
     }
@@ -191,7 +321,7 @@ fn parse_types(types: &[String]) -> Result<Option<Vec<IssueType>>> {
 }
 
 /// Parse priority filter strings to Priority values.
-fn parse_priorities(priorities: &[String]) -> Result<Option<Vec<Priority>>> {
+pub(crate) fn parse_priorities(priorities: &[String]) -> Result<Option<Vec<Priority>>> {
     if priorities.is_empty() {
         return Ok(None);
     }
@@ -0,0 +1,459 @@
+//! Recur command implementation.
+//!
+//! Recurrence rules live on template issues. `br recur run` materializes the
+//! current occurrence of each rule as a regular issue whose ID is derived from
+//! the template and the occurrence, so clones that run it independently create
+//! the same issue and the JSONL merge sees one record instead of duplicates.
+//! Missed periods are not backfilled: each run creates at most the latest due
+//! instance per rule.
+
+use crate::cli::{RecurCommands, RecurSetArgs};
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::model::{Issue, Recurrence, Status};
+use crate::output::OutputContext;
+use crate::storage::SqliteStorage;
+use crate::util::id::{IdResolver, ResolverConfig, compute_id_hash, find_matching_ids, parse_id};
+use crate::util::schedule::Schedule;
+use crate::util::time::{parse_duration, parse_flexible_timestamp};
+use crate::validation::IssueValidator;
+use chrono::{DateTime, Duration, DurationRound, Utc};
+use serde::Serialize;
+
+/// Hash length of instance IDs; fixed so every clone derives the same ID.
+const INSTANCE_HASH_LENGTH: usize = 8;
+
+/// JSON output for a rule in `br recur set` and `br recur list`.
+#[derive(Debug, Serialize)]
+struct RuleReport {
+    template_id: String,
+    title: String,
+    #[serde(flatten)]
+    recurrence: Recurrence,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    next_occurrence: Option<DateTime<Utc>>,
+}
+
+/// An instance created (or to be created) by `br recur run`.
+#[derive(Debug, Serialize)]
+struct RecurInstance {
+    id: String,
+    template_id: String,
+    title: String,
+    occurrence: DateTime<Utc>,
+}
+
+/// JSON output for `br recur run`.
+#[derive(Debug, Serialize)]
+struct RunReport {
+    created: Vec<RecurInstance>,
+    dry_run: bool,
+}
+
+/// Execute the recur command.
+///
+/// # Errors
+///
+/// Returns an error if the database cannot be opened, an ID cannot be
+/// resolved, a rule or duration is invalid, or an instance cannot be created.
+pub fn execute(
+    command: &RecurCommands,
+    json: bool,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+
+    let config_layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+    let id_config = config::id_config_from_layer(&config_layer);
+    let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
+    let all_ids = storage_ctx.storage.get_all_ids()?;
+    let actor = config::resolve_actor(&config_layer);
+    let resolve = |input: &str| -> Result<String> {
+        let resolution = resolver.resolve(
+            input,
+            |id| all_ids.iter().any(|existing| existing == id),
+            |hash| find_matching_ids(&all_ids, hash),
+        )?;
+        Ok(resolution.id)
+    };
+    let storage = &mut storage_ctx.storage;
+
+    match command {
+        RecurCommands::Set(args) => {
+            let id = resolve(&args.id)?;
+            let report = set_rule(storage, &id, &actor, args)?;
+            crate::util::set_last_touched_id(&beads_dir, &id);
+            if json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else {
+                let next = report
+                    .next_occurrence
+                    .map(|at| format!(" (next: {})", format_occurrence(at)))
+                    .unwrap_or_default();
+                ctx.success(&format!(
+                    "{id} recurs on '{}'{next}",
+                    report.recurrence.rule
+                ));
+            }
+        }
+        RecurCommands::Remove(args) => {
+            let id = resolve(&args.id)?;
+            let removed = storage.clear_recurrence(&id, &actor)?;
+            if json {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&serde_json::json!({
+                        "template_id": id,
+                        "removed": removed,
+                    }))?
+                );
+            } else if removed {
+                ctx.success(&format!("Removed recurrence from {id}"));
+            } else {
+                println!("{id} has no recurrence rule.");
+            }
+        }
+        RecurCommands::List => {
+            let rules = list_rules(storage, Utc::now())?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&rules)?);
+            } else if rules.is_empty() {
+                println!("No recurrence rules.");
+            } else {
+                for rule in &rules {
+                    let next = rule
+                        .next_occurrence
+                        .map_or_else(|| "never".to_string(), format_occurrence);
+                    println!(
+                        "{}  {}  next {next}  {}",
+                        rule.template_id, rule.recurrence.rule, rule.title
+                    );
+                }
+            }
+        }
+        RecurCommands::Run(args) => {
+            let created = run_due(storage, &actor, Utc::now(), args.dry_run)?;
+            if json {
+                let report = RunReport {
+                    created,
+                    dry_run: args.dry_run,
+                };
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else if created.is_empty() {
+                println!("No recurring issues due.");
+            } else {
+                let verb = if args.dry_run {
+                    "Would create"
+                } else {
+                    "Created"
+                };
+                for instance in &created {
+                    println!(
+                        "{verb} {}: {} (from {})",
+                        instance.id, instance.title, instance.template_id
+                    );
+                }
+            }
+        }
+    }
+
+    storage_ctx.flush_no_db_if_dirty()?;
+    Ok(())
+}
+
+fn set_rule(
+    storage: &mut SqliteStorage,
+    id: &str,
+    actor: &str,
+    args: &RecurSetArgs,
+) -> Result<RuleReport> {
+    let template = storage
+        .get_issue(id)?
+        .ok_or_else(|| BeadsError::IssueNotFound { id: id.to_string() })?;
+    let starts_at = match &args.start {
+        Some(start) => parse_flexible_timestamp(start, "start")?,
+        None => Utc::now(),
+    };
+    // Occurrences fall on whole minutes; keep the start's own minute eligible.
+    let starts_at = starts_at
+        .duration_trunc(Duration::minutes(1))
+        .unwrap_or(starts_at);
+    let minutes = |value: &Option<String>, field: &str| -> Result<Option<i64>> {
+        value
+            .as_deref()
+            .map(|value| parse_duration(value, field).map(|d| d.num_minutes()))
+            .transpose()
+    };
+    let recurrence = Recurrence {
+        rule: args.rule.trim().to_string(),
+        starts_at,
+        lead_minutes: minutes(&args.lead, "lead")?,
+        due_minutes: minutes(&args.due, "due")?,
+    };
+
+    let schedule = Schedule::parse(&recurrence.rule, starts_at)?;
+    let next_occurrence = next_occurrence(&recurrence, &schedule, Utc::now());
+    if next_occurrence.is_none() {
+        return Err(BeadsError::validation(
+            "rule",
+            format!("'{}' never fires", recurrence.rule),
+        ));
+    }
+
+    storage.set_recurrence(id, &recurrence, actor)?;
+    Ok(RuleReport {
+        template_id: id.to_string(),
+        title: template.title,
+        recurrence,
+        next_occurrence,
+    })
+}
+
+fn list_rules(storage: &SqliteStorage, now: DateTime<Utc>) -> Result<Vec<RuleReport>> {
+    let mut rules: Vec<(String, Recurrence)> = storage.get_all_recurrences()?.into_iter().collect();
+    rules.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut reports = Vec::new();
+    for (template_id, recurrence) in rules {
+        let Some(template) = storage.get_issue(&template_id)? else {
+            continue;
+        };
+        let next_occurrence = Schedule::parse(&recurrence.rule, recurrence.starts_at)
+            .ok()
+            .and_then(|schedule| next_occurrence(&recurrence, &schedule, now));
+        reports.push(RuleReport {
+            template_id,
+            title: template.title,
+            recurrence,
+            next_occurrence,
+        });
+    }
+    Ok(reports)
+}
+
+/// Create the latest due instance of every active rule that does not exist yet.
+fn run_due(
+    storage: &mut SqliteStorage,
+    actor: &str,
+    now: DateTime<Utc>,
+    dry_run: bool,
+) -> Result<Vec<RecurInstance>> {
+    let mut rules: Vec<(String, Recurrence)> = storage.get_all_recurrences()?.into_iter().collect();
+    rules.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut created = Vec::new();
+    for (template_id, recurrence) in rules {
+        let Some(mut template) = storage.get_issue(&template_id)? else {
+            continue;
+        };
+        // Closing or deleting the template pauses its rule.
+        if matches!(template.status, Status::Closed | Status::Tombstone) {
+            continue;
+        }
+        let schedule = match Schedule::parse(&recurrence.rule, recurrence.starts_at) {
+            Ok(schedule) => schedule,
+            Err(err) => {
+                tracing::warn!(template = %template_id, error = %err, "Skipping invalid recurrence rule");
+                continue;
+            }
+        };
+        let Some(occurrence) = due_occurrence(&recurrence, &schedule, now) else {
+            continue;
+        };
+        let id = instance_id(&template_id, occurrence);
+        if storage.id_exists(&id)? {
+            continue;
+        }
+
+        template.labels = storage.get_labels(&template_id)?;
+        let instance = build_instance(&template, &recurrence, &id, occurrence, actor, now);
+        IssueValidator::validate(&instance).map_err(BeadsError::from_validation_errors)?;
+        if !dry_run {
+            storage.create_issue(&instance, actor)?;
+        }
+        created.push(RecurInstance {
+            id,
+            template_id,
+            title: instance.title,
+            occurrence,
+        });
+    }
+    Ok(created)
+}
+
+/// Latest occurrence whose instance should exist at `now`, honoring the lead time.
+fn due_occurrence(
+    recurrence: &Recurrence,
+    schedule: &Schedule,
+    now: DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+    let horizon = now + Duration::minutes(recurrence.lead_minutes.unwrap_or(0));
+    schedule
+        .latest_at_or_before(horizon)
+        .filter(|occurrence| *occurrence >= recurrence.starts_at)
+}
+
+/// First occurrence whose instance does not exist yet at `now`.
+fn next_occurrence(
+    recurrence: &Recurrence,
+    schedule: &Schedule,
+    now: DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+    let horizon = now + Duration::minutes(recurrence.lead_minutes.unwrap_or(0));
+    let after = horizon.max(recurrence.starts_at - Duration::seconds(1));
+    schedule.next_after(after)
+}
+
+/// Deterministic instance ID for a template's occurrence.
+fn instance_id(template_id: &str, occurrence: DateTime<Utc>) -> String {
+    let prefix = parse_id(template_id).map_or_else(
+        |_| {
+            template_id
+                .split('-')
+                .next()
+                .unwrap_or(template_id)
+                .to_string()
+        },
+        |parsed| parsed.prefix,
+    );
+    let seed = format!(
+        "recur|{template_id}|{}",
+        occurrence.format("%Y-%m-%dT%H:%MZ")
+    );
+    format!("{prefix}-{}", compute_id_hash(&seed, INSTANCE_HASH_LENGTH))
+}
+
+fn build_instance(
+    template: &Issue,
+    recurrence: &Recurrence,
+    id: &str,
+    occurrence: DateTime<Utc>,
+    actor: &str,
+    now: DateTime<Utc>,
+) -> Issue {
+    let mut instance = Issue {
+        id: id.to_string(),
+        title: format!("{} ({})", template.title, occurrence.format("%Y-%m-%d")),
+        description: template.description.clone(),
+        design: template.design.clone(),
+        acceptance_criteria: template.acceptance_criteria.clone(),
+        notes: template.notes.clone(),
+        status: Status::Open,
+        priority: template.priority,
+        issue_type: template.issue_type.clone(),
+        assignee: template.assignee.clone(),
+        owner: template.owner.clone(),
+        estimated_minutes: template.estimated_minutes,
+        created_at: now,
+        created_by: Some(actor.to_string()),
+        updated_at: now,
+        defer_until: Some(occurrence),
+        due_at: recurrence
+            .due_minutes
+            .map(|minutes| occurrence + Duration::minutes(minutes)),
+        labels: template.labels.clone(),
+        ..Issue::default()
+    };
+    instance.content_hash = Some(instance.compute_content_hash());
+    instance
+}
+
+fn format_occurrence(at: DateTime<Utc>) -> String {
+    at.format("%Y-%m-%d %H:%M UTC").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(y: i32, mo: u32, d: u32, h: u32, mi: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, mo, d, h, mi, 0).unwrap()
+    }
+
+    fn weekly_rule(lead_minutes: Option<i64>) -> Recurrence {
+        Recurrence {
+            rule: "0 9 * * MON".to_string(),
+            starts_at: at(2026, 10, 1, 0, 0),
+            lead_minutes,
+            due_minutes: Some(60 * 24 * 2),
+        }
+    }
+
+    #[test]
+    fn test_instance_id_is_stable_per_occurrence() {
+        let monday = at(2026, 10, 12, 9, 0);
+        let id = instance_id("bd-abc", monday);
+        assert_eq!(id, instance_id("bd-abc", monday));
+        assert!(id.starts_with("bd-"));
+        assert_eq!(id.len(), "bd-".len() + INSTANCE_HASH_LENGTH);
+        assert_ne!(id, instance_id("bd-abc", at(2026, 10, 19, 9, 0)));
+        assert_ne!(id, instance_id("bd-xyz", monday));
+    }
+
+    #[test]
+    fn test_due_occurrence_respects_lead_and_start() {
+        // Saturday 2026-10-17: the latest due occurrence is the previous Monday.
+        let saturday = at(2026, 10, 17, 12, 0);
+        let rule = weekly_rule(None);
+        let schedule = Schedule::parse(&rule.rule, rule.starts_at).unwrap();
+        assert_eq!(
+            due_occurrence(&rule, &schedule, saturday),
+            Some(at(2026, 10, 12, 9, 0))
+        );
+        assert_eq!(
+            next_occurrence(&rule, &schedule, saturday),
+            Some(at(2026, 10, 19, 9, 0))
+        );
+
+        // With three days of lead time the upcoming Monday is already due.
+        let early = weekly_rule(Some(60 * 24 * 3));
+        assert_eq!(
+            due_occurrence(&early, &schedule, saturday),
+            Some(at(2026, 10, 19, 9, 0))
+        );
+
+        let later = Recurrence {
+            starts_at: at(2026, 10, 13, 0, 0),
+            ..weekly_rule(None)
+        };
+        assert_eq!(due_occurrence(&later, &schedule, saturday), None);
+    }
+
+    #[test]
+    fn test_run_due_is_idempotent() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let template = Issue {
+            id: "bd-tpl".to_string(),
+            title: "Dependency audit".to_string(),
+            ..Default::default()
+        };
+        storage.create_issue(&template, "alice").unwrap();
+        storage.add_label("bd-tpl", "maintenance", "alice").unwrap();
+        storage
+            .set_recurrence("bd-tpl", &weekly_rule(None), "alice")
+            .unwrap();
+
+        let now = at(2026, 10, 17, 12, 0);
+        let created = run_due(&mut storage, "alice", now, false).unwrap();
+        assert_eq!(created.len(), 1);
+        assert!(
+            run_due(&mut storage, "alice", now, false)
+                .unwrap()
+                .is_empty()
+        );
+
+        let instance = storage.get_issue(&created[0].id).unwrap().unwrap();
+        assert_eq!(instance.title, "Dependency audit (2026-10-12)");
+        assert_eq!(instance.defer_until, Some(at(2026, 10, 12, 9, 0)));
+        assert_eq!(instance.due_at, Some(at(2026, 10, 14, 9, 0)));
+        assert!(!instance.is_template);
+        assert_eq!(
+            storage.get_labels(&instance.id).unwrap(),
+            vec!["maintenance".to_string()]
+        );
+        assert!(storage.get_issue("bd-tpl").unwrap().unwrap().is_template);
+    }
+}
@@ -2,7 +2,9 @@
 
 use crate::cli::ReopenArgs;
 use crate::config;
+use crate::config::workflow::Workflow;
 use crate::error::{BeadsError, Result};
+use crate::hooks::{HookEvent, Hooks};
 use crate::model::Status;
 use crate::output::{OutputContext, OutputMode};
 use crate::storage::IssueUpdate;
@@ -58,6 +60,8 @@ pub fn execute(
     let actor = config::resolve_actor(&config_layer);
     let id_config = config::id_config_from_layer(&config_layer);
     let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
+    let workflow = Workflow::load(&config_layer)?;
+    let hooks = Hooks::load(&beads_dir, &config_layer)?;
     let all_ids = storage_ctx.storage.get_all_ids()?;
     let storage = &mut storage_ctx.storage;
 
@@ -109,6 +113,34 @@ pub fn execute(
 
         tracing::debug!(previous_status = ?issue.status, "Issue was previously {:?}", issue.status);
 
+        // Check the workflow allows reopening and no pre-hook vetoes it
+        let mut reopening = issue.clone();
+        if !workflow.is_empty() || hooks.is_active() {
+            reopening.labels = storage.get_labels(id)?;
+        }
+        if let Some(reason) = workflow.violation(&reopening, &Status::Open) {
+            skipped_issues.push(SkippedIssue {
+                id: id.clone(),
+                reason,
+            });
+            continue;
+        }
+        let event = HookEvent::StatusChange {
+            from: &issue.status,
+            to: &Status::Open,
+        };
+        match hooks.pre(&event, &actor, &reopening) {
+            Ok(()) => {}
+            Err(err @ BeadsError::HookRejected { .. }) => {
+                skipped_issues.push(SkippedIssue {
+                    id: id.clone(),
+                    reason: err.to_string(),
+                });
+                continue;
+            }
+            Err(err) => return Err(err),
+        }
+
         // Build update: set status=open, clear closed_at, clear tombstone fields
         let update = IssueUpdate {
             status: Some(Status::Open),
@@ -132,6 +164,13 @@ pub fn execute(
             storage.add_comment(id, &actor, &comment_text)?;
         }
 
+        if hooks.is_active() {
+            if let Some(mut reopened) = storage.get_issue(id)? {
+                reopened.labels = reopening.labels;
+                hooks.post(&event, &actor, &reopened);
+            }
+        }
+
         // Update last touched
         crate::util::set_last_touched_id(&beads_dir, id);
 
@@ -1,16 +1,21 @@
 //! Search command implementation.
 //!
-//! Classic bd-style LIKE search across title/description/id with list-like filters.
+//! Full-text search (FTS5, BM25 ranked) across title, description, design,
+//! acceptance criteria, notes and comments, plus ID substring matching, with
+//! list-like filters. Query syntax is documented in [`crate::storage::fts`].
 
 use crate::cli::{ListArgs, OutputFormat, SearchArgs, resolve_output_format};
 use crate::config;
+use crate::config::federation::{self, Project};
 use crate::error::{BeadsError, Result};
 use crate::format::{
-    IssueWithCounts, TextFormatOptions, csv, format_issue_line_with, terminal_width,
+    IssueWithCounts, ProjectItem, SearchResult, TextFormatOptions, csv, format_issue_line_with,
+    terminal_width,
 };
 use crate::model::{IssueType, Priority, Status};
 use crate::output::{IssueTable, IssueTableColumns, OutputContext, OutputMode};
-use crate::storage::{ListFilters, SqliteStorage};
+use crate::storage::filter_expr::Field;
+use crate::storage::{FilterExpr, ListFilters, SqliteStorage, fts};
 use chrono::Utc;
 use regex::{Regex, RegexBuilder};
 use std::collections::{HashMap, HashSet};
@@ -54,24 +59,120 @@ pub fn execute(
         wrap: args.filters.wrap,
     };
 
-    let mut filters = build_filters(&args.filters)?;
-    let client_filters = needs_client_filters(&args.filters);
+    let output_format = resolve_output_format(args.filters.format, outer_ctx.is_json(), false);
+    let needs_counts = matches!(output_format, OutputFormat::Json | OutputFormat::Toon);
+    let quiet = cli.quiet.unwrap_or(false);
+    let ctx = OutputContext::from_output_format(output_format, quiet, !use_color);
+
+    if args.filters.federation.is_federated() {
+        let projects = federation::select_projects(
+            &config_layer,
+            &beads_dir,
+            &storage_ctx.paths.db_path,
+            args.filters.federation.all_projects,
+            &args.filters.federation.projects,
+        )?;
+        let results = federation::for_each_project(&projects, storage, |_, storage| {
+            search_project(storage, query, &args.filters, needs_counts)
+        })?;
+        render_federated(results, query, args, output_format, &ctx, format_options);
+        return Ok(());
+    }
+
+    let (issues_with_counts, mut relevance) =
+        search_project(storage, query, &args.filters, needs_counts)?;
+
+    if matches!(ctx.mode(), OutputMode::Quiet) {
+        return Ok(());
+    }
+
+    match output_format {
+        OutputFormat::Json => {
+            ctx.json_pretty(&to_search_results(issues_with_counts, &mut relevance));
+            return Ok(());
+        }
+        OutputFormat::Toon => {
+            ctx.toon_with_stats(
+                &to_search_results(issues_with_counts, &mut relevance),
+                args.filters.stats,
+            );
+            return Ok(());
+        }
+        OutputFormat::Csv => {
+            let issues: Vec<_> = issues_with_counts
+                .iter()
+                .map(|iwc| iwc.issue.clone())
+                .collect();
+            let fields = csv::parse_fields(args.filters.fields.as_deref());
+            let csv_output = csv::format_csv(&issues, &fields);
+            print!("{csv_output}");
+            return Ok(());
+        }
+        OutputFormat::Text => {}
+    }
+
+    if matches!(ctx.mode(), OutputMode::Rich) {
+        let title = format!(
+            "Search: \"{}\" - {} result{}",
+            query,
+            issues_with_counts.len(),
+            if issues_with_counts.len() == 1 {
+                ""
+            } else {
+                "s"
+            }
+        );
+        render_rich_table(&issues_with_counts, query, &relevance, args, &ctx, title);
+        return Ok(());
+    }
+
+    ctx.info(&format!(
+        "Found {} issue(s) matching '{}'",
+        issues_with_counts.len(),
+        query
+    ));
+    for iwc in &issues_with_counts {
+        let line = format_issue_line_with(&iwc.issue, format_options);
+        ctx.print(&line);
+    }
+
+    Ok(())
+}
+
+/// Score and snippet per issue ID, from the full-text index.
+type Relevance = HashMap<String, (Option<f64>, Option<String>)>;
+
+/// Run the search against one database: ranked hits, client-side filters,
+/// optional dependency counts, sort and limit.
+fn search_project(
+    storage: &SqliteStorage,
+    query: &str,
+    args: &ListArgs,
+    needs_counts: bool,
+) -> Result<(Vec<IssueWithCounts>, Relevance)> {
+    let mut filters = build_filters(args)?;
+    let client_filters = needs_client_filters(args);
     let limit = if client_filters {
         filters.limit.take()
     } else {
         None
     };
 
-    let issues = storage.search_issues(query, &filters)?;
+    let hits = storage.search_issues_ranked(query, &filters)?;
+    let mut relevance: Relevance = HashMap::with_capacity(hits.len());
+    let issues: Vec<_> = hits
+        .into_iter()
+        .map(|hit| {
+            relevance.insert(hit.issue.id.clone(), (hit.score, hit.snippet));
+            hit.issue
+        })
+        .collect();
     let issues = if client_filters {
-        apply_client_filters(storage, issues, &args.filters)?
+        apply_client_filters(storage, issues, args)?
     } else {
         issues
     };
 
-    let output_format = resolve_output_format(args.filters.format, outer_ctx.is_json(), false);
-    let needs_counts = matches!(output_format, OutputFormat::Json | OutputFormat::Toon);
-
     // Batch count dependencies/dependents (JSON/TOON output only).
     let issue_ids: Vec<String> = issues.iter().map(|i| i.id.clone()).collect();
     let (dep_counts, dependent_counts) = if needs_counts {
@@ -96,8 +197,8 @@ pub fn execute(
         })
         .collect();
 
-    apply_sort(&mut issues_with_counts, args.filters.sort.as_deref())?;
-    if args.filters.reverse {
+    apply_sort(&mut issues_with_counts, args.sort.as_deref())?;
+    if args.reverse {
         issues_with_counts.reverse();
     }
     if let Some(limit) = limit {
@@ -105,93 +206,166 @@ pub fn execute(
             issues_with_counts.truncate(limit);
         }
     }
+    Ok((issues_with_counts, relevance))
+}
 
-    let quiet = cli.quiet.unwrap_or(false);
-    let ctx = OutputContext::from_output_format(output_format, quiet, !use_color);
+fn render_rich_table(
+    issues_with_counts: &[IssueWithCounts],
+    query: &str,
+    relevance: &Relevance,
+    args: &SearchArgs,
+    ctx: &OutputContext,
+    title: String,
+) {
+    let issues: Vec<_> = issues_with_counts
+        .iter()
+        .map(|iwc| iwc.issue.clone())
+        .collect();
+    let context_snippets = build_context_snippets(&issues, query, relevance);
+    let show_context = !context_snippets.is_empty();
+    let columns = IssueTableColumns {
+        id: true,
+        priority: true,
+        status: true,
+        issue_type: true,
+        title: true,
+        assignee: true,
+        context: show_context,
+        ..Default::default()
+    };
+    let mut table = IssueTable::new(&issues, ctx.theme())
+        .columns(columns)
+        .title(title)
+        .highlight_query(query)
+        .wrap(args.filters.wrap);
+    if args.filters.wrap {
+        table = table.width(Some(ctx.width()));
+    }
+    if show_context {
+        table = table.context_snippets(context_snippets);
+    }
+    ctx.render(&table.build());
+}
 
+/// `--all-projects`/`--projects`: results grouped by project, each tagged
+/// with its project in JSON/TOON/CSV. Limits apply per project.
+fn render_federated(
+    results: Vec<(&Project, (Vec<IssueWithCounts>, Relevance))>,
+    query: &str,
+    args: &SearchArgs,
+    output_format: OutputFormat,
+    ctx: &OutputContext,
+    format_options: TextFormatOptions,
+) {
     if matches!(ctx.mode(), OutputMode::Quiet) {
-        return Ok(());
+        return;
     }
 
     match output_format {
-        OutputFormat::Json => {
-            ctx.json_pretty(&issues_with_counts);
-            return Ok(());
-        }
-        OutputFormat::Toon => {
-            ctx.toon_with_stats(&issues_with_counts, args.filters.stats);
-            return Ok(());
+        OutputFormat::Json | OutputFormat::Toon => {
+            let items: Vec<ProjectItem<SearchResult>> = results
+                .into_iter()
+                .flat_map(|(project, (issues, mut relevance))| {
+                    to_search_results(issues, &mut relevance)
+                        .into_iter()
+                        .map(|item| ProjectItem {
+                            project: project.name.clone(),
+                            item,
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect();
+            if output_format == OutputFormat::Toon {
+                ctx.toon_with_stats(&items, args.filters.stats);
+            } else {
+                ctx.json_pretty(&items);
+            }
         }
         OutputFormat::Csv => {
-            let issues: Vec<_> = issues_with_counts
-                .iter()
-                .map(|iwc| iwc.issue.clone())
-                .collect();
             let fields = csv::parse_fields(args.filters.fields.as_deref());
-            let csv_output = csv::format_csv(&issues, &fields);
-            print!("{csv_output}");
-            return Ok(());
-        }
-        OutputFormat::Text => {}
-    }
-
-    if matches!(ctx.mode(), OutputMode::Rich) {
-        let issues: Vec<_> = issues_with_counts
-            .iter()
-            .map(|iwc| iwc.issue.clone())
-            .collect();
-        let context_snippets = build_context_snippets(&issues, query);
-        let show_context = !context_snippets.is_empty();
-        let columns = IssueTableColumns {
-            id: true,
-            priority: true,
-            status: true,
-            issue_type: true,
-            title: true,
-            assignee: true,
-            context: show_context,
-            ..Default::default()
-        };
-        let mut table = IssueTable::new(&issues, ctx.theme())
-            .columns(columns)
-            .title(format!(
-                "Search: \"{}\" - {} result{}",
-                query,
-                issues.len(),
-                if issues.len() == 1 { "" } else { "s" }
-            ))
-            .highlight_query(query)
-            .wrap(args.filters.wrap);
-        if args.filters.wrap {
-            table = table.width(Some(ctx.width()));
+            let mut out = format!("project,{}\n", fields.join(","));
+            for (project, (issues, _)) in &results {
+                for iwc in issues {
+                    out.push_str(&csv::escape_field(&project.name));
+                    out.push(',');
+                    out.push_str(&csv::format_issue_row(&iwc.issue, &fields));
+                    out.push('\n');
+                }
+            }
+            print!("{out}");
         }
-        if show_context {
-            table = table.context_snippets(context_snippets);
+        OutputFormat::Text => {
+            let total: usize = results.iter().map(|(_, (issues, _))| issues.len()).sum();
+            if !matches!(ctx.mode(), OutputMode::Rich) {
+                ctx.info(&format!(
+                    "Found {total} issue(s) matching '{query}' in {} project(s)",
+                    results.len()
+                ));
+            }
+            for (project, (issues, relevance)) in &results {
+                if issues.is_empty() {
+                    continue;
+                }
+                if matches!(ctx.mode(), OutputMode::Rich) {
+                    let title =
+                        format!("{}: \"{query}\" - {} result(s)", project.name, issues.len());
+                    render_rich_table(issues, query, relevance, args, ctx, title);
+                } else {
+                    ctx.print(&format!("{} ({}):", project.name, issues.len()));
+                    for iwc in issues {
+                        ctx.print(&format!(
+                            "  {}",
+                            format_issue_line_with(&iwc.issue, format_options)
+                        ));
+                    }
+                }
+            }
         }
-        ctx.render(&table.build());
-        return Ok(());
-    }
-
-    ctx.info(&format!(
-        "Found {} issue(s) matching '{}'",
-        issues_with_counts.len(),
-        query
-    ));
-    for iwc in &issues_with_counts {
-        let line = format_issue_line_with(&iwc.issue, format_options);
-        ctx.print(&line);
     }
+}
 
-    Ok(())
+fn to_search_results(issues: Vec<IssueWithCounts>, relevance: &mut Relevance) -> Vec<SearchResult> {
+    issues
+        .into_iter()
+        .map(|iwc| {
+            let (score, snippet) = relevance.remove(&iwc.issue.id).unwrap_or_default();
+            SearchResult {
+                issue: iwc.issue,
+                dependency_count: iwc.dependency_count,
+                dependent_count: iwc.dependent_count,
+                score,
+                snippet,
+            }
+        })
+        .collect()
 }
 
-fn build_context_snippets(issues: &[crate::model::Issue], query: &str) -> HashMap<String, String> {
-    let Some(regex) = build_highlight_regex(query) else {
-        return HashMap::new();
-    };
+fn build_context_snippets(
+    issues: &[crate::model::Issue],
+    query: &str,
+    relevance: &Relevance,
+) -> HashMap<String, String> {
+    let regex = build_highlight_regex(query);
 
     let mut snippets = HashMap::new();
     for issue in issues {
+        // Prefer the FTS excerpt (minus markers; the table highlights on its
+        // own) unless it just repeats the title.
+        if let Some((_, Some(snippet))) = relevance.get(&issue.id) {
+            let plain = normalize_whitespace(
+                &snippet
+                    .replace(fts::SNIPPET_OPEN, "")
+                    .replace(fts::SNIPPET_CLOSE, ""),
+            );
+            if plain != normalize_whitespace(&issue.title) {
+                snippets.insert(issue.id.clone(), plain);
+                continue;
+            }
+        }
+
+        let Some(regex) = regex.as_ref() else {
+            continue;
+        };
         if let Some(description) = issue.description.as_deref() {
             if let Some(mat) = regex.find(description) {
                 let snippet = snippet_around_match(description, mat.start(), mat.end(), 32);
@@ -259,7 +433,7 @@ fn normalize_whitespace(input: &str) -> String {
     input.split_whitespace().collect::<Vec<_>>().join(" ")
 }
 
-fn build_filters(args: &ListArgs) -> Result<ListFilters> {
+pub(crate) fn build_filters(args: &ListArgs) -> Result<ListFilters> {
     let statuses = if args.status.is_empty() {
         None
     } else {
@@ -292,7 +466,14 @@ fn build_filters(args: &ListArgs) -> Result<ListFilters> {
         Some(parsed)
     };
 
+    let expr = args.where_.as_deref().map(FilterExpr::parse).transpose()?;
+    // A status condition in the expression takes over status scoping.
+    let expr_scopes_status = expr
+        .as_ref()
+        .is_some_and(|expr| expr.references(Field::Status));
+
     let include_closed = args.all
+        || expr_scopes_status
         || statuses
             .as_ref()
             .is_some_and(|parsed| parsed.iter().any(Status::is_terminal));
@@ -300,6 +481,7 @@ fn build_filters(args: &ListArgs) -> Result<ListFilters> {
     // Deferred issues are included by default (consistent with "open" status semantics).
     let include_deferred = args.deferred
         || args.all
+        || expr_scopes_status
         || statuses.is_none()
         || statuses
             .as_ref()
@@ -326,6 +508,7 @@ fn build_filters(args: &ListArgs) -> Result<ListFilters> {
         labels_or: None,
         updated_before: None,
         updated_after: None,
+        expr,
     })
 }
 
@@ -522,6 +705,9 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            time_entries: vec![],
+            recurrence: None,
+            attachments: vec![],
         }
     }
 
@@ -552,6 +738,80 @@ mod tests {
         assert_eq!(results[0].id, "bd-xyz");
     }
 
+    #[test]
+    fn test_search_ranks_title_hits_first_and_returns_snippets() {
+        let mut storage = SqliteStorage::open_memory().expect("db");
+        let t1 = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+
+        let in_notes = Issue {
+            notes: Some("the cache eviction logic needs review".to_string()),
+            ..make_issue("bd-notes", "Cleanup", None, t1)
+        };
+        let in_title = make_issue("bd-title", "Cache eviction bug", None, t1);
+        storage.create_issue(&in_notes, "tester").expect("create");
+        storage.create_issue(&in_title, "tester").expect("create");
+        storage
+            .add_comment("bd-title", "tester", "eviction happens too early")
+            .expect("comment");
+
+        let filters = ListFilters::default();
+        let hits = storage
+            .search_issues_ranked("eviction", &filters)
+            .expect("search");
+        let ids: Vec<&str> = hits.iter().map(|hit| hit.issue.id.as_str()).collect();
+        assert_eq!(ids, vec!["bd-title", "bd-notes"]);
+        assert!(hits.iter().all(|hit| hit.score.is_some()));
+        assert!(
+            hits[1]
+                .snippet
+                .as_deref()
+                .is_some_and(|s| s.contains("**eviction**"))
+        );
+
+        // Field scoping and comment search.
+        let hits = storage
+            .search_issues_ranked("notes:eviction", &filters)
+            .expect("search");
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].issue.id, "bd-notes");
+
+        let hits = storage
+            .search_issues_ranked("comments:early", &filters)
+            .expect("search");
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].issue.id, "bd-title");
+    }
+
+    #[test]
+    fn test_search_stems_and_tolerates_punctuation() {
+        let mut storage = SqliteStorage::open_memory().expect("db");
+        let t1 = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        storage
+            .create_issue(
+                &make_issue("bd-run", "Runner crashes", Some("C++ (v2.0) build"), t1),
+                "tester",
+            )
+            .expect("create");
+
+        let filters = ListFilters::default();
+        for query in [
+            "crashing",
+            "\"runner crash\"",
+            "C++",
+            "v2.0",
+            "build)",
+            "\"unbalanced",
+        ] {
+            let result = storage.search_issues(query, &filters);
+            assert!(result.is_ok(), "query {query:?} failed: {result:?}");
+        }
+        assert_eq!(
+            storage.search_issues("crashing", &filters).unwrap().len(),
+            1
+        );
+        assert_eq!(storage.search_issues("C++", &filters).unwrap().len(), 1);
+    }
+
     #[test]
     fn test_sort_by_title_and_reverse() {
         let t1 = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
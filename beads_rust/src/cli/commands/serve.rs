@@ -0,0 +1,1064 @@
+//! Serve command implementation.
+//!
+//! `br serve` is a long-running JSON-RPC 2.0 server on stdio: one request per
+//! line on stdin, one response per line on stdout. A single storage
+//! connection stays open for the whole session, so callers skip the per-call
+//! database open, schema check and JSONL staleness check.
+//!
+//! Operations are callable by method name (`create`, `update`, `close`,
+//! `ready`, `dep_add`, `dep_remove`, `comment`, `search`, `show`, `flush`)
+//! and as MCP tools through `initialize`, `tools/list` and `tools/call`.
+//! Failures carry the same structured error object the CLI prints, in the
+//! JSON-RPC `error.data` field (or the tool result for MCP calls).
+//!
+//! Writes are flushed to JSONL in batches: after `--flush-every` mutating
+//! requests, after `--flush-interval` seconds with pending writes, on the
+//! `flush` method, and when stdin closes.
+
+use crate::cli::commands::close::{self, CloseArgs, CloseOutcome, CloseResult};
+use crate::cli::commands::create::{CreateConfig, create_issue_impl};
+use crate::cli::commands::dep::{self, DepActionResult};
+use crate::cli::commands::{comments, ready, search, update};
+use crate::cli::{CreateArgs, ListArgs, ServeArgs, UpdateArgs};
+use crate::config;
+use crate::config::workflow::Workflow;
+use crate::error::{BeadsError, Result, StructuredError};
+use crate::format::{ReadyIssue, SearchResult};
+use crate::hooks::Hooks;
+use crate::output::OutputContext;
+use crate::storage::{ReadyFilters, ReadySortPolicy};
+use crate::sync::auto_flush;
+use crate::util::id::{IdResolver, ResolverConfig};
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+use tracing::{debug, info, warn};
+
+const JSONRPC_VERSION: &str = "2.0";
+const MCP_PROTOCOL_VERSION: &str = "2024-11-05";
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+/// Operation failed; `data` holds the structured error.
+const APPLICATION_ERROR: i64 = -32000;
+
+/// A method exposed over JSON-RPC and as an MCP tool.
+struct Tool {
+    name: &'static str,
+    description: &'static str,
+    mutating: bool,
+}
+
+const TOOLS: &[Tool] = &[
+    Tool {
+        name: "create",
+        description: "Create an issue and return it",
+        mutating: true,
+    },
+    Tool {
+        name: "update",
+        description: "Update fields, labels or parent of an issue",
+        mutating: true,
+    },
+    Tool {
+        name: "close",
+        description: "Close an issue (skipped if blocked unless force is set)",
+        mutating: true,
+    },
+    Tool {
+        name: "ready",
+        description: "List issues that are ready to work on",
+        mutating: false,
+    },
+    Tool {
+        name: "dep_add",
+        description: "Add a dependency: issue depends on depends_on",
+        mutating: true,
+    },
+    Tool {
+        name: "dep_remove",
+        description: "Remove a dependency between two issues",
+        mutating: true,
+    },
+    Tool {
+        name: "comment",
+        description: "Add a comment to an issue",
+        mutating: true,
+    },
+    Tool {
+        name: "search",
+        description: "Full-text search issues, ranked by relevance",
+        mutating: false,
+    },
+    Tool {
+        name: "show",
+        description: "Show an issue with labels, dependencies and comments",
+        mutating: false,
+    },
+    Tool {
+        name: "flush",
+        description: "Export pending changes to JSONL now",
+        mutating: false,
+    },
+];
+
+/// JSON-RPC error object.
+#[derive(Debug)]
+struct RpcError {
+    code: i64,
+    message: String,
+    data: Option<Value>,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            data: None,
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        let mut error = json!({ "code": self.code, "message": self.message });
+        if let Some(data) = &self.data {
+            error["data"] = data.clone();
+        }
+        error
+    }
+}
+
+impl From<BeadsError> for RpcError {
+    fn from(err: BeadsError) -> Self {
+        let structured = StructuredError::from_error(&err);
+        Self {
+            code: APPLICATION_ERROR,
+            message: structured.message.clone(),
+            data: structured.to_json().get_mut("error").map(Value::take),
+        }
+    }
+}
+
+/// A priority given as a JSON number (`1`) or string (`"P1"`, `"1"`).
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum PriorityParam {
+    Number(i64),
+    Text(String),
+}
+
+impl PriorityParam {
+    fn into_arg(self) -> String {
+        match self {
+            Self::Number(n) => n.to_string(),
+            Self::Text(s) => s,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CreateParams {
+    title: String,
+    #[serde(rename = "type")]
+    issue_type: Option<String>,
+    priority: Option<PriorityParam>,
+    description: Option<String>,
+    assignee: Option<String>,
+    owner: Option<String>,
+    #[serde(default)]
+    labels: Vec<String>,
+    parent: Option<String>,
+    #[serde(default)]
+    deps: Vec<String>,
+    estimate: Option<i32>,
+    due: Option<String>,
+    defer: Option<String>,
+    external_ref: Option<String>,
+    status: Option<String>,
+    #[serde(default)]
+    ephemeral: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[allow(clippy::struct_excessive_bools)]
+struct UpdateParams {
+    id: String,
+    title: Option<String>,
+    description: Option<String>,
+    design: Option<String>,
+    acceptance_criteria: Option<String>,
+    notes: Option<String>,
+    status: Option<String>,
+    priority: Option<PriorityParam>,
+    #[serde(rename = "type")]
+    issue_type: Option<String>,
+    assignee: Option<String>,
+    owner: Option<String>,
+    #[serde(default)]
+    claim: bool,
+    #[serde(default)]
+    force: bool,
+    due: Option<String>,
+    defer: Option<String>,
+    estimate: Option<i32>,
+    #[serde(default)]
+    add_labels: Vec<String>,
+    #[serde(default)]
+    remove_labels: Vec<String>,
+    #[serde(default)]
+    set_labels: Vec<String>,
+    parent: Option<String>,
+    external_ref: Option<String>,
+    session: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CloseParams {
+    id: String,
+    reason: Option<String>,
+    #[serde(default)]
+    force: bool,
+    session: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[allow(clippy::struct_excessive_bools)]
+struct ReadyParams {
+    assignee: Option<String>,
+    #[serde(default)]
+    unassigned: bool,
+    #[serde(default)]
+    labels: Vec<String>,
+    #[serde(default)]
+    labels_any: Vec<String>,
+    #[serde(default)]
+    types: Vec<String>,
+    #[serde(default)]
+    priorities: Vec<PriorityParam>,
+    #[serde(default)]
+    include_deferred: bool,
+    parent: Option<String>,
+    #[serde(default)]
+    recursive: bool,
+    /// Maximum results (0 = unlimited); defaults to 20 like `br ready`.
+    #[serde(default = "default_ready_limit")]
+    limit: usize,
+    sort: Option<String>,
+}
+
+const fn default_ready_limit() -> usize {
+    20
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DepAddParams {
+    issue: String,
+    depends_on: String,
+    #[serde(rename = "type", default = "default_dep_type")]
+    dep_type: String,
+}
+
+fn default_dep_type() -> String {
+    "blocks".to_string()
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DepRemoveParams {
+    issue: String,
+    depends_on: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CommentParams {
+    id: String,
+    text: String,
+    author: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SearchParams {
+    query: String,
+    #[serde(default)]
+    status: Vec<String>,
+    #[serde(default)]
+    types: Vec<String>,
+    #[serde(default)]
+    priorities: Vec<PriorityParam>,
+    assignee: Option<String>,
+    #[serde(default)]
+    labels: Vec<String>,
+    #[serde(default)]
+    include_closed: bool,
+    limit: Option<usize>,
+    #[serde(rename = "where")]
+    where_: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ShowParams {
+    id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ToolCallParams {
+    name: String,
+    arguments: Option<Value>,
+}
+
+/// Execute the serve command.
+///
+/// # Errors
+///
+/// Returns an error if the workspace cannot be opened or the final flush
+/// fails. Malformed input is answered with a parse error; stdio failures end
+/// the session after flushing.
+pub fn execute(args: &ServeArgs, cli: &config::CliOverrides, _ctx: &OutputContext) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+
+    let id_config = config::id_config_from_layer(&layer);
+    let mut server = Server {
+        resolver: IdResolver::new(ResolverConfig::with_prefix(id_config.prefix.clone())),
+        create_config: CreateConfig {
+            id_config,
+            default_priority: config::default_priority_from_layer(&layer)?,
+            default_issue_type: config::default_issue_type_from_layer(&layer)?,
+            actor: config::resolve_actor(&layer),
+            template: None,
+            hooks: Hooks::load(&beads_dir, &layer)?,
+        },
+        claim_exclusive: config::claim_exclusive_from_layer(&layer),
+        workflow: Workflow::load(&layer)?,
+        external_db_paths: config::external_project_db_paths(&layer, &beads_dir),
+        // no-db mode keeps JSONL as the source of truth, so it always flushes.
+        auto_flush: storage_ctx.no_db || !cli.no_auto_flush.unwrap_or(false),
+        flush_every: args.flush_every,
+        flush_interval: (args.flush_interval > 0).then(|| Duration::from_secs(args.flush_interval)),
+        pending_writes: 0,
+        last_flush: Instant::now(),
+        storage_ctx,
+        beads_dir,
+    };
+
+    // Read stdin on its own thread so pending writes can be flushed while idle.
+    // Lines are read as bytes so invalid UTF-8 is answered, not fatal.
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let stdin = io::stdin();
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+            let read = stdin.lock().read_until(b'\n', &mut buf);
+            let line = match read {
+                Ok(0) => break,
+                Ok(_) => String::from_utf8(buf.clone()).map_err(|e| e.utf8_error()),
+                Err(e) => {
+                    warn!(?e, "Failed to read stdin, shutting down");
+                    break;
+                }
+            };
+            if tx.send(line).is_err() {
+                break;
+            }
+        }
+    });
+
+    info!("Serving JSON-RPC on stdio");
+    let mut stdout = io::stdout().lock();
+    loop {
+        let line = match server.flush_interval {
+            Some(interval) => match rx.recv_timeout(interval) {
+                Ok(line) => line,
+                Err(RecvTimeoutError::Timeout) => {
+                    server.flush_if_due(true);
+                    continue;
+                }
+                Err(RecvTimeoutError::Disconnected) => break,
+            },
+            None => match rx.recv() {
+                Ok(line) => line,
+                Err(_) => break,
+            },
+        };
+
+        let response = match line {
+            Ok(line) if line.trim().is_empty() => continue,
+            Ok(line) => server.handle_line(&line),
+            Err(e) => Some(error_response(
+                &Value::Null,
+                &RpcError::new(PARSE_ERROR, format!("Parse error: {e}")),
+            )),
+        };
+        if let Some(response) = response {
+            // A closed or broken stdout ends the session; pending writes are
+            // still flushed below.
+            if let Err(e) = writeln!(stdout, "{response}").and_then(|()| stdout.flush()) {
+                warn!(?e, "Failed to write response, shutting down");
+                break;
+            }
+        }
+        server.flush_if_due(false);
+    }
+
+    info!("stdin closed, shutting down");
+    server.flush()?;
+    Ok(())
+}
+
+struct Server {
+    storage_ctx: config::OpenStorageResult,
+    beads_dir: PathBuf,
+    resolver: IdResolver,
+    create_config: CreateConfig,
+    claim_exclusive: bool,
+    workflow: Workflow,
+    external_db_paths: HashMap<String, PathBuf>,
+    auto_flush: bool,
+    flush_every: usize,
+    flush_interval: Option<Duration>,
+    pending_writes: usize,
+    last_flush: Instant,
+}
+
+impl Server {
+    /// Handle one line of input (a request or a batch). Returns `None` when
+    /// nothing should be written back (notifications only).
+    fn handle_line(&mut self, line: &str) -> Option<Value> {
+        match serde_json::from_str::<Value>(line) {
+            Err(e) => Some(error_response(
+                &Value::Null,
+                &RpcError::new(PARSE_ERROR, format!("Parse error: {e}")),
+            )),
+            Ok(Value::Array(batch)) if batch.is_empty() => Some(error_response(
+                &Value::Null,
+                &RpcError::new(INVALID_REQUEST, "Empty batch"),
+            )),
+            Ok(Value::Array(batch)) => {
+                let responses: Vec<Value> = batch
+                    .into_iter()
+                    .filter_map(|request| self.handle_request(request))
+                    .collect();
+                (!responses.is_empty()).then_some(Value::Array(responses))
+            }
+            Ok(request) => self.handle_request(request),
+        }
+    }
+
+    fn handle_request(&mut self, request: Value) -> Option<Value> {
+        let Value::Object(mut request) = request else {
+            return Some(error_response(
+                &Value::Null,
+                &RpcError::new(INVALID_REQUEST, "Request must be a JSON object"),
+            ));
+        };
+        let id = request.remove("id");
+        let Some(Value::String(method)) = request.remove("method") else {
+            return Some(error_response(
+                &id.unwrap_or(Value::Null),
+                &RpcError::new(INVALID_REQUEST, "Request is missing a method"),
+            ));
+        };
+
+        debug!(method = %method, "Handling request");
+        let result = self.dispatch(&method, request.remove("params"));
+
+        // Notifications (no id) are executed but never answered.
+        let id = id?;
+        Some(match result {
+            Ok(value) => json!({ "jsonrpc": JSONRPC_VERSION, "id": id, "result": value }),
+            Err(err) => error_response(&id, &err),
+        })
+    }
+
+    fn dispatch(
+        &mut self,
+        method: &str,
+        params: Option<Value>,
+    ) -> std::result::Result<Value, RpcError> {
+        match method {
+            "initialize" => Ok(initialize_result(params.as_ref())),
+            "ping" => Ok(json!({})),
+            "tools/list" => Ok(json!({ "tools": tool_descriptors() })),
+            "tools/call" => self.call_tool(params),
+            _ if method.starts_with("notifications/") => Ok(Value::Null),
+            _ => self.call(method, params),
+        }
+    }
+
+    fn call(
+        &mut self,
+        method: &str,
+        params: Option<Value>,
+    ) -> std::result::Result<Value, RpcError> {
+        let tool = TOOLS
+            .iter()
+            .find(|tool| tool.name == method)
+            .ok_or_else(|| {
+                RpcError::new(METHOD_NOT_FOUND, format!("Method not found: {method}"))
+            })?;
+
+        let result = match tool.name {
+            "create" => self.create(parse_params(params)?),
+            "update" => self.update(parse_params(params)?),
+            "close" => self.close(parse_params(params)?),
+            "ready" => self.ready(parse_params(params)?),
+            "dep_add" => self.dep_add(parse_params(params)?),
+            "dep_remove" => self.dep_remove(parse_params(params)?),
+            "comment" => self.comment(&parse_params(params)?),
+            "search" => self.search(parse_params(params)?),
+            "show" => self.show(&parse_params(params)?),
+            "flush" => self.flush().map(|flushed| json!({ "flushed": flushed })),
+            _ => unreachable!("every tool has a handler"),
+        };
+
+        if tool.mutating && result.is_ok() {
+            self.pending_writes += 1;
+        }
+        result.map_err(RpcError::from)
+    }
+
+    /// MCP `tools/call`: operation failures become tool results with
+    /// `isError`, protocol problems stay JSON-RPC errors.
+    fn call_tool(&mut self, params: Option<Value>) -> std::result::Result<Value, RpcError> {
+        let call: ToolCallParams = parse_params(params)?;
+        match self.call(&call.name, call.arguments) {
+            Ok(value) => Ok(tool_result(&value, false)),
+            Err(err) if err.code == APPLICATION_ERROR => {
+                let error = err
+                    .data
+                    .unwrap_or_else(|| json!({ "message": err.message }));
+                Ok(tool_result(&json!({ "error": error }), true))
+            }
+            Err(err) if err.code == METHOD_NOT_FOUND => Err(RpcError::new(
+                INVALID_PARAMS,
+                format!("Unknown tool: {}", call.name),
+            )),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn resolve_id(&self, input: &str) -> Result<String> {
+        update::resolve_issue_id(&self.resolver, &self.storage_ctx.storage, input)
+    }
+
+    fn export_issue(&self, id: &str) -> Result<Value> {
+        let issue = self
+            .storage_ctx
+            .storage
+            .get_issue_for_export(id)?
+            .ok_or_else(|| BeadsError::IssueNotFound { id: id.to_string() })?;
+        Ok(serde_json::to_value(issue)?)
+    }
+
+    fn create(&mut self, params: CreateParams) -> Result<Value> {
+        let parent = params
+            .parent
+            .as_deref()
+            .map(|parent| self.resolve_id(parent))
+            .transpose()?;
+        let args = CreateArgs {
+            title: Some(params.title),
+            type_: params.issue_type,
+            priority: params.priority.map(PriorityParam::into_arg),
+            description: params.description,
+            assignee: params.assignee,
+            owner: params.owner,
+            labels: params.labels,
+            parent,
+            deps: params.deps,
+            estimate: params.estimate,
+            due: params.due,
+            defer: params.defer,
+            external_ref: params.external_ref,
+            status: params.status,
+            ephemeral: params.ephemeral,
+            ..CreateArgs::default()
+        };
+
+        let issue = create_issue_impl(&mut self.storage_ctx.storage, &args, &self.create_config)?;
+        crate::util::set_last_touched_id(&self.beads_dir, &issue.id);
+        self.export_issue(&issue.id)
+    }
+
+    fn update(&mut self, params: UpdateParams) -> Result<Value> {
+        let id = self.resolve_id(&params.id)?;
+        let args = UpdateArgs {
+            ids: vec![id.clone()],
+            title: params.title,
+            description: params.description,
+            design: params.design,
+            acceptance_criteria: params.acceptance_criteria,
+            notes: params.notes,
+            status: params.status,
+            priority: params.priority.map(PriorityParam::into_arg),
+            type_: params.issue_type,
+            assignee: params.assignee,
+            owner: params.owner,
+            claim: params.claim,
+            force: params.force,
+            due: params.due,
+            defer: params.defer,
+            estimate: params.estimate,
+            add_label: params.add_labels,
+            remove_label: params.remove_labels,
+            set_labels: params.set_labels,
+            parent: params.parent,
+            external_ref: params.external_ref,
+            session: params.session,
+        };
+
+        let actor = &self.create_config.actor;
+        let changes = update::build_update(&args, actor, self.claim_exclusive, &self.workflow)?;
+        let storage = &mut self.storage_ctx.storage;
+        let before = storage.get_issue(&id)?;
+        update::apply_update(
+            storage,
+            &id,
+            &args,
+            &changes,
+            before.as_ref(),
+            &self.resolver,
+            actor,
+            &self.workflow,
+            &self.create_config.hooks,
+        )?;
+        crate::util::set_last_touched_id(&self.beads_dir, &id);
+        self.export_issue(&id)
+    }
+
+    fn close(&mut self, params: CloseParams) -> Result<Value> {
+        let id = self.resolve_id(&params.id)?;
+        let args = CloseArgs {
+            ids: vec![id.clone()],
+            reason: params.reason,
+            force: params.force,
+            session: params.session,
+            suggest_next: false,
+        };
+
+        let mut result = CloseResult {
+            closed: Vec::new(),
+            skipped: Vec::new(),
+        };
+        match close::close_issue(
+            &mut self.storage_ctx.storage,
+            &id,
+            &args,
+            &self.create_config.actor,
+            &self.workflow,
+            &self.create_config.hooks,
+        )? {
+            CloseOutcome::Closed(closed) => {
+                crate::util::set_last_touched_id(&self.beads_dir, &id);
+                result.closed.push(closed);
+            }
+            CloseOutcome::Skipped(skipped) => result.skipped.push(skipped),
+        }
+        Ok(serde_json::to_value(result)?)
+    }
+
+    fn ready(&mut self, params: ReadyParams) -> Result<Value> {
+        let sort = match params.sort.as_deref().unwrap_or("hybrid") {
+            "hybrid" => ReadySortPolicy::Hybrid,
+            "priority" => ReadySortPolicy::Priority,
+            "oldest" => ReadySortPolicy::Oldest,
+            other => {
+                return Err(BeadsError::validation(
+                    "sort",
+                    format!("unknown sort '{other}' (expected hybrid, priority or oldest)"),
+                ));
+            }
+        };
+        let priorities: Vec<String> = params
+            .priorities
+            .into_iter()
+            .map(PriorityParam::into_arg)
+            .collect();
+        let filters = ReadyFilters {
+            assignee: params.assignee,
+            unassigned: params.unassigned,
+            labels_and: params.labels,
+            labels_or: params.labels_any,
+            types: ready::parse_types(&params.types)?,
+            priorities: ready::parse_priorities(&priorities)?,
+            include_deferred: params.include_deferred,
+            limit: None,
+            parent: params.parent,
+            recursive: params.recursive,
+        };
+
+        let storage = &mut self.storage_ctx.storage;
+        let expired = storage.expire_leases(&self.create_config.actor)?;
+        if !expired.is_empty() {
+            debug!(count = expired.len(), "Reaped expired claim leases");
+            // Reaping reopens issues, so it counts toward the next flush.
+            self.pending_writes += expired.len();
+        }
+
+        let mut issues = storage.get_ready_issues(&filters, sort)?;
+        let external_statuses =
+            storage.resolve_external_dependency_statuses(&self.external_db_paths, true)?;
+        let external_blockers = storage.external_blockers(&external_statuses)?;
+        if !external_blockers.is_empty() {
+            issues.retain(|issue| !external_blockers.contains_key(&issue.id));
+        }
+        if params.limit > 0 {
+            issues.truncate(params.limit);
+        }
+
+        let ready: Vec<ReadyIssue> = issues.iter().map(ReadyIssue::from).collect();
+        Ok(serde_json::to_value(ready)?)
+    }
+
+    fn dep_add(&mut self, params: DepAddParams) -> Result<Value> {
+        let issue_id = self.resolve_id(&params.issue)?;
+        let depends_on_id = if params.depends_on.starts_with("external:") {
+            params.depends_on
+        } else {
+            self.resolve_id(&params.depends_on)?
+        };
+
+        let (dep_type, added) = dep::add_dependency_checked(
+            &mut self.storage_ctx.storage,
+            &issue_id,
+            &depends_on_id,
+            &params.dep_type,
+            &self.create_config.actor,
+            &self.create_config.hooks,
+        )?;
+        Ok(serde_json::to_value(DepActionResult {
+            status: if added { "ok" } else { "exists" }.to_string(),
+            issue_id,
+            depends_on_id,
+            dep_type: dep_type.as_str().to_string(),
+            action: if added { "added" } else { "already_exists" }.to_string(),
+        })?)
+    }
+
+    fn dep_remove(&mut self, params: DepRemoveParams) -> Result<Value> {
+        let issue_id = self.resolve_id(&params.issue)?;
+        let depends_on_id = if params.depends_on.starts_with("external:") {
+            params.depends_on
+        } else {
+            self.resolve_id(&params.depends_on)?
+        };
+
+        let removed = self.storage_ctx.storage.remove_dependency(
+            &issue_id,
+            &depends_on_id,
+            &self.create_config.actor,
+        )?;
+        Ok(serde_json::to_value(DepActionResult {
+            status: if removed { "ok" } else { "not_found" }.to_string(),
+            issue_id,
+            depends_on_id,
+            dep_type: "unknown".to_string(),
+            action: if removed { "removed" } else { "not_found" }.to_string(),
+        })?)
+    }
+
+    fn comment(&mut self, params: &CommentParams) -> Result<Value> {
+        let id = self.resolve_id(&params.id)?;
+        if params.text.trim().is_empty() {
+            return Err(BeadsError::validation(
+                "text",
+                "comment text cannot be empty",
+            ));
+        }
+        let author =
+            comments::resolve_author(params.author.as_deref(), Some(&self.create_config.actor));
+        let comment = self
+            .storage_ctx
+            .storage
+            .add_comment(&id, &author, &params.text)?;
+        Ok(serde_json::to_value(comment)?)
+    }
+
+    fn search(&self, params: SearchParams) -> Result<Value> {
+        let query = params.query.trim();
+        if query.is_empty() {
+            return Err(BeadsError::validation(
+                "query",
+                "search query cannot be empty",
+            ));
+        }
+        let args = ListArgs {
+            status: params.status,
+            type_: params.types,
+            priority: params
+                .priorities
+                .into_iter()
+                .map(PriorityParam::into_arg)
+                .collect(),
+            assignee: params.assignee,
+            label: params.labels,
+            all: params.include_closed,
+            limit: params.limit,
+            where_: params.where_,
+            ..ListArgs::default()
+        };
+        let filters = search::build_filters(&args)?;
+
+        let storage = &self.storage_ctx.storage;
+        let hits = storage.search_issues_ranked(query, &filters)?;
+        let ids: Vec<String> = hits.iter().map(|hit| hit.issue.id.clone()).collect();
+        let dependency_counts = storage.count_dependencies_for_issues(&ids)?;
+        let dependent_counts = storage.count_dependents_for_issues(&ids)?;
+
+        let results: Vec<SearchResult> = hits
+            .into_iter()
+            .map(|hit| SearchResult {
+                dependency_count: dependency_counts.get(&hit.issue.id).copied().unwrap_or(0),
+                dependent_count: dependent_counts.get(&hit.issue.id).copied().unwrap_or(0),
+                score: hit.score,
+                snippet: hit.snippet,
+                issue: hit.issue,
+            })
+            .collect();
+        Ok(serde_json::to_value(results)?)
+    }
+
+    fn show(&self, params: &ShowParams) -> Result<Value> {
+        let id = self.resolve_id(&params.id)?;
+        self.export_issue(&id)
+    }
+
+    /// Flush automatically if the batch size or interval has been reached.
+    /// `idle` is set when the interval elapsed with no incoming request.
+    fn flush_if_due(&mut self, idle: bool) {
+        if !self.auto_flush || self.pending_writes == 0 {
+            return;
+        }
+        let batch_full = self.flush_every > 0 && self.pending_writes >= self.flush_every;
+        let interval_elapsed = self
+            .flush_interval
+            .is_some_and(|interval| self.last_flush.elapsed() >= interval);
+        if idle || batch_full || interval_elapsed {
+            if let Err(e) = self.flush() {
+                warn!(?e, "Auto-flush failed (non-fatal)");
+            }
+        }
+    }
+
+    /// Export dirty issues to JSONL. Returns whether anything was written.
+    fn flush(&mut self) -> Result<bool> {
+        self.pending_writes = 0;
+        self.last_flush = Instant::now();
+
+        if self.storage_ctx.storage.get_dirty_issue_count()? == 0 {
+            return Ok(false);
+        }
+        if self.storage_ctx.no_db {
+            self.storage_ctx.flush_no_db_if_dirty()?;
+        } else {
+            let result = auto_flush(&mut self.storage_ctx.storage, &self.beads_dir)?;
+            debug!(exported = result.exported_count, "Serve flush completed");
+        }
+        Ok(true)
+    }
+}
+
+fn parse_params<T: DeserializeOwned>(params: Option<Value>) -> std::result::Result<T, RpcError> {
+    let params = match params {
+        None | Some(Value::Null) => json!({}),
+        Some(params) => params,
+    };
+    serde_json::from_value(params)
+        .map_err(|e| RpcError::new(INVALID_PARAMS, format!("Invalid params: {e}")))
+}
+
+fn error_response(id: &Value, err: &RpcError) -> Value {
+    json!({ "jsonrpc": JSONRPC_VERSION, "id": id, "error": err.to_json() })
+}
+
+fn initialize_result(params: Option<&Value>) -> Value {
+    let protocol_version = params
+        .and_then(|params| params.get("protocolVersion"))
+        .and_then(Value::as_str)
+        .unwrap_or(MCP_PROTOCOL_VERSION);
+    json!({
+        "protocolVersion": protocol_version,
+        "capabilities": { "tools": {} },
+        "serverInfo": { "name": "br", "version": env!("CARGO_PKG_VERSION") },
+    })
+}
+
+fn tool_result(value: &Value, is_error: bool) -> Value {
+    let text = serde_json::to_string_pretty(value).unwrap_or_else(|_| value.to_string());
+    json!({
+        "content": [{ "type": "text", "text": text }],
+        "isError": is_error,
+    })
+}
+
+fn tool_descriptors() -> Vec<Value> {
+    TOOLS
+        .iter()
+        .map(|tool| {
+            json!({
+                "name": tool.name,
+                "description": tool.description,
+                "inputSchema": input_schema(tool.name),
+            })
+        })
+        .collect()
+}
+
+fn input_schema(tool: &str) -> Value {
+    let string = json!({ "type": "string" });
+    let strings = json!({ "type": "array", "items": { "type": "string" } });
+    let boolean = json!({ "type": "boolean" });
+    let integer = json!({ "type": "integer" });
+    let priority = json!({ "type": ["integer", "string"], "description": "0-4 or P0-P4" });
+    let priorities = json!({ "type": "array", "items": priority });
+
+    let (properties, required) = match tool {
+        "create" => (
+            json!({
+                "title": string, "type": string, "priority": priority,
+                "description": string, "assignee": string, "owner": string,
+                "labels": strings, "parent": string,
+                "deps": { "type": "array", "items": { "type": "string" },
+                          "description": "type:id or id (blocks)" },
+                "estimate": integer, "due": string, "defer": string,
+                "external_ref": string, "status": string, "ephemeral": boolean,
+            }),
+            vec!["title"],
+        ),
+        "update" => (
+            json!({
+                "id": string, "title": string, "description": string, "design": string,
+                "acceptance_criteria": string, "notes": string, "status": string,
+                "priority": priority, "type": string,
+                "assignee": { "type": "string", "description": "empty string clears" },
+                "owner": string, "claim": boolean, "force": boolean,
+                "due": string, "defer": string, "estimate": integer,
+                "add_labels": strings, "remove_labels": strings, "set_labels": strings,
+                "parent": { "type": "string", "description": "empty string removes parent" },
+                "external_ref": string, "session": string,
+            }),
+            vec!["id"],
+        ),
+        "close" => (
+            json!({ "id": string, "reason": string, "force": boolean, "session": string }),
+            vec!["id"],
+        ),
+        "ready" => (
+            json!({
+                "assignee": string, "unassigned": boolean, "labels": strings,
+                "labels_any": strings, "types": strings, "priorities": priorities,
+                "include_deferred": boolean, "parent": string, "recursive": boolean,
+                "limit": { "type": "integer", "description": "0 = unlimited (default 20)" },
+                "sort": { "type": "string", "enum": ["hybrid", "priority", "oldest"] },
+            }),
+            vec![],
+        ),
+        "dep_add" => (
+            json!({
+                "issue": string, "depends_on": string,
+                "type": { "type": "string", "description": "default: blocks" },
+            }),
+            vec!["issue", "depends_on"],
+        ),
+        "dep_remove" => (
+            json!({ "issue": string, "depends_on": string }),
+            vec!["issue", "depends_on"],
+        ),
+        "comment" => (
+            json!({ "id": string, "text": string, "author": string }),
+            vec!["id", "text"],
+        ),
+        "search" => (
+            json!({
+                "query": string, "status": strings, "types": strings,
+                "priorities": priorities, "assignee": string, "labels": strings,
+                "include_closed": boolean, "limit": integer,
+                "where": { "type": "string", "description": "filter expression, as in --where" },
+            }),
+            vec!["query"],
+        ),
+        "show" => (json!({ "id": string }), vec!["id"]),
+        _ => (json!({}), vec![]),
+    };
+
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn beads_errors_map_to_structured_rpc_errors() {
+        let err = RpcError::from(BeadsError::IssueNotFound {
+            id: "bd-404".to_string(),
+        });
+        assert_eq!(err.code, APPLICATION_ERROR);
+        let data = err.data.as_ref().expect("structured data");
+        assert_eq!(data["code"], "ISSUE_NOT_FOUND");
+        assert_eq!(err.to_json()["data"]["code"], "ISSUE_NOT_FOUND");
+    }
+
+    #[test]
+    fn params_accept_numeric_and_string_priorities() {
+        let params: CreateParams =
+            parse_params(Some(json!({ "title": "A", "priority": 1 }))).unwrap();
+        assert_eq!(
+            params.priority.map(PriorityParam::into_arg).as_deref(),
+            Some("1")
+        );
+
+        let params: ReadyParams = parse_params(Some(json!({ "priorities": ["P0", 2] }))).unwrap();
+        let priorities: Vec<String> = params
+            .priorities
+            .into_iter()
+            .map(PriorityParam::into_arg)
+            .collect();
+        assert_eq!(priorities, vec!["P0", "2"]);
+        assert_eq!(params.limit, 20);
+    }
+
+    #[test]
+    fn invalid_params_are_rejected() {
+        let missing = parse_params::<CloseParams>(None).unwrap_err();
+        assert_eq!(missing.code, INVALID_PARAMS);
+        assert!(missing.message.contains("id"), "{}", missing.message);
+
+        let unknown = parse_params::<ShowParams>(Some(json!({ "id": "bd-1", "x": 1 })));
+        assert_eq!(unknown.unwrap_err().code, INVALID_PARAMS);
+    }
+
+    #[test]
+    fn every_tool_has_an_object_schema() {
+        let tools = tool_descriptors();
+        assert_eq!(tools.len(), TOOLS.len());
+        for tool in &tools {
+            assert_eq!(tool["inputSchema"]["type"], "object", "{}", tool["name"]);
+        }
+        let create = tools.iter().find(|t| t["name"] == "create").unwrap();
+        assert_eq!(create["inputSchema"]["required"], json!(["title"]));
+    }
+}
@@ -1,9 +1,10 @@
 //! Show command implementation.
 
+use crate::cli::commands::claim;
 use crate::cli::{ShowArgs, resolve_output_format_basic};
 use crate::config;
 use crate::error::{BeadsError, Result};
-use crate::format::{format_priority_label, format_status_icon_colored};
+use crate::format::{format_bytes, format_priority_label, format_status_icon_colored};
 use crate::output::{IssuePanel, OutputContext, OutputMode};
 use crate::util::id::{IdResolver, ResolverConfig};
 use std::fmt::Write as FmtWrite;
@@ -20,8 +21,7 @@ pub fn execute(
     outer_ctx: &OutputContext,
 ) -> Result<()> {
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
-    let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
-    let storage = &storage_ctx.storage;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
 
     let mut target_ids = args.ids.clone();
     if target_ids.is_empty() {
@@ -35,7 +35,9 @@ pub fn execute(
         target_ids.push(last_touched);
     }
 
-    let config_layer = config::load_config(&beads_dir, Some(storage), cli)?;
+    let config_layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+    claim::reap_expired_leases(&mut storage_ctx, cli, &config::resolve_actor(&config_layer))?;
+    let storage = &storage_ctx.storage;
     let id_config = config::id_config_from_layer(&config_layer);
     let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
     let use_color = config::should_use_color(&config_layer);
@@ -169,6 +171,25 @@ fn format_issue_details(details: &crate::format::IssueDetails, use_color: bool)
         }
     }
 
+    if !issue.attachments.is_empty() {
+        output.push('\n');
+        let _ = writeln!(output, "Attachments:");
+        for attachment in &issue.attachments {
+            let _ = writeln!(
+                output,
+                "  {} ({}, {}){}",
+                attachment.name,
+                format_bytes(attachment.size),
+                &attachment.sha256[..attachment.sha256.len().min(12)],
+                if attachment.local_only {
+                    " [local only]"
+                } else {
+                    ""
+                }
+            );
+        }
+    }
+
     output
 }
 
@@ -227,6 +248,9 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            time_entries: vec![],
+            recurrence: None,
+            attachments: vec![],
         }
     }
 
@@ -1,15 +1,20 @@
 //! Stats command implementation.
 //!
 //! Shows project statistics including issue counts by status, type, priority,
-//! assignee, and label. Also supports recent activity tracking via git.
+//! assignee, and label. Also supports recent activity tracking via git, and
+//! estimate accuracy from time tracked with `br time`.
 
+use crate::cli::commands::claim;
+use crate::cli::commands::plan::format_minutes;
 use crate::cli::{OutputFormat, StatsArgs, resolve_output_format_basic};
 use crate::config;
+use crate::config::federation::{self, Project};
 use crate::error::Result;
 use crate::format::{
-    Breakdown, BreakdownEntry, RecentActivity, Statistics, StatsSummary, truncate_title,
+    AccuracyBreakdown, AccuracyEntry, Breakdown, BreakdownEntry, ProjectItem, RecentActivity,
+    Statistics, StatsSummary, truncate_title,
 };
-use crate::model::{IssueType, Status};
+use crate::model::{Issue, IssueType, Status};
 use crate::output::{OutputContext, OutputMode};
 use crate::storage::{ListFilters, SqliteStorage};
 use chrono::Utc;
@@ -32,14 +37,62 @@ pub fn execute(
     outer_ctx: &OutputContext,
 ) -> Result<()> {
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
-    let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let config_layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+    claim::reap_expired_leases(&mut storage_ctx, cli, &config::resolve_actor(&config_layer))?;
     let storage = &storage_ctx.storage;
-    let config_layer = config::load_config(&beads_dir, Some(storage), cli)?;
     let use_color = config::should_use_color(&config_layer);
     let output_format = resolve_output_format_basic(args.format, outer_ctx.is_json(), args.robot);
     let quiet = cli.quiet.unwrap_or(false);
     let ctx = OutputContext::from_output_format(output_format, quiet, !use_color);
 
+    if args.federation.is_federated() {
+        let projects = federation::select_projects(
+            &config_layer,
+            &beads_dir,
+            &storage_ctx.paths.db_path,
+            args.federation.all_projects,
+            &args.federation.projects,
+        )?;
+        let results = federation::for_each_project(&projects, storage, |project, storage| {
+            compute_statistics(storage, &project.beads_dir, args)
+        })?;
+        render_federated(results, args, output_format, &ctx);
+        return Ok(());
+    }
+
+    let output = compute_statistics(storage, &beads_dir, args)?;
+
+    // Output based on mode
+    if matches!(ctx.mode(), OutputMode::Quiet) {
+        return Ok(());
+    }
+
+    match output_format {
+        OutputFormat::Json => {
+            ctx.json_pretty(&output);
+        }
+        OutputFormat::Toon => {
+            ctx.toon_with_stats(&output, args.stats);
+        }
+        OutputFormat::Text | OutputFormat::Csv => {
+            if matches!(ctx.mode(), OutputMode::Rich) {
+                render_stats_rich(&output, &ctx);
+            } else {
+                print_text_output(&output);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Compute the statistics for one project.
+fn compute_statistics(
+    storage: &SqliteStorage,
+    beads_dir: &Path,
+    args: &StatsArgs,
+) -> Result<Statistics> {
     info!("Computing project statistics");
 
     // Get all issues including closed and tombstones for comprehensive stats
@@ -76,37 +129,62 @@ pub fn execute(
     let recent_activity = if args.no_activity {
         None
     } else {
-        compute_recent_activity(&beads_dir, args.activity_hours)
+        compute_recent_activity(beads_dir, args.activity_hours)
     };
 
-    let output = Statistics {
+    let estimate_accuracy = if args.accuracy {
+        compute_estimate_accuracy(storage, &all_issues)?
+    } else {
+        Vec::new()
+    };
+
+    Ok(Statistics {
         summary,
         breakdowns,
         recent_activity,
-    };
+        estimate_accuracy,
+    })
+}
 
-    // Output based on mode
+/// `--all-projects`/`--projects`: one set of statistics per project.
+fn render_federated(
+    results: Vec<(&Project, Statistics)>,
+    args: &StatsArgs,
+    output_format: OutputFormat,
+    ctx: &OutputContext,
+) {
     if matches!(ctx.mode(), OutputMode::Quiet) {
-        return Ok(());
+        return;
     }
-
     match output_format {
-        OutputFormat::Json => {
-            ctx.json_pretty(&output);
-        }
-        OutputFormat::Toon => {
-            ctx.toon_with_stats(&output, args.stats);
+        OutputFormat::Json | OutputFormat::Toon => {
+            let items: Vec<ProjectItem<Statistics>> = results
+                .into_iter()
+                .map(|(project, item)| ProjectItem {
+                    project: project.name.clone(),
+                    item,
+                })
+                .collect();
+            if output_format == OutputFormat::Toon {
+                ctx.toon_with_stats(&items, args.stats);
+            } else {
+                ctx.json_pretty(&items);
+            }
         }
         OutputFormat::Text | OutputFormat::Csv => {
-            if matches!(ctx.mode(), OutputMode::Rich) {
-                render_stats_rich(&output, &ctx);
-            } else {
-                print_text_output(&output);
+            for (index, (project, output)) in results.iter().enumerate() {
+                if index > 0 {
+                    println!();
+                }
+                println!("== {} ({}) ==", project.name, project.beads_dir.display());
+                if matches!(ctx.mode(), OutputMode::Rich) {
+                    render_stats_rich(output, ctx);
+                } else {
+                    print_text_output(output);
+                }
             }
         }
     }
-
-    Ok(())
 }
 
 /// Compute summary statistics.
@@ -350,6 +428,127 @@ fn compute_label_breakdown(
     })
 }
 
+/// Compare tracked time with estimates by type, assignee and label.
+///
+/// Only closed issues with an estimate and at least one finished session are
+/// counted, so work still in flight does not drag the ratios down.
+#[allow(clippy::cast_precision_loss)]
+fn compute_estimate_accuracy(
+    storage: &SqliteStorage,
+    issues: &[Issue],
+) -> Result<Vec<AccuracyBreakdown>> {
+    let time_entries = storage.get_all_time_entries()?;
+    let samples: Vec<(&Issue, i64, i64)> = issues
+        .iter()
+        .filter(|issue| issue.status == Status::Closed)
+        .filter_map(|issue| {
+            let estimate = i64::from(issue.estimated_minutes.filter(|e| *e > 0)?);
+            let actual: i64 = time_entries
+                .get(&issue.id)?
+                .iter()
+                .filter(|entry| !entry.is_running())
+                .map(|entry| i64::from(entry.minutes))
+                .sum();
+            (actual > 0).then_some((issue, estimate, actual))
+        })
+        .collect();
+
+    let ids: Vec<String> = samples
+        .iter()
+        .map(|(issue, _, _)| issue.id.clone())
+        .collect();
+    let labels = storage.get_labels_for_issues(&ids)?;
+
+    let mut by_type: BTreeMap<String, Vec<(i64, i64)>> = BTreeMap::new();
+    let mut by_assignee: BTreeMap<String, Vec<(i64, i64)>> = BTreeMap::new();
+    let mut by_label: BTreeMap<String, Vec<(i64, i64)>> = BTreeMap::new();
+    for &(issue, estimate, actual) in &samples {
+        by_type
+            .entry(issue.issue_type.as_str().to_string())
+            .or_default()
+            .push((estimate, actual));
+        by_assignee
+            .entry(
+                issue
+                    .assignee
+                    .clone()
+                    .unwrap_or_else(|| "(unassigned)".to_string()),
+            )
+            .or_default()
+            .push((estimate, actual));
+        match labels.get(&issue.id).filter(|l| !l.is_empty()) {
+            Some(issue_labels) => {
+                for label in issue_labels {
+                    by_label
+                        .entry(label.clone())
+                        .or_default()
+                        .push((estimate, actual));
+                }
+            }
+            None => by_label
+                .entry("(no labels)".to_string())
+                .or_default()
+                .push((estimate, actual)),
+        }
+    }
+
+    Ok([
+        ("type", by_type),
+        ("assignee", by_assignee),
+        ("label", by_label),
+    ]
+    .into_iter()
+    .map(|(dimension, groups)| AccuracyBreakdown {
+        dimension: dimension.to_string(),
+        entries: groups
+            .into_iter()
+            .map(|(key, pairs)| accuracy_entry(key, &pairs))
+            .collect(),
+    })
+    .collect())
+}
+
+/// Summarize `(estimated, actual)` minute pairs for one group.
+#[allow(clippy::cast_precision_loss)]
+fn accuracy_entry(key: String, pairs: &[(i64, i64)]) -> AccuracyEntry {
+    let estimated_minutes: i64 = pairs.iter().map(|(estimate, _)| estimate).sum();
+    let actual_minutes: i64 = pairs.iter().map(|(_, actual)| actual).sum();
+    let mut ratios: Vec<f64> = pairs
+        .iter()
+        .map(|&(estimate, actual)| actual as f64 / estimate as f64)
+        .collect();
+    ratios.sort_by(f64::total_cmp);
+    let mid = ratios.len() / 2;
+    let median_ratio = if ratios.len() % 2 == 0 {
+        f64::midpoint(ratios[mid - 1], ratios[mid])
+    } else {
+        ratios[mid]
+    };
+
+    AccuracyEntry {
+        key,
+        issues: pairs.len(),
+        estimated_minutes,
+        actual_minutes,
+        ratio: actual_minutes as f64 / estimated_minutes as f64,
+        median_ratio,
+    }
+}
+
+/// One line of the accuracy report, e.g. `bug: 3 issues, est 6h, actual 9h (x1.50, median x1.40)`.
+fn format_accuracy_entry(entry: &AccuracyEntry) -> String {
+    format!(
+        "{}: {} issue{}, est {}, actual {} (x{:.2}, median x{:.2})",
+        entry.key,
+        entry.issues,
+        if entry.issues == 1 { "" } else { "s" },
+        format_minutes(entry.estimated_minutes),
+        format_minutes(entry.actual_minutes),
+        entry.ratio,
+        entry.median_ratio
+    )
+}
+
 /// Compute recent activity from git log on issues.jsonl.
 fn compute_recent_activity(beads_dir: &Path, hours: u32) -> Option<RecentActivity> {
     let jsonl_path = beads_dir.join("issues.jsonl");
@@ -467,6 +666,16 @@ fn print_text_output(output: &Statistics) {
         }
     }
 
+    for accuracy in &output.estimate_accuracy {
+        println!("\nEstimate Accuracy by {}:", accuracy.dimension);
+        if accuracy.entries.is_empty() {
+            println!("  (no closed issues with an estimate and tracked time)");
+        }
+        for entry in &accuracy.entries {
+            println!("  {}", format_accuracy_entry(entry));
+        }
+    }
+
     if let Some(activity) = &output.recent_activity {
         println!("\nRecent Activity (last {} hours):", activity.hours_tracked);
         println!("  Commits:                {}", activity.commit_count);
@@ -526,6 +735,29 @@ fn render_stats_rich(output: &Statistics, ctx: &OutputContext) {
         content.append("\n");
     }
 
+    // === Estimate Accuracy ===
+    for accuracy in &output.estimate_accuracy {
+        content.append_styled(
+            &format!(
+                "\u{23f1} Estimate Accuracy by {}\n",
+                capitalize(&accuracy.dimension)
+            ),
+            theme.section.clone(),
+        );
+        if accuracy.entries.is_empty() {
+            content.append_styled(
+                "   No closed issues with an estimate and tracked time\n",
+                theme.dimmed.clone(),
+            );
+        }
+        for entry in &accuracy.entries {
+            content.append("   ");
+            content.append(&format_accuracy_entry(entry));
+            content.append("\n");
+        }
+        content.append("\n");
+    }
+
     // === Recent Activity ===
     if let Some(activity) = &output.recent_activity {
         content.append_styled(
@@ -732,6 +964,9 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            time_entries: vec![],
+            recurrence: None,
+            attachments: vec![],
             content_hash: None,
         }
     }
@@ -926,4 +1161,71 @@ mod tests {
         assert_eq!(capitalize(""), "");
         assert_eq!(capitalize("ALREADY"), "ALREADY");
     }
+
+    #[test]
+    fn test_accuracy_entry_ratio_and_median() {
+        let entry = accuracy_entry("bug".to_string(), &[(60, 120), (60, 60), (120, 60)]);
+        assert_eq!(entry.issues, 3);
+        assert_eq!(entry.estimated_minutes, 240);
+        assert_eq!(entry.actual_minutes, 240);
+        assert!((entry.ratio - 1.0).abs() < f64::EPSILON);
+        assert!((entry.median_ratio - 1.0).abs() < f64::EPSILON);
+
+        let even = accuracy_entry("task".to_string(), &[(60, 30), (60, 120)]);
+        assert!((even.median_ratio - 1.25).abs() < f64::EPSILON);
+        assert_eq!(
+            format_accuracy_entry(&even),
+            "task: 2 issues, est 2h, actual 2h30m (x1.25, median x1.25)"
+        );
+    }
+
+    #[test]
+    fn test_estimate_accuracy_uses_closed_issues_with_tracked_time() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let mut bug = make_issue("bd-1", Status::Open, IssueType::Bug);
+        bug.estimated_minutes = Some(60);
+        bug.assignee = Some("alice".to_string());
+        let mut open = make_issue("bd-2", Status::Open, IssueType::Bug);
+        open.estimated_minutes = Some(60);
+        let mut unestimated = make_issue("bd-3", Status::Open, IssueType::Task);
+        unestimated.estimated_minutes = None;
+        for issue in [&bug, &open, &unestimated] {
+            storage.create_issue(issue, "test").unwrap();
+        }
+        storage.add_label("bd-1", "backend", "test").unwrap();
+        for id in ["bd-1", "bd-2", "bd-3"] {
+            storage.log_time(id, "alice", 90, Utc::now(), None).unwrap();
+        }
+        for id in ["bd-1", "bd-3"] {
+            storage
+                .update_issue(
+                    id,
+                    &crate::storage::IssueUpdate {
+                        status: Some(Status::Closed),
+                        closed_at: Some(Some(Utc::now())),
+                        ..Default::default()
+                    },
+                    "test",
+                )
+                .unwrap();
+        }
+
+        let issues = storage
+            .list_issues(&ListFilters {
+                include_closed: true,
+                ..Default::default()
+            })
+            .unwrap();
+        let report = compute_estimate_accuracy(&storage, &issues).unwrap();
+        let dimensions: Vec<&str> = report.iter().map(|b| b.dimension.as_str()).collect();
+        assert_eq!(dimensions, vec!["type", "assignee", "label"]);
+        for breakdown in &report {
+            assert_eq!(breakdown.entries.len(), 1, "{breakdown:?}");
+            assert_eq!(breakdown.entries[0].issues, 1);
+            assert_eq!(breakdown.entries[0].actual_minutes, 90);
+        }
+        assert_eq!(report[0].entries[0].key, "bug");
+        assert_eq!(report[1].entries[0].key, "alice");
+        assert_eq!(report[2].entries[0].key, "backend");
+    }
 }
@@ -31,6 +31,7 @@ pub struct FlushResult {
     pub exported_dependencies: usize,
     pub exported_labels: usize,
     pub exported_comments: usize,
+    pub exported_time_entries: usize,
     pub content_hash: String,
     pub cleared_dirty: usize,
     pub policy: ExportErrorPolicy,
@@ -554,6 +555,7 @@ fn execute_flush(
                 exported_dependencies: 0,
                 exported_labels: 0,
                 exported_comments: 0,
+                exported_time_entries: 0,
                 content_hash: String::new(),
                 cleared_dirty: 0,
                 policy: export_policy,
@@ -588,6 +590,7 @@ fn execute_flush(
         dependencies_exported = report.dependencies_exported,
         labels_exported = report.labels_exported,
         comments_exported = report.comments_exported,
+        time_entries_exported = report.time_entries_exported,
         errors = report.errors.len(),
         "Export completed"
     );
@@ -632,6 +635,7 @@ fn execute_flush(
         exported_dependencies: report.dependencies_exported,
         exported_labels: report.labels_exported,
         exported_comments: report.comments_exported,
+        exported_time_entries: report.time_entries_exported,
         content_hash: export_result.content_hash,
         cleared_dirty,
         policy: report.policy_used,
@@ -680,6 +684,18 @@ fn execute_flush(
             },
             format_error_suffix(&report.errors, ExportEntityType::Comment)
         );
+        if result.exported_time_entries > 0 {
+            println!(
+                "  {} time entr{}{}",
+                result.exported_time_entries,
+                if result.exported_time_entries == 1 {
+                    "y"
+                } else {
+                    "ies"
+                },
+                format_error_suffix(&report.errors, ExportEntityType::TimeEntry)
+            );
+        }
 
         if result.cleared_dirty > 0 {
             println!(
@@ -1117,6 +1133,9 @@ fn execute_merge(
     let all_deps = storage.get_all_dependency_records()?;
     let all_labels = storage.get_all_labels()?;
     let all_comments = storage.get_all_comments()?;
+    let all_time_entries = storage.get_all_time_entries()?;
+    let mut all_recurrences = storage.get_all_recurrences()?;
+    let mut all_attachments = storage.get_all_attachments()?;
 
     for issue in &mut left_issues {
         if let Some(deps) = all_deps.get(&issue.id) {
@@ -1128,6 +1147,11 @@ fn execute_merge(
         if let Some(comments) = all_comments.get(&issue.id) {
             issue.comments = comments.clone();
         }
+        if let Some(entries) = all_time_entries.get(&issue.id) {
+            issue.time_entries = entries.clone();
+        }
+        issue.recurrence = all_recurrences.remove(&issue.id);
+        issue.attachments = all_attachments.remove(&issue.id).unwrap_or_default();
     }
 
     let mut left = HashMap::new();
@@ -1189,6 +1213,9 @@ fn execute_merge(
         storage.sync_labels_for_import(&issue.id, &issue.labels)?;
         storage.sync_dependencies_for_import(&issue.id, &issue.dependencies)?;
         storage.sync_comments_for_import(&issue.id, &issue.comments)?;
+        storage.sync_time_entries_for_import(&issue.id, &issue.time_entries)?;
+        storage.sync_recurrence_for_import(&issue.id, issue.recurrence.as_ref())?;
+        storage.sync_attachments_for_import(&issue.id, &issue.attachments)?;
     }
 
     // Rebuild cache
@@ -1225,6 +1252,7 @@ fn execute_merge(
             "merged_issues": report.kept.len(),
             "deleted_issues": report.deleted.len(),
             "conflicts": report.conflicts.len(),
+            "field_conflicts": report.field_conflicts,
             "notes": report.notes,
         });
         ctx.json_pretty(&output);
@@ -1240,6 +1268,16 @@ fn execute_merge(
                 println!("    - {id}: {note}");
             }
         }
+        if !report.field_conflicts.is_empty() {
+            println!("  Field conflicts:");
+            for conflict in &report.field_conflicts {
+                println!(
+                    "    - {} {}",
+                    conflict.issue_id,
+                    describe_field_conflict(conflict)
+                );
+            }
+        }
         println!("  Base snapshot updated.");
         println!("  JSONL exported.");
     }
@@ -1286,6 +1324,26 @@ fn render_merge_conflicts_rich(
     console.print_renderable(&panel);
 }
 
+/// One-line description of a field conflict: `<field>: kept <side> (...)`.
+pub(crate) fn describe_field_conflict(conflict: &crate::sync::FieldConflict) -> String {
+    const MAX_VALUE_CHARS: usize = 40;
+    let shorten = |value: &str| {
+        if value.chars().count() > MAX_VALUE_CHARS {
+            let head: String = value.chars().take(MAX_VALUE_CHARS - 3).collect();
+            format!("{head}...")
+        } else {
+            value.to_string()
+        }
+    };
+    format!(
+        "{}: kept {} (local {}, external {})",
+        conflict.field,
+        conflict.kept,
+        shorten(&conflict.local),
+        shorten(&conflict.external)
+    )
+}
+
 /// Render merge result with rich formatting.
 fn render_merge_result_rich(report: &crate::sync::MergeReport, ctx: &OutputContext) {
     let console = Console::default();
@@ -1333,6 +1391,25 @@ fn render_merge_result_rich(report: &crate::sync::MergeReport, ctx: &OutputConte
         }
     }
 
+    // Field conflicts section
+    if !report.field_conflicts.is_empty() {
+        text.append("\n");
+        text.append_styled("Field conflicts:\n", theme.warning.clone());
+        for (i, conflict) in report.field_conflicts.iter().enumerate() {
+            let prefix = if i == report.field_conflicts.len() - 1 {
+                "└──"
+            } else {
+                "├──"
+            };
+            text.append_styled(prefix, theme.muted.clone());
+            text.append(" ");
+            text.append_styled(&conflict.issue_id, theme.issue_id.clone());
+            text.append(" ");
+            text.append_styled(&describe_field_conflict(conflict), theme.warning.clone());
+            text.append("\n");
+        }
+    }
+
     // Final status
     text.append("\n");
     text.append_styled("✓ ", theme.success.clone());
@@ -1394,6 +1471,9 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            time_entries: vec![],
+            recurrence: None,
+            attachments: vec![],
         }
     }
 
@@ -0,0 +1,298 @@
+//! Time command implementation.
+//!
+//! `br time` records actual effort on issues: `start`/`stop` run a timer per
+//! actor and issue, `log` records a session after the fact, and `list` shows
+//! what was tracked next to the estimate. Sessions are exported with their
+//! issue in JSONL; `br stats --accuracy` aggregates them against estimates.
+
+use crate::cli::commands::plan::format_minutes;
+use crate::cli::{TimeCommands, TimeListArgs, TimeLogArgs};
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::model::{TimeEntry, elapsed_minutes};
+use crate::output::OutputContext;
+use crate::storage::SqliteStorage;
+use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
+use crate::util::time::{parse_duration, parse_flexible_timestamp};
+use chrono::Utc;
+use serde::Serialize;
+
+/// JSON output for `br time list`.
+#[derive(Debug, Serialize)]
+struct TimeReport {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    issue_id: Option<String>,
+    entries: Vec<TimeEntry>,
+    /// Tracked minutes, counting running timers up to now.
+    total_minutes: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    estimated_minutes: Option<i32>,
+}
+
+/// Execute the time command.
+///
+/// # Errors
+///
+/// Returns an error if the database cannot be opened, an ID cannot be
+/// resolved, a duration is invalid, or the timer operation is rejected.
+pub fn execute(
+    command: &TimeCommands,
+    json: bool,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+
+    let config_layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+    let id_config = config::id_config_from_layer(&config_layer);
+    let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
+    let all_ids = storage_ctx.storage.get_all_ids()?;
+    let actor = config::resolve_actor(&config_layer);
+    let resolve = |input: &str| -> Result<String> {
+        let resolution = resolver.resolve(
+            input,
+            |id| all_ids.iter().any(|existing| existing == id),
+            |hash| find_matching_ids(&all_ids, hash),
+        )?;
+        Ok(resolution.id)
+    };
+    let storage = &mut storage_ctx.storage;
+
+    match command {
+        TimeCommands::Start(args) => {
+            let id = resolve(&args.id)?;
+            let entry = storage.start_timer(&id, &actor, args.note.as_deref())?;
+            crate::util::set_last_touched_id(&beads_dir, &id);
+            if json {
+                println!("{}", serde_json::to_string_pretty(&entry)?);
+            } else {
+                ctx.success(&format!("Started timer on {id}"));
+            }
+        }
+        TimeCommands::Stop(args) => {
+            let id = args.id.as_deref().map(&resolve).transpose()?;
+            let entry = storage.stop_timer(id.as_deref(), &actor)?;
+            crate::util::set_last_touched_id(&beads_dir, &entry.issue_id);
+            if json {
+                println!("{}", serde_json::to_string_pretty(&entry)?);
+            } else {
+                let total = total_minutes(&storage.get_time_entries(&entry.issue_id)?);
+                ctx.success(&format!(
+                    "Stopped timer on {}: {} ({} total)",
+                    entry.issue_id,
+                    format_minutes(i64::from(entry.minutes)),
+                    format_minutes(total)
+                ));
+            }
+        }
+        TimeCommands::Log(args) => {
+            let id = resolve(&args.id)?;
+            let entry = log_time(storage, &id, &actor, args)?;
+            crate::util::set_last_touched_id(&beads_dir, &id);
+            if json {
+                println!("{}", serde_json::to_string_pretty(&entry)?);
+            } else {
+                ctx.success(&format!(
+                    "Logged {} on {id}",
+                    format_minutes(i64::from(entry.minutes))
+                ));
+            }
+        }
+        TimeCommands::List(args) => {
+            let id = args.id.as_deref().map(&resolve).transpose()?;
+            let report = list_time(storage, id, args)?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else {
+                print_report(&report);
+            }
+        }
+    }
+
+    storage_ctx.flush_no_db_if_dirty()?;
+    Ok(())
+}
+
+fn log_time(
+    storage: &mut SqliteStorage,
+    id: &str,
+    actor: &str,
+    args: &TimeLogArgs,
+) -> Result<TimeEntry> {
+    let duration = parse_duration(&args.duration, "duration")?;
+    let minutes = i32::try_from(elapsed_minutes(Utc::now() - duration, Utc::now()))
+        .map_err(|_| BeadsError::validation("duration", "duration is too large"))?;
+    if minutes == 0 {
+        return Err(BeadsError::validation(
+            "duration",
+            "logged time must be at least one minute",
+        ));
+    }
+    let ended_at = match &args.at {
+        Some(at) => parse_flexible_timestamp(at, "at")?,
+        None => Utc::now(),
+    };
+    storage.log_time(id, actor, minutes, ended_at, args.note.as_deref())
+}
+
+fn list_time(
+    storage: &SqliteStorage,
+    id: Option<String>,
+    args: &TimeListArgs,
+) -> Result<TimeReport> {
+    let mut entries = match &id {
+        Some(id) => storage.get_time_entries(id)?,
+        None if args.running => storage.get_running_timers(None)?,
+        None => {
+            let mut all: Vec<TimeEntry> = storage
+                .get_all_time_entries()?
+                .into_values()
+                .flatten()
+                .collect();
+            all.sort_by_key(|entry| (entry.started_at, entry.id));
+            all
+        }
+    };
+    entries.retain(|entry| {
+        (!args.running || entry.is_running())
+            && args.actor.as_deref().is_none_or(|a| entry.actor == a)
+    });
+
+    let estimated_minutes = match &id {
+        Some(id) => {
+            storage
+                .get_issue(id)?
+                .ok_or_else(|| BeadsError::IssueNotFound { id: id.clone() })?
+                .estimated_minutes
+        }
+        None => None,
+    };
+
+    Ok(TimeReport {
+        issue_id: id,
+        total_minutes: total_minutes(&entries),
+        entries,
+        estimated_minutes,
+    })
+}
+
+/// Minutes tracked across `entries`, counting running timers up to now.
+fn total_minutes(entries: &[TimeEntry]) -> i64 {
+    let now = Utc::now();
+    entries.iter().map(|entry| entry.minutes_at(now)).sum()
+}
+
+fn print_report(report: &TimeReport) {
+    if report.entries.is_empty() {
+        println!("No time tracked.");
+        return;
+    }
+
+    let now = Utc::now();
+    for entry in &report.entries {
+        let duration = if entry.is_running() {
+            format!("{} (running)", format_minutes(entry.minutes_at(now)))
+        } else {
+            format_minutes(entry.minutes_at(now))
+        };
+        let note = entry
+            .note
+            .as_deref()
+            .map(|n| format!("  {n}"))
+            .unwrap_or_default();
+        println!(
+            "  {}  {:<8}  {:<12}  {}{note}",
+            entry.started_at.format("%Y-%m-%d %H:%M"),
+            duration,
+            entry.actor,
+            entry.issue_id
+        );
+    }
+
+    print!("\nTotal: {}", format_minutes(report.total_minutes));
+    if let Some(estimate) = report.estimated_minutes.filter(|e| *e > 0) {
+        let used = report.total_minutes * 100 / i64::from(estimate);
+        print!(
+            " of {} estimated ({used}%)",
+            format_minutes(i64::from(estimate))
+        );
+    }
+    println!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn entry(minutes: i32, ended: bool) -> TimeEntry {
+        let now = Utc::now();
+        TimeEntry {
+            id: 1,
+            issue_id: "bd-1".to_string(),
+            actor: "alice".to_string(),
+            started_at: now - Duration::minutes(i64::from(minutes)),
+            ended_at: ended.then_some(now),
+            minutes: if ended { minutes } else { 0 },
+            note: None,
+        }
+    }
+
+    #[test]
+    fn test_total_minutes_counts_running_timers() {
+        let entries = vec![entry(30, true), entry(15, false)];
+        assert_eq!(total_minutes(&entries), 45);
+    }
+
+    #[test]
+    fn test_storage_timer_lifecycle() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let issue = crate::model::Issue {
+            id: "bd-1".to_string(),
+            title: "Work".to_string(),
+            ..Default::default()
+        };
+        storage.create_issue(&issue, "alice").unwrap();
+
+        let started = storage.start_timer("bd-1", "alice", Some("spike")).unwrap();
+        assert!(started.is_running());
+        assert!(storage.start_timer("bd-1", "alice", None).is_err());
+        // Another actor can time the same issue.
+        storage.start_timer("bd-1", "bob", None).unwrap();
+
+        let stopped = storage.stop_timer(None, "alice").unwrap();
+        assert_eq!(stopped.id, started.id);
+        assert!(!stopped.is_running());
+        assert!(storage.stop_timer(None, "alice").is_err());
+
+        let logged = storage
+            .log_time("bd-1", "alice", 90, Utc::now(), None)
+            .unwrap();
+        assert_eq!(logged.minutes, 90);
+        assert_eq!(
+            logged.ended_at.unwrap() - logged.started_at,
+            Duration::minutes(90)
+        );
+        assert!(
+            storage
+                .log_time("bd-1", "alice", 0, Utc::now(), None)
+                .is_err()
+        );
+
+        assert_eq!(storage.get_time_entries("bd-1").unwrap().len(), 3);
+        assert_eq!(storage.get_running_timers(Some("bob")).unwrap().len(), 1);
+        assert!(
+            storage
+                .get_running_timers(Some("alice"))
+                .unwrap()
+                .is_empty()
+        );
+        assert!(
+            storage
+                .get_dirty_issue_ids()
+                .unwrap()
+                .contains(&"bd-1".to_string())
+        );
+    }
+}
@@ -2,7 +2,9 @@
 
 use crate::cli::UpdateArgs;
 use crate::config;
+use crate::config::workflow::Workflow;
 use crate::error::{BeadsError, Result};
+use crate::hooks::{self, Hooks};
 use crate::model::{DependencyType, Issue, Status};
 use crate::output::OutputContext;
 use crate::storage::{IssueUpdate, SqliteStorage};
@@ -50,7 +52,9 @@ pub fn execute(args: &UpdateArgs, cli: &config::CliOverrides, ctx: &OutputContex
     let resolved_ids = resolve_target_ids(args, &beads_dir, &resolver, &storage_ctx.storage)?;
 
     let claim_exclusive = config::claim_exclusive_from_layer(&config_layer);
-    let update = build_update(args, &actor, claim_exclusive)?;
+    let workflow = Workflow::load(&config_layer)?;
+    let hooks = Hooks::load(&beads_dir, &config_layer)?;
+    let update = build_update(args, &actor, claim_exclusive, &workflow)?;
     let has_updates = !update.is_empty()
         || !args.add_label.is_empty()
         || !args.remove_label.is_empty()
@@ -65,83 +69,17 @@ pub fn execute(args: &UpdateArgs, cli: &config::CliOverrides, ctx: &OutputContex
         // Get issue before update for change tracking
         let issue_before = storage.get_issue(id)?;
 
-        // Claim guard is now inside the IMMEDIATE transaction (see IssueUpdate.expect_unassigned)
-        // to prevent TOCTOU races between concurrent agents.
-
-        // Check if transitioning to in_progress (via --claim or --status in_progress)
-        // and if so, validate that the issue is not blocked
-        let transitioning_to_in_progress = args.claim
-            || args
-                .status
-                .as_ref()
-                .is_some_and(|s| s.eq_ignore_ascii_case("in_progress"));
-
-        if transitioning_to_in_progress && !args.force && storage.is_blocked(id)? {
-            let blockers = storage.get_blockers(id)?;
-            let blocker_list = if blockers.is_empty() {
-                "blocking dependencies".to_string()
-            } else {
-                blockers.join(", ")
-            };
-            return Err(BeadsError::validation(
-                "claim",
-                format!("cannot claim blocked issue: {blocker_list}"),
-            ));
-        }
-
-        // Reassignment safeguard: prevent silently stealing an in_progress bead from
-        // another agent. Require --claim (atomic) or --force (explicit override).
-        if !args.claim && !args.force {
-            if let Some(ref new_assignee) = args.assignee {
-                if !new_assignee.is_empty() {
-                    if let Some(ref before) = issue_before {
-                        if before.status == Status::InProgress {
-                            let current = before.assignee.as_deref().unwrap_or("").trim();
-                            if !current.is_empty() && current != new_assignee.as_str() {
-                                return Err(BeadsError::validation(
-                                    "reassign",
-                                    format!(
-                                        "{id} is actively in progress by {current} — use --claim to take ownership or --force to override"
-                                    ),
-                                ));
-                            }
-                        }
-                    }
-                }
-            }
-        }
-
-        // Apply basic field updates
-        if !update.is_empty() {
-            storage.update_issue(id, &update, &actor)?;
-        }
-
-        // Apply labels
-        for label in &args.add_label {
-            LabelValidator::validate(label)
-                .map_err(|e| BeadsError::validation("label", e.message))?;
-            storage.add_label(id, label, &actor)?;
-        }
-        for label in &args.remove_label {
-            storage.remove_label(id, label, &actor)?;
-        }
-        if !args.set_labels.is_empty() {
-            // Remove all then add new
-            storage.remove_all_labels(id, &actor)?;
-            // Join all flag values, then split by comma (handles both --set-labels a,b and --set-labels a --set-labels b)
-            let combined = args.set_labels.join(",");
-            for label in combined.split(',') {
-                let label = label.trim();
-                if !label.is_empty() {
-                    LabelValidator::validate(label)
-                        .map_err(|e| BeadsError::validation("label", e.message))?;
-                    storage.add_label(id, label, &actor)?;
-                }
-            }
-        }
-
-        // Apply parent
-        apply_parent_update(storage, id, args.parent.as_deref(), &resolver, &actor)?;
+        apply_update(
+            storage,
+            id,
+            args,
+            &update,
+            issue_before.as_ref(),
+            &resolver,
+            &actor,
+            &workflow,
+            &hooks,
+        )?;
 
         // Update last touched
         crate::util::set_last_touched_id(&beads_dir, id);
@@ -168,6 +106,188 @@ pub fn execute(args: &UpdateArgs, cli: &config::CliOverrides, ctx: &OutputContex
     Ok(())
 }
 
+/// Apply an update to a single, already-resolved issue.
+///
+/// Enforces the blocked-claim, reassignment and workflow guards and runs the
+/// status-change hooks, then writes field, label and parent changes. Shared
+/// by `br update` and `br serve`.
+///
+/// # Errors
+///
+/// Returns an error if a guard rejects the change or a storage write fails.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn apply_update(
+    storage: &mut SqliteStorage,
+    id: &str,
+    args: &UpdateArgs,
+    update: &IssueUpdate,
+    issue_before: Option<&Issue>,
+    resolver: &IdResolver,
+    actor: &str,
+    workflow: &Workflow,
+    hooks: &Hooks,
+) -> Result<()> {
+    // Claim guard is now inside the IMMEDIATE transaction (see IssueUpdate.expect_unassigned)
+    // to prevent TOCTOU races between concurrent agents.
+
+    // Check if transitioning to in_progress (via --claim or --status in_progress)
+    // and if so, validate that the issue is not blocked
+    let transitioning_to_in_progress = args.claim
+        || args
+            .status
+            .as_ref()
+            .is_some_and(|s| s.eq_ignore_ascii_case("in_progress"));
+
+    if transitioning_to_in_progress && !args.force && storage.is_blocked(id)? {
+        let blockers = storage.get_blockers(id)?;
+        let blocker_list = if blockers.is_empty() {
+            "blocking dependencies".to_string()
+        } else {
+            blockers.join(", ")
+        };
+        return Err(BeadsError::validation(
+            "claim",
+            format!("cannot claim blocked issue: {blocker_list}"),
+        ));
+    }
+
+    // Reassignment safeguard: prevent silently stealing an in_progress bead from
+    // another agent. Require --claim (atomic) or --force (explicit override).
+    if !args.claim && !args.force {
+        if let Some(ref new_assignee) = args.assignee {
+            if !new_assignee.is_empty() {
+                if let Some(before) = issue_before {
+                    if before.status == Status::InProgress {
+                        let current = before.assignee.as_deref().unwrap_or("").trim();
+                        if !current.is_empty() && current != new_assignee.as_str() {
+                            return Err(BeadsError::validation(
+                                "reassign",
+                                format!(
+                                    "{id} is actively in progress by {current} — use --claim to take ownership or --force to override"
+                                ),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // Workflow: the status change must be a legal transition, judged on the
+    // issue as it will look once the rest of the update is applied.
+    if let (Some(status), Some(before)) = (&update.status, issue_before) {
+        if !workflow.is_empty() {
+            let after = preview_update(storage, before, args, update)?;
+            workflow.check(&after, status)?;
+        }
+    }
+
+    // Pre-hooks for a real status change, on the same preview of the issue.
+    let close_reason = update.close_reason.as_ref().and_then(Option::as_deref);
+    let status_change = update
+        .status
+        .as_ref()
+        .zip(issue_before)
+        .filter(|(to, before)| **to != before.status && hooks.is_active());
+    if let Some((to, before)) = status_change {
+        let preview = preview_update(storage, before, args, update)?;
+        for event in hooks::status_events(&before.status, to, close_reason) {
+            hooks.pre(&event, actor, &preview)?;
+        }
+    }
+
+    // Apply basic field updates
+    if !update.is_empty() {
+        storage.update_issue(id, update, actor)?;
+    }
+
+    // Apply labels
+    for label in &args.add_label {
+        LabelValidator::validate(label).map_err(|e| BeadsError::validation("label", e.message))?;
+        storage.add_label(id, label, actor)?;
+    }
+    for label in &args.remove_label {
+        storage.remove_label(id, label, actor)?;
+    }
+    if !args.set_labels.is_empty() {
+        // Remove all then add new
+        storage.remove_all_labels(id, actor)?;
+        // Join all flag values, then split by comma (handles both --set-labels a,b and --set-labels a --set-labels b)
+        let combined = args.set_labels.join(",");
+        for label in combined.split(',') {
+            let label = label.trim();
+            if !label.is_empty() {
+                LabelValidator::validate(label)
+                    .map_err(|e| BeadsError::validation("label", e.message))?;
+                storage.add_label(id, label, actor)?;
+            }
+        }
+    }
+
+    // Apply parent
+    apply_parent_update(storage, id, args.parent.as_deref(), resolver, actor)?;
+
+    if let Some((to, before)) = status_change {
+        if let Some(mut after) = storage.get_issue(id)? {
+            after.labels = storage.get_labels(id)?;
+            for event in hooks::status_events(&before.status, to, close_reason) {
+                hooks.post(&event, actor, &after);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The issue with `update` and the label flags applied, status unchanged.
+fn preview_update(
+    storage: &SqliteStorage,
+    before: &Issue,
+    args: &UpdateArgs,
+    update: &IssueUpdate,
+) -> Result<Issue> {
+    let mut issue = before.clone();
+    if let Some(issue_type) = &update.issue_type {
+        issue.issue_type = issue_type.clone();
+    }
+    for (field, value) in [
+        (&mut issue.description, &update.description),
+        (&mut issue.design, &update.design),
+        (&mut issue.acceptance_criteria, &update.acceptance_criteria),
+        (&mut issue.notes, &update.notes),
+        (&mut issue.assignee, &update.assignee),
+        (&mut issue.owner, &update.owner),
+        (&mut issue.external_ref, &update.external_ref),
+    ] {
+        if let Some(value) = value {
+            field.clone_from(value);
+        }
+    }
+    if let Some(estimate) = update.estimated_minutes {
+        issue.estimated_minutes = estimate;
+    }
+    if let Some(due_at) = update.due_at {
+        issue.due_at = due_at;
+    }
+
+    // Same order as apply_update: add, remove, then --set-labels replaces all.
+    issue.labels = if args.set_labels.is_empty() {
+        let mut labels = storage.get_labels(&issue.id)?;
+        labels.extend(args.add_label.iter().cloned());
+        labels.retain(|label| !args.remove_label.contains(label));
+        labels
+    } else {
+        args.set_labels
+            .join(",")
+            .split(',')
+            .map(str::trim)
+            .filter(|label| !label.is_empty())
+            .map(str::to_string)
+            .collect()
+    };
+    Ok(issue)
+}
+
 /// Print a summary of what changed for the issue.
 fn print_update_summary(id: &str, title: &str, before: Option<&Issue>, after: &Issue) {
     println!("Updated {id}: {title}");
@@ -208,7 +328,10 @@ fn print_update_summary(id: &str, title: &str, before: Option<&Issue>, after: &I
     }
 }
 
-fn build_resolver(config_layer: &config::ConfigLayer, _storage: &SqliteStorage) -> IdResolver {
+pub(crate) fn build_resolver(
+    config_layer: &config::ConfigLayer,
+    _storage: &SqliteStorage,
+) -> IdResolver {
     let id_config = config::id_config_from_layer(config_layer);
     IdResolver::new(ResolverConfig::with_prefix(id_config.prefix))
 }
@@ -240,11 +363,19 @@ fn resolve_target_ids(
     Ok(resolved_ids.into_iter().map(|r| r.id).collect())
 }
 
-fn build_update(args: &UpdateArgs, actor: &str, claim_exclusive: bool) -> Result<IssueUpdate> {
+pub(crate) fn build_update(
+    args: &UpdateArgs,
+    actor: &str,
+    claim_exclusive: bool,
+    workflow: &Workflow,
+) -> Result<IssueUpdate> {
     let status = if args.claim {
         Some(Status::InProgress)
     } else {
-        args.status.as_ref().map(|s| s.parse()).transpose()?
+        args.status
+            .as_deref()
+            .map(|s| workflow.parse_status(s))
+            .transpose()?
     };
 
     let priority = args.priority.as_ref().map(|p| p.parse()).transpose()?;
@@ -263,7 +394,7 @@ fn build_update(args: &UpdateArgs, actor: &str, claim_exclusive: bool) -> Result
 
     let closed_at = match &status {
         Some(Status::Closed | Status::Tombstone) => Some(Some(Utc::now())),
-        Some(Status::Open | Status::InProgress) => Some(None),
+        Some(Status::Open | Status::InProgress | Status::Custom(_)) => Some(None),
         _ => None,
     };
 
@@ -324,7 +455,11 @@ fn optional_date_field(value: Option<&str>) -> Result<Option<Option<DateTime<Utc
         .transpose()
 }
 
-fn resolve_issue_id(resolver: &IdResolver, storage: &SqliteStorage, input: &str) -> Result<String> {
+pub(crate) fn resolve_issue_id(
+    resolver: &IdResolver,
+    storage: &SqliteStorage,
+    input: &str,
+) -> Result<String> {
     resolver
         .resolve(
             input,
@@ -503,7 +638,7 @@ mod tests {
             claim: true,
             ..Default::default()
         };
-        let update = build_update(&args, "test_actor", false).unwrap();
+        let update = build_update(&args, "test_actor", false, &Workflow::default()).unwrap();
         assert_eq!(update.status, Some(Status::InProgress));
         assert_eq!(update.assignee, Some(Some("test_actor".to_string())));
         info!("test_build_update_with_claim: assertions passed");
@@ -517,13 +652,31 @@ mod tests {
             status: Some("closed".to_string()),
             ..Default::default()
         };
-        let update = build_update(&args, "test_actor", false).unwrap();
+        let update = build_update(&args, "test_actor", false, &Workflow::default()).unwrap();
         assert_eq!(update.status, Some(Status::Closed));
         // closed_at should be set
         assert!(update.closed_at.is_some());
         info!("test_build_update_with_status: assertions passed");
     }
 
+    #[test]
+    fn test_build_update_with_workflow_state() {
+        let mut layer = crate::config::ConfigLayer::default();
+        layer.runtime.insert(
+            "workflow.default.states".to_string(),
+            "open,review,closed".to_string(),
+        );
+        let workflow = Workflow::load(&layer).unwrap();
+        let args = UpdateArgs {
+            status: Some("review".to_string()),
+            ..Default::default()
+        };
+        let update = build_update(&args, "test_actor", false, &workflow).unwrap();
+        assert_eq!(update.status, Some(Status::Custom("review".to_string())));
+        assert_eq!(update.closed_at, Some(None));
+        assert!(build_update(&args, "test_actor", false, &Workflow::default()).is_err());
+    }
+
     #[test]
     fn test_build_update_with_priority() {
         init_test_logging();
@@ -532,7 +685,7 @@ mod tests {
             priority: Some("1".to_string()),
             ..Default::default()
         };
-        let update = build_update(&args, "test_actor", false).unwrap();
+        let update = build_update(&args, "test_actor", false, &Workflow::default()).unwrap();
         assert_eq!(update.priority, Some(Priority(1)));
         info!("test_build_update_with_priority: assertions passed");
     }
@@ -542,7 +695,7 @@ mod tests {
         init_test_logging();
         info!("test_build_update_empty: starting");
         let args = UpdateArgs::default();
-        let update = build_update(&args, "test_actor", false).unwrap();
+        let update = build_update(&args, "test_actor", false, &Workflow::default()).unwrap();
         assert!(update.is_empty());
         info!("test_build_update_empty: assertions passed");
     }
@@ -0,0 +1,483 @@
+//! Watch command implementation.
+//!
+//! `br watch` streams issue changes as newline-delimited JSON so dashboards
+//! and orchestrators can react to them instead of polling `br list --json`.
+//!
+//! Changes come from two places:
+//! - The `events` audit table. Each row is emitted once, in ID order, and its
+//!   ID is the event's `cursor`; `--since <CURSOR>` resumes after it.
+//! - Writes that leave no event row, such as a JSONL import after `git pull`.
+//!   These are found by comparing `(updated_at, status)` snapshots of all
+//!   issues and emitted as synthesized `created`/`updated`/`closed`/`deleted`
+//!   events with `"source": "sync"`. They carry the current cursor but are
+//!   not replayed by `--since`.
+//!
+//! Other processes' commits are noticed through `PRAGMA data_version`, so an
+//! idle watcher costs one cheap query per interval.
+
+use crate::cli::WatchArgs;
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::model::{Event, EventType, Issue, IssueType, Priority, Status};
+use crate::output::OutputContext;
+use crate::storage::SqliteStorage;
+use crate::sync::auto_import_if_stale;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::thread;
+use std::time::{Duration, SystemTime};
+use tracing::{debug, info, warn};
+
+/// Where a watch event came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WatchSource {
+    /// A row in the `events` table.
+    Event,
+    /// A change with no event row (JSONL import), found by snapshot diff.
+    Sync,
+}
+
+/// One line of `br watch` output.
+#[derive(Debug, Clone, Serialize)]
+pub struct WatchEvent {
+    /// Resume point: pass to `--since` to continue after this event.
+    pub cursor: i64,
+    pub source: WatchSource,
+    pub event_type: EventType,
+    pub issue_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    pub created_at: DateTime<Utc>,
+    /// Current state of the issue (`null` once it is gone).
+    pub issue: Option<WatchIssue>,
+}
+
+/// Issue summary attached to each event.
+#[derive(Debug, Clone, Serialize)]
+pub struct WatchIssue {
+    pub id: String,
+    pub title: String,
+    pub status: Status,
+    pub priority: Priority,
+    pub issue_type: IssueType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<String>,
+    pub labels: Vec<String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl WatchIssue {
+    fn new(issue: Issue, labels: Vec<String>) -> Self {
+        Self {
+            id: issue.id,
+            title: issue.title,
+            status: issue.status,
+            priority: issue.priority,
+            issue_type: issue.issue_type,
+            assignee: issue.assignee,
+            labels,
+            updated_at: issue.updated_at,
+        }
+    }
+}
+
+/// `--status` / `--label` / `--assignee` filters, applied to the issue's
+/// current state.
+#[derive(Debug, Default)]
+struct WatchFilter {
+    statuses: Vec<Status>,
+    labels: Vec<String>,
+    assignee: Option<String>,
+}
+
+impl WatchFilter {
+    fn from_args(args: &WatchArgs) -> Result<Self> {
+        let statuses = args
+            .status
+            .iter()
+            .map(|s| Status::from_str(s))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            statuses,
+            labels: args.label.clone(),
+            assignee: args.assignee.clone(),
+        })
+    }
+
+    fn is_empty(&self) -> bool {
+        self.statuses.is_empty() && self.labels.is_empty() && self.assignee.is_none()
+    }
+
+    /// Events for issues that no longer exist always pass, since there is
+    /// nothing left to test; consumers drop IDs they are not tracking.
+    fn matches(&self, issue: Option<&WatchIssue>) -> bool {
+        let Some(issue) = issue else {
+            return true;
+        };
+        (self.statuses.is_empty() || self.statuses.contains(&issue.status))
+            && self.labels.iter().all(|label| issue.labels.contains(label))
+            && self
+                .assignee
+                .as_ref()
+                .is_none_or(|assignee| issue.assignee.as_ref() == Some(assignee))
+    }
+}
+
+/// Size and mtime of the JSONL file, to notice rewrites cheaply.
+type FileStamp = Option<(u64, SystemTime)>;
+
+fn file_stamp(path: &Path) -> FileStamp {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.len(), metadata.modified().ok()?))
+}
+
+/// Polling state carried between intervals.
+struct Watcher {
+    cursor: i64,
+    markers: HashMap<String, (String, String)>,
+    /// `None` until the first poll, which always reads.
+    data_version: Option<i64>,
+    /// JSONL to import from; `None` when auto-import is disabled.
+    jsonl: Option<JsonlSource>,
+}
+
+struct JsonlSource {
+    beads_dir: PathBuf,
+    path: PathBuf,
+    expected_prefix: Option<String>,
+    /// `None` until the first poll, which always checks staleness.
+    stamp: Option<FileStamp>,
+}
+
+impl Watcher {
+    /// Collect everything that changed since the last poll.
+    fn poll(&mut self, storage: &mut SqliteStorage) -> Result<Vec<WatchEvent>> {
+        // This connection's own import does not bump data_version.
+        let imported = self.import_if_changed(storage);
+
+        let version = storage.data_version()?;
+        if !imported && self.data_version == Some(version) {
+            return Ok(Vec::new());
+        }
+        self.data_version = Some(version);
+
+        let events = storage.get_events_after(self.cursor)?;
+        if let Some(last) = events.last() {
+            self.cursor = last.id;
+        }
+        let touched: HashSet<&str> = events.iter().map(|e| e.issue_id.as_str()).collect();
+
+        let markers = storage.get_issue_markers()?;
+        let synced = diff_markers(&self.markers, &markers, &touched);
+        self.markers = markers;
+
+        let mut out = Vec::with_capacity(events.len() + synced.len());
+        let mut issues: HashMap<String, Option<WatchIssue>> = HashMap::new();
+        for event in events {
+            let issue = load_issue(storage, &mut issues, &event.issue_id)?;
+            out.push(from_event(event, issue));
+        }
+        let now = Utc::now();
+        for (issue_id, event_type) in synced {
+            let issue = load_issue(storage, &mut issues, &issue_id)?;
+            out.push(WatchEvent {
+                cursor: self.cursor,
+                source: WatchSource::Sync,
+                event_type,
+                issue_id,
+                actor: None,
+                old_value: None,
+                new_value: None,
+                comment: None,
+                created_at: now,
+                issue,
+            });
+        }
+        Ok(out)
+    }
+
+    /// Import the JSONL if it changed on disk. Failures (e.g. a file caught
+    /// mid-`git checkout`) are logged and retried on the next change.
+    fn import_if_changed(&mut self, storage: &mut SqliteStorage) -> bool {
+        let Some(jsonl) = self.jsonl.as_mut() else {
+            return false;
+        };
+        let stamp = file_stamp(&jsonl.path);
+        if jsonl.stamp == Some(stamp) {
+            return false;
+        }
+        jsonl.stamp = Some(stamp);
+
+        match auto_import_if_stale(
+            storage,
+            &jsonl.beads_dir,
+            &jsonl.path,
+            jsonl.expected_prefix.as_deref(),
+            false,
+            false,
+        ) {
+            Ok(result) => {
+                if result.attempted {
+                    debug!(imported = result.imported_count, "Watch imported JSONL");
+                }
+                result.attempted
+            }
+            Err(e) => {
+                warn!(?e, "JSONL import failed; waiting for the next change");
+                false
+            }
+        }
+    }
+}
+
+fn from_event(event: Event, issue: Option<WatchIssue>) -> WatchEvent {
+    WatchEvent {
+        cursor: event.id,
+        source: WatchSource::Event,
+        event_type: event.event_type,
+        issue_id: event.issue_id,
+        actor: Some(event.actor),
+        old_value: event.old_value,
+        new_value: event.new_value,
+        comment: event.comment,
+        created_at: event.created_at,
+        issue,
+    }
+}
+
+fn load_issue(
+    storage: &SqliteStorage,
+    cache: &mut HashMap<String, Option<WatchIssue>>,
+    id: &str,
+) -> Result<Option<WatchIssue>> {
+    if let Some(issue) = cache.get(id) {
+        return Ok(issue.clone());
+    }
+    let issue = match storage.get_issue(id)? {
+        Some(issue) => {
+            let labels = storage.get_labels(id)?;
+            Some(WatchIssue::new(issue, labels))
+        }
+        None => None,
+    };
+    cache.insert(id.to_string(), issue.clone());
+    Ok(issue)
+}
+
+/// Classify issues whose `(updated_at, status)` changed without an event row.
+/// Issues in `touched` already have events and are skipped.
+fn diff_markers(
+    before: &HashMap<String, (String, String)>,
+    after: &HashMap<String, (String, String)>,
+    touched: &HashSet<&str>,
+) -> Vec<(String, EventType)> {
+    let mut changes: Vec<(String, EventType)> = Vec::new();
+    for (id, marker) in after {
+        if touched.contains(id.as_str()) {
+            continue;
+        }
+        match before.get(id) {
+            None => changes.push((id.clone(), EventType::Created)),
+            Some(old) if old == marker => {}
+            Some(old) => {
+                let closed = marker.1 == Status::Closed.as_str() && old.1 != marker.1;
+                let event_type = if closed {
+                    EventType::Closed
+                } else {
+                    EventType::Updated
+                };
+                changes.push((id.clone(), event_type));
+            }
+        }
+    }
+    for id in before.keys() {
+        if !after.contains_key(id) && !touched.contains(id.as_str()) {
+            changes.push((id.clone(), EventType::Deleted));
+        }
+    }
+    changes.sort_by(|a, b| a.0.cmp(&b.0));
+    changes
+}
+
+/// Execute the watch command.
+///
+/// # Errors
+///
+/// Returns an error if the database cannot be opened, a filter is invalid,
+/// or a poll fails. A closed stdout ends the watch without an error.
+pub fn execute(args: &WatchArgs, cli: &config::CliOverrides, _ctx: &OutputContext) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    if storage_ctx.no_db {
+        return Err(BeadsError::validation(
+            "no-db",
+            "br watch needs the SQLite database; it is not available with --no-db",
+        ));
+    }
+    let filter = WatchFilter::from_args(args)?;
+    let storage = &mut storage_ctx.storage;
+
+    let jsonl = if cli.no_auto_import.unwrap_or(false) {
+        None
+    } else {
+        Some(JsonlSource {
+            beads_dir: storage_ctx.paths.beads_dir.clone(),
+            path: storage_ctx.paths.jsonl_path.clone(),
+            expected_prefix: storage.get_config("issue_prefix")?,
+            stamp: None,
+        })
+    };
+    let mut watcher = Watcher {
+        cursor: match args.since {
+            Some(cursor) => cursor.max(0),
+            None => storage.latest_event_id()?,
+        },
+        markers: storage.get_issue_markers()?,
+        data_version: None,
+        jsonl,
+    };
+
+    info!(
+        cursor = watcher.cursor,
+        filtered = !filter.is_empty(),
+        "Watching for changes"
+    );
+    let interval = Duration::from_millis(args.interval.max(1));
+    let mut stdout = io::stdout().lock();
+    loop {
+        let events = watcher.poll(storage)?;
+        let mut written = Ok(());
+        for event in events.iter().filter(|e| filter.matches(e.issue.as_ref())) {
+            written = writeln!(stdout, "{}", serde_json::to_string(event)?);
+            if written.is_err() {
+                break;
+            }
+        }
+        if let Err(e) = written.and_then(|()| stdout.flush()) {
+            // The reader went away (e.g. `br watch | head`); that ends the watch.
+            debug!(?e, "stdout closed, stopping watch");
+            return Ok(());
+        }
+
+        if args.once {
+            return Ok(());
+        }
+        thread::sleep(interval);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn marker(updated: &str, status: &str) -> (String, String) {
+        (updated.to_string(), status.to_string())
+    }
+
+    #[test]
+    fn diff_markers_classifies_changes_without_events() {
+        let before = HashMap::from([
+            ("bd-1".to_string(), marker("t1", "open")),
+            ("bd-2".to_string(), marker("t1", "open")),
+            ("bd-3".to_string(), marker("t1", "open")),
+            ("bd-4".to_string(), marker("t1", "open")),
+            ("bd-5".to_string(), marker("t1", "open")),
+        ]);
+        let after = HashMap::from([
+            ("bd-1".to_string(), marker("t1", "open")),
+            ("bd-2".to_string(), marker("t2", "open")),
+            ("bd-3".to_string(), marker("t2", "closed")),
+            ("bd-5".to_string(), marker("t2", "in_progress")),
+            ("bd-6".to_string(), marker("t2", "open")),
+        ]);
+        let touched = HashSet::from(["bd-5"]);
+
+        let changes = diff_markers(&before, &after, &touched);
+        assert_eq!(
+            changes,
+            vec![
+                ("bd-2".to_string(), EventType::Updated),
+                ("bd-3".to_string(), EventType::Closed),
+                ("bd-4".to_string(), EventType::Deleted),
+                ("bd-6".to_string(), EventType::Created),
+            ]
+        );
+    }
+
+    #[test]
+    fn filter_matches_current_issue_state() {
+        let issue = WatchIssue {
+            id: "bd-1".to_string(),
+            title: "Watch me".to_string(),
+            status: Status::InProgress,
+            priority: Priority(1),
+            issue_type: IssueType::Task,
+            assignee: Some("alice".to_string()),
+            labels: vec!["backend".to_string(), "api".to_string()],
+            updated_at: Utc::now(),
+        };
+        let args = WatchArgs {
+            status: vec!["in_progress".to_string()],
+            label: vec!["backend".to_string()],
+            assignee: Some("alice".to_string()),
+            ..WatchArgs::default()
+        };
+        let filter = WatchFilter::from_args(&args).unwrap();
+        assert!(filter.matches(Some(&issue)));
+        assert!(filter.matches(None));
+
+        let args = WatchArgs {
+            label: vec!["backend".to_string(), "ui".to_string()],
+            ..WatchArgs::default()
+        };
+        assert!(!WatchFilter::from_args(&args).unwrap().matches(Some(&issue)));
+
+        let args = WatchArgs {
+            status: vec!["bogus".to_string()],
+            ..WatchArgs::default()
+        };
+        assert!(WatchFilter::from_args(&args).is_err());
+    }
+
+    #[test]
+    fn poll_reads_events_after_cursor_once() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let mut watcher = Watcher {
+            cursor: storage.latest_event_id().unwrap(),
+            markers: storage.get_issue_markers().unwrap(),
+            data_version: None,
+            jsonl: None,
+        };
+        assert!(watcher.poll(&mut storage).unwrap().is_empty());
+
+        let issue = crate::model::Issue {
+            id: "bd-w1".to_string(),
+            title: "Watched".to_string(),
+            ..crate::model::Issue::default()
+        };
+        storage.create_issue(&issue, "alice").unwrap();
+        // Same-connection writes do not bump data_version; force a read.
+        watcher.data_version = None;
+        let events = watcher.poll(&mut storage).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type, EventType::Created);
+        assert_eq!(events[0].source, WatchSource::Event);
+        assert_eq!(events[0].issue.as_ref().unwrap().title, "Watched");
+        assert_eq!(watcher.cursor, events[0].cursor);
+
+        watcher.data_version = None;
+        assert!(watcher.poll(&mut storage).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,299 @@
+//! Workflow command implementation.
+//!
+//! Renders the `workflow.*` config enforced by `update`, `close` and
+//! `reopen`; see [`crate::config::workflow`] for the rules.
+
+use crate::cli::{WorkflowCommands, WorkflowShowArgs};
+use crate::config;
+use crate::config::workflow::{DEFAULT_WORKFLOW, TypeWorkflow, Workflow};
+use crate::error::{BeadsError, Result};
+use crate::model::{IssueType, Status};
+use crate::output::OutputContext;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+
+/// JSON output for one workflow.
+#[derive(Debug, Serialize)]
+struct WorkflowReport {
+    name: String,
+    states: Vec<String>,
+    transitions: BTreeMap<String, Vec<String>>,
+    guards: BTreeMap<String, Vec<String>>,
+}
+
+impl From<&TypeWorkflow> for WorkflowReport {
+    fn from(workflow: &TypeWorkflow) -> Self {
+        Self {
+            name: workflow.name.clone(),
+            states: workflow
+                .states
+                .iter()
+                .map(|state| state.as_str().to_string())
+                .collect(),
+            transitions: workflow
+                .transitions
+                .iter()
+                .map(|(from, targets)| {
+                    let targets = targets.iter().map(|t| t.as_str().to_string()).collect();
+                    (from.clone(), targets)
+                })
+                .collect(),
+            guards: workflow
+                .guards
+                .iter()
+                .map(|(to, fields)| {
+                    let fields = fields.iter().map(|f| f.as_str().to_string()).collect();
+                    (to.clone(), fields)
+                })
+                .collect(),
+        }
+    }
+}
+
+/// JSON output for `br workflow show`.
+#[derive(Debug, Serialize)]
+struct ShowReport {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    issue_type: Option<String>,
+    workflows: Vec<WorkflowReport>,
+}
+
+/// Execute the workflow command.
+///
+/// # Errors
+///
+/// Returns an error if the workflow config is invalid or the format unknown.
+pub fn execute(
+    command: &WorkflowCommands,
+    json: bool,
+    cli: &config::CliOverrides,
+    _ctx: &OutputContext,
+) -> Result<()> {
+    let WorkflowCommands::Show(args) = command;
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let config_layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+    let workflow = Workflow::load(&config_layer)?;
+
+    let issue_type: Option<IssueType> = args.type_.as_deref().map(str::parse).transpose()?;
+    let selected: Vec<&TypeWorkflow> = issue_type.as_ref().map_or_else(
+        || workflow.iter().collect(),
+        |issue_type| workflow.for_type(issue_type).into_iter().collect(),
+    );
+
+    if json {
+        let report = ShowReport {
+            issue_type: issue_type.map(|t| t.as_str().to_string()),
+            workflows: selected.into_iter().map(WorkflowReport::from).collect(),
+        };
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
+    match args.format.as_str() {
+        "text" => print!("{}", render_text(&selected, args)),
+        "mermaid" => {
+            let [single] = selected.as_slice() else {
+                return Err(BeadsError::validation(
+                    "format",
+                    "mermaid renders one workflow; pick it with --type",
+                ));
+            };
+            print!("{}", render_mermaid(single));
+        }
+        other => {
+            return Err(BeadsError::validation(
+                "format",
+                format!("unknown format '{other}' (expected: text, mermaid)"),
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn join(states: &[Status]) -> String {
+    states
+        .iter()
+        .map(Status::as_str)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Source states in workflow order, then any others alphabetically.
+fn ordered_sources(workflow: &TypeWorkflow) -> Vec<&String> {
+    let known = workflow.known_states();
+    let mut sources: Vec<&String> = workflow.transitions.keys().collect();
+    sources.sort_by_key(|from| {
+        known
+            .iter()
+            .position(|state| state.as_str() == from.as_str())
+            .unwrap_or(usize::MAX)
+    });
+    sources
+}
+
+fn render_text(selected: &[&TypeWorkflow], args: &WorkflowShowArgs) -> String {
+    let mut out = String::new();
+    if selected.is_empty() {
+        if let Some(issue_type) = &args.type_ {
+            let _ = writeln!(
+                out,
+                "No workflow applies to {issue_type}: any status can move to any other."
+            );
+        } else {
+            out.push_str("No workflow configured: any status can move to any other.\n");
+            out.push_str(
+                "Define workflow.default or workflow.<type> in .beads/config.yaml \
+                 (see `br workflow --help`).\n",
+            );
+        }
+        return out;
+    }
+
+    for (index, workflow) in selected.iter().enumerate() {
+        if index > 0 {
+            out.push('\n');
+        }
+        if workflow.name == DEFAULT_WORKFLOW {
+            out.push_str("default (types without their own workflow)\n");
+        } else {
+            let _ = writeln!(out, "{}", workflow.name);
+        }
+
+        let states = if workflow.states.is_empty() {
+            "any".to_string()
+        } else {
+            join(&workflow.states)
+        };
+        let _ = writeln!(out, "  states: {states}");
+
+        if workflow.transitions.is_empty() {
+            out.push_str("  transitions: any\n");
+        } else {
+            out.push_str("  transitions:\n");
+            let sources = ordered_sources(workflow);
+            let width = sources.iter().map(|from| from.len()).max().unwrap_or(0);
+            for from in sources {
+                let targets = &workflow.transitions[from];
+                let targets = if targets.is_empty() {
+                    "(none)".to_string()
+                } else {
+                    join(targets)
+                };
+                let _ = writeln!(out, "    {from:<width$} -> {targets}");
+            }
+        }
+
+        if !workflow.guards.is_empty() {
+            out.push_str("  guards:\n");
+            for (to, fields) in &workflow.guards {
+                let fields: Vec<&str> = fields.iter().map(|field| field.as_str()).collect();
+                let _ = writeln!(out, "    {to} requires {}", fields.join(", "));
+            }
+        }
+    }
+    out
+}
+
+fn mermaid_id(state: &str) -> String {
+    state.replace('-', "_")
+}
+
+fn render_mermaid(workflow: &TypeWorkflow) -> String {
+    let mut out = String::from("stateDiagram-v2\n");
+    let states = workflow.known_states();
+    for state in &states {
+        let name = state.as_str();
+        let id = mermaid_id(name);
+        if id == name {
+            let _ = writeln!(out, "    {id}");
+        } else {
+            let _ = writeln!(out, "    state \"{name}\" as {id}");
+        }
+    }
+    if let Some(first) = states.first() {
+        let _ = writeln!(out, "    [*] --> {}", mermaid_id(first.as_str()));
+    }
+    for from in ordered_sources(workflow) {
+        for to in &workflow.transitions[from] {
+            let _ = writeln!(
+                out,
+                "    {} --> {}",
+                mermaid_id(from),
+                mermaid_id(to.as_str())
+            );
+        }
+    }
+    for (to, fields) in &workflow.guards {
+        let fields: Vec<&str> = fields.iter().map(|field| field.as_str()).collect();
+        let _ = writeln!(
+            out,
+            "    note right of {}: requires {}",
+            mermaid_id(to),
+            fields.join(", ")
+        );
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ConfigLayer;
+
+    fn workflow() -> Workflow {
+        let mut layer = ConfigLayer::default();
+        for (key, value) in [
+            (
+                "workflow.default.states",
+                "open,in_progress,code-review,closed",
+            ),
+            ("workflow.default.transitions.open", "in_progress"),
+            (
+                "workflow.default.transitions.in_progress",
+                "code-review,open",
+            ),
+            ("workflow.default.transitions.code-review", "closed"),
+            ("workflow.default.guards.closed", "acceptance_criteria"),
+        ] {
+            layer.runtime.insert(key.to_string(), value.to_string());
+        }
+        Workflow::load(&layer).unwrap()
+    }
+
+    #[test]
+    fn test_render_text_lists_transitions_in_state_order() {
+        let workflow = workflow();
+        let selected: Vec<_> = workflow.iter().collect();
+        let text = render_text(&selected, &WorkflowShowArgs::default());
+        assert_eq!(
+            text,
+            "default (types without their own workflow)\n\
+             \x20 states: open, in_progress, code-review, closed\n\
+             \x20 transitions:\n\
+             \x20   open        -> in_progress\n\
+             \x20   in_progress -> code-review, open\n\
+             \x20   code-review -> closed\n\
+             \x20 guards:\n\
+             \x20   closed requires acceptance_criteria\n"
+        );
+    }
+
+    #[test]
+    fn test_render_mermaid() {
+        let workflow = workflow();
+        let diagram = render_mermaid(workflow.iter().next().unwrap());
+        assert!(diagram.starts_with("stateDiagram-v2\n"));
+        assert!(diagram.contains("    state \"code-review\" as code_review\n"));
+        assert!(diagram.contains("    [*] --> open\n"));
+        assert!(diagram.contains("    in_progress --> code_review\n"));
+        assert!(diagram.contains("    note right of closed: requires acceptance_criteria\n"));
+    }
+
+    #[test]
+    fn test_render_text_without_workflow() {
+        let text = render_text(&[], &WorkflowShowArgs::default());
+        assert!(text.starts_with("No workflow configured"));
+    }
+}
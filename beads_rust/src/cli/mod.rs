@@ -146,6 +146,27 @@ const SORT_KEY_CANDIDATES: &[(&str, &str)] = &[
 const DEP_TREE_FORMAT_CANDIDATES: &[(&str, &str)] =
     &[("text", "Text output"), ("mermaid", "Mermaid graph")];
 
+const PLAN_FORMAT_CANDIDATES: &[(&str, &str)] = &[
+    ("text", "Text output"),
+    ("mermaid", "Mermaid graph"),
+    ("dot", "Graphviz DOT graph"),
+];
+
+const GRAPH_FORMAT_CANDIDATES: &[(&str, &str)] = &[
+    ("text", "Text output"),
+    ("dot", "Graphviz DOT graph"),
+    ("mermaid", "Mermaid graph"),
+    ("graphml", "GraphML document"),
+    ("cytoscape-json", "Cytoscape.js elements JSON"),
+];
+
+const EXPORT_FORMAT_CANDIDATES: &[(&str, &str)] = &[("html", "Static HTML site")];
+
+const WORKFLOW_FORMAT_CANDIDATES: &[(&str, &str)] = &[
+    ("text", "Text output"),
+    ("mermaid", "Mermaid state diagram"),
+];
+
 const CSV_FIELD_CANDIDATES: &[(&str, &str)] = &[
     ("id", "Issue ID"),
     ("title", "Title"),
@@ -590,6 +611,34 @@ fn dep_tree_format_completer(current: &OsStr) -> Vec<CompletionCandidate> {
     static_candidates(prefix, DEP_TREE_FORMAT_CANDIDATES)
 }
 
+fn plan_format_completer(current: &OsStr) -> Vec<CompletionCandidate> {
+    let Some(prefix) = current.to_str() else {
+        return Vec::new();
+    };
+    static_candidates(prefix, PLAN_FORMAT_CANDIDATES)
+}
+
+fn graph_format_completer(current: &OsStr) -> Vec<CompletionCandidate> {
+    let Some(prefix) = current.to_str() else {
+        return Vec::new();
+    };
+    static_candidates(prefix, GRAPH_FORMAT_CANDIDATES)
+}
+
+fn export_format_completer(current: &OsStr) -> Vec<CompletionCandidate> {
+    let Some(prefix) = current.to_str() else {
+        return Vec::new();
+    };
+    static_candidates(prefix, EXPORT_FORMAT_CANDIDATES)
+}
+
+fn workflow_format_completer(current: &OsStr) -> Vec<CompletionCandidate> {
+    let Some(prefix) = current.to_str() else {
+        return Vec::new();
+    };
+    static_candidates(prefix, WORKFLOW_FORMAT_CANDIDATES)
+}
+
 fn saved_query_completer(current: &OsStr) -> Vec<CompletionCandidate> {
     let Some(prefix) = current.to_str() else {
         return Vec::new();
@@ -716,6 +765,10 @@ pub enum Commands {
         #[arg(long)]
         force: bool,
 
+        /// Install the git merge driver for issues.jsonl (.gitattributes + git config)
+        #[arg(long)]
+        merge_driver: bool,
+
         /// Backend type (ignored, always sqlite)
         #[arg(long)]
         backend: Option<String>,
@@ -776,12 +829,18 @@ pub enum Commands {
     #[command(alias = "comment")]
     Comments(CommentsArgs),
 
+    /// Attach files to an issue, or list, fetch and remove attachments
+    Attach(AttachArgs),
+
     /// Show project statistics
     Stats(StatsArgs),
 
     /// Alias for stats
     Status(StatsArgs),
 
+    /// Burndown, throughput, cycle time and aging WIP from issue history
+    Analytics(AnalyticsArgs),
+
     /// Count issues with optional grouping
     Count(CountArgs),
 
@@ -797,6 +856,50 @@ pub enum Commands {
     /// Undefer issues (make ready again)
     Undefer(UndeferArgs),
 
+    /// Atomically claim a ready issue with a time-limited lease
+    Claim(ClaimArgs),
+
+    /// Track time spent on issues
+    Time {
+        #[command(subcommand)]
+        command: TimeCommands,
+    },
+
+    /// Create issues on a schedule from template issues
+    Recur {
+        #[command(subcommand)]
+        command: RecurCommands,
+    },
+
+    /// Show the status workflows configured per issue type
+    #[command(after_help = "\
+Configure in .beads/config.yaml (workflow.<type>, or workflow.default for
+every type without its own section):
+  workflow:
+    default:
+      states: [open, in_progress, review, closed]
+      transitions:
+        open: [in_progress]
+        in_progress: [review, open]
+        review: [closed, in_progress]
+        closed: [open]
+      guards:
+        in_progress: [assignee]
+        closed: [acceptance_criteria]
+
+`br update --status`, `br close` and `br reopen` reject moves the workflow does
+not list and moves into a state whose guard fields are empty. States other than
+the built-in ones (review above) become custom statuses.
+
+EXAMPLES:
+  br workflow show                     All workflows
+  br workflow show --type bug          The workflow bugs follow
+  br workflow show --format mermaid    State diagram for docs")]
+    Workflow {
+        #[command(subcommand)]
+        command: WorkflowCommands,
+    },
+
     /// Configuration management
     Config {
         #[command(subcommand)]
@@ -841,8 +944,42 @@ EXAMPLES:
   br sync --status               Show current sync status")]
     Sync(SyncArgs),
 
-    /// Run read-only diagnostics
-    Doctor,
+    /// Merge three versions of issues.jsonl (git merge driver)
+    #[command(after_help = "\
+Install with `br init --merge-driver`, which adds to .gitattributes:
+  .beads/issues.jsonl merge=beads
+and to the repository's git config:
+  merge.beads.driver = br merge-driver %O %A %B
+
+Writes the merged issues, sorted by ID, over OURS. Exits nonzero and leaves
+OURS untouched if a version cannot be parsed.")]
+    MergeDriver(MergeDriverArgs),
+
+    /// Sync issues with GitHub or GitLab issues via external_ref
+    #[command(after_help = "\
+Configure in .beads/config.yaml:
+  bridge:
+    provider: github          # or gitlab
+    repo: owner/name          # GitLab: group/project
+    api-url: https://api.github.com   # optional (GitHub Enterprise, self-hosted GitLab)
+    token-env: GITHUB_TOKEN   # optional; GITLAB_TOKEN for gitlab
+
+Linked issues carry external_ref gh-<number> or gl-<iid>. Title, description,
+open/closed status, labels and comments sync both ways; a field edited on
+both sides since the last sync keeps the newer edit. Requires `curl`.
+
+EXAMPLES:
+  br bridge link bd-abc           Create a tracker issue for bd-abc
+  br bridge link bd-abc --ref 42  Link bd-abc to existing issue #42
+  br bridge sync --dry-run        Preview pulls and pushes
+  br bridge sync                  Pull new tracker issues, sync linked ones")]
+    Bridge {
+        #[command(subcommand)]
+        command: BridgeCommands,
+    },
+
+    /// Run diagnostics (read-only unless --fix repairs search index and blobs)
+    Doctor(DoctorArgs),
 
     /// Show diagnostic metadata about the workspace
     Info(InfoArgs),
@@ -886,8 +1023,20 @@ EXAMPLES:
     /// Visualize dependency graph
     Graph(GraphArgs),
 
+    /// Export issues as a static HTML site
+    Export(ExportArgs),
+
+    /// Forecast a schedule: critical path, slack and missed due dates
+    Plan(PlanArgs),
+
     /// Manage AGENTS.md workflow instructions
     Agents(AgentsArgs),
+
+    /// Serve JSON-RPC (and MCP tools) over stdio with one open database
+    Serve(ServeArgs),
+
+    /// Stream issue change events as newline-delimited JSON
+    Watch(WatchArgs),
 }
 
 /// Arguments for the completions command.
@@ -995,6 +1144,10 @@ pub struct CreateArgs {
     /// Create issues from a markdown file (bulk import)
     #[arg(long, short = 'f')]
     pub file: Option<std::path::PathBuf>,
+
+    /// Start from a template (built-in, config, or .beads/templates/<name>.md)
+    #[arg(long)]
+    pub template: Option<String>,
 }
 
 #[derive(Args, Debug)]
@@ -1139,6 +1292,15 @@ pub struct DeleteArgs {
     pub dry_run: bool,
 }
 
+/// Arguments for the doctor command.
+#[derive(Args, Debug, Default, Clone)]
+pub struct DoctorArgs {
+    /// Repair what can be repaired safely (rebuilds the full-text search index,
+    /// deletes orphaned attachment blobs)
+    #[arg(long)]
+    pub fix: bool,
+}
+
 /// Arguments for the info command.
 #[derive(Args, Debug, Default, Clone)]
 pub struct InfoArgs {
@@ -1181,6 +1343,8 @@ pub enum SchemaTarget {
     Issue,
     /// List/search row: Issue + dependency/dependent counts
     IssueWithCounts,
+    /// Search row: `IssueWithCounts` + relevance score and snippet
+    SearchResult,
     /// Show view: Issue + relations/comments/events
     IssueDetails,
     /// Ready list row
@@ -1293,6 +1457,26 @@ pub fn resolve_output_format_basic(
     }
 }
 
+/// Project selection for commands that can read other projects' databases.
+#[derive(Args, Debug, Default, Clone)]
+pub struct ProjectsArgs {
+    /// Also query every external project (`external_projects.*` and routes)
+    #[arg(long)]
+    pub all_projects: bool,
+
+    /// Also query these external projects (comma-separated)
+    #[arg(long, value_delimiter = ',', conflicts_with = "all_projects")]
+    pub projects: Vec<String>,
+}
+
+impl ProjectsArgs {
+    /// Whether any project besides the local one was requested.
+    #[must_use]
+    pub fn is_federated(&self) -> bool {
+        self.all_projects || !self.projects.is_empty()
+    }
+}
+
 /// Arguments for the list command.
 #[derive(Args, Debug, Default, Clone)]
 #[allow(clippy::struct_excessive_bools)]
@@ -1349,6 +1533,10 @@ pub struct ListArgs {
     #[arg(long)]
     pub notes_contains: Option<String>,
 
+    /// Filter expression, e.g. 'status:open AND (label:backend OR priority<=1)'
+    #[arg(long = "where", value_name = "EXPR")]
+    pub where_: Option<String>,
+
     /// Include closed issues (default excludes closed)
     #[arg(long, short = 'a')]
     pub all: bool,
@@ -1373,6 +1561,9 @@ pub struct ListArgs {
     #[arg(long)]
     pub overdue: bool,
 
+    #[command(flatten)]
+    pub federation: ProjectsArgs,
+
     /// Use long output format
     #[arg(long)]
     pub long: bool,
@@ -1407,7 +1598,8 @@ pub struct ListArgs {
 /// Arguments for the search command.
 #[derive(Args, Debug, Default)]
 pub struct SearchArgs {
-    /// Search query
+    /// Search query (words match as prefixes; supports "exact phrases",
+    /// field:term for title/desc/design/acceptance/notes/comments, and AND/OR/NOT)
     pub query: String,
 
     #[command(flatten)]
@@ -1629,6 +1821,37 @@ pub struct CommentsArgs {
     pub wrap: bool,
 }
 
+/// Arguments for the attach command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct AttachArgs {
+    /// Issue ID
+    #[arg(add = ArgValueCompleter::new(issue_id_completer))]
+    pub id: String,
+
+    /// Files to attach (omit to list attachments)
+    pub files: Vec<PathBuf>,
+
+    /// Attachment name (defaults to the file name; one file only)
+    #[arg(long, requires = "files")]
+    pub name: Option<String>,
+
+    /// Keep the blob out of git regardless of size
+    #[arg(long, requires = "files")]
+    pub local: bool,
+
+    /// Remove the named attachment
+    #[arg(long, value_name = "NAME", conflicts_with_all = ["files", "get"])]
+    pub remove: Option<String>,
+
+    /// Write the named attachment's content to --output (or stdout)
+    #[arg(long, value_name = "NAME", conflicts_with = "files")]
+    pub get: Option<String>,
+
+    /// Destination file for --get
+    #[arg(short = 'o', long, requires = "get")]
+    pub output: Option<PathBuf>,
+}
+
 #[derive(Subcommand, Debug)]
 pub enum CommentCommands {
     Add(CommentAddArgs),
@@ -1871,6 +2094,224 @@ pub struct UndeferArgs {
     pub robot: bool,
 }
 
+#[derive(Subcommand, Debug)]
+pub enum TimeCommands {
+    /// Start a timer on an issue
+    Start(TimeStartArgs),
+    /// Stop a running timer and record the session
+    Stop(TimeStopArgs),
+    /// Record time worked without a timer
+    Log(TimeLogArgs),
+    /// List recorded sessions and running timers
+    List(TimeListArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct TimeStartArgs {
+    /// Issue ID
+    #[arg(add = ArgValueCompleter::new(open_issue_id_completer))]
+    pub id: String,
+
+    /// What the session is about
+    #[arg(long, short = 'n')]
+    pub note: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct TimeStopArgs {
+    /// Issue ID (default: your only running timer)
+    #[arg(add = ArgValueCompleter::new(issue_id_completer))]
+    pub id: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct TimeLogArgs {
+    /// Issue ID
+    #[arg(add = ArgValueCompleter::new(issue_id_completer))]
+    pub id: String,
+
+    /// Time worked: 45m, 2h, 1d (bare number = minutes)
+    pub duration: String,
+
+    /// When the work ended (RFC3339, YYYY-MM-DD, or relative like -2h; default: now)
+    #[arg(long)]
+    pub at: Option<String>,
+
+    /// What the session was about
+    #[arg(long, short = 'n')]
+    pub note: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct TimeListArgs {
+    /// Issue ID (default: all issues)
+    #[arg(add = ArgValueCompleter::new(issue_id_completer))]
+    pub id: Option<String>,
+
+    /// Only sessions recorded by this actor
+    #[arg(long)]
+    pub actor: Option<String>,
+
+    /// Only running timers
+    #[arg(long)]
+    pub running: bool,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum WorkflowCommands {
+    /// Show states, transitions and guards (from `workflow.*` in config)
+    Show(WorkflowShowArgs),
+}
+
+#[derive(Args, Debug, Clone, Default)]
+pub struct WorkflowShowArgs {
+    /// Show only the workflow that applies to this issue type
+    #[arg(long = "type", short = 't', add = ArgValueCompleter::new(issue_type_completer))]
+    pub type_: Option<String>,
+
+    /// Output format: text, mermaid
+    #[arg(long, default_value = "text", add = ArgValueCompleter::new(workflow_format_completer))]
+    pub format: String,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum RecurCommands {
+    /// Attach a recurrence rule to an issue, making it a template
+    Set(RecurSetArgs),
+    /// Remove the recurrence rule from a template
+    Remove(RecurRemoveArgs),
+    /// List recurrence rules and their next occurrence
+    List,
+    /// Create the current instance of every rule that is due
+    Run(RecurRunArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct RecurSetArgs {
+    /// Template issue ID
+    #[arg(add = ArgValueCompleter::new(issue_id_completer))]
+    pub id: String,
+
+    /// Cron expression ("0 9 * * MON") or RRULE ("FREQ=WEEKLY;BYDAY=MO"), in UTC
+    pub rule: String,
+
+    /// First instant the rule may fire (RFC3339, YYYY-MM-DD, or relative; default: now)
+    #[arg(long)]
+    pub start: Option<String>,
+
+    /// Create instances this long before their occurrence (e.g., 2d)
+    #[arg(long)]
+    pub lead: Option<String>,
+
+    /// Set each instance due this long after its occurrence (e.g., 3d)
+    #[arg(long)]
+    pub due: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct RecurRemoveArgs {
+    /// Template issue ID
+    #[arg(add = ArgValueCompleter::new(issue_id_completer))]
+    pub id: String,
+}
+
+#[derive(Args, Debug)]
+pub struct RecurRunArgs {
+    /// Show what would be created without writing anything
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+/// Arguments for the claim command.
+#[derive(Args, Debug, Clone, Default)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct ClaimArgs {
+    /// Issue ID to claim (default: highest-priority unassigned ready issue)
+    #[arg(add = ArgValueCompleter::new(open_issue_id_completer))]
+    pub id: Option<String>,
+
+    /// Lease length (e.g., `90s`, `30m`, `2h`; bare numbers are minutes).
+    /// Default: `claim.lease-ttl` config or 30m.
+    #[arg(long)]
+    pub ttl: Option<String>,
+
+    /// Extend the lease you hold on ID instead of claiming
+    #[arg(long, requires = "id", conflicts_with_all = ["release", "list"])]
+    pub renew: bool,
+
+    /// Release the lease on ID and return the issue to ready
+    #[arg(long, requires = "id", conflicts_with_all = ["renew", "list"])]
+    pub release: bool,
+
+    /// Release a lease held by another actor (with --release)
+    #[arg(long, requires = "release")]
+    pub force: bool,
+
+    /// List active leases
+    #[arg(long, conflicts_with = "id")]
+    pub list: bool,
+
+    /// Only pick issues with this label (AND logic, can be repeated)
+    #[arg(long, short = 'l', add = ArgValueCompleter::new(label_completer))]
+    pub label: Vec<String>,
+
+    /// Only pick issues of this type (can be repeated)
+    #[arg(long = "type", short = 't', add = ArgValueCompleter::new(issue_type_completer))]
+    pub type_: Vec<String>,
+
+    /// Only pick issues with this priority (can be repeated, 0-4 or P0-P4)
+    #[arg(long, short = 'p', add = ArgValueCompleter::new(priority_completer))]
+    pub priority: Vec<String>,
+
+    /// Only pick children of this parent issue ID
+    #[arg(long, add = ArgValueCompleter::new(issue_id_completer))]
+    pub parent: Option<String>,
+
+    /// Machine-readable output (alias for --json)
+    #[arg(long)]
+    pub robot: bool,
+}
+
+/// Arguments for the serve command.
+#[derive(Args, Debug, Clone)]
+pub struct ServeArgs {
+    /// Flush dirty issues to JSONL after this many mutating requests (0 = only on `flush` or exit)
+    #[arg(long, default_value_t = 20)]
+    pub flush_every: usize,
+
+    /// Also flush pending writes once this many seconds have passed since the last flush (0 disables)
+    #[arg(long, default_value_t = 5)]
+    pub flush_interval: u64,
+}
+
+/// Arguments for the watch command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct WatchArgs {
+    /// Resume after this event cursor (0 = replay all recorded events; default: only new changes)
+    #[arg(long, value_name = "CURSOR")]
+    pub since: Option<i64>,
+
+    /// Polling interval in milliseconds
+    #[arg(long, value_name = "MS", default_value_t = 500)]
+    pub interval: u64,
+
+    /// Only emit events for issues with this status (can be repeated)
+    #[arg(long, short = 's')]
+    pub status: Vec<String>,
+
+    /// Only emit events for issues with this label (AND logic, can be repeated)
+    #[arg(long, short = 'l', add = ArgValueCompleter::new(label_completer))]
+    pub label: Vec<String>,
+
+    /// Only emit events for issues with this assignee
+    #[arg(long, add = ArgValueCompleter::new(assignee_completer))]
+    pub assignee: Option<String>,
+
+    /// Emit pending events once and exit instead of streaming
+    #[arg(long)]
+    pub once: bool,
+}
+
 /// Arguments for the ready command.
 #[derive(Args, Debug, Clone, Default)]
 #[allow(clippy::struct_excessive_bools)]
@@ -1919,6 +2360,9 @@ pub struct ReadyArgs {
     #[arg(long, short = 'r')]
     pub recursive: bool,
 
+    #[command(flatten)]
+    pub federation: ProjectsArgs,
+
     /// Wrap long lines instead of truncating in text output
     #[arg(long)]
     pub wrap: bool,
@@ -2033,6 +2477,60 @@ pub enum SortPolicy {
     Oldest,
 }
 
+/// Arguments for the merge-driver command.
+#[derive(Args, Debug, Clone)]
+pub struct MergeDriverArgs {
+    /// Common ancestor version (git's %O)
+    pub base: PathBuf,
+
+    /// Current branch version (git's %A); receives the merged result
+    pub ours: PathBuf,
+
+    /// Other branch version (git's %B)
+    pub theirs: PathBuf,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum BridgeCommands {
+    /// Pull tracker changes and push local changes for linked issues
+    Sync(BridgeSyncArgs),
+    /// Link an issue to a tracker issue, creating one unless --ref is given
+    Link(BridgeLinkArgs),
+    /// Remove an issue's link to the tracker
+    Unlink(BridgeUnlinkArgs),
+    /// Show bridge configuration, linked issues and the last sync
+    Status,
+}
+
+#[derive(Args, Debug)]
+pub struct BridgeSyncArgs {
+    /// Show what would change on either side without writing anything
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct BridgeLinkArgs {
+    /// Issue ID
+    #[arg(add = ArgValueCompleter::new(issue_id_completer))]
+    pub id: String,
+
+    /// Existing tracker issue to link (number or gh-N / gl-N)
+    #[arg(long = "ref")]
+    pub reference: Option<String>,
+
+    /// Show what would happen without writing anything
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct BridgeUnlinkArgs {
+    /// Issue ID
+    #[arg(add = ArgValueCompleter::new(issue_id_completer))]
+    pub id: String,
+}
+
 /// Arguments for the sync command.
 #[derive(Args, Debug, Clone, Default)]
 #[allow(clippy::struct_excessive_bools)]
@@ -2178,10 +2676,17 @@ pub struct StatsArgs {
     #[arg(long)]
     pub no_activity: bool,
 
+    /// Compare tracked time with estimates by type, assignee and label
+    #[arg(long)]
+    pub accuracy: bool,
+
     /// Activity window in hours (default: 24)
     #[arg(long, default_value_t = 24)]
     pub activity_hours: u32,
 
+    #[command(flatten)]
+    pub federation: ProjectsArgs,
+
     /// Output format (text, json, toon). Env: BR_OUTPUT_FORMAT, TOON_DEFAULT_FORMAT.
     #[arg(long, value_enum)]
     pub format: Option<OutputFormatBasic>,
@@ -2195,6 +2700,54 @@ pub struct StatsArgs {
     pub robot: bool,
 }
 
+/// Report computed by `br analytics`.
+#[derive(ValueEnum, Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum AnalyticsReport {
+    /// Every report below
+    #[default]
+    All,
+    /// Daily open/closed counts
+    Burndown,
+    /// Issues closed per week
+    Throughput,
+    /// Lead time (created to closed) and cycle time (started to closed)
+    CycleTime,
+    /// In-progress issues by time since they were started
+    Aging,
+}
+
+/// Arguments for the analytics command.
+#[derive(Args, Debug, Default)]
+pub struct AnalyticsArgs {
+    /// Report to compute
+    #[arg(value_enum, default_value = "all")]
+    pub report: AnalyticsReport,
+
+    /// Only issues under this epic (recursively)
+    #[arg(long, add = ArgValueCompleter::new(issue_id_completer))]
+    pub epic: Option<String>,
+
+    /// Only issues with this label
+    #[arg(long, add = ArgValueCompleter::new(label_completer))]
+    pub label: Option<String>,
+
+    /// Days of burndown history
+    #[arg(long, default_value_t = 30)]
+    pub days: u32,
+
+    /// Weeks of throughput and lead/cycle time history
+    #[arg(long, default_value_t = 12)]
+    pub weeks: u32,
+
+    /// Output format (text, json, csv, toon). CSV needs a single report.
+    #[arg(long, value_enum)]
+    pub format: Option<OutputFormat>,
+
+    /// Machine-readable output (alias for --json)
+    #[arg(long)]
+    pub robot: bool,
+}
+
 #[derive(Args, Debug)]
 pub struct HistoryArgs {
     #[command(subcommand)]
@@ -2360,6 +2913,50 @@ pub struct GraphArgs {
     /// One line per issue (compact output)
     #[arg(long)]
     pub compact: bool,
+
+    /// Output format: text, dot, mermaid, graphml, cytoscape-json
+    #[arg(long, default_value = "text", add = ArgValueCompleter::new(graph_format_completer))]
+    pub format: String,
+
+    /// Maximum traversal depth from the issue (default: unlimited)
+    #[arg(long)]
+    pub depth: Option<usize>,
+}
+
+/// Arguments for the export command.
+#[derive(Args, Debug, Clone)]
+pub struct ExportArgs {
+    /// Output format: html
+    #[arg(long, default_value = "html", add = ArgValueCompleter::new(export_format_completer))]
+    pub format: String,
+
+    /// Output directory (created if missing; existing pages are replaced)
+    #[arg(long, short = 'o', default_value = "site")]
+    pub out: PathBuf,
+
+    /// Site title shown in the page header (default: the issue prefix)
+    #[arg(long)]
+    pub title: Option<String>,
+}
+
+/// Arguments for the plan command.
+#[derive(Args, Debug, Clone)]
+pub struct PlanArgs {
+    /// Number of parallel workers
+    #[arg(long, short = 'w', default_value_t = 1)]
+    pub workers: usize,
+
+    /// Working hours per calendar day, used to turn work time into dates
+    #[arg(long, default_value_t = 8)]
+    pub hours_per_day: u32,
+
+    /// Estimate in minutes for issues without `estimated_minutes`
+    #[arg(long, default_value_t = 0)]
+    pub default_estimate: u32,
+
+    /// Output format: text, mermaid, dot
+    #[arg(long, default_value = "text", add = ArgValueCompleter::new(plan_format_completer))]
+    pub format: String,
 }
 
 /// Arguments for the agents command.
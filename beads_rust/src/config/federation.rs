@@ -0,0 +1,216 @@
+//! Project selection for cross-project queries.
+//!
+//! `list`, `ready`, `search` and `stats` take `--all-projects` or
+//! `--projects a,b` to run against the external projects known through
+//! `external_projects.<name>` and `routes.jsonl` as well as the local
+//! database. See [`super::external_project_paths`] for how names map to
+//! databases.
+
+use super::{
+    CliOverrides, ConfigLayer, ConfigPaths, external_project_paths, id_config_from_layer,
+    load_config,
+};
+use crate::error::{BeadsError, Result};
+use crate::storage::SqliteStorage;
+use std::path::{Path, PathBuf};
+use tracing::warn;
+
+/// One database taking part in a federated query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Project {
+    /// Name used for provenance: the external project name, or the local
+    /// issue prefix.
+    pub name: String,
+    /// The project's `.beads` directory.
+    pub beads_dir: PathBuf,
+    /// The project's database.
+    pub db_path: PathBuf,
+    /// Whether this is the current project.
+    pub is_local: bool,
+}
+
+/// Pick the projects a command should read.
+///
+/// The local project always comes first. With `all`, every known external
+/// project follows in name order; otherwise only those in `names`.
+///
+/// # Errors
+///
+/// Returns a validation error naming the known projects if `names` contains
+/// an unknown one.
+pub fn select_projects(
+    layer: &ConfigLayer,
+    beads_dir: &Path,
+    local_db_path: &Path,
+    all: bool,
+    names: &[String],
+) -> Result<Vec<Project>> {
+    let local_name = id_config_from_layer(layer).prefix;
+    let mut projects = vec![Project {
+        name: local_name.clone(),
+        beads_dir: beads_dir.to_path_buf(),
+        db_path: local_db_path.to_path_buf(),
+        is_local: true,
+    }];
+    if !all && names.is_empty() {
+        return Ok(projects);
+    }
+
+    let mut known: Vec<(String, ConfigPaths)> = external_project_paths(layer, beads_dir)
+        .into_iter()
+        .filter(|(_, paths)| paths.db_path != local_db_path)
+        .collect();
+    known.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut wanted: Vec<&str> = names.iter().map(|name| name.trim()).collect();
+    wanted.retain(|name| !name.is_empty() && *name != local_name);
+    if let Some(unknown) = wanted
+        .iter()
+        .find(|name| !known.iter().any(|(known_name, _)| known_name == *name))
+    {
+        let known_names: Vec<&str> = known.iter().map(|(name, _)| name.as_str()).collect();
+        return Err(BeadsError::validation(
+            "projects",
+            format!(
+                "unknown project '{unknown}' (known: {})",
+                if known_names.is_empty() {
+                    "none; configure external_projects.<name> or routes.jsonl".to_string()
+                } else {
+                    known_names.join(", ")
+                }
+            ),
+        ));
+    }
+
+    projects.extend(
+        known
+            .into_iter()
+            .filter(|(name, _)| all || wanted.contains(&name.as_str()))
+            .map(|(name, paths)| Project {
+                name,
+                beads_dir: paths.beads_dir,
+                db_path: paths.db_path,
+                is_local: false,
+            }),
+    );
+    Ok(projects)
+}
+
+/// Open an external project's database.
+///
+/// Returns `None` (with a warning) when the database is missing or cannot be
+/// opened, so one unavailable project does not fail the whole query.
+#[must_use]
+pub fn open_project(project: &Project) -> Option<SqliteStorage> {
+    if !project.db_path.is_file() {
+        warn!(
+            project = %project.name,
+            path = %project.db_path.display(),
+            "External project database not found; skipping"
+        );
+        return None;
+    }
+    match SqliteStorage::open(&project.db_path) {
+        Ok(storage) => Some(storage),
+        Err(err) => {
+            warn!(
+                project = %project.name,
+                path = %project.db_path.display(),
+                error = %err,
+                "Failed to open external project; skipping"
+            );
+            None
+        }
+    }
+}
+
+/// Run `f` against each project's database, local storage first.
+///
+/// External projects that cannot be opened are skipped.
+///
+/// # Errors
+///
+/// Returns the first error returned by `f`.
+pub fn for_each_project<'a, T>(
+    projects: &'a [Project],
+    local: &SqliteStorage,
+    mut f: impl FnMut(&Project, &SqliteStorage) -> Result<T>,
+) -> Result<Vec<(&'a Project, T)>> {
+    let mut results = Vec::with_capacity(projects.len());
+    for project in projects {
+        if project.is_local {
+            results.push((project, f(project, local)?));
+        } else if let Some(storage) = open_project(project) {
+            results.push((project, f(project, &storage)?));
+        }
+    }
+    Ok(results)
+}
+
+/// Load a project's own config, for settings such as its external projects.
+///
+/// # Errors
+///
+/// Returns an error if the project's config files cannot be read.
+pub fn project_config(project: &Project, storage: &SqliteStorage) -> Result<ConfigLayer> {
+    load_config(&project.beads_dir, Some(storage), &CliOverrides::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn layer_with(projects: &[(&str, &Path)]) -> ConfigLayer {
+        let mut layer = ConfigLayer::default();
+        layer
+            .runtime
+            .insert("issue_prefix".to_string(), "bd".to_string());
+        for (name, path) in projects {
+            layer.runtime.insert(
+                format!("external_projects.{name}"),
+                path.display().to_string(),
+            );
+        }
+        layer
+    }
+
+    #[test]
+    fn select_projects_defaults_to_local() {
+        let dir = TempDir::new().unwrap();
+        let beads_dir = dir.path().join(".beads");
+        let layer = layer_with(&[]);
+        let projects =
+            select_projects(&layer, &beads_dir, &beads_dir.join("beads.db"), false, &[]).unwrap();
+        assert_eq!(projects.len(), 1);
+        assert!(projects[0].is_local);
+    }
+
+    #[test]
+    fn select_projects_filters_and_validates_names() {
+        let dir = TempDir::new().unwrap();
+        let beads_dir = dir.path().join("here/.beads");
+        std::fs::create_dir_all(&beads_dir).unwrap();
+        for name in ["api", "web"] {
+            std::fs::create_dir_all(dir.path().join(name).join(".beads")).unwrap();
+        }
+        let api = dir.path().join("api");
+        let web = dir.path().join("web");
+        let layer = layer_with(&[("api", &api), ("web", &web)]);
+        let local_db = beads_dir.join("beads.db");
+
+        let all = select_projects(&layer, &beads_dir, &local_db, true, &[]).unwrap();
+        let names: Vec<&str> = all.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["bd", "api", "web"]);
+        assert!(all[2].db_path.ends_with("web/.beads/beads.db"));
+
+        let some =
+            select_projects(&layer, &beads_dir, &local_db, false, &["web".to_string()]).unwrap();
+        assert_eq!(some.len(), 2);
+        assert_eq!(some[1].name, "web");
+
+        let err = select_projects(&layer, &beads_dir, &local_db, false, &["nope".to_string()])
+            .unwrap_err();
+        assert!(err.to_string().contains("known: api, web"), "{err}");
+    }
+}
@@ -9,7 +9,10 @@
 //! 6. DB config table
 //! 7. Defaults
 
+pub mod federation;
 pub mod routing;
+pub mod templates;
+pub mod workflow;
 
 use crate::error::{BeadsError, Result};
 use crate::model::{IssueType, Priority};
@@ -43,6 +46,7 @@ const EXCLUDED_JSONL_FILES: &[&str] = &[
     "beads.left.jsonl",
     "beads.right.jsonl",
     "sync_base.jsonl",
+    "bridge.base.jsonl",
 ];
 
 /// Startup metadata describing DB + JSONL paths.
@@ -914,17 +918,26 @@ pub fn external_projects_from_layer(
     map
 }
 
-/// Resolve external project DB paths from config.
+/// Resolve external project paths from config and routes.
 ///
 /// Projects are expected to be either a `.beads` directory or a project root
-/// containing `.beads/`.
+/// containing `.beads/`. Projects reachable through `routes.jsonl` are added
+/// under their prefix (e.g. `fe` for `fe-`) unless config already names one.
 #[must_use]
-pub fn external_project_db_paths(
+pub fn external_project_paths(
     layer: &ConfigLayer,
     beads_dir: &Path,
-) -> HashMap<String, PathBuf> {
-    let projects = external_projects_from_layer(layer, beads_dir);
-    let mut db_paths = HashMap::new();
+) -> HashMap<String, ConfigPaths> {
+    let mut projects = external_projects_from_layer(layer, beads_dir);
+    match routing::routed_projects(beads_dir) {
+        Ok(routed) => {
+            for (name, path) in routed {
+                projects.entry(name).or_insert(path);
+            }
+        }
+        Err(err) => warn!(error = %err, "Failed to load routes for external projects"),
+    }
+    let mut resolved = HashMap::new();
 
     for (name, path) in projects {
         let beads_path = if path.file_name().is_some_and(|name| name == ".beads") {
@@ -944,7 +957,7 @@ pub fn external_project_db_paths(
 
         match ConfigPaths::resolve(&beads_path, None) {
             Ok(paths) => {
-                db_paths.insert(name, paths.db_path);
+                resolved.insert(name, paths);
             }
             Err(err) => {
                 warn!(
@@ -957,7 +970,21 @@ pub fn external_project_db_paths(
         }
     }
 
-    db_paths
+    resolved
+}
+
+/// Resolve external project DB paths from config and routes.
+///
+/// See [`external_project_paths`].
+#[must_use]
+pub fn external_project_db_paths(
+    layer: &ConfigLayer,
+    beads_dir: &Path,
+) -> HashMap<String, PathBuf> {
+    external_project_paths(layer, beads_dir)
+        .into_iter()
+        .map(|(name, paths)| (name, paths.db_path))
+        .collect()
 }
 
 /// Resolve actor from a merged config layer.
@@ -992,6 +1019,22 @@ pub fn claim_exclusive_from_layer(layer: &ConfigLayer) -> bool {
         .is_some_and(|v| v.eq_ignore_ascii_case("true") || v == "1")
 }
 
+/// Read the default claim lease TTL (`claim.lease-ttl`), e.g. `30m` or `2h`.
+#[must_use]
+pub fn claim_lease_ttl_from_layer(layer: &ConfigLayer) -> Option<String> {
+    get_value(
+        layer,
+        &[
+            "claim.lease-ttl",
+            "claim.lease_ttl",
+            "claim-lease-ttl",
+            "claim_lease_ttl",
+        ],
+    )
+    .map(|v| v.trim().to_string())
+    .filter(|v| !v.is_empty())
+}
+
 /// Determine if a key is startup-only.
 ///
 /// Startup-only keys can only be set in YAML config files, not in the database.
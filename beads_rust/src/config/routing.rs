@@ -318,6 +318,54 @@ fn resolve_route_entry(
     }
 }
 
+/// List every external project reachable through routes.
+///
+/// Reads the local `routes.jsonl` and, if different, the town root's. Each
+/// entry is keyed by its prefix without the trailing hyphen (e.g. `fe-` ->
+/// `fe`); local routes win over town routes, and routes that resolve to the
+/// local beads directory or to a missing directory are skipped.
+///
+/// # Errors
+///
+/// Returns an error if a route file cannot be read or parsed.
+pub fn routed_projects(local_beads_dir: &Path) -> Result<Vec<(String, PathBuf)>> {
+    let project_root = local_beads_dir.parent().unwrap_or(local_beads_dir);
+    let mut sources = vec![(
+        load_routes(&local_beads_dir.join("routes.jsonl"))?,
+        project_root.to_path_buf(),
+    )];
+    if let Some(town_root) = find_town_root(project_root) {
+        let town_beads_dir = town_root.join(".beads");
+        if town_beads_dir != *local_beads_dir && town_beads_dir.is_dir() {
+            sources.push((
+                load_routes(&town_beads_dir.join("routes.jsonl"))?,
+                town_root,
+            ));
+        }
+    }
+
+    let mut projects: Vec<(String, PathBuf)> = Vec::new();
+    for (routes, base_dir) in sources {
+        for route in routes {
+            let name = route.prefix.trim_end_matches('-').to_string();
+            if name.is_empty() || projects.iter().any(|(existing, _)| *existing == name) {
+                continue;
+            }
+            let resolved = match resolve_route_entry(&route, &base_dir, local_beads_dir) {
+                Ok(resolved) => resolved,
+                Err(err) => {
+                    warn!(prefix = %route.prefix, error = %err, "Skipping unresolvable route");
+                    continue;
+                }
+            };
+            if resolved.is_external && resolved.beads_dir.is_dir() {
+                projects.push((name, resolved.beads_dir));
+            }
+        }
+    }
+    Ok(projects)
+}
+
 /// Check if an issue ID would be routed externally.
 ///
 /// Quick check without fully resolving the route.
@@ -484,6 +532,26 @@ mod tests {
         assert_eq!(result.project_path, Some("../frontend".to_string()));
     }
 
+    #[test]
+    fn routed_projects_lists_external_routes() {
+        let dir = TempDir::new().unwrap();
+        let local_beads = dir.path().join("current/.beads");
+        fs::create_dir_all(&local_beads).unwrap();
+        fs::create_dir_all(dir.path().join("frontend/.beads")).unwrap();
+        fs::write(
+            local_beads.join("routes.jsonl"),
+            "{\"prefix\":\"cur-\",\"path\":\".beads\"}\n\
+             {\"prefix\":\"fe-\",\"path\":\"../frontend\"}\n\
+             {\"prefix\":\"gone-\",\"path\":\"../missing\"}\n",
+        )
+        .unwrap();
+
+        let projects = routed_projects(&local_beads).unwrap();
+        assert_eq!(projects.len(), 1);
+        assert_eq!(projects[0].0, "fe");
+        assert!(projects[0].1.ends_with("frontend/.beads"));
+    }
+
     #[test]
     fn find_town_root_test() {
         let dir = TempDir::new().unwrap();
@@ -0,0 +1,531 @@
+//! Issue templates shared by `br create --template` and `br lint`.
+//!
+//! A template names required fields, default type/priority/labels and a
+//! skeleton of `##` sections for the description. Templates come from, in
+//! increasing precedence:
+//!
+//! 1. Built-ins: `bug`, `task`, `feature`, `epic` (the sections `lint` has
+//!    always checked)
+//! 2. Config: `templates.<name>.{type,priority,labels,required,sections}`
+//! 3. Files: `.beads/templates/<name>.md`, with optional YAML front matter
+//!    using the same keys and a markdown body used as the skeleton
+//!
+//! `lint` checks each issue against the template named after its type, so
+//! overriding `bug` changes both what `create --template bug` scaffolds and
+//! what `lint` reports for bugs.
+
+use super::{ConfigLayer, flatten_yaml};
+use crate::error::{BeadsError, Result};
+use crate::model::{Issue, IssueType, Priority};
+use crate::validation::LabelValidator;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Directory under `.beads` holding template files.
+pub const TEMPLATES_DIR: &str = "templates";
+
+/// Issue fields a template can require.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequiredField {
+    Description,
+    Design,
+    AcceptanceCriteria,
+    Notes,
+    Assignee,
+    Owner,
+    Estimate,
+    Due,
+    ExternalRef,
+    Labels,
+}
+
+impl RequiredField {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Description => "description",
+            Self::Design => "design",
+            Self::AcceptanceCriteria => "acceptance_criteria",
+            Self::Notes => "notes",
+            Self::Assignee => "assignee",
+            Self::Owner => "owner",
+            Self::Estimate => "estimate",
+            Self::Due => "due",
+            Self::ExternalRef => "external_ref",
+            Self::Labels => "labels",
+        }
+    }
+
+    /// Whether `issue` leaves this field empty.
+    #[must_use]
+    pub fn is_missing(self, issue: &Issue) -> bool {
+        match self {
+            Self::Description => is_blank(issue.description.as_deref()),
+            Self::Design => is_blank(issue.design.as_deref()),
+            Self::AcceptanceCriteria => is_blank(issue.acceptance_criteria.as_deref()),
+            Self::Notes => is_blank(issue.notes.as_deref()),
+            Self::Assignee => is_blank(issue.assignee.as_deref()),
+            Self::Owner => is_blank(issue.owner.as_deref()),
+            Self::Estimate => issue.estimated_minutes.is_none(),
+            Self::Due => issue.due_at.is_none(),
+            Self::ExternalRef => is_blank(issue.external_ref.as_deref()),
+            Self::Labels => issue.labels.is_empty(),
+        }
+    }
+}
+
+impl fmt::Display for RequiredField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for RequiredField {
+    type Err = BeadsError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().replace('-', "_").as_str() {
+            "description" => Ok(Self::Description),
+            "design" => Ok(Self::Design),
+            "acceptance_criteria" | "acceptance" => Ok(Self::AcceptanceCriteria),
+            "notes" => Ok(Self::Notes),
+            "assignee" => Ok(Self::Assignee),
+            "owner" => Ok(Self::Owner),
+            "estimate" | "estimated_minutes" => Ok(Self::Estimate),
+            "due" | "due_at" => Ok(Self::Due),
+            "external_ref" => Ok(Self::ExternalRef),
+            "labels" => Ok(Self::Labels),
+            _ => Err(BeadsError::validation(
+                "required",
+                format!(
+                    "unknown field '{s}' (expected description, design, acceptance_criteria, \
+                     notes, assignee, owner, estimate, due, external_ref or labels)"
+                ),
+            )),
+        }
+    }
+}
+
+/// A named issue template.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IssueTemplate {
+    pub name: String,
+    pub issue_type: Option<IssueType>,
+    pub priority: Option<Priority>,
+    pub labels: Vec<String>,
+    pub required: Vec<RequiredField>,
+    /// Required description headings, e.g. `## Steps to Reproduce`.
+    pub sections: Vec<String>,
+    /// Description skeleton; defaults to the section headings.
+    pub body: Option<String>,
+}
+
+impl IssueTemplate {
+    fn builtin(issue_type: IssueType, sections: &[&str]) -> Self {
+        Self {
+            name: issue_type.as_str().to_string(),
+            issue_type: Some(issue_type),
+            sections: sections.iter().map(|s| format!("## {s}")).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// The description skeleton new issues start from.
+    #[must_use]
+    pub fn skeleton(&self) -> String {
+        self.body.clone().unwrap_or_else(|| {
+            self.sections
+                .iter()
+                .map(|heading| format!("{heading}\n"))
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+    }
+
+    /// Fill in a description: the skeleton when none is given, otherwise the
+    /// given text followed by any required headings it lacks.
+    #[must_use]
+    pub fn scaffold(&self, description: Option<&str>) -> Option<String> {
+        let Some(description) = description else {
+            let skeleton = self.skeleton();
+            return (!skeleton.trim().is_empty()).then_some(skeleton);
+        };
+        let missing = self.missing_sections(description);
+        if missing.is_empty() {
+            return Some(description.to_string());
+        }
+        let mut scaffolded = description.trim_end().to_string();
+        for heading in missing {
+            scaffolded.push_str("\n\n");
+            scaffolded.push_str(heading);
+        }
+        scaffolded.push('\n');
+        Some(scaffolded)
+    }
+
+    /// Required headings not found in `description` (case-insensitive).
+    #[must_use]
+    pub fn missing_sections(&self, description: &str) -> Vec<&str> {
+        let desc_lower = description.to_lowercase();
+        self.sections
+            .iter()
+            .filter(|heading| !desc_lower.contains(&strip_heading_prefix(heading).to_lowercase()))
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Required fields `issue` leaves empty.
+    ///
+    /// A description made only of the skeleton does not count as filled in.
+    #[must_use]
+    pub fn missing_fields(&self, issue: &Issue) -> Vec<RequiredField> {
+        let skeleton = self.skeleton();
+        self.required
+            .iter()
+            .copied()
+            .filter(|field| match field {
+                RequiredField::Description => {
+                    !has_own_content(issue.description.as_deref().unwrap_or_default(), &skeleton)
+                }
+                other => other.is_missing(issue),
+            })
+            .collect()
+    }
+
+    /// Build a template from flattened `key -> value` settings.
+    fn from_settings(name: &str, settings: &HashMap<String, String>) -> Result<Self> {
+        let mut template = Self {
+            name: name.to_string(),
+            ..Self::default()
+        };
+        for (key, value) in settings {
+            match key.replace('-', "_").as_str() {
+                "type" => template.issue_type = Some(value.parse()?),
+                "priority" => template.priority = Some(value.parse()?),
+                "labels" => {
+                    template.labels = split_list(value);
+                    for label in &template.labels {
+                        LabelValidator::validate(label)
+                            .map_err(|e| BeadsError::validation("labels", e.message))?;
+                    }
+                }
+                "required" => {
+                    template.required = split_list(value)
+                        .iter()
+                        .map(|field| field.parse())
+                        .collect::<Result<_>>()?;
+                }
+                "sections" => {
+                    template.sections = split_list(value)
+                        .iter()
+                        .map(|s| format!("## {}", strip_heading_prefix(s)))
+                        .collect();
+                }
+                other => {
+                    return Err(BeadsError::validation(
+                        other,
+                        "unknown template key (expected type, priority, labels, required, sections)",
+                    ));
+                }
+            }
+        }
+        Ok(template)
+    }
+
+    /// Parse a template file: optional `---` YAML front matter, then the body.
+    fn from_markdown(name: &str, contents: &str) -> Result<Self> {
+        let (front, body) = split_front_matter(contents);
+        let mut settings = HashMap::new();
+        if let Some(front) = front {
+            let value: serde_yaml::Value = serde_yaml::from_str(front)?;
+            flatten_yaml(&value, "", &mut settings);
+        }
+        let mut template = Self::from_settings(name, &settings)?;
+
+        for line in body.lines() {
+            let trimmed = line.trim();
+            if trimmed.starts_with("## ") && !template.sections.iter().any(|s| s == trimmed) {
+                template.sections.push(trimmed.to_string());
+            }
+        }
+        let body = body.trim();
+        if !body.is_empty() {
+            template.body = Some(format!("{body}\n"));
+        }
+        Ok(template)
+    }
+}
+
+/// All templates available in a workspace, keyed by name.
+#[derive(Debug, Clone, Default)]
+pub struct TemplateSet {
+    templates: BTreeMap<String, IssueTemplate>,
+}
+
+impl TemplateSet {
+    /// The built-in templates.
+    #[must_use]
+    pub fn builtin() -> Self {
+        let mut set = Self::default();
+        for template in [
+            IssueTemplate::builtin(
+                IssueType::Bug,
+                &["Steps to Reproduce", "Acceptance Criteria"],
+            ),
+            IssueTemplate::builtin(IssueType::Task, &["Acceptance Criteria"]),
+            IssueTemplate::builtin(IssueType::Feature, &["Acceptance Criteria"]),
+            IssueTemplate::builtin(IssueType::Epic, &["Success Criteria"]),
+        ] {
+            set.insert(template);
+        }
+        set
+    }
+
+    /// Load built-in, config and `.beads/templates/*.md` templates.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a template in config or on disk is invalid.
+    pub fn load(beads_dir: &Path, layer: &ConfigLayer) -> Result<Self> {
+        let mut set = Self::builtin();
+
+        let mut from_config: BTreeMap<String, HashMap<String, String>> = BTreeMap::new();
+        for (key, value) in &layer.runtime {
+            let Some(rest) = key.strip_prefix("templates.") else {
+                continue;
+            };
+            if let Some((name, field)) = rest.split_once('.') {
+                from_config
+                    .entry(name.to_lowercase())
+                    .or_default()
+                    .insert(field.to_lowercase(), value.clone());
+            }
+        }
+        for (name, settings) in &from_config {
+            let template = IssueTemplate::from_settings(name, settings)
+                .map_err(|e| BeadsError::Config(format!("template '{name}' in config: {e}")))?;
+            set.insert(template);
+        }
+
+        let dir = beads_dir.join(TEMPLATES_DIR);
+        if dir.is_dir() {
+            let mut paths: Vec<_> = fs::read_dir(&dir)?
+                .filter_map(std::result::Result::ok)
+                .map(|entry| entry.path())
+                .filter(|path| path.extension().is_some_and(|ext| ext == "md"))
+                .collect();
+            paths.sort();
+            for path in paths {
+                let Some(name) = path.file_stem().and_then(|s| s.to_str()) else {
+                    continue;
+                };
+                let contents = fs::read_to_string(&path)?;
+                let template = IssueTemplate::from_markdown(&name.to_lowercase(), &contents)
+                    .map_err(|e| BeadsError::Config(format!("{}: {e}", path.display())))?;
+                set.insert(template);
+            }
+        }
+        Ok(set)
+    }
+
+    fn insert(&mut self, template: IssueTemplate) {
+        self.templates.insert(template.name.clone(), template);
+    }
+
+    /// Look up a template by name.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error listing the available names if none match.
+    pub fn get(&self, name: &str) -> Result<&IssueTemplate> {
+        self.templates.get(&name.to_lowercase()).ok_or_else(|| {
+            BeadsError::validation(
+                "template",
+                format!(
+                    "unknown template '{name}' (available: {})",
+                    self.names().collect::<Vec<_>>().join(", ")
+                ),
+            )
+        })
+    }
+
+    /// The template `lint` applies to issues of `issue_type`.
+    #[must_use]
+    pub fn for_type(&self, issue_type: &IssueType) -> Option<&IssueTemplate> {
+        self.templates.get(issue_type.as_str())
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.templates.keys().map(String::as_str)
+    }
+
+    /// Whether any template requires `field`.
+    #[must_use]
+    pub fn requires(&self, field: RequiredField) -> bool {
+        self.templates.values().any(|t| t.required.contains(&field))
+    }
+}
+
+fn split_front_matter(contents: &str) -> (Option<&str>, &str) {
+    let Some(rest) = contents
+        .strip_prefix("---\n")
+        .or_else(|| contents.strip_prefix("---\r\n"))
+    else {
+        return (None, contents);
+    };
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        if line.trim_end() == "---" {
+            return (Some(&rest[..offset]), &rest[offset + line.len()..]);
+        }
+        offset += line.len();
+    }
+    (None, contents)
+}
+
+pub(super) fn split_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn strip_heading_prefix(heading: &str) -> &str {
+    let trimmed = heading.trim();
+    trimmed
+        .strip_prefix("## ")
+        .or_else(|| trimmed.strip_prefix("# "))
+        .unwrap_or(trimmed)
+}
+
+fn is_blank(value: Option<&str>) -> bool {
+    value.is_none_or(|v| v.trim().is_empty())
+}
+
+/// Whether `description` has text beyond headings and skeleton lines.
+fn has_own_content(description: &str, skeleton: &str) -> bool {
+    description.lines().map(str::trim).any(|line| {
+        !line.is_empty() && !line.starts_with('#') && !skeleton.lines().any(|s| s.trim() == line)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_builtin_templates_match_lint_sections() {
+        let set = TemplateSet::builtin();
+        let bug = set.for_type(&IssueType::Bug).unwrap();
+        assert_eq!(
+            bug.sections,
+            vec!["## Steps to Reproduce", "## Acceptance Criteria"]
+        );
+        assert!(set.for_type(&IssueType::Chore).is_none());
+        assert_eq!(
+            set.names().collect::<Vec<_>>(),
+            ["bug", "epic", "feature", "task"]
+        );
+    }
+
+    #[test]
+    fn test_file_template_with_front_matter() {
+        let contents = "---\ntype: bug\npriority: P1\nlabels: [triage, bug]\nrequired: [description, assignee]\n---\n## Impact\n\n<!-- who is affected -->\n\n## Steps to Reproduce\n";
+        let template = IssueTemplate::from_markdown("incident", contents).unwrap();
+        assert_eq!(template.issue_type, Some(IssueType::Bug));
+        assert_eq!(template.priority, Some(Priority(1)));
+        assert_eq!(template.labels, vec!["triage", "bug"]);
+        assert_eq!(
+            template.required,
+            vec![RequiredField::Description, RequiredField::Assignee]
+        );
+        assert_eq!(
+            template.sections,
+            vec!["## Impact", "## Steps to Reproduce"]
+        );
+        assert!(template.skeleton().contains("<!-- who is affected -->"));
+    }
+
+    #[test]
+    fn test_invalid_template_settings_are_rejected() {
+        let bad_field = "---\nrequired: [colour]\n---\n";
+        assert!(IssueTemplate::from_markdown("x", bad_field).is_err());
+        let bad_key = "---\nassignee: alice\n---\n";
+        assert!(IssueTemplate::from_markdown("x", bad_key).is_err());
+    }
+
+    #[test]
+    fn test_scaffold_appends_missing_sections() {
+        let template = TemplateSet::builtin().get("bug").unwrap().clone();
+        let skeleton = template.scaffold(None).unwrap();
+        assert_eq!(
+            skeleton,
+            "## Steps to Reproduce\n\n## Acceptance Criteria\n"
+        );
+
+        let filled = template
+            .scaffold(Some("Crashes on start.\n## Steps to Reproduce\n1. run"))
+            .unwrap();
+        assert!(filled.ends_with("1. run\n\n## Acceptance Criteria\n"));
+        assert!(template.missing_sections(&filled).is_empty());
+    }
+
+    #[test]
+    fn test_skeleton_only_description_is_not_content() {
+        let template = IssueTemplate {
+            required: vec![RequiredField::Description, RequiredField::Labels],
+            sections: vec!["## Context".to_string()],
+            body: Some("## Context\n\n<!-- why -->\n".to_string()),
+            ..IssueTemplate::default()
+        };
+        let mut issue = Issue {
+            description: template.scaffold(None),
+            ..Issue::default()
+        };
+        assert_eq!(
+            template.missing_fields(&issue),
+            vec![RequiredField::Description, RequiredField::Labels]
+        );
+
+        issue.description = template.scaffold(Some("Because the cache is stale."));
+        issue.labels.push("infra".to_string());
+        assert!(template.missing_fields(&issue).is_empty());
+    }
+
+    #[test]
+    fn test_load_precedence_files_over_config_over_builtin() {
+        let temp = TempDir::new().unwrap();
+        let beads_dir = temp.path();
+        fs::create_dir(beads_dir.join(TEMPLATES_DIR)).unwrap();
+        fs::write(
+            beads_dir.join(TEMPLATES_DIR).join("bug.md"),
+            "---\nrequired: [assignee]\n---\n## Repro\n",
+        )
+        .unwrap();
+
+        let mut layer = ConfigLayer::default();
+        for (key, value) in [
+            ("templates.bug.sections", "Ignored"),
+            ("templates.spike.type", "task"),
+            ("templates.spike.sections", "Question,Findings"),
+            ("templates.spike.labels", "research"),
+        ] {
+            layer.runtime.insert(key.to_string(), value.to_string());
+        }
+
+        let set = TemplateSet::load(beads_dir, &layer).unwrap();
+        let bug = set.get("bug").unwrap();
+        assert_eq!(bug.sections, vec!["## Repro"]);
+        assert_eq!(bug.required, vec![RequiredField::Assignee]);
+        let spike = set.get("Spike").unwrap();
+        assert_eq!(spike.issue_type, Some(IssueType::Task));
+        assert_eq!(spike.sections, vec!["## Question", "## Findings"]);
+        assert!(set.get("nope").is_err());
+    }
+}
@@ -0,0 +1,435 @@
+//! Custom workflows: allowed statuses, transitions and guards per issue type.
+//!
+//! Configured in `.beads/config.yaml` under `workflow.<type>`, with
+//! `workflow.default` covering every type without its own section:
+//!
+//! ```yaml
+//! workflow:
+//!   default:
+//!     states: [open, in_progress, review, closed, deferred]
+//!     transitions:
+//!       open: [in_progress, deferred, closed]
+//!       in_progress: [review, open]
+//!       review: [closed, in_progress]
+//!       deferred: [open]
+//!       closed: [open]
+//!     guards:
+//!       in_progress: [assignee]
+//!       closed: [acceptance_criteria]
+//! ```
+//!
+//! Every part is optional:
+//!
+//! - `states` lists the statuses issues of the type may have. Names other
+//!   than the built-in ones (`review` above) become custom statuses.
+//! - `transitions.<from>` lists where an issue may move from `<from>`. Once
+//!   any transition is listed, unlisted moves are rejected; an issue whose
+//!   current status is outside the workflow may move to any state, so
+//!   existing data can migrate.
+//! - `guards.<to>` lists fields (the same names as template `required`) that
+//!   must be filled in before an issue enters `<to>`.
+//!
+//! Without a `workflow` section nothing is enforced. Deleting an issue is
+//! never restricted.
+
+use super::ConfigLayer;
+use super::templates::{RequiredField, split_list};
+use crate::error::{BeadsError, Result};
+use crate::model::{Issue, IssueType, Status};
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr;
+
+/// Section name that applies to types without their own workflow.
+pub const DEFAULT_WORKFLOW: &str = "default";
+
+/// The workflow for one issue type (or the default one).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TypeWorkflow {
+    /// Issue type name, or [`DEFAULT_WORKFLOW`].
+    pub name: String,
+    /// Allowed statuses; empty allows any.
+    pub states: Vec<Status>,
+    /// Allowed targets keyed by source status; empty allows any move.
+    pub transitions: BTreeMap<String, Vec<Status>>,
+    /// Fields required to enter a status, keyed by that status.
+    pub guards: BTreeMap<String, Vec<RequiredField>>,
+}
+
+impl TypeWorkflow {
+    /// Build a workflow from flattened `key -> value` settings.
+    fn from_settings(name: &str, settings: &HashMap<String, String>) -> Result<Self> {
+        let mut workflow = Self {
+            name: name.to_string(),
+            ..Self::default()
+        };
+        for (key, value) in settings {
+            if key == "states" {
+                workflow.states = parse_states(value)?;
+            } else if let Some(from) = key.strip_prefix("transitions.") {
+                let from = parse_state(from)?;
+                workflow
+                    .transitions
+                    .insert(from.as_str().to_string(), parse_states(value)?);
+            } else if let Some(to) = key.strip_prefix("guards.") {
+                let to = parse_state(to)?;
+                let fields = split_list(value)
+                    .iter()
+                    .map(|field| field.parse())
+                    .collect::<Result<_>>()?;
+                workflow.guards.insert(to.as_str().to_string(), fields);
+            } else {
+                return Err(BeadsError::validation(
+                    key,
+                    "unknown workflow key (expected states, transitions.<status>, guards.<status>)",
+                ));
+            }
+        }
+
+        if !workflow.states.is_empty() {
+            let mentioned = workflow
+                .transitions
+                .iter()
+                .flat_map(|(from, targets)| {
+                    std::iter::once(from.as_str()).chain(targets.iter().map(Status::as_str))
+                })
+                .chain(workflow.guards.keys().map(String::as_str));
+            for status in mentioned {
+                if !workflow.states.iter().any(|state| state.as_str() == status) {
+                    return Err(BeadsError::validation(
+                        "states",
+                        format!("'{status}' is used in transitions or guards but not listed"),
+                    ));
+                }
+            }
+        }
+        Ok(workflow)
+    }
+
+    /// Statuses this workflow knows: `states`, or everything mentioned in
+    /// transitions and guards when `states` is omitted.
+    #[must_use]
+    pub fn known_states(&self) -> Vec<Status> {
+        if !self.states.is_empty() {
+            return self.states.clone();
+        }
+        let mut known: Vec<Status> = Vec::new();
+        let mentioned = self
+            .transitions
+            .iter()
+            .flat_map(|(from, targets)| {
+                std::iter::once(parse_state(from).ok())
+                    .flatten()
+                    .chain(targets.iter().cloned())
+            })
+            .chain(self.guards.keys().filter_map(|to| parse_state(to).ok()));
+        for status in mentioned {
+            if !known.contains(&status) {
+                known.push(status);
+            }
+        }
+        known
+    }
+
+    /// Why moving `issue` to `to` is not allowed, if it is not.
+    ///
+    /// `issue` should already carry the rest of the change (e.g. a new
+    /// assignee), so guards see the fields as they will be.
+    #[must_use]
+    pub fn violation(&self, issue: &Issue, to: &Status) -> Option<String> {
+        let from = &issue.status;
+        if from == to || *to == Status::Tombstone {
+            return None;
+        }
+        if !self.states.is_empty() && !self.states.contains(to) {
+            return Some(format!(
+                "'{to}' is not a state of the {} workflow (states: {})",
+                self.name,
+                join(&self.states)
+            ));
+        }
+        if !self.transitions.is_empty() && self.known_states().contains(from) {
+            let targets = self
+                .transitions
+                .get(from.as_str())
+                .map_or(&[][..], Vec::as_slice);
+            if !targets.contains(to) {
+                let allowed = if targets.is_empty() {
+                    "none".to_string()
+                } else {
+                    join(targets)
+                };
+                return Some(format!(
+                    "the {} workflow does not allow {from} -> {to} (from {from}: {allowed})",
+                    self.name
+                ));
+            }
+        }
+        let missing: Vec<&str> = self
+            .guards
+            .get(to.as_str())
+            .into_iter()
+            .flatten()
+            .filter(|field| field.is_missing(issue))
+            .map(|field| field.as_str())
+            .collect();
+        if !missing.is_empty() {
+            return Some(format!(
+                "{} requires {} before moving to {to}",
+                issue.id,
+                missing.join(", ")
+            ));
+        }
+        None
+    }
+}
+
+/// Workflows for every configured issue type.
+#[derive(Debug, Clone, Default)]
+pub struct Workflow {
+    types: BTreeMap<String, TypeWorkflow>,
+}
+
+impl Workflow {
+    /// Load `workflow.*` settings from config.
+    ///
+    /// # Errors
+    ///
+    /// Returns a config error naming the section if a setting is invalid.
+    pub fn load(layer: &ConfigLayer) -> Result<Self> {
+        let mut sections: BTreeMap<String, HashMap<String, String>> = BTreeMap::new();
+        for (key, value) in &layer.runtime {
+            let Some(rest) = key.strip_prefix("workflow.") else {
+                continue;
+            };
+            if let Some((name, field)) = rest.split_once('.') {
+                sections
+                    .entry(name.to_lowercase())
+                    .or_default()
+                    .insert(field.to_lowercase(), value.clone());
+            }
+        }
+
+        let mut workflow = Self::default();
+        for (name, settings) in &sections {
+            let rules = TypeWorkflow::from_settings(name, settings)
+                .map_err(|e| BeadsError::Config(format!("workflow '{name}' in config: {e}")))?;
+            workflow.types.insert(name.clone(), rules);
+        }
+        Ok(workflow)
+    }
+
+    /// Whether no workflow is configured.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.types.is_empty()
+    }
+
+    /// All configured workflows, `default` first.
+    pub fn iter(&self) -> impl Iterator<Item = &TypeWorkflow> {
+        self.types.get(DEFAULT_WORKFLOW).into_iter().chain(
+            self.types
+                .values()
+                .filter(|rules| rules.name != DEFAULT_WORKFLOW),
+        )
+    }
+
+    /// The workflow governing `issue_type`: its own section, else `default`.
+    #[must_use]
+    pub fn for_type(&self, issue_type: &IssueType) -> Option<&TypeWorkflow> {
+        self.types
+            .get(&issue_type.as_str().to_lowercase())
+            .or_else(|| self.types.get(DEFAULT_WORKFLOW))
+    }
+
+    /// Why moving `issue` to `to` is not allowed, if it is not.
+    #[must_use]
+    pub fn violation(&self, issue: &Issue, to: &Status) -> Option<String> {
+        self.for_type(&issue.issue_type)?.violation(issue, to)
+    }
+
+    /// Reject moving `issue` to `to` if its workflow forbids it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error describing the violated rule.
+    pub fn check(&self, issue: &Issue, to: &Status) -> Result<()> {
+        self.violation(issue, to).map_or(Ok(()), |message| {
+            Err(BeadsError::validation("status", message))
+        })
+    }
+
+    /// Parse a status name, accepting custom states declared in any workflow.
+    ///
+    /// # Errors
+    ///
+    /// Returns the usual invalid-status error for names no workflow declares.
+    pub fn parse_status(&self, value: &str) -> Result<Status> {
+        Status::from_str(value).or_else(|err| {
+            let name = value.trim().to_lowercase();
+            self.types
+                .values()
+                .flat_map(TypeWorkflow::known_states)
+                .find(|state| state.as_str() == name)
+                .ok_or(err)
+        })
+    }
+}
+
+/// Parse a state name: a built-in status or a custom one.
+fn parse_state(value: &str) -> Result<Status> {
+    let name = value.trim().to_lowercase();
+    if let Ok(status) = Status::from_str(&name) {
+        return Ok(status);
+    }
+    let valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    if valid {
+        Ok(Status::Custom(name))
+    } else {
+        Err(BeadsError::validation(
+            "states",
+            format!("invalid state name '{value}' (use letters, digits, '_' or '-')"),
+        ))
+    }
+}
+
+fn parse_states(value: &str) -> Result<Vec<Status>> {
+    split_list(value).iter().map(|s| parse_state(s)).collect()
+}
+
+fn join(states: &[Status]) -> String {
+    states
+        .iter()
+        .map(Status::as_str)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layer(entries: &[(&str, &str)]) -> ConfigLayer {
+        let mut layer = ConfigLayer::default();
+        for (key, value) in entries {
+            layer
+                .runtime
+                .insert((*key).to_string(), (*value).to_string());
+        }
+        layer
+    }
+
+    fn review_workflow() -> Workflow {
+        Workflow::load(&layer(&[
+            ("workflow.default.states", "open,in_progress,review,closed"),
+            ("workflow.default.transitions.open", "in_progress"),
+            ("workflow.default.transitions.in_progress", "review,open"),
+            ("workflow.default.transitions.review", "closed,in_progress"),
+            ("workflow.default.transitions.closed", "open"),
+            ("workflow.default.guards.in_progress", "assignee"),
+            ("workflow.default.guards.closed", "acceptance_criteria"),
+            ("workflow.chore.states", "open,closed"),
+        ]))
+        .unwrap()
+    }
+
+    fn issue(status: Status) -> Issue {
+        Issue {
+            id: "bd-1".to_string(),
+            status,
+            ..Issue::default()
+        }
+    }
+
+    #[test]
+    fn test_empty_workflow_allows_everything() {
+        let workflow = Workflow::load(&ConfigLayer::default()).unwrap();
+        assert!(workflow.is_empty());
+        assert!(
+            workflow
+                .check(&issue(Status::Open), &Status::Closed)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_transitions_are_enforced() {
+        let workflow = review_workflow();
+        let message = workflow
+            .violation(&issue(Status::Open), &Status::Closed)
+            .unwrap();
+        assert_eq!(
+            message,
+            "the default workflow does not allow open -> closed (from open: in_progress)"
+        );
+
+        let mut reviewed = issue(Status::Custom("review".to_string()));
+        reviewed.acceptance_criteria = Some("Works".to_string());
+        assert!(workflow.check(&reviewed, &Status::Closed).is_ok());
+        assert!(workflow.check(&reviewed, &Status::Tombstone).is_ok());
+    }
+
+    #[test]
+    fn test_guards_require_fields() {
+        let workflow = review_workflow();
+        let mut open = issue(Status::Open);
+        assert_eq!(
+            workflow.violation(&open, &Status::InProgress).unwrap(),
+            "bd-1 requires assignee before moving to in_progress"
+        );
+        open.assignee = Some("alice".to_string());
+        assert!(workflow.check(&open, &Status::InProgress).is_ok());
+    }
+
+    #[test]
+    fn test_states_per_type_and_migration() {
+        let workflow = review_workflow();
+        let mut chore = issue(Status::Open);
+        chore.issue_type = IssueType::Chore;
+        assert!(
+            workflow
+                .violation(&chore, &Status::Custom("review".to_string()))
+                .unwrap()
+                .contains("not a state of the chore workflow")
+        );
+        // The chore workflow lists no transitions, so any listed state is fine.
+        assert!(workflow.check(&chore, &Status::Closed).is_ok());
+
+        // Blocked is outside the default workflow; it may move to any state.
+        let mut blocked = issue(Status::Blocked);
+        blocked.assignee = Some("alice".to_string());
+        assert!(workflow.check(&blocked, &Status::InProgress).is_ok());
+    }
+
+    #[test]
+    fn test_parse_status_accepts_custom_states() {
+        let workflow = review_workflow();
+        assert_eq!(
+            workflow.parse_status("Review").unwrap(),
+            Status::Custom("review".to_string())
+        );
+        assert_eq!(workflow.parse_status("open").unwrap(), Status::Open);
+        assert!(workflow.parse_status("qa").is_err());
+        assert!(Workflow::default().parse_status("review").is_err());
+    }
+
+    #[test]
+    fn test_invalid_settings_are_rejected() {
+        let err = Workflow::load(&layer(&[
+            ("workflow.bug.states", "open,closed"),
+            ("workflow.bug.transitions.open", "review"),
+        ]))
+        .unwrap_err();
+        assert!(err.to_string().contains("workflow 'bug'"), "{err}");
+        assert!(err.to_string().contains("review"), "{err}");
+
+        let err =
+            Workflow::load(&layer(&[("workflow.bug.guards.closed", "reviewer")])).unwrap_err();
+        assert!(err.to_string().contains("reviewer"), "{err}");
+
+        let err = Workflow::load(&layer(&[("workflow.bug.order", "1")])).unwrap_err();
+        assert!(err.to_string().contains("unknown workflow key"), "{err}");
+    }
+}
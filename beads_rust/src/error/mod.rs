@@ -84,6 +84,14 @@ pub enum BeadsError {
     #[error("Priority must be 0-4, got: {priority}")]
     InvalidPriority { priority: i32 },
 
+    /// Filter expression could not be parsed.
+    #[error("Invalid query at position {position}: {reason}")]
+    QueryParse {
+        query: String,
+        position: usize,
+        reason: String,
+    },
+
     // === JSONL Errors ===
     /// Failed to parse a line in the JSONL file.
     #[error("JSONL parse error at line {line}: {reason}")]
@@ -131,6 +139,15 @@ pub enum BeadsError {
     #[error("Already initialized at '{path}'")]
     AlreadyInitialized { path: PathBuf },
 
+    // === Hook Errors ===
+    /// A pre-hook exited non-zero and vetoed the change.
+    #[error("Hook '{hook}' rejected the change: {reason}")]
+    HookRejected { hook: String, reason: String },
+
+    /// A hook could not be started, failed after the change, or timed out.
+    #[error("Hook '{hook}' failed: {reason}")]
+    HookFailed { hook: String, reason: String },
+
     // === I/O Errors ===
     /// File system I/O error.
     #[error("I/O error: {0}")]
@@ -199,8 +216,10 @@ impl BeadsError {
                 | Self::InvalidStatus { .. }
                 | Self::InvalidType { .. }
                 | Self::InvalidPriority { .. }
+                | Self::QueryParse { .. }
                 | Self::PrefixMismatch { .. }
                 | Self::AmbiguousId { .. }
+                | Self::HookRejected { .. }
         )
     }
 
@@ -234,6 +253,9 @@ impl BeadsError {
                 Some("Valid statuses: open, in_progress, blocked, deferred, closed")
             }
             Self::InvalidType { .. } => Some("Valid types: task, bug, feature, epic, chore"),
+            Self::HookRejected { .. } => {
+                Some("Fix what the hook reported, or set hooks.enabled: false")
+            }
             _ => None,
         }
     }
@@ -65,6 +65,8 @@ pub enum ErrorCode {
     InvalidPriority,
     /// Required field missing
     RequiredField,
+    /// Filter expression syntax error
+    QueryParseError,
 
     // === Dependency Errors (exit code 5) ===
     /// Dependency cycle detected
@@ -106,6 +108,12 @@ pub enum ErrorCode {
     /// YAML parsing error
     YamlError,
 
+    // === Hook Errors (exit code 9) ===
+    /// A pre-hook vetoed the change
+    HookRejected,
+    /// A hook could not run or timed out
+    HookFailed,
+
     // === Internal Errors (exit code 1) ===
     /// Unexpected internal error
     InternalError,
@@ -134,6 +142,7 @@ impl ErrorCode {
             Self::InvalidType => "INVALID_TYPE",
             Self::InvalidPriority => "INVALID_PRIORITY",
             Self::RequiredField => "REQUIRED_FIELD",
+            Self::QueryParseError => "QUERY_PARSE_ERROR",
             // Dependency
             Self::CycleDetected => "CYCLE_DETECTED",
             Self::DependencyNotFound => "DEPENDENCY_NOT_FOUND",
@@ -150,6 +159,9 @@ impl ErrorCode {
             Self::ConfigError => "CONFIG_ERROR",
             Self::ConfigNotFound => "CONFIG_NOT_FOUND",
             Self::ConfigParseError => "CONFIG_PARSE_ERROR",
+            // Hook
+            Self::HookRejected => "HOOK_REJECTED",
+            Self::HookFailed => "HOOK_FAILED",
             // I/O
             Self::IoError => "IO_ERROR",
             Self::JsonError => "JSON_ERROR",
@@ -174,7 +186,9 @@ impl ErrorCode {
                 | Self::InvalidType
                 | Self::InvalidPriority
                 | Self::RequiredField
+                | Self::QueryParseError
                 | Self::AmbiguousId
+                | Self::HookRejected
         )
     }
 
@@ -189,6 +203,7 @@ impl ErrorCode {
     /// - 6: Sync/JSONL errors
     /// - 7: Config errors
     /// - 8: I/O errors
+    /// - 9: Hook errors
     #[must_use]
     pub const fn exit_code(&self) -> i32 {
         match self {
@@ -206,7 +221,8 @@ impl ErrorCode {
             | Self::InvalidStatus
             | Self::InvalidType
             | Self::InvalidPriority
-            | Self::RequiredField => 4,
+            | Self::RequiredField
+            | Self::QueryParseError => 4,
             // Dependency (5)
             Self::CycleDetected
             | Self::DependencyNotFound
@@ -223,6 +239,8 @@ impl ErrorCode {
             Self::ConfigError | Self::ConfigNotFound | Self::ConfigParseError => 7,
             // I/O (8)
             Self::IoError | Self::JsonError | Self::YamlError => 8,
+            // Hook (9)
+            Self::HookRejected | Self::HookFailed => 9,
             // Internal (1)
             Self::InternalError => 1,
         }
@@ -558,6 +576,14 @@ impl StructuredError {
                     })),
                 )
             }
+            BeadsError::QueryParse {
+                query,
+                position,
+                reason,
+            } => (
+                ErrorCode::QueryParseError,
+                Some(json!({"query": query, "position": position, "reason": reason})),
+            ),
             BeadsError::JsonlParse { line, reason } => (
                 ErrorCode::JsonlParseError,
                 Some(json!({"line": line, "reason": reason})),
@@ -588,6 +614,14 @@ impl StructuredError {
                 Some(json!({"from": from, "to": to})),
             ),
             BeadsError::Config(_) => (ErrorCode::ConfigError, None),
+            BeadsError::HookRejected { hook, reason } => (
+                ErrorCode::HookRejected,
+                Some(json!({"hook": hook, "reason": reason})),
+            ),
+            BeadsError::HookFailed { hook, reason } => (
+                ErrorCode::HookFailed,
+                Some(json!({"hook": hook, "reason": reason})),
+            ),
             BeadsError::Io(_) => (ErrorCode::IoError, None),
             BeadsError::Json(_) => (ErrorCode::JsonError, None),
             BeadsError::Yaml(_) => (ErrorCode::YamlError, None),
@@ -641,6 +675,20 @@ impl StructuredError {
                 }
                 Some(format!("Use --force to delete '{id}' anyway."))
             }
+            BeadsError::QueryParse {
+                query, position, ..
+            } => {
+                let near: String = query
+                    .chars()
+                    .skip(position.saturating_sub(1))
+                    .take(20)
+                    .collect();
+                if near.is_empty() {
+                    Some("The expression ended early; complete the last condition.".to_string())
+                } else {
+                    Some(format!("Check the expression near: {near}"))
+                }
+            }
             BeadsError::JsonlParse { line, .. } => Some(format!(
                 "Check line {line} of the JSONL file for syntax errors."
             )),
@@ -929,6 +977,7 @@ mod tests {
         assert_eq!(ErrorCode::JsonlParseError.exit_code(), 6);
         assert_eq!(ErrorCode::ConfigError.exit_code(), 7);
         assert_eq!(ErrorCode::IoError.exit_code(), 8);
+        assert_eq!(ErrorCode::HookRejected.exit_code(), 9);
         assert_eq!(ErrorCode::InternalError.exit_code(), 1);
     }
 
@@ -947,6 +996,26 @@ mod tests {
         assert!(!json["error"]["retryable"].as_bool().unwrap());
     }
 
+    #[test]
+    fn test_query_parse_error_carries_position() {
+        let err = BeadsError::QueryParse {
+            query: "status:open AND bogus:1".to_string(),
+            position: 17,
+            reason: "unknown field 'bogus'".to_string(),
+        };
+        let structured = StructuredError::from_error(&err);
+        assert_eq!(structured.code, ErrorCode::QueryParseError);
+        assert_eq!(structured.code.exit_code(), 4);
+        assert!(structured.retryable);
+        let context = structured.context.unwrap();
+        assert_eq!(context["position"], 17);
+        assert_eq!(context["reason"], "unknown field 'bogus'");
+        assert_eq!(
+            structured.hint.as_deref(),
+            Some("Check the expression near: bogus:1")
+        );
+    }
+
     #[test]
     fn test_levenshtein_distance() {
         assert_eq!(levenshtein_distance("", ""), 0);
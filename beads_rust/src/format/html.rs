@@ -0,0 +1,528 @@
+//! HTML rendering for the static site written by `br export --format html`.
+//!
+//! Every page is self-contained: styles and the index filter script are
+//! inlined, and links are relative, so the output directory can be served
+//! from any path or opened straight from disk. Issue pages live in
+//! `issues/`, everything else at the top level.
+
+use crate::format::{IssueDetails, IssueWithDependencyMetadata};
+use crate::model::{EpicStatus, Event, Issue};
+use chrono::{DateTime, Utc};
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+
+const STYLE: &str = "\
+body{font-family:-apple-system,'Segoe UI',Helvetica,Arial,sans-serif;margin:0;color:#1f2328;background:#fff}\
+header{background:#24292f;color:#fff;padding:10px 24px;display:flex;gap:24px;align-items:baseline}\
+header a{color:#fff;text-decoration:none}header .site{font-weight:bold}\
+main{padding:16px 24px;max-width:1200px}\
+a{color:#0969da}h1 .id{color:#59636e;font-weight:normal;margin-right:8px}\
+table{border-collapse:collapse;width:100%}th,td{text-align:left;padding:6px 8px;border-bottom:1px solid #d1d9e0;vertical-align:top}\
+th{background:#f6f8fa}table.meta{width:auto}table.meta th{background:none;color:#59636e;font-weight:normal}\
+.filters{display:flex;flex-wrap:wrap;gap:8px;margin:12px 0}.filters input{flex:1;min-width:200px}\
+.status,.prio,.label{display:inline-block;padding:1px 8px;border-radius:10px;font-size:12px;background:#eaeef2}\
+.s-open{background:#ddf4ff}.s-in_progress{background:#fff8c5}.s-blocked{background:#ffebe9}\
+.s-closed{background:#dafbe1}.s-deferred{background:#fbefff}\
+.p0{background:#ffebe9;color:#a40e26}.p1{background:#fff1e5;color:#953800}\
+.text{white-space:pre-wrap;background:#f6f8fa;padding:12px;border-radius:6px;font-family:inherit}\
+.comment{border:1px solid #d1d9e0;border-radius:6px;margin:8px 0}\
+.comment .who{background:#f6f8fa;padding:6px 12px;border-bottom:1px solid #d1d9e0;color:#59636e}\
+.comment .text{background:none;margin:0}\
+.bar{display:inline-block;width:200px;height:10px;background:#eaeef2;border-radius:5px;vertical-align:middle}\
+.bar div{height:10px;background:#1a7f37;border-radius:5px}\
+.muted{color:#59636e}.graph-wrap{overflow:auto;border:1px solid #d1d9e0;border-radius:6px}\
+";
+
+const FILTER_SCRIPT: &str = "\
+(function(){\
+var rows=Array.prototype.slice.call(document.querySelectorAll('#issues tbody tr'));\
+var selects=Array.prototype.slice.call(document.querySelectorAll('select[data-filter]'));\
+var search=document.getElementById('q');var count=document.getElementById('count');\
+function apply(){var q=search.value.trim().toLowerCase();var shown=0;\
+rows.forEach(function(row){var ok=!q||row.dataset.text.indexOf(q)>=0;\
+selects.forEach(function(s){if(!s.value)return;var v=row.dataset[s.dataset.filter];\
+ok=ok&&(s.dataset.filter==='labels'?v.split(' ').indexOf(s.value)>=0:v===s.value);});\
+row.hidden=!ok;if(ok)shown++;});\
+count.textContent=shown+' of '+rows.length+' issues';}\
+search.addEventListener('input',apply);\
+selects.forEach(function(s){s.addEventListener('change',apply);});apply();})();\
+";
+
+/// Escape text for HTML element content and attribute values.
+#[must_use]
+pub fn escape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+/// Path of an issue page relative to the site root.
+#[must_use]
+pub fn issue_path(id: &str) -> String {
+    format!("issues/{id}.html")
+}
+
+/// Wrap a page body in the shared layout. `root` is the relative path from
+/// the page back to the site root (`""` or `"../"`).
+fn page(site: &str, title: &str, root: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n\
+         <title>{} - {}</title>\n<style>{STYLE}</style>\n</head>\n<body>\n\
+         <header><a class=\"site\" href=\"{root}index.html\">{}</a>\
+         <a href=\"{root}index.html\">Issues</a><a href=\"{root}epics.html\">Epics</a>\
+         <a href=\"{root}graph.html\">Graph</a></header>\n<main>\n{body}</main>\n</body>\n</html>\n",
+        escape(title),
+        escape(site),
+        escape(site)
+    )
+}
+
+fn format_time(at: DateTime<Utc>) -> String {
+    at.format("%Y-%m-%d %H:%M UTC").to_string()
+}
+
+fn status_badge(status: &str) -> String {
+    format!(
+        "<span class=\"status s-{}\">{}</span>",
+        escape(status),
+        escape(status)
+    )
+}
+
+fn priority_badge(priority: i32) -> String {
+    format!("<span class=\"prio p{priority}\">P{priority}</span>")
+}
+
+/// `closed/total (pct%)` with a progress bar.
+fn progress(closed: usize, total: usize) -> String {
+    let percentage = (closed * 100).checked_div(total).unwrap_or(0);
+    format!(
+        "<span class=\"bar\"><div style=\"width:{percentage}%\"></div></span> \
+         {closed}/{total} closed ({percentage}%)"
+    )
+}
+
+fn select(filter: &str, label: &str, values: &BTreeSet<String>) -> String {
+    let mut out = format!(
+        "<select data-filter=\"{filter}\" aria-label=\"{label}\"><option value=\"\">{label}: all</option>"
+    );
+    for value in values {
+        let _ = write!(
+            out,
+            "<option value=\"{}\">{}</option>",
+            escape(value),
+            escape(value)
+        );
+    }
+    out.push_str("</select>");
+    out
+}
+
+/// The index: every issue in one table, filterable in the browser.
+///
+/// Issue labels are read from `issue.labels`.
+#[must_use]
+pub fn render_index(site: &str, issues: &[Issue], generated_at: DateTime<Utc>) -> String {
+    let mut statuses = BTreeSet::new();
+    let mut types = BTreeSet::new();
+    let mut priorities = BTreeSet::new();
+    let mut assignees = BTreeSet::new();
+    let mut labels = BTreeSet::new();
+    for issue in issues {
+        statuses.insert(issue.status.as_str().to_string());
+        types.insert(issue.issue_type.as_str().to_string());
+        priorities.insert(format!("P{}", issue.priority.0));
+        if let Some(assignee) = &issue.assignee {
+            assignees.insert(assignee.clone());
+        }
+        labels.extend(issue.labels.iter().cloned());
+    }
+
+    let mut body = format!(
+        "<h1>Issues</h1>\n<p class=\"muted\"><span id=\"count\">{} issues</span> · generated {}</p>\n\
+         <div class=\"filters\"><input id=\"q\" type=\"search\" placeholder=\"Filter by ID, title, label…\">{}{}{}{}{}</div>\n",
+        issues.len(),
+        format_time(generated_at),
+        select("status", "Status", &statuses),
+        select("type", "Type", &types),
+        select("priority", "Priority", &priorities),
+        select("assignee", "Assignee", &assignees),
+        select("labels", "Label", &labels)
+    );
+    body.push_str(
+        "<table id=\"issues\">\n<thead><tr><th>ID</th><th>Title</th><th>Status</th>\
+         <th>Priority</th><th>Type</th><th>Assignee</th><th>Labels</th><th>Updated</th></tr></thead>\n<tbody>\n",
+    );
+    for issue in issues {
+        let assignee = issue.assignee.as_deref().unwrap_or("");
+        let text = format!(
+            "{} {} {} {}",
+            issue.id,
+            issue.title,
+            assignee,
+            issue.labels.join(" ")
+        )
+        .to_lowercase();
+        let label_badges: Vec<String> = issue
+            .labels
+            .iter()
+            .map(|label| format!("<span class=\"label\">{}</span>", escape(label)))
+            .collect();
+        let _ = writeln!(
+            body,
+            "<tr data-status=\"{status}\" data-type=\"{kind}\" data-priority=\"P{priority}\" \
+             data-assignee=\"{assignee}\" data-labels=\"{labels}\" data-text=\"{text}\">\
+             <td><a href=\"{href}\">{id}</a></td><td><a href=\"{href}\">{title}</a></td>\
+             <td>{status_badge}</td><td>{priority_badge}</td><td>{kind}</td><td>{assignee}</td>\
+             <td>{label_badges}</td><td>{updated}</td></tr>",
+            status = escape(issue.status.as_str()),
+            kind = escape(issue.issue_type.as_str()),
+            priority = issue.priority.0,
+            assignee = escape(assignee),
+            labels = escape(&issue.labels.join(" ")),
+            text = escape(&text),
+            href = escape(&issue_path(&issue.id)),
+            id = escape(&issue.id),
+            title = escape(&issue.title),
+            status_badge = status_badge(issue.status.as_str()),
+            priority_badge = priority_badge(issue.priority.0),
+            label_badges = label_badges.join(" "),
+            updated = issue.updated_at.format("%Y-%m-%d"),
+        );
+    }
+    body.push_str("</tbody>\n</table>\n");
+    let _ = writeln!(body, "<script>{FILTER_SCRIPT}</script>");
+    page(site, "Issues", "", &body)
+}
+
+fn related_list(heading: &str, related: &[IssueWithDependencyMetadata]) -> String {
+    if related.is_empty() {
+        return String::new();
+    }
+    let mut out = format!("<h2>{heading}</h2>\n<table>\n");
+    for item in related {
+        let _ = writeln!(
+            out,
+            "<tr><td><a href=\"{}.html\">{}</a></td><td>{}</td><td>{}</td><td>{}</td><td class=\"muted\">{}</td></tr>",
+            escape(&item.id),
+            escape(&item.id),
+            escape(&item.title),
+            status_badge(item.status.as_str()),
+            priority_badge(item.priority.0),
+            escape(&item.dep_type)
+        );
+    }
+    out.push_str("</table>\n");
+    out
+}
+
+fn describe_event(event: &Event) -> String {
+    let mut text = escape(event.event_type.as_str());
+    match (&event.old_value, &event.new_value) {
+        (Some(old), Some(new)) => {
+            let _ = write!(text, ": {} → {}", escape(old), escape(new));
+        }
+        (None, Some(new)) => {
+            let _ = write!(text, ": {}", escape(new));
+        }
+        (Some(old), None) => {
+            let _ = write!(text, ": {} removed", escape(old));
+        }
+        (None, None) => {}
+    }
+    if let Some(comment) = &event.comment {
+        let _ = write!(text, " <span class=\"muted\">({})</span>", escape(comment));
+    }
+    text
+}
+
+/// One issue: fields, text sections, relations, comments and history.
+/// `epic` adds a progress section for epics. Events are shown oldest first.
+#[must_use]
+pub fn render_issue(site: &str, details: &IssueDetails, epic: Option<&EpicStatus>) -> String {
+    let issue = &details.issue;
+    let mut body = format!(
+        "<h1><span class=\"id\">{}</span>{}</h1>\n<p>{} {} <span class=\"label\">{}</span>",
+        escape(&issue.id),
+        escape(&issue.title),
+        status_badge(issue.status.as_str()),
+        priority_badge(issue.priority.0),
+        escape(issue.issue_type.as_str())
+    );
+    for label in &details.labels {
+        let _ = write!(body, " <span class=\"label\">{}</span>", escape(label));
+    }
+    body.push_str("</p>\n<table class=\"meta\">\n");
+
+    let mut meta: Vec<(&str, String)> = Vec::new();
+    if let Some(parent) = &details.parent {
+        meta.push((
+            "Parent",
+            format!("<a href=\"{}.html\">{}</a>", escape(parent), escape(parent)),
+        ));
+    }
+    for (name, value) in [
+        ("Assignee", issue.assignee.as_deref()),
+        ("Owner", issue.owner.as_deref()),
+        ("External ref", issue.external_ref.as_deref()),
+        ("Close reason", issue.close_reason.as_deref()),
+    ] {
+        if let Some(value) = value {
+            meta.push((name, escape(value)));
+        }
+    }
+    meta.push(("Created", format_time(issue.created_at)));
+    meta.push(("Updated", format_time(issue.updated_at)));
+    for (name, value) in [
+        ("Due", issue.due_at),
+        ("Deferred until", issue.defer_until),
+        ("Closed", issue.closed_at),
+    ] {
+        if let Some(value) = value {
+            meta.push((name, format_time(value)));
+        }
+    }
+    if let Some(minutes) = issue.estimated_minutes {
+        meta.push(("Estimate", format!("{minutes} min")));
+    }
+    for (name, value) in meta {
+        let _ = writeln!(body, "<tr><th>{name}</th><td>{value}</td></tr>");
+    }
+    body.push_str("</table>\n");
+
+    if let Some(status) = epic {
+        let _ = writeln!(
+            body,
+            "<h2>Progress</h2>\n<p>{}</p>",
+            progress(status.closed_children, status.total_children)
+        );
+    }
+
+    for (heading, text) in [
+        ("Description", &issue.description),
+        ("Design", &issue.design),
+        ("Acceptance criteria", &issue.acceptance_criteria),
+        ("Notes", &issue.notes),
+    ] {
+        if let Some(text) = text.as_deref().filter(|text| !text.trim().is_empty()) {
+            let _ = writeln!(
+                body,
+                "<h2>{heading}</h2>\n<div class=\"text\">{}</div>",
+                escape(text)
+            );
+        }
+    }
+
+    body.push_str(&related_list("Depends on", &details.dependencies));
+    body.push_str(&related_list("Dependents", &details.dependents));
+
+    if !details.comments.is_empty() {
+        let _ = writeln!(body, "<h2>Comments ({})</h2>", details.comments.len());
+        for comment in &details.comments {
+            let _ = writeln!(
+                body,
+                "<div class=\"comment\"><div class=\"who\"><b>{}</b> · {}</div>\
+                 <div class=\"text\">{}</div></div>",
+                escape(&comment.author),
+                format_time(comment.created_at),
+                escape(&comment.body)
+            );
+        }
+    }
+
+    if !details.events.is_empty() {
+        body.push_str("<h2>History</h2>\n<table>\n<thead><tr><th>When</th><th>Who</th><th>What</th></tr></thead>\n<tbody>\n");
+        let mut events: Vec<&Event> = details.events.iter().collect();
+        events.sort_by_key(|event| (event.created_at, event.id));
+        for event in events {
+            let _ = writeln!(
+                body,
+                "<tr><td>{}</td><td>{}</td><td>{}</td></tr>",
+                format_time(event.created_at),
+                escape(&event.actor),
+                describe_event(event)
+            );
+        }
+        body.push_str("</tbody>\n</table>\n");
+    }
+
+    page(site, &format!("{} {}", issue.id, issue.title), "../", &body)
+}
+
+/// Every epic with its progress and children.
+#[must_use]
+pub fn render_epics(
+    site: &str,
+    epics: &[(EpicStatus, Vec<IssueWithDependencyMetadata>)],
+) -> String {
+    let mut body = String::from("<h1>Epics</h1>\n");
+    if epics.is_empty() {
+        body.push_str("<p class=\"muted\">No epics.</p>\n");
+    }
+    for (status, children) in epics {
+        let epic = &status.epic;
+        let _ = writeln!(
+            body,
+            "<h2><a href=\"{}\">{}</a> {}</h2>\n<p>{} {}</p>",
+            escape(&issue_path(&epic.id)),
+            escape(&epic.id),
+            escape(&epic.title),
+            status_badge(epic.status.as_str()),
+            progress(status.closed_children, status.total_children)
+        );
+        if children.is_empty() {
+            continue;
+        }
+        body.push_str("<table>\n");
+        for child in children {
+            let _ = writeln!(
+                body,
+                "<tr><td><a href=\"{}\">{}</a></td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                escape(&issue_path(&child.id)),
+                escape(&child.id),
+                escape(&child.title),
+                status_badge(child.status.as_str()),
+                priority_badge(child.priority.0)
+            );
+        }
+        body.push_str("</table>\n");
+    }
+    page(site, "Epics", "", &body)
+}
+
+/// The dependency graph page around a pre-rendered SVG.
+#[must_use]
+pub fn render_graph(site: &str, svg: &str) -> String {
+    let body = format!(
+        "<h1>Dependency graph</h1>\n<p class=\"muted\">Open, in-progress and blocked issues. \
+         Each issue is drawn to the right of what it depends on; fill shows status, \
+         border shows priority.</p>\n<div class=\"graph-wrap\">\n{svg}</div>\n"
+    );
+    page(site, "Dependency graph", "", &body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{Comment, EventType, IssueType, Priority, Status};
+
+    fn issue(id: &str, title: &str) -> Issue {
+        Issue {
+            id: id.to_string(),
+            title: title.to_string(),
+            ..Issue::default()
+        }
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(
+            escape("<a href=\"x\">Tom & Jerry's</a>"),
+            "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&#39;s&lt;/a&gt;"
+        );
+    }
+
+    #[test]
+    fn test_render_index_rows_carry_filter_data() {
+        let mut bug = issue("bd-1", "Crash <on> save");
+        bug.labels = vec!["backend".to_string(), "urgent".to_string()];
+        bug.assignee = Some("alice".to_string());
+        bug.priority = Priority(0);
+        let mut feature = issue("bd-2", "Dark mode");
+        feature.issue_type = IssueType::Feature;
+        feature.status = Status::Closed;
+
+        let html = render_index("demo", &[bug, feature], Utc::now());
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("Crash &lt;on&gt; save"));
+        assert!(html.contains(
+            "data-status=\"open\" data-type=\"task\" data-priority=\"P0\" \
+             data-assignee=\"alice\" data-labels=\"backend urgent\""
+        ));
+        assert!(html.contains("<a href=\"issues/bd-2.html\">bd-2</a>"));
+        assert!(html.contains("<option value=\"feature\">feature</option>"));
+        assert!(html.contains("<option value=\"urgent\">urgent</option>"));
+        assert!(html.contains("<script>"));
+        assert!(!html.contains("src=\"http"));
+    }
+
+    #[test]
+    fn test_render_issue_links_relations_and_orders_history() {
+        let mut epic = issue("bd-epic", "Launch");
+        epic.issue_type = IssueType::Epic;
+        let event = |id, event_type, minute: i64| Event {
+            id,
+            issue_id: "bd-epic".to_string(),
+            event_type,
+            actor: "alice".to_string(),
+            old_value: None,
+            new_value: None,
+            comment: None,
+            created_at: DateTime::from_timestamp(1_700_000_000 + minute * 60, 0).unwrap(),
+        };
+        let details = IssueDetails {
+            issue: epic.clone(),
+            labels: vec!["q4".to_string()],
+            dependencies: Vec::new(),
+            dependents: vec![IssueWithDependencyMetadata {
+                id: "bd-task".to_string(),
+                title: "Write docs".to_string(),
+                status: Status::Closed,
+                priority: Priority::MEDIUM,
+                dep_type: "parent-child".to_string(),
+            }],
+            comments: vec![Comment {
+                id: 1,
+                issue_id: "bd-epic".to_string(),
+                author: "bob".to_string(),
+                body: "Ship it & celebrate".to_string(),
+                created_at: Utc::now(),
+            }],
+            events: vec![
+                event(2, EventType::StatusChanged, 5),
+                event(1, EventType::Created, 0),
+            ],
+            parent: None,
+        };
+        let status = EpicStatus {
+            epic,
+            total_children: 2,
+            closed_children: 1,
+            eligible_for_close: false,
+        };
+
+        let html = render_issue("demo", &details, Some(&status));
+        assert!(html.contains("href=\"../index.html\""));
+        assert!(html.contains("<a href=\"bd-task.html\">bd-task</a>"));
+        assert!(html.contains("1/2 closed (50%)"));
+        assert!(html.contains("Ship it &amp; celebrate"));
+        let created = html.find("<td>created</td>").unwrap();
+        let changed = html.find("<td>status_changed</td>").unwrap();
+        assert!(created < changed);
+    }
+
+    #[test]
+    fn test_render_epics_shows_progress() {
+        let epic = issue("bd-epic", "Launch");
+        let status = EpicStatus {
+            epic,
+            total_children: 0,
+            closed_children: 0,
+            eligible_for_close: false,
+        };
+        let html = render_epics("demo", &[(status, Vec::new())]);
+        assert!(html.contains("<a href=\"issues/bd-epic.html\">bd-epic</a>"));
+        assert!(html.contains("0/0 closed (0%)"));
+        assert!(render_epics("demo", &[]).contains("No epics."));
+    }
+}
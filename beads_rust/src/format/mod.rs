@@ -6,7 +6,8 @@
 //! # Output Types
 //!
 //! These types match the classic bd JSON schemas for CLI compatibility:
-//! - [`IssueWithCounts`] - Issue with dependency/dependent counts (list/search)
+//! - [`IssueWithCounts`] - Issue with dependency/dependent counts (list)
+//! - [`SearchResult`] - Issue with counts, relevance score and snippet (search)
 //! - [`IssueDetails`] - Issue with full relations (show)
 //! - [`BlockedIssue`] - Issue with blocking info (blocked)
 //! - [`TreeNode`] - Issue in dependency tree (dep tree)
@@ -26,6 +27,11 @@
 //! - Trees for dependency visualization
 //! - Consistent theming via [`Theme`]
 //!
+//! # HTML Export
+//!
+//! The [`html`] module renders the pages of the static site written by
+//! `br export --format html`.
+//!
 //! Output mode is determined by [`OutputContext`]:
 //! - Rich: TTY with colors enabled
 //! - Plain: TTY with `--no-color` or not a TTY
@@ -34,6 +40,7 @@
 
 pub mod context;
 pub mod csv;
+pub mod html;
 pub mod markdown;
 mod output;
 pub mod rich;
@@ -42,12 +49,12 @@ mod text;
 pub mod theme;
 
 pub use output::{
-    BlockedIssue, BlockedIssueOutput, Breakdown, BreakdownEntry, IssueDetails, IssueWithCounts,
-    IssueWithDependencyMetadata, ReadyIssue, RecentActivity, StaleIssue, Statistics, StatsSummary,
-    TreeNode,
+    AccuracyBreakdown, AccuracyEntry, BlockedIssue, BlockedIssueOutput, Breakdown, BreakdownEntry,
+    IssueDetails, IssueWithCounts, IssueWithDependencyMetadata, ProjectItem, ReadyIssue,
+    RecentActivity, SearchResult, StaleIssue, Statistics, StatsSummary, TreeNode,
 };
 pub use text::{
-    TextFormatOptions, format_issue_line, format_issue_line_with, format_priority,
+    TextFormatOptions, format_bytes, format_issue_line, format_issue_line_with, format_priority,
     format_priority_badge, format_priority_label, format_status_icon, format_status_icon_colored,
     format_status_label, format_type_badge, format_type_badge_colored, terminal_width,
     truncate_title,
@@ -110,6 +110,29 @@ pub struct IssueWithCounts {
     pub dependent_count: usize,
 }
 
+/// A result tagged with the project it came from (`--all-projects`).
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectItem<T> {
+    pub project: String,
+    #[serde(flatten)]
+    pub item: T,
+}
+
+/// Search row: issue with counts plus full-text relevance.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SearchResult {
+    #[serde(flatten)]
+    pub issue: Issue,
+    pub dependency_count: usize,
+    pub dependent_count: usize,
+    /// BM25 relevance, higher is better (absent for ID-only matches)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score: Option<f64>,
+    /// Best-matching excerpt with matched terms wrapped in `**`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<String>,
+}
+
 /// Issue details with full relations for show view.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct IssueDetails {
@@ -201,6 +224,27 @@ pub struct RecentActivity {
     pub total_changes: usize,
 }
 
+/// Estimate accuracy by a dimension (`br stats --accuracy`).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AccuracyBreakdown {
+    pub dimension: String,
+    pub entries: Vec<AccuracyEntry>,
+}
+
+/// Tracked time versus estimate for one group of closed issues.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AccuracyEntry {
+    pub key: String,
+    /// Closed issues with both an estimate and tracked time.
+    pub issues: usize,
+    pub estimated_minutes: i64,
+    pub actual_minutes: i64,
+    /// Total actual over total estimated (2.0 = took twice as long).
+    pub ratio: f64,
+    /// Median of the per-issue ratios, less swayed by a single outlier.
+    pub median_ratio: f64,
+}
+
 /// Aggregate statistics output.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Statistics {
@@ -209,6 +253,8 @@ pub struct Statistics {
     pub breakdowns: Vec<Breakdown>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub recent_activity: Option<RecentActivity>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub estimate_accuracy: Vec<AccuracyBreakdown>,
 }
 
 #[cfg(test)]
@@ -257,6 +303,9 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            time_entries: vec![],
+            recurrence: None,
+            attachments: vec![],
         }
     }
 
@@ -275,6 +324,29 @@ mod tests {
         assert!(json.contains("\"id\":\"bd-1\""));
     }
 
+    #[test]
+    fn search_result_omits_missing_relevance() {
+        let hit = SearchResult {
+            issue: base_issue("bd-3", "Search"),
+            dependency_count: 0,
+            dependent_count: 0,
+            score: Some(1.5),
+            snippet: Some("a **match** here".to_string()),
+        };
+        let json = serde_json::to_string(&hit).unwrap();
+        assert!(json.contains("\"score\":1.5"));
+        assert!(json.contains("\"snippet\":\"a **match** here\""));
+
+        let id_only = SearchResult {
+            score: None,
+            snippet: None,
+            ..hit
+        };
+        let json = serde_json::to_string(&id_only).unwrap();
+        assert!(!json.contains("score"));
+        assert!(!json.contains("snippet"));
+    }
+
     #[test]
     fn issue_details_serializes_parent_and_relations() {
         let issue = base_issue("bd-2", "Details");
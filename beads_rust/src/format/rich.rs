@@ -365,6 +365,9 @@ mod tests {
             is_template: false,
             dependencies: vec![],
             comments: vec![],
+            time_entries: vec![],
+            recurrence: None,
+            attachments: vec![],
         }
     }
 
@@ -0,0 +1,538 @@
+//! User-defined lifecycle hooks in `.beads/hooks/`.
+//!
+//! A hook is an executable whose file name (ignoring any extension) is
+//! `pre_<event>` or `on_<event>`, for the events `create`, `close`,
+//! `status_change` and `dep_added`:
+//!
+//! - `pre_*` hooks run before the change is written. A non-zero exit vetoes
+//!   it with [`BeadsError::HookRejected`], using the hook's stderr as reason.
+//! - `on_*` hooks run after the change. They only observe; failures are
+//!   printed as warnings.
+//!
+//! Each hook gets a JSON payload on stdin (`event`, `phase`, `actor`, the
+//! `issue`, plus `from`/`to`, `reason` or `dependency` depending on the
+//! event) and the `BR_HOOK_*` environment variables, and runs from the
+//! repository root. Several hooks for one event (`on_close.sh`,
+//! `on_close.py`) run in file name order.
+//!
+//! Hooks fire for `create`, `q`, `update`, `close`, `reopen`, `dep add` and
+//! the matching `br serve` tools. Sync and import never run hooks.
+//! Settings: `hooks.enabled` (default true) and `hooks.timeout` (seconds or
+//! a duration like `2m`, default 30s).
+
+use crate::config::ConfigLayer;
+use crate::error::{BeadsError, Result};
+use crate::model::{Issue, Status};
+use crate::util::time::parse_duration;
+use serde_json::{Value, json};
+use std::fs;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus, Stdio};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Hooks directory inside `.beads/`.
+pub const HOOKS_DIR: &str = "hooks";
+
+/// Time a hook may run before it is killed.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How often a running hook is polled for exit.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Whether a hook runs before or after the change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Pre,
+    Post,
+}
+
+impl Phase {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Pre => "pre",
+            Self::Post => "post",
+        }
+    }
+
+    /// File name prefix of hooks for this phase.
+    #[must_use]
+    pub const fn prefix(self) -> &'static str {
+        match self {
+            Self::Pre => "pre_",
+            Self::Post => "on_",
+        }
+    }
+}
+
+/// A mutation hooks can observe, with its event-specific payload fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HookEvent<'a> {
+    Create,
+    Close {
+        reason: &'a str,
+    },
+    StatusChange {
+        from: &'a Status,
+        to: &'a Status,
+    },
+    DepAdded {
+        depends_on_id: &'a str,
+        dep_type: &'a str,
+    },
+}
+
+impl HookEvent<'_> {
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Create => "create",
+            Self::Close { .. } => "close",
+            Self::StatusChange { .. } => "status_change",
+            Self::DepAdded { .. } => "dep_added",
+        }
+    }
+
+    /// JSON written to the hook's stdin.
+    #[must_use]
+    pub fn payload(&self, phase: Phase, actor: &str, issue: &Issue) -> Value {
+        let mut payload = json!({
+            "event": self.name(),
+            "phase": phase.as_str(),
+            "actor": actor,
+            "issue": issue,
+        });
+        match self {
+            Self::Create => {}
+            Self::Close { reason } => payload["reason"] = json!(reason),
+            Self::StatusChange { from, to } => {
+                payload["from"] = json!(from.as_str());
+                payload["to"] = json!(to.as_str());
+            }
+            Self::DepAdded {
+                depends_on_id,
+                dep_type,
+            } => {
+                payload["dependency"] = json!({
+                    "issue_id": issue.id,
+                    "depends_on_id": depends_on_id,
+                    "type": dep_type,
+                });
+            }
+        }
+        payload
+    }
+}
+
+/// Events fired by a status change: `status_change`, plus `close` when the
+/// issue is being closed.
+#[must_use]
+pub fn status_events<'a>(
+    from: &'a Status,
+    to: &'a Status,
+    close_reason: Option<&'a str>,
+) -> Vec<HookEvent<'a>> {
+    let mut events = vec![HookEvent::StatusChange { from, to }];
+    if *to == Status::Closed {
+        events.push(HookEvent::Close {
+            reason: close_reason.unwrap_or("done"),
+        });
+    }
+    events
+}
+
+/// Hooks configured for a workspace. The default runs nothing.
+#[derive(Debug, Clone, Default)]
+pub struct Hooks {
+    /// Hooks directory, or `None` when hooks are disabled or absent.
+    dir: Option<PathBuf>,
+    beads_dir: PathBuf,
+    timeout: Duration,
+}
+
+impl Hooks {
+    /// Load hook settings for the workspace at `beads_dir`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a config error if `hooks.enabled` or `hooks.timeout` is invalid.
+    pub fn load(beads_dir: &Path, layer: &ConfigLayer) -> Result<Self> {
+        let value = |key: &str| {
+            layer
+                .runtime
+                .get(key)
+                .map(|value| value.trim())
+                .filter(|value| !value.is_empty())
+        };
+        let enabled = match value("hooks.enabled").map(str::to_lowercase).as_deref() {
+            None | Some("1" | "true" | "yes" | "on") => true,
+            Some("0" | "false" | "no" | "off") => false,
+            Some(other) => {
+                return Err(BeadsError::Config(format!(
+                    "hooks.enabled must be true or false, got '{other}'"
+                )));
+            }
+        };
+        let timeout = value("hooks.timeout")
+            .map(parse_timeout)
+            .transpose()?
+            .unwrap_or(DEFAULT_TIMEOUT);
+
+        let dir = beads_dir.join(HOOKS_DIR);
+        Ok(Self {
+            dir: (enabled && dir.is_dir()).then_some(dir),
+            beads_dir: beads_dir.to_path_buf(),
+            timeout,
+        })
+    }
+
+    /// Whether any hook could run. Callers use this to skip building
+    /// payloads that would be thrown away.
+    #[must_use]
+    pub const fn is_active(&self) -> bool {
+        self.dir.is_some()
+    }
+
+    /// Run the `pre_<event>` hooks, stopping at the first that fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BeadsError::HookRejected`] if a hook exits non-zero, or
+    /// [`BeadsError::HookFailed`] if it cannot be started or times out.
+    pub fn pre(&self, event: &HookEvent<'_>, actor: &str, issue: &Issue) -> Result<()> {
+        for script in self.scripts(Phase::Pre, event) {
+            self.run(&script, Phase::Pre, event, actor, issue)?;
+        }
+        Ok(())
+    }
+
+    /// Run the `on_<event>` hooks, printing failures as warnings.
+    pub fn post(&self, event: &HookEvent<'_>, actor: &str, issue: &Issue) {
+        for script in self.scripts(Phase::Post, event) {
+            if let Err(err) = self.run(&script, Phase::Post, event, actor, issue) {
+                eprintln!("warning: {err}");
+            }
+        }
+    }
+
+    /// Executable hooks for `event` in `phase`, sorted by file name.
+    fn scripts(&self, phase: Phase, event: &HookEvent<'_>) -> Vec<PathBuf> {
+        let Some(dir) = &self.dir else {
+            return Vec::new();
+        };
+        let Ok(entries) = fs::read_dir(dir) else {
+            return Vec::new();
+        };
+        let wanted = format!("{}{}", phase.prefix(), event.name());
+        let mut scripts: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| {
+                let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+                    return false;
+                };
+                name.split('.').next() == Some(wanted.as_str())
+                    && !name.ends_with(".sample")
+                    && is_executable(path)
+            })
+            .collect();
+        scripts.sort();
+        scripts
+    }
+
+    fn run(
+        &self,
+        script: &Path,
+        phase: Phase,
+        event: &HookEvent<'_>,
+        actor: &str,
+        issue: &Issue,
+    ) -> Result<()> {
+        let hook = script
+            .file_name()
+            .map_or_else(String::new, |name| name.to_string_lossy().into_owned());
+        let failed = |reason: String| BeadsError::HookFailed {
+            hook: hook.clone(),
+            reason,
+        };
+
+        let payload = serde_json::to_vec(&event.payload(phase, actor, issue))?;
+        let root = self.beads_dir.parent().unwrap_or(&self.beads_dir);
+        let mut child = Command::new(script)
+            .current_dir(root)
+            .env("BR_HOOK_EVENT", event.name())
+            .env("BR_HOOK_PHASE", phase.as_str())
+            .env("BR_ISSUE_ID", &issue.id)
+            .env("BR_ACTOR", actor)
+            .env("BR_BEADS_DIR", &self.beads_dir)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| failed(format!("could not start: {e}")))?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            // A hook that ignores its input closes the pipe early; that is fine.
+            thread::spawn(move || {
+                let _ = stdin.write_all(&payload);
+            });
+        }
+        let stdout = read_pipe(child.stdout.take());
+        let stderr = read_pipe(child.stderr.take());
+
+        let deadline = Instant::now() + self.timeout;
+        let status = loop {
+            if let Some(status) = child
+                .try_wait()
+                .map_err(|e| failed(format!("could not wait: {e}")))?
+            {
+                break status;
+            }
+            if Instant::now() >= deadline {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(failed(format!(
+                    "timed out after {}s",
+                    self.timeout.as_secs_f64()
+                )));
+            }
+            thread::sleep(POLL_INTERVAL);
+        };
+        if status.success() {
+            return Ok(());
+        }
+
+        // A background process started by the hook may keep the pipes open,
+        // so output is only collected until the deadline.
+        let stderr = collect(&stderr, deadline);
+        let stdout = collect(&stdout, deadline);
+        let reason = failure_reason(status, &stderr, &stdout);
+        match phase {
+            Phase::Pre => Err(BeadsError::HookRejected { hook, reason }),
+            Phase::Post => Err(failed(reason)),
+        }
+    }
+}
+
+/// Parse `hooks.timeout`: bare numbers are seconds.
+fn parse_timeout(value: &str) -> Result<Duration> {
+    let parsed = if value.chars().all(|c| c.is_ascii_digit()) {
+        parse_duration(&format!("{value}s"), "hooks.timeout")
+    } else {
+        parse_duration(value, "hooks.timeout")
+    };
+    parsed
+        .ok()
+        .and_then(|duration| duration.to_std().ok())
+        .ok_or_else(|| {
+            BeadsError::Config(format!(
+                "hooks.timeout must be a positive duration like 30 or 2m, got '{value}'"
+            ))
+        })
+}
+
+fn read_pipe(pipe: Option<impl Read + Send + 'static>) -> mpsc::Receiver<Vec<u8>> {
+    let (tx, rx) = mpsc::channel();
+    if let Some(mut pipe) = pipe {
+        thread::spawn(move || {
+            let mut buf = Vec::new();
+            let _ = pipe.read_to_end(&mut buf);
+            let _ = tx.send(buf);
+        });
+    }
+    rx
+}
+
+fn collect(rx: &mpsc::Receiver<Vec<u8>>, deadline: Instant) -> String {
+    let wait = deadline.saturating_duration_since(Instant::now());
+    rx.recv_timeout(wait)
+        .map(|buf| String::from_utf8_lossy(&buf).trim().to_string())
+        .unwrap_or_default()
+}
+
+fn failure_reason(status: ExitStatus, stderr: &str, stdout: &str) -> String {
+    if !stderr.is_empty() {
+        return stderr.to_string();
+    }
+    if !stdout.is_empty() {
+        return stdout.to_string();
+    }
+    status.code().map_or_else(
+        || "terminated by a signal".to_string(),
+        |code| format!("exited with status {code}"),
+    )
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    fs::metadata(path).is_ok_and(|meta| meta.is_file() && meta.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Priority;
+    use chrono::Utc;
+    use tempfile::TempDir;
+
+    fn issue() -> Issue {
+        let now = Utc::now();
+        Issue {
+            id: "bd-1".to_string(),
+            title: "Hooked".to_string(),
+            priority: Priority::MEDIUM,
+            created_at: now,
+            updated_at: now,
+            ..Issue::default()
+        }
+    }
+
+    fn layer(pairs: &[(&str, &str)]) -> ConfigLayer {
+        let mut layer = ConfigLayer::default();
+        for (key, value) in pairs {
+            layer
+                .runtime
+                .insert((*key).to_string(), (*value).to_string());
+        }
+        layer
+    }
+
+    #[cfg(unix)]
+    fn write_hook(beads_dir: &Path, name: &str, body: &str) {
+        use std::os::unix::fs::PermissionsExt;
+        let dir = beads_dir.join(HOOKS_DIR);
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(name);
+        fs::write(&path, format!("#!/bin/sh\n{body}\n")).unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+    }
+
+    #[test]
+    fn test_payload_fields_per_event() {
+        let issue = issue();
+        let payload = HookEvent::StatusChange {
+            from: &Status::Open,
+            to: &Status::InProgress,
+        }
+        .payload(Phase::Pre, "alice", &issue);
+        assert_eq!(payload["event"], "status_change");
+        assert_eq!(payload["phase"], "pre");
+        assert_eq!(payload["from"], "open");
+        assert_eq!(payload["to"], "in_progress");
+        assert_eq!(payload["issue"]["id"], "bd-1");
+
+        let payload = HookEvent::DepAdded {
+            depends_on_id: "bd-2",
+            dep_type: "blocks",
+        }
+        .payload(Phase::Post, "alice", &issue);
+        assert_eq!(payload["dependency"]["issue_id"], "bd-1");
+        assert_eq!(payload["dependency"]["depends_on_id"], "bd-2");
+        assert!(payload.get("from").is_none());
+    }
+
+    #[test]
+    fn test_status_events_add_close() {
+        let events = status_events(&Status::Open, &Status::Closed, Some("fixed"));
+        let names: Vec<_> = events.iter().map(HookEvent::name).collect();
+        assert_eq!(names, ["status_change", "close"]);
+        assert_eq!(events[1], HookEvent::Close { reason: "fixed" });
+        assert_eq!(status_events(&Status::Closed, &Status::Open, None).len(), 1);
+    }
+
+    #[test]
+    fn test_load_settings() {
+        let temp = TempDir::new().unwrap();
+        fs::create_dir_all(temp.path().join(HOOKS_DIR)).unwrap();
+
+        let hooks = Hooks::load(temp.path(), &layer(&[])).unwrap();
+        assert!(hooks.is_active());
+        assert_eq!(hooks.timeout, DEFAULT_TIMEOUT);
+
+        let hooks = Hooks::load(temp.path(), &layer(&[("hooks.timeout", "5")])).unwrap();
+        assert_eq!(hooks.timeout, Duration::from_secs(5));
+        let hooks = Hooks::load(temp.path(), &layer(&[("hooks.timeout", "2m")])).unwrap();
+        assert_eq!(hooks.timeout, Duration::from_secs(120));
+
+        let hooks = Hooks::load(temp.path(), &layer(&[("hooks.enabled", "false")])).unwrap();
+        assert!(!hooks.is_active());
+
+        assert!(Hooks::load(temp.path(), &layer(&[("hooks.enabled", "maybe")])).is_err());
+        assert!(Hooks::load(temp.path(), &layer(&[("hooks.timeout", "-1")])).is_err());
+    }
+
+    #[test]
+    fn test_missing_dir_is_inactive() {
+        let temp = TempDir::new().unwrap();
+        let hooks = Hooks::load(temp.path(), &layer(&[])).unwrap();
+        assert!(!hooks.is_active());
+        assert!(hooks.pre(&HookEvent::Create, "alice", &issue()).is_ok());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_scripts_match_event_and_skip_samples() {
+        let temp = TempDir::new().unwrap();
+        write_hook(temp.path(), "pre_close.sh", "exit 0");
+        write_hook(temp.path(), "pre_close", "exit 0");
+        write_hook(temp.path(), "pre_close.sample", "exit 1");
+        write_hook(temp.path(), "pre_closed", "exit 1");
+        write_hook(temp.path(), "on_close", "exit 1");
+        fs::write(temp.path().join(HOOKS_DIR).join("pre_close.txt"), "").unwrap();
+
+        let hooks = Hooks::load(temp.path(), &layer(&[])).unwrap();
+        let names: Vec<_> = hooks
+            .scripts(Phase::Pre, &HookEvent::Close { reason: "done" })
+            .iter()
+            .map(|path| path.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(names, ["pre_close", "pre_close.sh"]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_pre_hook_veto_reports_stderr() {
+        let temp = TempDir::new().unwrap();
+        write_hook(
+            temp.path(),
+            "pre_create",
+            "grep -q '\"title\":\"Hooked\"' || exit 0\necho 'no hooks allowed' >&2\nexit 3",
+        );
+        let hooks = Hooks::load(temp.path(), &layer(&[])).unwrap();
+        let err = hooks
+            .pre(&HookEvent::Create, "alice", &issue())
+            .unwrap_err();
+        match err {
+            BeadsError::HookRejected { hook, reason } => {
+                assert_eq!(hook, "pre_create");
+                assert_eq!(reason, "no hooks allowed");
+            }
+            other => panic!("unexpected error: {other}"),
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_hook_timeout_is_a_failure() {
+        let temp = TempDir::new().unwrap();
+        write_hook(temp.path(), "pre_create", "sleep 5");
+        let hooks = Hooks::load(temp.path(), &layer(&[("hooks.timeout", "1s")])).unwrap();
+        let started = Instant::now();
+        let err = hooks
+            .pre(&HookEvent::Create, "alice", &issue())
+            .unwrap_err();
+        assert!(started.elapsed() < Duration::from_secs(4));
+        assert!(
+            matches!(&err, BeadsError::HookFailed { reason, .. } if reason.contains("timed out")),
+            "{err}"
+        );
+    }
+}
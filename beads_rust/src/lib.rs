@@ -7,23 +7,29 @@
 //!
 //! The crate is organized into the following modules:
 //!
+//! - [`attachments`] - Content-addressed attachment blobs in `.beads/blobs/`
 //! - [`cli`] - Command-line interface using clap
 //! - [`model`] - Data types (Issue, Dependency, Comment, Event)
 //! - [`storage`] - `SQLite` database layer
 //! - [`sync`] - JSONL import/export operations
+//! - [`bridge`] - Two-way sync with GitHub/GitLab issues
 //! - [`config`] - Configuration management
 //! - [`error`] - Error types and handling
 //! - [`format`] - Output formatting (text, JSON)
+//! - [`hooks`] - User-defined lifecycle hooks in `.beads/hooks/`
 //! - [`util`] - Utility functions (hashing, time, paths)
 
 #![forbid(unsafe_code)]
 // Lint configuration is in Cargo.toml [lints.clippy] section
 #![allow(clippy::module_name_repetitions)]
 
+pub mod attachments;
+pub mod bridge;
 pub mod cli;
 pub mod config;
 pub mod error;
 pub mod format;
+pub mod hooks;
 pub mod logging;
 pub mod model;
 pub mod output;
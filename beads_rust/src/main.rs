@@ -39,8 +39,9 @@ fn main() {
         Commands::Init {
             prefix,
             force,
+            merge_driver,
             backend: _,
-        } => commands::init::execute(prefix, force, None, &output_ctx),
+        } => commands::init::execute(prefix, force, merge_driver, None, &output_ctx),
         Commands::Create(args) => commands::create::execute(&args, &overrides, &output_ctx),
         Commands::Update(args) => commands::update::execute(&args, &overrides, &output_ctx),
         Commands::Delete(args) => {
@@ -78,7 +79,13 @@ fn main() {
             commands::blocked::execute(&args, cli.json || args.robot, &overrides, &output_ctx)
         }
         Commands::Sync(args) => commands::sync::execute(&args, cli.json, &overrides, &output_ctx),
-        Commands::Doctor => commands::doctor::execute(&overrides, &output_ctx),
+        Commands::MergeDriver(args) => {
+            commands::merge_driver::execute(&args, cli.json, &output_ctx)
+        }
+        Commands::Bridge { command } => {
+            commands::bridge::execute(&command, cli.json, &overrides, &output_ctx)
+        }
+        Commands::Doctor(args) => commands::doctor::execute(&args, &overrides, &output_ctx),
         Commands::Info(args) => commands::info::execute(&args, &overrides, &output_ctx),
         Commands::Schema(args) => commands::schema::execute(&args, &overrides, &output_ctx),
         Commands::Where => commands::r#where::execute(&overrides, &output_ctx),
@@ -93,6 +100,9 @@ fn main() {
         Commands::Stats(args) | Commands::Status(args) => {
             commands::stats::execute(&args, cli.json || args.robot, &overrides, &output_ctx)
         }
+        Commands::Analytics(args) => {
+            commands::analytics::execute(&args, cli.json || args.robot, &overrides, &output_ctx)
+        }
         Commands::Config { command } => {
             commands::config::execute(&command, cli.json, &overrides, &output_ctx)
         }
@@ -103,6 +113,21 @@ fn main() {
         Commands::Undefer(args) => {
             commands::defer::execute_undefer(&args, cli.json || args.robot, &overrides, &output_ctx)
         }
+        Commands::Claim(args) => {
+            commands::claim::execute(&args, cli.json || args.robot, &overrides, &output_ctx)
+        }
+        Commands::Attach(args) => {
+            commands::attach::execute(&args, cli.json, &overrides, &output_ctx)
+        }
+        Commands::Time { command } => {
+            commands::time::execute(&command, cli.json, &overrides, &output_ctx)
+        }
+        Commands::Recur { command } => {
+            commands::recur::execute(&command, cli.json, &overrides, &output_ctx)
+        }
+        Commands::Workflow { command } => {
+            commands::workflow::execute(&command, cli.json, &overrides, &output_ctx)
+        }
         Commands::Orphans(args) => {
             commands::orphans::execute(&args, cli.json || args.robot, &overrides, &output_ctx)
         }
@@ -111,6 +136,12 @@ fn main() {
         }
         Commands::Query { command } => commands::query::execute(&command, &overrides, &output_ctx),
         Commands::Graph(args) => commands::graph::execute(&args, &overrides, &output_ctx),
+        Commands::Export(args) => {
+            commands::export::execute(&args, cli.json, &overrides, &output_ctx)
+        }
+        Commands::Plan(args) => commands::plan::execute(&args, &overrides, &output_ctx),
+        Commands::Serve(args) => commands::serve::execute(&args, &overrides, &output_ctx),
+        Commands::Watch(args) => commands::watch::execute(&args, &overrides, &output_ctx),
         Commands::Agents(args) => {
             let agents_args = commands::agents::AgentsArgs {
                 add: args.add,
@@ -148,11 +179,28 @@ const fn is_mutating_command(cmd: &Commands) -> bool {
         | Commands::Label { .. }
         | Commands::Comments(_)
         | Commands::Defer(_)
-        | Commands::Undefer(_) => true,
+        | Commands::Undefer(_)
+        | Commands::Claim(_) => true,
         Commands::Epic { command } => matches!(
             command,
             beads_rust::cli::EpicCommands::CloseEligible(args) if !args.dry_run
         ),
+        // Listing leaves nothing dirty, so flushing after it is a no-op.
+        Commands::Attach(args) => args.get.is_none(),
+        Commands::Time { command } => !matches!(command, beads_rust::cli::TimeCommands::List(_)),
+        Commands::Recur { command } => match command {
+            beads_rust::cli::RecurCommands::Set(_) | beads_rust::cli::RecurCommands::Remove(_) => {
+                true
+            }
+            beads_rust::cli::RecurCommands::Run(args) => !args.dry_run,
+            beads_rust::cli::RecurCommands::List => false,
+        },
+        Commands::Bridge { command } => match command {
+            beads_rust::cli::BridgeCommands::Sync(args) => !args.dry_run,
+            beads_rust::cli::BridgeCommands::Link(args) => !args.dry_run,
+            beads_rust::cli::BridgeCommands::Unlink(_) => true,
+            beads_rust::cli::BridgeCommands::Status => false,
+        },
         _ => false,
     }
 }
@@ -173,9 +221,12 @@ const fn should_auto_import(cmd: &Commands) -> bool {
         | Commands::Lint(_)
         | Commands::Stats(_)
         | Commands::Status(_)
+        | Commands::Analytics(_)
         | Commands::Orphans(_)
         | Commands::Changelog(_)
         | Commands::Graph(_)
+        | Commands::Export(_)
+        | Commands::Plan(_)
         | Commands::Create(_)
         | Commands::Update(_)
         | Commands::Delete(_)
@@ -184,16 +235,23 @@ const fn should_auto_import(cmd: &Commands) -> bool {
         | Commands::Q(_)
         | Commands::Defer(_)
         | Commands::Undefer(_)
+        | Commands::Claim(_)
+        | Commands::Time { .. }
+        | Commands::Recur { .. }
+        | Commands::Bridge { .. }
         | Commands::Comments(_)
+        | Commands::Attach(_)
         | Commands::Dep { .. }
         | Commands::Label { .. }
         | Commands::Epic { .. }
-        | Commands::Query { .. } => true,
+        | Commands::Query { .. }
+        | Commands::Serve(_) => true,
 
         // Explicitly excluded: init, sync, diagnostic, and config commands
         Commands::Init { .. }
         | Commands::Sync(_)
-        | Commands::Doctor
+        | Commands::MergeDriver(_)
+        | Commands::Doctor(_)
         | Commands::Info(_)
         | Commands::Schema(_)
         | Commands::Where
@@ -201,9 +259,13 @@ const fn should_auto_import(cmd: &Commands) -> bool {
         | Commands::Completions(_)
         | Commands::Audit { .. }
         | Commands::Config { .. }
+        | Commands::Workflow { .. }
         | Commands::History(_)
         | Commands::Agents(_) => false,
 
+        // Watch imports JSONL itself so the imported changes are emitted.
+        Commands::Watch(_) => false,
+
         #[cfg(feature = "self_update")]
         Commands::Upgrade(_) => false,
     }
@@ -366,6 +428,7 @@ mod tests {
             dry_run: false,
             silent: false,
             file: None,
+            template: None,
         }
     }
 
@@ -295,6 +295,14 @@ pub enum EventType {
     Compacted,
     Deleted,
     Restored,
+    Claimed,
+    LeaseRenewed,
+    LeaseReleased,
+    LeaseExpired,
+    TimerStarted,
+    TimeLogged,
+    Attached,
+    Detached,
     Custom(String),
 }
 
@@ -317,6 +325,14 @@ impl EventType {
             Self::Compacted => "compacted",
             Self::Deleted => "deleted",
             Self::Restored => "restored",
+            Self::Claimed => "claimed",
+            Self::LeaseRenewed => "lease_renewed",
+            Self::LeaseReleased => "lease_released",
+            Self::LeaseExpired => "lease_expired",
+            Self::TimerStarted => "timer_started",
+            Self::TimeLogged => "time_logged",
+            Self::Attached => "attached",
+            Self::Detached => "detached",
             Self::Custom(value) => value,
         }
     }
@@ -347,6 +363,14 @@ impl<'de> Deserialize<'de> for EventType {
             "compacted" => Self::Compacted,
             "deleted" => Self::Deleted,
             "restored" => Self::Restored,
+            "claimed" => Self::Claimed,
+            "lease_renewed" => Self::LeaseRenewed,
+            "lease_released" => Self::LeaseReleased,
+            "lease_expired" => Self::LeaseExpired,
+            "timer_started" => Self::TimerStarted,
+            "time_logged" => Self::TimeLogged,
+            "attached" => Self::Attached,
+            "detached" => Self::Detached,
             _ => Self::Custom(value),
         };
         Ok(event_type)
@@ -500,6 +524,12 @@ pub struct Issue {
     pub dependencies: Vec<Dependency>,
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub comments: Vec<Comment>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub time_entries: Vec<TimeEntry>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub recurrence: Option<Recurrence>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub attachments: Vec<Attachment>,
 }
 
 impl Default for Issue {
@@ -544,6 +574,9 @@ impl Default for Issue {
             labels: Vec::new(),
             dependencies: Vec::new(),
             comments: Vec::new(),
+            time_entries: Vec::new(),
+            recurrence: None,
+            attachments: Vec::new(),
         }
     }
 }
@@ -635,6 +668,89 @@ pub struct Comment {
     pub created_at: DateTime<Utc>,
 }
 
+/// A work session tracked against an issue (`br time`).
+///
+/// Entries are exported with their issue in JSONL. A running timer has no
+/// `ended_at` and counts zero minutes until it is stopped.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+pub struct TimeEntry {
+    pub id: i64,
+    pub issue_id: String,
+    pub actor: String,
+    pub started_at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ended_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub minutes: i32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+impl TimeEntry {
+    /// Whether this is a timer that has not been stopped yet.
+    #[must_use]
+    pub const fn is_running(&self) -> bool {
+        self.ended_at.is_none()
+    }
+
+    /// Minutes worked as of `now`, counting a running timer up to `now`.
+    #[must_use]
+    pub fn minutes_at(&self, now: DateTime<Utc>) -> i64 {
+        if self.is_running() {
+            elapsed_minutes(self.started_at, now)
+        } else {
+            i64::from(self.minutes)
+        }
+    }
+}
+
+/// Recurrence rule attached to a template issue (`br recur`).
+///
+/// Exported with the template in JSONL. Each occurrence is materialized as a
+/// regular issue deferred until the occurrence and due `due_minutes` after it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+pub struct Recurrence {
+    /// Cron expression (`0 9 * * MON`) or RRULE (`FREQ=WEEKLY;BYDAY=MO`), in UTC.
+    pub rule: String,
+    /// Occurrences before this instant are never materialized.
+    pub starts_at: DateTime<Utc>,
+    /// Materialize instances this many minutes ahead of their occurrence.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lead_minutes: Option<i64>,
+    /// Due window after the occurrence.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due_minutes: Option<i64>,
+}
+
+/// A file attached to an issue (`br attach`).
+///
+/// The metadata is exported with the issue in JSONL; the content lives in
+/// `.beads/blobs/` under its SHA-256. Blobs over `attachments.git_max_size`
+/// are kept out of git, so other clones see the record but not the content.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+pub struct Attachment {
+    /// File name, unique per issue.
+    pub name: String,
+    /// Lowercase hex SHA-256 of the content.
+    pub sha256: String,
+    /// Size in bytes.
+    pub size: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub media_type: Option<String>,
+    pub created_at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+    /// The blob is not committed to git.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub local_only: bool,
+}
+
+/// Whole minutes between two instants, rounded to nearest and never negative.
+#[must_use]
+pub fn elapsed_minutes(start: DateTime<Utc>, end: DateTime<Utc>) -> i64 {
+    ((end - start).num_seconds().max(0) + 30) / 60
+}
+
 /// An event in the issue's history (audit log).
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
 pub struct Event {
@@ -651,6 +767,26 @@ pub struct Event {
     pub created_at: DateTime<Utc>,
 }
 
+/// A time-limited claim on an issue held by a single actor.
+///
+/// Leases are local coordination state (like the blocked cache) and are
+/// never exported to JSONL.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+pub struct ClaimLease {
+    pub issue_id: String,
+    pub actor: String,
+    pub claimed_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl ClaimLease {
+    /// Whether the lease has lapsed as of `now`.
+    #[must_use]
+    pub fn is_expired_at(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -731,6 +867,9 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            time_entries: vec![],
+            recurrence: None,
+            attachments: vec![],
         };
 
         let json = serde_json::to_string(&issue).unwrap();
@@ -1186,6 +1325,9 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            time_entries: vec![],
+            recurrence: None,
+            attachments: vec![],
         }
     }
 
@@ -1356,6 +1498,14 @@ mod tests {
         assert_eq!(EventType::Compacted.as_str(), "compacted");
         assert_eq!(EventType::Deleted.as_str(), "deleted");
         assert_eq!(EventType::Restored.as_str(), "restored");
+        assert_eq!(EventType::Claimed.as_str(), "claimed");
+        assert_eq!(EventType::LeaseRenewed.as_str(), "lease_renewed");
+        assert_eq!(EventType::LeaseReleased.as_str(), "lease_released");
+        assert_eq!(EventType::LeaseExpired.as_str(), "lease_expired");
+        assert_eq!(EventType::TimerStarted.as_str(), "timer_started");
+        assert_eq!(EventType::TimeLogged.as_str(), "time_logged");
+        assert_eq!(EventType::Attached.as_str(), "attached");
+        assert_eq!(EventType::Detached.as_str(), "detached");
         assert_eq!(
             EventType::Custom("my_event".to_string()).as_str(),
             "my_event"
@@ -1380,6 +1530,14 @@ mod tests {
             ("\"compacted\"", EventType::Compacted),
             ("\"deleted\"", EventType::Deleted),
             ("\"restored\"", EventType::Restored),
+            ("\"claimed\"", EventType::Claimed),
+            ("\"lease_renewed\"", EventType::LeaseRenewed),
+            ("\"lease_released\"", EventType::LeaseReleased),
+            ("\"lease_expired\"", EventType::LeaseExpired),
+            ("\"timer_started\"", EventType::TimerStarted),
+            ("\"time_logged\"", EventType::TimeLogged),
+            ("\"attached\"", EventType::Attached),
+            ("\"detached\"", EventType::Detached),
         ];
 
         for (json, expected) in events {
@@ -1,5 +1,5 @@
-use crate::format::{IssueDetails, IssueWithDependencyMetadata};
-use crate::model::{Comment, Dependency, Issue};
+use crate::format::{IssueDetails, IssueWithDependencyMetadata, format_bytes};
+use crate::model::{Attachment, Comment, Dependency, Issue};
 use crate::output::{OutputContext, Theme};
 use rich_rust::prelude::*;
 
@@ -165,6 +165,9 @@ impl<'a> IssuePanel<'a> {
             }
         }
 
+        // Attachments
+        render_attachments(&self.issue.attachments, &mut content, self.theme);
+
         // Build and print panel
         let panel_width = if wrap { ctx.width() } else { 80 };
         let content = if wrap {
@@ -194,6 +197,29 @@ fn wrap_rich_text(text: &Text, panel_width: usize) -> Text {
     wrapped
 }
 
+fn render_attachments(attachments: &[Attachment], content: &mut Text, theme: &Theme) {
+    if attachments.is_empty() {
+        return;
+    }
+    content.append_styled("\nAttachments:\n", theme.emphasis.clone());
+    for attachment in attachments {
+        content.append("  ");
+        content.append(&attachment.name);
+        content.append_styled(
+            &format!(
+                " {} {}",
+                format_bytes(attachment.size),
+                &attachment.sha256[..attachment.sha256.len().min(12)]
+            ),
+            theme.dimmed.clone(),
+        );
+        if attachment.local_only {
+            content.append_styled(" local only", theme.warning.clone());
+        }
+        content.append("\n");
+    }
+}
+
 fn render_dependency_list(
     title: &str,
     deps: &[IssueWithDependencyMetadata],
@@ -457,6 +457,61 @@ pub fn get_all_events(conn: &Connection, limit: usize) -> Result<Vec<Event>> {
     Ok(events)
 }
 
+/// Get events with an ID greater than `after_id`, oldest first.
+///
+/// Event IDs are never reused (`AUTOINCREMENT`), so the last ID a reader has
+/// seen works as a resume cursor.
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+pub fn get_events_after(conn: &Connection, after_id: i64) -> Result<Vec<Event>> {
+    let mut stmt = conn.prepare(
+        r"
+        SELECT id, issue_id, event_type, actor, old_value, new_value, comment, created_at
+        FROM events
+        WHERE id > ?1
+        ORDER BY id ASC
+        ",
+    )?;
+    let events = stmt
+        .query_map(params![after_id], event_from_row)?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    Ok(events)
+}
+
+/// Get all events of one type, oldest first.
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+pub fn get_events_of_type(conn: &Connection, event_type: &EventType) -> Result<Vec<Event>> {
+    let mut stmt = conn.prepare(
+        r"
+        SELECT id, issue_id, event_type, actor, old_value, new_value, comment, created_at
+        FROM events
+        WHERE event_type = ?1
+        ORDER BY created_at ASC, id ASC
+        ",
+    )?;
+    let events = stmt
+        .query_map(params![event_type.as_str()], event_from_row)?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    Ok(events)
+}
+
+/// Get the highest event ID, or 0 if there are no events.
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+pub fn latest_event_id(conn: &Connection) -> Result<i64> {
+    let id: i64 = conn.query_row("SELECT COALESCE(MAX(id), 0) FROM events", [], |row| {
+        row.get(0)
+    })?;
+    Ok(id)
+}
+
 /// Get event count for an issue.
 ///
 /// # Errors
@@ -489,6 +544,14 @@ fn parse_event_type(s: &str) -> EventType {
         "compacted" => EventType::Compacted,
         "deleted" => EventType::Deleted,
         "restored" => EventType::Restored,
+        "claimed" => EventType::Claimed,
+        "lease_renewed" => EventType::LeaseRenewed,
+        "lease_released" => EventType::LeaseReleased,
+        "lease_expired" => EventType::LeaseExpired,
+        "timer_started" => EventType::TimerStarted,
+        "time_logged" => EventType::TimeLogged,
+        "attached" => EventType::Attached,
+        "detached" => EventType::Detached,
         other => EventType::Custom(other.to_string()),
     }
 }
@@ -758,6 +821,26 @@ mod tests {
         assert_eq!(all_events.len(), 2);
     }
 
+    #[test]
+    fn test_get_events_after_cursor() {
+        let conn = setup_test_db();
+        assert_eq!(latest_event_id(&conn).unwrap(), 0);
+
+        let tx = conn.unchecked_transaction().expect("Failed to start tx");
+        let first = insert_created_event(&tx, "test-001", "alice").expect("Created");
+        insert_status_changed_event(&tx, "test-001", "alice", "open", "in_progress")
+            .expect("Status change");
+        let last = insert_closed_event(&tx, "test-001", "alice", None).expect("Closed");
+        tx.commit().expect("Commit");
+
+        assert_eq!(latest_event_id(&conn).unwrap(), last);
+        let events = get_events_after(&conn, first).expect("Failed to get events");
+        let types: Vec<EventType> = events.into_iter().map(|e| e.event_type).collect();
+        // Oldest first, starting after the cursor.
+        assert_eq!(types, vec![EventType::StatusChanged, EventType::Closed]);
+        assert!(get_events_after(&conn, last).unwrap().is_empty());
+    }
+
     #[test]
     fn test_multiple_event_types_sequence() {
         let conn = setup_test_db();
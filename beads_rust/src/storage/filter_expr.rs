@@ -0,0 +1,723 @@
+//! Filter expression language for `br list --where`.
+//!
+//! Expressions combine `field<op>value` conditions with `AND`, `OR`, `NOT`
+//! and parentheses; adjacent conditions without an operator are AND-ed:
+//!
+//! ```text
+//! status:open AND (label:backend OR priority<=1) AND updated>-7d AND blocked_by:bd-abc
+//! ```
+//!
+//! Expressions are parsed into a [`FilterExpr`] tree (values are validated at
+//! parse time so errors can point at the offending token) and compiled to a
+//! parameterized SQL fragment that [`crate::storage::SqliteStorage::list_issues`]
+//! appends to its `WHERE` clause.
+//!
+//! | Field | Operators | Values |
+//! |-------|-----------|--------|
+//! | `status`, `id`, `label` | `:` `=` `!=` | exact match |
+//! | `type` | `:` `=` `!=` | built-in or custom type name |
+//! | `priority` | `:` `=` `!=` `<` `<=` `>` `>=` | `0`-`4` or `P0`-`P4` |
+//! | `assignee`, `owner` | `:` `=` `!=` | name, or `none` for unset |
+//! | `title`, `description`/`desc`, `notes` | `:` `=` `!=` | case-insensitive substring |
+//! | `created`, `updated`, `closed`, `due`, `defer` | `<` `<=` `>` `>=` | `2025-01-15`, RFC3339, `-7d`, `+1w`, `tomorrow` |
+//!
+//! A timestamp condition is false for an issue without that timestamp, so
+//! `NOT due<+1w` includes issues with no due date.
+//! | `blocked_by`, `blocks`, `parent` | `:` `=` `!=` | issue ID |
+
+use crate::error::{BeadsError, Result};
+use crate::model::{IssueType, Priority, Status};
+use crate::util::time::parse_flexible_timestamp;
+use chrono::{DateTime, Utc};
+use std::fmt::Write as _;
+
+/// Maximum depth of nested parentheses and `NOT`s.
+const MAX_NESTING: usize = 64;
+
+/// A field that can appear on the left of a condition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    Id,
+    Status,
+    Type,
+    Priority,
+    Assignee,
+    Owner,
+    Label,
+    Title,
+    Description,
+    Notes,
+    Created,
+    Updated,
+    Closed,
+    Due,
+    Defer,
+    BlockedBy,
+    Blocks,
+    Parent,
+}
+
+impl Field {
+    fn from_name(name: &str) -> Option<Self> {
+        let field = match name.to_ascii_lowercase().as_str() {
+            "id" => Self::Id,
+            "status" => Self::Status,
+            "type" | "issue_type" => Self::Type,
+            "priority" | "p" => Self::Priority,
+            "assignee" => Self::Assignee,
+            "owner" => Self::Owner,
+            "label" | "labels" => Self::Label,
+            "title" => Self::Title,
+            "description" | "desc" => Self::Description,
+            "notes" => Self::Notes,
+            "created" | "created_at" => Self::Created,
+            "updated" | "updated_at" => Self::Updated,
+            "closed" | "closed_at" => Self::Closed,
+            "due" | "due_at" => Self::Due,
+            "defer" | "defer_until" => Self::Defer,
+            "blocked_by" => Self::BlockedBy,
+            "blocks" => Self::Blocks,
+            "parent" => Self::Parent,
+            _ => return None,
+        };
+        Some(field)
+    }
+
+    const fn is_ordered(self) -> bool {
+        matches!(
+            self,
+            Self::Priority | Self::Created | Self::Updated | Self::Closed | Self::Due | Self::Defer
+        )
+    }
+
+    const fn is_timestamp(self) -> bool {
+        matches!(
+            self,
+            Self::Created | Self::Updated | Self::Closed | Self::Due | Self::Defer
+        )
+    }
+}
+
+/// Comparison operator of a condition. `:` and `=` both parse to `Eq`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl CompareOp {
+    const fn as_sql(self) -> &'static str {
+        match self {
+            Self::Eq => "=",
+            Self::Ne => "!=",
+            Self::Lt => "<",
+            Self::Le => "<=",
+            Self::Gt => ">",
+            Self::Ge => ">=",
+        }
+    }
+}
+
+/// Right-hand side of a condition, already validated for its field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Text(String),
+    Status(Status),
+    Type(IssueType),
+    Priority(Priority),
+    Timestamp(DateTime<Utc>),
+    /// `assignee:none` / `owner:none`
+    Unset,
+}
+
+/// A single `field<op>value` test.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Condition {
+    pub field: Field,
+    pub op: CompareOp,
+    pub value: Value,
+}
+
+/// Parsed filter expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FilterExpr {
+    And(Box<Self>, Box<Self>),
+    Or(Box<Self>, Box<Self>),
+    Not(Box<Self>),
+    Condition(Condition),
+}
+
+impl FilterExpr {
+    /// Parse an expression.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BeadsError::QueryParse`] with the 1-based character position
+    /// of the offending token if the expression is malformed or a value is
+    /// invalid for its field.
+    pub fn parse(input: &str) -> Result<Self> {
+        let mut parser = Parser {
+            input,
+            chars: input.chars().collect(),
+            pos: 0,
+            depth: 0,
+        };
+        parser.skip_whitespace();
+        if parser.at_end() {
+            return Err(parser.error_at(0, "expression is empty"));
+        }
+        let expr = parser.parse_or()?;
+        parser.skip_whitespace();
+        if !parser.at_end() {
+            let message = if parser.peek() == Some(')') {
+                "unmatched ')'"
+            } else {
+                "expected AND, OR or end of expression"
+            };
+            return Err(parser.error_at(parser.pos, message));
+        }
+        Ok(expr)
+    }
+
+    /// Whether any condition in the expression tests `field`.
+    #[must_use]
+    pub fn references(&self, field: Field) -> bool {
+        match self {
+            Self::And(left, right) | Self::Or(left, right) => {
+                left.references(field) || right.references(field)
+            }
+            Self::Not(inner) => inner.references(field),
+            Self::Condition(condition) => condition.field == field,
+        }
+    }
+
+    /// Compile to a SQL boolean expression over the `issues` table, pushing
+    /// bind values onto `params` in placeholder order.
+    pub fn to_sql(&self, params: &mut Vec<Box<dyn rusqlite::ToSql>>) -> String {
+        match self {
+            Self::And(left, right) => {
+                format!("({} AND {})", left.to_sql(params), right.to_sql(params))
+            }
+            Self::Or(left, right) => {
+                format!("({} OR {})", left.to_sql(params), right.to_sql(params))
+            }
+            Self::Not(inner) => format!("NOT {}", inner.to_sql(params)),
+            Self::Condition(condition) => condition.to_sql(params),
+        }
+    }
+}
+
+impl Condition {
+    fn to_sql(&self, params: &mut Vec<Box<dyn rusqlite::ToSql>>) -> String {
+        let negate = self.op == CompareOp::Ne;
+        let mut sql = String::new();
+        match (&self.field, &self.value) {
+            (Field::Status, Value::Status(status)) => {
+                let _ = write!(sql, "issues.status {} ?", self.op.as_sql());
+                params.push(Box::new(status.as_str().to_string()));
+            }
+            (Field::Type, Value::Type(issue_type)) => {
+                let _ = write!(sql, "issues.issue_type {} ?", self.op.as_sql());
+                params.push(Box::new(issue_type.as_str().to_string()));
+            }
+            (Field::Priority, Value::Priority(priority)) => {
+                let _ = write!(sql, "issues.priority {} ?", self.op.as_sql());
+                params.push(Box::new(priority.0));
+            }
+            (Field::Id, Value::Text(id)) => {
+                let _ = write!(sql, "issues.id {} ?", self.op.as_sql());
+                params.push(Box::new(id.clone()));
+            }
+            (Field::Assignee | Field::Owner, Value::Unset) => {
+                let column = person_column(self.field);
+                if negate {
+                    let _ = write!(sql, "COALESCE(issues.{column}, '') != ''");
+                } else {
+                    let _ = write!(sql, "COALESCE(issues.{column}, '') = ''");
+                }
+            }
+            (Field::Assignee | Field::Owner, Value::Text(name)) => {
+                let column = person_column(self.field);
+                let _ = write!(sql, "COALESCE(issues.{column}, '') {} ?", self.op.as_sql());
+                params.push(Box::new(name.clone()));
+            }
+            (Field::Title | Field::Description | Field::Notes, Value::Text(needle)) => {
+                let column = match self.field {
+                    Field::Title => "title",
+                    Field::Description => "description",
+                    _ => "notes",
+                };
+                let like = if negate { "NOT LIKE" } else { "LIKE" };
+                let _ = write!(sql, "COALESCE(issues.{column}, '') {like} ? ESCAPE '\\'");
+                params.push(Box::new(format!("%{}%", escape_like(needle))));
+            }
+            (Field::Label, Value::Text(label)) => {
+                let exists = if negate { "NOT EXISTS" } else { "EXISTS" };
+                let _ = write!(
+                    sql,
+                    "{exists} (SELECT 1 FROM labels WHERE labels.issue_id = issues.id AND labels.label = ?)"
+                );
+                params.push(Box::new(label.clone()));
+            }
+            (Field::BlockedBy | Field::Blocks | Field::Parent, Value::Text(other)) => {
+                let exists = if negate { "NOT EXISTS" } else { "EXISTS" };
+                let (this_side, other_side, types) = match self.field {
+                    Field::BlockedBy => (
+                        "issue_id",
+                        "depends_on_id",
+                        "'blocks', 'conditional-blocks', 'waits-for'",
+                    ),
+                    Field::Blocks => (
+                        "depends_on_id",
+                        "issue_id",
+                        "'blocks', 'conditional-blocks', 'waits-for'",
+                    ),
+                    _ => ("issue_id", "depends_on_id", "'parent-child'"),
+                };
+                let _ = write!(
+                    sql,
+                    "{exists} (SELECT 1 FROM dependencies d WHERE d.{this_side} = issues.id \
+                     AND d.{other_side} = ? AND d.type IN ({types}))"
+                );
+                params.push(Box::new(other.clone()));
+            }
+            (field, Value::Timestamp(ts)) if field.is_timestamp() => {
+                let column = match field {
+                    Field::Created => "created_at",
+                    Field::Updated => "updated_at",
+                    Field::Closed => "closed_at",
+                    Field::Due => "due_at",
+                    _ => "defer_until",
+                };
+                // Keep NULL out of the comparison so NOT stays two-valued.
+                let _ = write!(
+                    sql,
+                    "(issues.{column} IS NOT NULL AND issues.{column} {} ?)",
+                    self.op.as_sql()
+                );
+                params.push(Box::new(ts.to_rfc3339()));
+            }
+            // The parser only builds the combinations above.
+            _ => sql.push('0'),
+        }
+        sql
+    }
+}
+
+const fn person_column(field: Field) -> &'static str {
+    match field {
+        Field::Owner => "owner",
+        _ => "assignee",
+    }
+}
+
+fn escape_like(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+/// Recursive-descent parser working directly on characters, so that values
+/// such as RFC3339 timestamps (which contain `:`) need no quoting.
+struct Parser<'a> {
+    input: &'a str,
+    chars: Vec<char>,
+    pos: usize,
+    /// Current nesting of parentheses and `NOT`s, capped at [`MAX_NESTING`].
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn error_at(&self, pos: usize, reason: impl Into<String>) -> BeadsError {
+        BeadsError::QueryParse {
+            query: self.input.to_string(),
+            position: pos + 1,
+            reason: reason.into(),
+        }
+    }
+
+    fn at_end(&self) -> bool {
+        self.pos >= self.chars.len()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    /// Consume `keyword` (case-insensitive) if it is the next whole word.
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let len = keyword.len();
+        let Some(candidate) = self.chars.get(self.pos..self.pos + len) else {
+            return false;
+        };
+        let matches = candidate
+            .iter()
+            .zip(keyword.chars())
+            .all(|(a, b)| a.eq_ignore_ascii_case(&b));
+        let boundary = self
+            .chars
+            .get(self.pos + len)
+            .is_none_or(|c| c.is_whitespace() || *c == '(' || *c == ')');
+        if matches && boundary {
+            self.pos += len;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<FilterExpr> {
+        let mut left = self.parse_and()?;
+        loop {
+            self.skip_whitespace();
+            if !self.eat_keyword("OR") {
+                return Ok(left);
+            }
+            let right = self.parse_and()?;
+            left = FilterExpr::Or(Box::new(left), Box::new(right));
+        }
+    }
+
+    fn parse_and(&mut self) -> Result<FilterExpr> {
+        let mut left = self.parse_unary()?;
+        loop {
+            self.skip_whitespace();
+            let explicit = self.eat_keyword("AND");
+            if !explicit && (self.at_end() || self.peek() == Some(')') || self.peek_keyword("OR")) {
+                return Ok(left);
+            }
+            let right = self.parse_unary()?;
+            left = FilterExpr::And(Box::new(left), Box::new(right));
+        }
+    }
+
+    fn peek_keyword(&mut self, keyword: &str) -> bool {
+        let start = self.pos;
+        let found = self.eat_keyword(keyword);
+        self.pos = start;
+        found
+    }
+
+    /// Track one more level of nesting starting at `at`, so deeply nested
+    /// input is rejected instead of overflowing the stack.
+    fn enter(&mut self, at: usize) -> Result<()> {
+        if self.depth >= MAX_NESTING {
+            return Err(self.error_at(
+                at,
+                format!("expression nests too deeply (max {MAX_NESTING} levels)"),
+            ));
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    fn parse_unary(&mut self) -> Result<FilterExpr> {
+        self.skip_whitespace();
+        let start = self.pos;
+        if self.eat_keyword("NOT") {
+            self.enter(start)?;
+            let inner = self.parse_unary()?;
+            self.depth -= 1;
+            return Ok(FilterExpr::Not(Box::new(inner)));
+        }
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> Result<FilterExpr> {
+        self.skip_whitespace();
+        match self.peek() {
+            None => Err(self.error_at(self.pos, "expected a condition")),
+            Some('(') => {
+                let open = self.pos;
+                self.enter(open)?;
+                self.pos += 1;
+                let expr = self.parse_or()?;
+                self.depth -= 1;
+                self.skip_whitespace();
+                if self.peek() == Some(')') {
+                    self.pos += 1;
+                    Ok(expr)
+                } else {
+                    Err(self.error_at(open, "unclosed '('"))
+                }
+            }
+            Some(')') => Err(self.error_at(self.pos, "expected a condition before ')'")),
+            Some(_) => self.parse_condition().map(FilterExpr::Condition),
+        }
+    }
+
+    fn parse_condition(&mut self) -> Result<Condition> {
+        let field_start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_ascii_alphanumeric() || c == '_')
+        {
+            self.pos += 1;
+        }
+        let name: String = self.chars[field_start..self.pos].iter().collect();
+        if name.is_empty() {
+            return Err(self.error_at(field_start, "expected a field name"));
+        }
+        let Some(field) = Field::from_name(&name) else {
+            return Err(self.error_at(field_start, format!("unknown field '{name}'")));
+        };
+
+        let op_start = self.pos;
+        let op = self.parse_operator().ok_or_else(|| {
+            self.error_at(op_start, format!("expected an operator after '{name}'"))
+        })?;
+        if field.is_timestamp() && matches!(op, CompareOp::Eq | CompareOp::Ne) {
+            return Err(self.error_at(op_start, format!("'{name}' only supports <, <=, > and >=")));
+        }
+        if !field.is_ordered() && !matches!(op, CompareOp::Eq | CompareOp::Ne) {
+            return Err(self.error_at(
+                op_start,
+                format!("'{name}' only supports ':', '=' and '!='"),
+            ));
+        }
+
+        let value_start = self.pos;
+        let raw = self.parse_value()?;
+        let value = self.convert_value(field, &name, &raw, value_start)?;
+        Ok(Condition { field, op, value })
+    }
+
+    fn parse_operator(&mut self) -> Option<CompareOp> {
+        let first = self.peek()?;
+        let second = self.chars.get(self.pos + 1).copied();
+        let (op, len) = match (first, second) {
+            ('!', Some('=')) => (CompareOp::Ne, 2),
+            ('<', Some('=')) => (CompareOp::Le, 2),
+            ('>', Some('=')) => (CompareOp::Ge, 2),
+            ('<', _) => (CompareOp::Lt, 1),
+            ('>', _) => (CompareOp::Gt, 1),
+            (':' | '=', _) => (CompareOp::Eq, 1),
+            _ => return None,
+        };
+        self.pos += len;
+        Some(op)
+    }
+
+    /// A double-quoted string (with `\"` escapes) or a bare run of characters
+    /// up to whitespace or `)`.
+    fn parse_value(&mut self) -> Result<String> {
+        let start = self.pos;
+        if self.peek() == Some('"') {
+            self.pos += 1;
+            let mut value = String::new();
+            loop {
+                match self.peek() {
+                    None => return Err(self.error_at(start, "unterminated quoted value")),
+                    Some('"') => {
+                        self.pos += 1;
+                        return Ok(value);
+                    }
+                    Some('\\') if self.chars.get(self.pos + 1) == Some(&'"') => {
+                        value.push('"');
+                        self.pos += 2;
+                    }
+                    Some(c) => {
+                        value.push(c);
+                        self.pos += 1;
+                    }
+                }
+            }
+        }
+
+        while self
+            .peek()
+            .is_some_and(|c| !c.is_whitespace() && c != ')' && c != '(')
+        {
+            self.pos += 1;
+        }
+        if self.pos == start {
+            return Err(self.error_at(start, "expected a value"));
+        }
+        Ok(self.chars[start..self.pos].iter().collect())
+    }
+
+    fn convert_value(&self, field: Field, name: &str, raw: &str, at: usize) -> Result<Value> {
+        let value = match field {
+            Field::Status => Value::Status(
+                raw.parse()
+                    .map_err(|_| self.error_at(at, format!("invalid status '{raw}'")))?,
+            ),
+            // Custom issue types are valid, so any type name is accepted.
+            Field::Type => Value::Type(
+                raw.parse()
+                    .unwrap_or_else(|_| IssueType::Custom(raw.to_ascii_lowercase())),
+            ),
+            Field::Priority => Value::Priority(raw.parse().map_err(|_| {
+                self.error_at(at, format!("invalid priority '{raw}' (use 0-4 or P0-P4)"))
+            })?),
+            Field::Assignee | Field::Owner if raw.eq_ignore_ascii_case("none") => Value::Unset,
+            f if f.is_timestamp() => {
+                Value::Timestamp(parse_flexible_timestamp(raw, name).map_err(|_| {
+                    self.error_at(
+                        at,
+                        format!("invalid date '{raw}' (try 2025-01-15, -7d, +1w or tomorrow)"),
+                    )
+                })?)
+            }
+            _ => Value::Text(raw.to_string()),
+        };
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_err(input: &str) -> (usize, String) {
+        match FilterExpr::parse(input) {
+            Err(BeadsError::QueryParse {
+                position, reason, ..
+            }) => (position, reason),
+            other => panic!("expected parse error for {input:?}, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_precedence_and_implicit_and() {
+        let expr = FilterExpr::parse("status:open label:a OR label:b").unwrap();
+        // AND binds tighter than OR.
+        let FilterExpr::Or(left, right) = expr else {
+            panic!("expected OR at the root");
+        };
+        assert!(matches!(*left, FilterExpr::And(_, _)));
+        assert!(matches!(*right, FilterExpr::Condition(_)));
+    }
+
+    #[test]
+    fn test_parse_full_example() {
+        let expr = FilterExpr::parse(
+            "status:open AND (label:backend OR priority<=1) AND updated>-7d AND blocked_by:bd-abc",
+        )
+        .unwrap();
+        assert!(expr.references(Field::Status));
+        assert!(expr.references(Field::BlockedBy));
+        assert!(!expr.references(Field::Assignee));
+
+        let mut params = Vec::new();
+        let sql = expr.to_sql(&mut params);
+        assert_eq!(params.len(), 5);
+        assert!(sql.contains("issues.priority <= ?"));
+        assert!(sql.contains("issues.updated_at > ?"));
+        assert!(sql.contains(" OR "));
+    }
+
+    #[test]
+    fn test_parse_values() {
+        let expr = FilterExpr::parse("priority:P1").unwrap();
+        assert_eq!(
+            expr,
+            FilterExpr::Condition(Condition {
+                field: Field::Priority,
+                op: CompareOp::Eq,
+                value: Value::Priority(Priority(1)),
+            })
+        );
+
+        let expr = FilterExpr::parse("title:\"login \\\"flow\\\"\"").unwrap();
+        let FilterExpr::Condition(condition) = expr else {
+            panic!("expected condition");
+        };
+        assert_eq!(condition.value, Value::Text("login \"flow\"".to_string()));
+
+        let expr = FilterExpr::parse("created>=2025-01-15T12:00:00Z").unwrap();
+        let FilterExpr::Condition(condition) = expr else {
+            panic!("expected condition");
+        };
+        assert!(matches!(condition.value, Value::Timestamp(_)));
+
+        let expr = FilterExpr::parse("NOT assignee:none").unwrap();
+        assert!(matches!(expr, FilterExpr::Not(_)));
+    }
+
+    #[test]
+    fn test_keywords_are_case_insensitive_whole_words() {
+        assert!(matches!(
+            FilterExpr::parse("label:a or label:b").unwrap(),
+            FilterExpr::Or(_, _)
+        ));
+        // A field value that starts with a keyword is not a keyword.
+        assert!(matches!(
+            FilterExpr::parse("title:or label:notable").unwrap(),
+            FilterExpr::And(_, _)
+        ));
+    }
+
+    #[test]
+    fn test_parse_errors_report_position() {
+        assert_eq!(parse_err("status:opn").0, 8);
+        assert_eq!(parse_err("status:open AND bogus:1").0, 17);
+        assert_eq!(parse_err("status:open AND").0, 16);
+        assert_eq!(parse_err("(status:open").0, 1);
+        assert_eq!(parse_err("status:open)").0, 12);
+        assert_eq!(parse_err("title").0, 6);
+        assert_eq!(parse_err("title:\"abc").0, 7);
+        assert_eq!(parse_err("").0, 1);
+
+        let (position, reason) = parse_err("label<3");
+        assert_eq!(position, 6);
+        assert!(reason.contains("only supports"));
+
+        let (position, reason) = parse_err("updated:yesterday-ish");
+        assert_eq!(position, 8);
+        assert!(reason.contains("only supports"));
+    }
+
+    #[test]
+    fn test_nesting_depth_is_capped() {
+        let nested = format!("{}status:open{}", "(".repeat(200), ")".repeat(200));
+        let (position, reason) = parse_err(&nested);
+        assert_eq!(position, MAX_NESTING + 1);
+        assert!(reason.contains("nests too deeply"), "{reason}");
+
+        let nots = format!("{}status:open", "NOT ".repeat(200));
+        assert!(parse_err(&nots).1.contains("nests too deeply"));
+
+        let ok = format!("{}status:open{}", "(".repeat(10), ")".repeat(10));
+        assert!(FilterExpr::parse(&ok).is_ok());
+    }
+
+    #[test]
+    fn test_timestamp_conditions_exclude_null() {
+        let expr = FilterExpr::parse("NOT due<+1w").unwrap();
+        let mut params = Vec::new();
+        let sql = expr.to_sql(&mut params);
+        assert_eq!(sql, "NOT (issues.due_at IS NOT NULL AND issues.due_at < ?)");
+    }
+
+    #[test]
+    fn test_custom_issue_types_are_accepted() {
+        let expr = FilterExpr::parse("type:Spike").unwrap();
+        let FilterExpr::Condition(condition) = expr else {
+            panic!("expected condition");
+        };
+        assert_eq!(
+            condition.value,
+            Value::Type(IssueType::Custom("spike".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_text_conditions_escape_like_wildcards() {
+        let expr = FilterExpr::parse("title:100%").unwrap();
+        let mut params = Vec::new();
+        let sql = expr.to_sql(&mut params);
+        assert_eq!(sql, "COALESCE(issues.title, '') LIKE ? ESCAPE '\\'");
+        assert_eq!(params.len(), 1);
+    }
+}
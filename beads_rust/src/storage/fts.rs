@@ -0,0 +1,218 @@
+//! Full-text search support.
+//!
+//! `br search` runs against the `issues_fts` FTS5 table (see [`crate::storage::schema`]).
+//! User input is never handed to FTS5 verbatim: it is rewritten into a MATCH
+//! expression where every term is quoted, so punctuation, hyphenated IDs and
+//! unbalanced quotes cannot produce FTS5 syntax errors.
+//!
+//! Supported query syntax:
+//! - `foo bar` - all terms must match (bare words are prefix matches)
+//! - `"foo bar"` - exact phrase
+//! - `title:foo`, `notes:"foo bar"` - restrict a term to one field
+//! - `AND`, `OR`, `NOT` - FTS5 boolean operators (uppercase only)
+
+/// Field prefixes accepted in queries, mapped to `issues_fts` column names.
+const FIELD_ALIASES: &[(&str, &str)] = &[
+    ("title", "title"),
+    ("description", "description"),
+    ("desc", "description"),
+    ("design", "design"),
+    ("acceptance_criteria", "acceptance_criteria"),
+    ("acceptance", "acceptance_criteria"),
+    ("notes", "notes"),
+    ("comments", "comments"),
+    ("comment", "comments"),
+];
+
+/// BM25 column weights, in `issues_fts` column order
+/// (id, title, description, design, acceptance_criteria, notes, comments).
+pub const BM25_WEIGHTS: &str = "0.0, 10.0, 5.0, 3.0, 3.0, 2.0, 1.0";
+
+/// Markers wrapped around matched terms in snippets.
+pub const SNIPPET_OPEN: &str = "**";
+pub const SNIPPET_CLOSE: &str = "**";
+
+/// Maximum number of tokens in a snippet.
+pub const SNIPPET_TOKENS: u32 = 16;
+
+/// Resolve a `field:` prefix to its FTS column, if it names one.
+#[must_use]
+pub fn resolve_field(name: &str) -> Option<&'static str> {
+    let lower = name.to_ascii_lowercase();
+    FIELD_ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == lower)
+        .map(|(_, column)| *column)
+}
+
+/// Translate a user query into an FTS5 MATCH expression.
+///
+/// Returns `None` when the query contains nothing searchable (for example
+/// only punctuation), in which case callers should fall back to ID matching.
+#[must_use]
+pub fn to_match_expression(query: &str) -> Option<String> {
+    let mut parts: Vec<String> = Vec::new();
+    let mut pending_op: Option<&str> = None;
+
+    for token in split_tokens(query) {
+        if let Some(op) = as_operator(&token) {
+            // Operators only make sense between two terms; drop leading or
+            // doubled ones instead of letting FTS5 reject the query.
+            if !parts.is_empty() {
+                pending_op = Some(op);
+            }
+            continue;
+        }
+
+        let Some(term) = render_term(&token) else {
+            continue;
+        };
+        if let Some(op) = pending_op.take() {
+            parts.push(op.to_string());
+        }
+        parts.push(term);
+    }
+
+    if parts.is_empty() {
+        None
+    } else {
+        Some(parts.join(" "))
+    }
+}
+
+fn as_operator(token: &str) -> Option<&'static str> {
+    match token {
+        "AND" => Some("AND"),
+        "OR" => Some("OR"),
+        "NOT" => Some("NOT"),
+        _ => None,
+    }
+}
+
+/// Split on whitespace, keeping double-quoted spans (including a `field:`
+/// prefix glued to them) together.
+fn split_tokens(query: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+
+    for ch in query.chars() {
+        match ch {
+            '"' => {
+                in_quotes = !in_quotes;
+                current.push(ch);
+            }
+            c if c.is_whitespace() && !in_quotes => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+    tokens
+}
+
+/// Render one token as a quoted FTS5 term, optionally column-scoped.
+fn render_term(token: &str) -> Option<String> {
+    let (column, body) = match token.split_once(':') {
+        Some((field, rest)) if !field.contains('"') && !rest.is_empty() => {
+            resolve_field(field).map_or((None, token), |column| (Some(column), rest))
+        }
+        _ => (None, token),
+    };
+
+    let (text, prefix) = body.strip_prefix('"').map_or_else(
+        || {
+            // Only plain words get prefix matching: `C++` as a prefix would be
+            // `c*`, which matches nearly everything.
+            let word = body.strip_suffix('*').unwrap_or(body);
+            (word, word.chars().all(|c| c.is_alphanumeric() || c == '_'))
+        },
+        |quoted| (quoted.strip_suffix('"').unwrap_or(quoted), false),
+    );
+
+    if !text.chars().any(char::is_alphanumeric) {
+        return None;
+    }
+
+    let mut rendered = String::new();
+    if let Some(column) = column {
+        rendered.push_str(column);
+        rendered.push_str(" : ");
+    }
+    rendered.push('"');
+    rendered.push_str(&text.replace('"', "\"\""));
+    rendered.push('"');
+    if prefix {
+        rendered.push('*');
+    }
+    Some(rendered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bare_terms_are_quoted_prefixes() {
+        assert_eq!(
+            to_match_expression("auth login").as_deref(),
+            Some("\"auth\"* \"login\"*")
+        );
+    }
+
+    #[test]
+    fn test_phrases_are_exact() {
+        assert_eq!(
+            to_match_expression("\"user login\" flow").as_deref(),
+            Some("\"user login\" \"flow\"*")
+        );
+    }
+
+    #[test]
+    fn test_field_scoped_terms() {
+        assert_eq!(
+            to_match_expression("title:crash desc:\"null pointer\"").as_deref(),
+            Some("title : \"crash\"* description : \"null pointer\"")
+        );
+        // Unknown fields are searched literally.
+        assert_eq!(
+            to_match_expression("owner:alice").as_deref(),
+            Some("\"owner:alice\"")
+        );
+    }
+
+    #[test]
+    fn test_operators_pass_through_between_terms() {
+        assert_eq!(
+            to_match_expression("crash OR panic").as_deref(),
+            Some("\"crash\"* OR \"panic\"*")
+        );
+        assert_eq!(
+            to_match_expression("OR crash NOT").as_deref(),
+            Some("\"crash\"*")
+        );
+        // Lowercase words are just terms.
+        assert_eq!(
+            to_match_expression("this or that").as_deref(),
+            Some("\"this\"* \"or\"* \"that\"*")
+        );
+    }
+
+    #[test]
+    fn test_punctuation_is_neutralized() {
+        assert_eq!(
+            to_match_expression("bd-abc1 C++").as_deref(),
+            Some("\"bd-abc1\" \"C++\"")
+        );
+        assert_eq!(
+            to_match_expression("say \"hi").as_deref(),
+            Some("\"say\"* \"hi\"")
+        );
+        assert_eq!(to_match_expression("-- ?? \"\"").as_deref(), None);
+    }
+}
@@ -9,11 +9,18 @@
 //! # Submodules
 //!
 //! - [`events`] - Audit event storage (insertion, retrieval)
+//! - [`filter_expr`] - `--where` filter expression parsing and SQL compilation
+//! - [`fts`] - Full-text search query translation
 //! - [`schema`] - Database schema definitions
 //! - [`sqlite`] - Main `SQLite` storage implementation
 
 pub mod events;
+pub mod filter_expr;
+pub mod fts;
 pub mod schema;
 pub mod sqlite;
 
-pub use sqlite::{IssueUpdate, ListFilters, ReadyFilters, ReadySortPolicy, SqliteStorage};
+pub use filter_expr::FilterExpr;
+pub use sqlite::{
+    ClaimOptions, IssueUpdate, ListFilters, ReadyFilters, ReadySortPolicy, SearchHit, SqliteStorage,
+};
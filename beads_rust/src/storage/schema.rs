@@ -191,6 +191,125 @@ pub const SCHEMA_SQL: &str = r"
         last_child INTEGER NOT NULL DEFAULT 0,
         FOREIGN KEY (parent_id) REFERENCES issues(id) ON DELETE CASCADE
     );
+
+    -- Claim Leases (br-only, local coordination state; never exported)
+    -- One active lease per issue; expired leases are reaped back to ready.
+    CREATE TABLE IF NOT EXISTS claim_leases (
+        issue_id TEXT PRIMARY KEY,
+        actor TEXT NOT NULL,
+        claimed_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        expires_at DATETIME NOT NULL,
+        FOREIGN KEY (issue_id) REFERENCES issues(id) ON DELETE CASCADE
+    );
+    CREATE INDEX IF NOT EXISTS idx_claim_leases_expires_at ON claim_leases(expires_at);
+
+    -- Time Entries (br time; exported with their issue in JSONL)
+    -- A row without ended_at is a running timer.
+    CREATE TABLE IF NOT EXISTS time_entries (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        issue_id TEXT NOT NULL,
+        actor TEXT NOT NULL DEFAULT '',
+        started_at DATETIME NOT NULL,
+        ended_at DATETIME,
+        minutes INTEGER NOT NULL DEFAULT 0 CHECK(minutes >= 0),
+        note TEXT,
+        FOREIGN KEY (issue_id) REFERENCES issues(id) ON DELETE CASCADE
+    );
+    CREATE INDEX IF NOT EXISTS idx_time_entries_issue ON time_entries(issue_id);
+    CREATE INDEX IF NOT EXISTS idx_time_entries_running ON time_entries(actor) WHERE ended_at IS NULL;
+
+    -- Recurrence Rules (br recur; exported with their template issue in JSONL)
+    CREATE TABLE IF NOT EXISTS recurrence_rules (
+        issue_id TEXT PRIMARY KEY,
+        rule TEXT NOT NULL,
+        starts_at DATETIME NOT NULL,
+        lead_minutes INTEGER,
+        due_minutes INTEGER,
+        FOREIGN KEY (issue_id) REFERENCES issues(id) ON DELETE CASCADE
+    );
+
+    -- Attachments (br attach; exported with their issue in JSONL)
+    -- Content lives in .beads/blobs/ keyed by sha256.
+    CREATE TABLE IF NOT EXISTS attachments (
+        issue_id TEXT NOT NULL,
+        name TEXT NOT NULL,
+        sha256 TEXT NOT NULL,
+        size INTEGER NOT NULL DEFAULT 0 CHECK(size >= 0),
+        media_type TEXT,
+        created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        created_by TEXT,
+        local_only INTEGER NOT NULL DEFAULT 0,
+        PRIMARY KEY (issue_id, name),
+        FOREIGN KEY (issue_id) REFERENCES issues(id) ON DELETE CASCADE
+    );
+    CREATE INDEX IF NOT EXISTS idx_attachments_sha256 ON attachments(sha256);
+
+    -- Full-text search (FTS5, BM25 ranked)
+    -- Rows share their rowid with issues; comments are folded into one column.
+    CREATE VIRTUAL TABLE IF NOT EXISTS issues_fts USING fts5(
+        id UNINDEXED,
+        title,
+        description,
+        design,
+        acceptance_criteria,
+        notes,
+        comments,
+        tokenize = 'porter unicode61'
+    );
+
+    -- INSERT OR REPLACE deletes the old row without firing DELETE triggers,
+    -- so drop its index entry before the replacement lands.
+    CREATE TRIGGER IF NOT EXISTS issues_fts_bi BEFORE INSERT ON issues BEGIN
+        DELETE FROM issues_fts WHERE rowid = (SELECT rowid FROM issues WHERE id = new.id);
+    END;
+
+    CREATE TRIGGER IF NOT EXISTS issues_fts_ai AFTER INSERT ON issues BEGIN
+        INSERT OR REPLACE INTO issues_fts(rowid, id, title, description, design, acceptance_criteria, notes, comments)
+        VALUES (
+            new.rowid, new.id, new.title,
+            COALESCE(new.description, ''), COALESCE(new.design, ''),
+            COALESCE(new.acceptance_criteria, ''), COALESCE(new.notes, ''),
+            COALESCE((SELECT group_concat(text, char(10)) FROM comments WHERE issue_id = new.id), '')
+        );
+    END;
+
+    CREATE TRIGGER IF NOT EXISTS issues_fts_au
+    AFTER UPDATE OF id, title, description, design, acceptance_criteria, notes ON issues BEGIN
+        UPDATE issues_fts SET
+            id = new.id,
+            title = new.title,
+            description = COALESCE(new.description, ''),
+            design = COALESCE(new.design, ''),
+            acceptance_criteria = COALESCE(new.acceptance_criteria, ''),
+            notes = COALESCE(new.notes, '')
+        WHERE rowid = new.rowid;
+    END;
+
+    CREATE TRIGGER IF NOT EXISTS issues_fts_ad AFTER DELETE ON issues BEGIN
+        DELETE FROM issues_fts WHERE rowid = old.rowid;
+    END;
+
+    CREATE TRIGGER IF NOT EXISTS comments_fts_ai AFTER INSERT ON comments BEGIN
+        UPDATE issues_fts
+        SET comments = COALESCE((SELECT group_concat(text, char(10)) FROM comments WHERE issue_id = new.issue_id), '')
+        WHERE rowid = (SELECT rowid FROM issues WHERE id = new.issue_id);
+    END;
+
+    -- A comment moved to another issue (ID remap) changes both issues' text.
+    CREATE TRIGGER IF NOT EXISTS comments_fts_au AFTER UPDATE OF text, issue_id ON comments BEGIN
+        UPDATE issues_fts
+        SET comments = COALESCE((SELECT group_concat(text, char(10)) FROM comments WHERE issue_id = old.issue_id), '')
+        WHERE rowid = (SELECT rowid FROM issues WHERE id = old.issue_id);
+        UPDATE issues_fts
+        SET comments = COALESCE((SELECT group_concat(text, char(10)) FROM comments WHERE issue_id = new.issue_id), '')
+        WHERE rowid = (SELECT rowid FROM issues WHERE id = new.issue_id);
+    END;
+
+    CREATE TRIGGER IF NOT EXISTS comments_fts_ad AFTER DELETE ON comments BEGIN
+        UPDATE issues_fts
+        SET comments = COALESCE((SELECT group_concat(text, char(10)) FROM comments WHERE issue_id = old.issue_id), '')
+        WHERE rowid = (SELECT rowid FROM issues WHERE id = old.issue_id);
+    END;
 ";
 
 /// Apply the schema to the database.
@@ -207,11 +326,18 @@ pub fn apply_schema(conn: &Connection) -> Result<()> {
     // statements that will fail if old tables have missing columns
     run_pre_schema_migrations(conn)?;
 
+    let had_search_index = table_exists(conn, "issues_fts");
+
     conn.execute_batch(SCHEMA_SQL)?;
 
     // Run migrations for existing databases
     run_migrations(conn)?;
 
+    // Databases created before full-text search get their index backfilled once.
+    if !had_search_index {
+        rebuild_search_index(conn)?;
+    }
+
     // Set journal mode to WAL for concurrency
     conn.pragma_update(None, "journal_mode", "WAL")?;
 
@@ -229,6 +355,30 @@ pub fn apply_schema(conn: &Connection) -> Result<()> {
     Ok(())
 }
 
+/// Rebuild the full-text search index from the issues and comments tables.
+///
+/// The triggers in [`SCHEMA_SQL`] keep `issues_fts` current; this is the
+/// backfill for older databases and the repair path used by `br doctor --fix`.
+///
+/// Returns the number of indexed issues.
+///
+/// # Errors
+///
+/// Returns an error if the index cannot be cleared or repopulated.
+pub fn rebuild_search_index(conn: &Connection) -> Result<usize> {
+    conn.execute("DELETE FROM issues_fts", [])?;
+    let indexed = conn.execute(
+        r"INSERT INTO issues_fts(rowid, id, title, description, design, acceptance_criteria, notes, comments)
+          SELECT i.rowid, i.id, i.title,
+                 COALESCE(i.description, ''), COALESCE(i.design, ''),
+                 COALESCE(i.acceptance_criteria, ''), COALESCE(i.notes, ''),
+                 COALESCE((SELECT group_concat(c.text, char(10)) FROM comments c WHERE c.issue_id = i.id), '')
+          FROM issues i",
+        [],
+    )?;
+    Ok(indexed)
+}
+
 fn table_exists(conn: &Connection, table: &str) -> bool {
     conn.prepare("SELECT 1 FROM sqlite_master WHERE type='table' AND name=?")
         .and_then(|mut stmt| stmt.exists([table]))
@@ -826,6 +976,110 @@ mod tests {
         .expect("Should allow tombstone without closed_at");
     }
 
+    #[test]
+    fn test_search_index_tracks_issues_and_comments() {
+        let conn = Connection::open_in_memory().unwrap();
+        apply_schema(&conn).expect("Failed to apply schema");
+
+        let matches = |query: &str| -> Vec<String> {
+            conn.prepare("SELECT id FROM issues_fts WHERE issues_fts MATCH ? ORDER BY id")
+                .unwrap()
+                .query_map([query], |row| row.get(0))
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap()
+        };
+
+        conn.execute(
+            "INSERT INTO issues (id, title, description) VALUES ('bd-1', 'Login crashes', 'stack overflow')",
+            [],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO comments (issue_id, author, text) VALUES ('bd-1', 'alice', 'seen on mobile')",
+            [],
+        )
+        .unwrap();
+        assert_eq!(matches("crash"), vec!["bd-1"]);
+        assert_eq!(matches("comments : mobile"), vec!["bd-1"]);
+
+        conn.execute(
+            "UPDATE issues SET title = 'Signup hangs' WHERE id = 'bd-1'",
+            [],
+        )
+        .unwrap();
+        assert!(matches("login").is_empty());
+        assert_eq!(matches("signup"), vec!["bd-1"]);
+
+        // Replacing a row must not leave a stale index entry behind.
+        conn.execute(
+            "INSERT OR REPLACE INTO issues (id, title) VALUES ('bd-1', 'Replaced title')",
+            [],
+        )
+        .unwrap();
+        assert!(matches("signup").is_empty());
+        let indexed: i64 = conn
+            .query_row("SELECT count(*) FROM issues_fts", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(indexed, 1);
+
+        conn.execute("DELETE FROM issues WHERE id = 'bd-1'", [])
+            .unwrap();
+        assert!(matches("replaced").is_empty());
+    }
+
+    #[test]
+    fn test_search_index_follows_comment_moved_to_another_issue() {
+        let conn = Connection::open_in_memory().unwrap();
+        apply_schema(&conn).expect("Failed to apply schema");
+        conn.execute_batch(
+            "INSERT INTO issues (id, title) VALUES ('bd-a', 'First'), ('bd-b', 'Second');
+             INSERT INTO comments (issue_id, author, text) VALUES ('bd-a', 'alice', 'flaky teardown');",
+        )
+        .unwrap();
+
+        conn.execute("UPDATE comments SET issue_id = 'bd-b'", [])
+            .unwrap();
+        let hits: Vec<String> = conn
+            .prepare("SELECT id FROM issues_fts WHERE issues_fts MATCH 'comments : teardown'")
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(hits, vec!["bd-b"]);
+    }
+
+    #[test]
+    fn test_search_index_backfilled_for_existing_database() {
+        let conn = Connection::open_in_memory().unwrap();
+        apply_schema(&conn).expect("Failed to apply schema");
+        conn.execute(
+            "INSERT INTO issues (id, title) VALUES ('bd-old', 'Legacy payment bug')",
+            [],
+        )
+        .unwrap();
+
+        // Simulate a database from before full-text search existed.
+        conn.execute_batch(
+            "DROP TRIGGER issues_fts_bi; DROP TRIGGER issues_fts_ai; DROP TRIGGER issues_fts_au;
+             DROP TRIGGER issues_fts_ad; DROP TRIGGER comments_fts_ai; DROP TRIGGER comments_fts_au;
+             DROP TRIGGER comments_fts_ad; DROP TABLE issues_fts;",
+        )
+        .unwrap();
+
+        apply_schema(&conn).expect("Failed to re-apply schema");
+        let hit: String = conn
+            .query_row(
+                "SELECT id FROM issues_fts WHERE issues_fts MATCH 'payment'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(hit, "bd-old");
+        assert_eq!(rebuild_search_index(&conn).unwrap(), 1);
+    }
+
     /// Test that migrations correctly upgrade old schemas.
     #[test]
     fn test_migration_blocked_cache_upgrade() {
@@ -2,8 +2,13 @@
 
 use crate::error::{BeadsError, Result};
 use crate::format::{IssueDetails, IssueWithDependencyMetadata};
-use crate::model::{Comment, DependencyType, Event, EventType, Issue, IssueType, Priority, Status};
+use crate::model::{
+    Attachment, ClaimLease, Comment, DependencyType, Event, EventType, Issue, IssueType, Priority,
+    Recurrence, Status, TimeEntry, elapsed_minutes,
+};
 use crate::storage::events::get_events;
+use crate::storage::filter_expr::FilterExpr;
+use crate::storage::fts;
 use crate::storage::schema::apply_schema;
 use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
 use rusqlite::{Connection, OpenFlags, OptionalExtension, Transaction};
@@ -136,6 +141,63 @@ impl SqliteStorage {
         crate::storage::events::get_all_events(&self.conn, limit)
     }
 
+    /// Get audit events newer than `after_id`, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_events_after(&self, after_id: i64) -> Result<Vec<Event>> {
+        crate::storage::events::get_events_after(&self.conn, after_id)
+    }
+
+    /// Get all audit events of one type, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_events_of_type(&self, event_type: &EventType) -> Result<Vec<Event>> {
+        crate::storage::events::get_events_of_type(&self.conn, event_type)
+    }
+
+    /// Get the highest audit event ID (0 if there are none).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn latest_event_id(&self) -> Result<i64> {
+        crate::storage::events::latest_event_id(&self.conn)
+    }
+
+    /// SQLite's `PRAGMA data_version`: changes whenever another connection
+    /// commits to the database (commits on this connection do not count).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pragma cannot be read.
+    pub fn data_version(&self) -> Result<i64> {
+        Ok(self
+            .conn
+            .query_row("PRAGMA data_version", [], |row| row.get(0))?)
+    }
+
+    /// Get `(updated_at, status)` for every issue, keyed by ID.
+    ///
+    /// Comparing two snapshots reveals changes that left no audit event,
+    /// such as JSONL imports.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_issue_markers(&self) -> Result<HashMap<String, (String, String)>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT id, updated_at, status FROM issues")?;
+        let markers = stmt
+            .query_map([], |row| Ok((row.get(0)?, (row.get(1)?, row.get(2)?))))?
+            .collect::<std::result::Result<HashMap<_, _>, _>>()?;
+        Ok(markers)
+    }
+
     /// Execute a mutation with the 4-step transaction protocol.
     ///
     /// # Errors
@@ -326,6 +388,27 @@ impl SqliteStorage {
                 );
             }
 
+            // Insert Time Entries
+            for entry in &issue.time_entries {
+                insert_time_entry_row(
+                    tx,
+                    &issue.id,
+                    &entry.actor,
+                    entry.started_at,
+                    entry.ended_at,
+                    entry.minutes.max(0),
+                    entry.note.as_deref(),
+                )?;
+            }
+
+            if let Some(recurrence) = &issue.recurrence {
+                upsert_recurrence_row(tx, &issue.id, recurrence)?;
+            }
+
+            for attachment in &issue.attachments {
+                insert_attachment_row(tx, &issue.id, attachment)?;
+            }
+
             ctx.record_event(
                 EventType::Created,
                 &issue.id,
@@ -499,6 +582,11 @@ impl SqliteStorage {
                     add_update("closed_at", Box::new(None::<String>));
                 }
 
+                // A lease only lives while its issue is being worked on.
+                if *status != Status::InProgress {
+                    tx.execute("DELETE FROM claim_leases WHERE issue_id = ?", [id])?;
+                }
+
                 if !updates.skip_cache_rebuild {
                     ctx.invalidate_cache();
                 }
@@ -692,6 +780,11 @@ impl SqliteStorage {
     ///
     /// Returns an error if the database query fails.
     pub fn get_issue(&self, id: &str) -> Result<Option<Issue>> {
+        Self::fetch_issue(&self.conn, id)
+    }
+
+    /// Fetch an issue by ID on an arbitrary connection (usable inside `mutate`).
+    fn fetch_issue(conn: &Connection, id: &str) -> Result<Option<Issue>> {
         let sql = r"
             SELECT id, content_hash, title, description, design, acceptance_criteria, notes,
                    status, priority, issue_type, assignee, owner, estimated_minutes,
@@ -703,8 +796,8 @@ impl SqliteStorage {
             FROM issues WHERE id = ?
         ";
 
-        let mut stmt = self.conn.prepare_cached(sql)?;
-        let result = stmt.query_row([id], |row| self.issue_from_row(row));
+        let mut stmt = conn.prepare_cached(sql)?;
+        let result = stmt.query_row([id], Self::issue_from_row);
 
         match result {
             Ok(issue) => Ok(Some(issue)),
@@ -746,7 +839,7 @@ impl SqliteStorage {
 
             let mut stmt = self.conn.prepare(&sql)?;
             let chunk_issues = stmt
-                .query_map(params.as_slice(), |row| self.issue_from_row(row))?
+                .query_map(params.as_slice(), Self::issue_from_row)?
                 .collect::<std::result::Result<Vec<_>, _>>()?;
             issues.extend(chunk_issues);
         }
@@ -863,6 +956,11 @@ impl SqliteStorage {
             params.push(Box::new(ts.to_rfc3339()));
         }
 
+        if let Some(ref expr) = filters.expr {
+            let clause = expr.to_sql(&mut params);
+            let _ = write!(sql, " AND {clause}");
+        }
+
         // Apply custom sort if provided
         if let Some(ref sort_field) = filters.sort {
             let order = if filters.reverse { "DESC" } else { "ASC" };
@@ -909,7 +1007,7 @@ impl SqliteStorage {
         let mut stmt = self.conn.prepare(&sql)?;
         let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(AsRef::as_ref).collect();
         let issues = stmt
-            .query_map(params_refs.as_slice(), |row| self.issue_from_row(row))?
+            .query_map(params_refs.as_slice(), Self::issue_from_row)?
             .collect::<std::result::Result<Vec<_>, _>>()?;
 
         Ok(issues)
@@ -917,38 +1015,76 @@ impl SqliteStorage {
 
     /// Search issues by query with optional filters.
     ///
+    /// Results are ordered by relevance; see [`Self::search_issues_ranked`].
+    ///
     /// # Errors
     ///
     /// Returns an error if the database query fails.
-    #[allow(clippy::too_many_lines)]
     pub fn search_issues(&self, query: &str, filters: &ListFilters) -> Result<Vec<Issue>> {
+        Ok(self
+            .search_issues_ranked(query, filters)?
+            .into_iter()
+            .map(|hit| hit.issue)
+            .collect())
+    }
+
+    /// Full-text search with BM25 ranking and highlighted snippets.
+    ///
+    /// Text fields and comments are matched through the `issues_fts` index
+    /// (query syntax in [`crate::storage::fts`]); the raw query is also
+    /// matched against issue IDs as a substring. Text hits come first, best
+    /// match first; ID-only hits follow in priority order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    #[allow(clippy::too_many_lines)]
+    pub fn search_issues_ranked(
+        &self,
+        query: &str,
+        filters: &ListFilters,
+    ) -> Result<Vec<SearchHit>> {
         let trimmed = query.trim();
         if trimmed.is_empty() {
             return Ok(Vec::new());
         }
 
-        let mut sql = String::from(
-            r"SELECT id, content_hash, title, description, design, acceptance_criteria, notes,
+        let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
+
+        let mut sql = String::from("WITH hits AS MATERIALIZED (");
+        if let Some(expr) = fts::to_match_expression(trimmed) {
+            let _ = write!(
+                sql,
+                "SELECT rowid, bm25(issues_fts, {}) AS rank, \
+                 snippet(issues_fts, -1, '{}', '{}', '...', {}) AS snippet \
+                 FROM issues_fts WHERE issues_fts MATCH ?",
+                fts::BM25_WEIGHTS,
+                fts::SNIPPET_OPEN,
+                fts::SNIPPET_CLOSE,
+                fts::SNIPPET_TOKENS
+            );
+            params.push(Box::new(expr));
+        } else {
+            sql.push_str("SELECT NULL AS rowid, NULL AS rank, NULL AS snippet WHERE 0");
+        }
+        sql.push_str(
+            r")
+              SELECT id, content_hash, title, description, design, acceptance_criteria, notes,
                      status, priority, issue_type, assignee, owner, estimated_minutes,
                      created_at, created_by, updated_at, closed_at, close_reason, closed_by_session,
                      due_at, defer_until, external_ref, source_system, source_repo,
                      deleted_at, deleted_by, delete_reason, original_type,
                      compaction_level, compacted_at, compacted_at_commit, original_size,
-                     sender, ephemeral, pinned, is_template
+                     sender, ephemeral, pinned, is_template,
+                     hits.rank, hits.snippet
               FROM issues
+              LEFT JOIN hits ON hits.rowid = issues.rowid
               WHERE 1=1",
         );
 
-        let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
-
-        sql.push_str(
-            " AND (title LIKE ? ESCAPE '\\' OR description LIKE ? ESCAPE '\\' OR id LIKE ? ESCAPE '\\')",
-        );
+        sql.push_str(" AND (hits.rowid IS NOT NULL OR id LIKE ? ESCAPE '\\')");
         let escaped = escape_like_pattern(trimmed);
-        let pattern = format!("%{escaped}%");
-        params.push(Box::new(pattern.clone()));
-        params.push(Box::new(pattern.clone()));
-        params.push(Box::new(pattern));
+        params.push(Box::new(format!("%{escaped}%")));
 
         if let Some(ref statuses) = filters.statuses {
             if !statuses.is_empty() {
@@ -1025,7 +1161,13 @@ impl SqliteStorage {
             params.push(Box::new(format!("%{escaped}%")));
         }
 
-        sql.push_str(" ORDER BY priority ASC, created_at DESC");
+        if let Some(ref expr) = filters.expr {
+            let clause = expr.to_sql(&mut params);
+            let _ = write!(sql, " AND {clause}");
+        }
+
+        // bm25() is negative (lower is better); ID-only hits have no rank.
+        sql.push_str(" ORDER BY hits.rank IS NULL, hits.rank, priority ASC, created_at DESC");
 
         if let Some(limit) = filters.limit {
             if limit > 0 {
@@ -1036,11 +1178,18 @@ impl SqliteStorage {
 
         let mut stmt = self.conn.prepare(&sql)?;
         let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(AsRef::as_ref).collect();
-        let issues = stmt
-            .query_map(params_refs.as_slice(), |row| self.issue_from_row(row))?
+        let hits = stmt
+            .query_map(params_refs.as_slice(), |row| {
+                let rank: Option<f64> = row.get(36)?;
+                Ok(SearchHit {
+                    issue: Self::issue_from_row(row)?,
+                    score: rank.map(|r| -r),
+                    snippet: row.get::<_, Option<String>>(37)?.filter(|s| !s.is_empty()),
+                })
+            })?
             .collect::<std::result::Result<Vec<_>, _>>()?;
 
-        Ok(issues)
+        Ok(hits)
     }
 
     /// Get ready issues (unblocked, not deferred, not pinned, not ephemeral).
@@ -1055,7 +1204,6 @@ impl SqliteStorage {
     /// # Errors
     ///
     /// Returns an error if the database query fails.
-    #[allow(clippy::too_many_lines)]
     pub fn get_ready_issues(
         &self,
         filters: &ReadyFilters,
@@ -1073,134 +1221,10 @@ impl SqliteStorage {
         );
 
         let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
-
-        // Ready condition 1: status is `open` OR `in_progress`
-        if filters.include_deferred {
-            sql.push_str(" AND status IN ('open', 'in_progress', 'deferred')");
-        } else {
-            sql.push_str(" AND status IN ('open', 'in_progress')");
-        }
-
-        // Ready condition 2: NOT in blocked_issues_cache (NOT EXISTS is faster than NOT IN)
-        sql.push_str(
-            " AND NOT EXISTS (SELECT 1 FROM blocked_issues_cache WHERE issue_id = issues.id)",
-        );
-
-        // Ready condition 3: `defer_until` is NULL or <= now (unless `include_deferred`)
-        if !filters.include_deferred {
-            // Compare using SQLite datetime parsing to handle RFC3339 timestamps consistently.
-            sql.push_str(" AND (defer_until IS NULL OR datetime(defer_until) <= datetime('now'))");
-        }
-
-        // Ready condition 4: not pinned
-        sql.push_str(" AND (pinned = 0 OR pinned IS NULL)");
-
-        // Ready condition 5: not ephemeral and not wisp
-        sql.push_str(" AND (ephemeral = 0 OR ephemeral IS NULL)");
-        sql.push_str(" AND id NOT LIKE '%-wisp-%'");
-
-        // Exclude templates
-        sql.push_str(" AND (is_template = 0 OR is_template IS NULL)");
-
-        // Filter by types
-        if let Some(ref types) = filters.types {
-            if !types.is_empty() {
-                let placeholders: Vec<String> = types.iter().map(|_| "?".to_string()).collect();
-                let _ = write!(sql, " AND issue_type IN ({}) ", placeholders.join(","));
-                for t in types {
-                    params.push(Box::new(t.as_str().to_string()));
-                }
-            }
-        }
-
-        // Filter by priorities
-        if let Some(ref priorities) = filters.priorities {
-            if !priorities.is_empty() {
-                let placeholders: Vec<String> =
-                    priorities.iter().map(|_| "?".to_string()).collect();
-                let _ = write!(sql, " AND priority IN ({})", placeholders.join(","));
-                for p in priorities {
-                    params.push(Box::new(p.0));
-                }
-            }
-        }
-
-        // Filter by assignee
-        if let Some(ref assignee) = filters.assignee {
-            sql.push_str(" AND assignee = ?");
-            params.push(Box::new(assignee.clone()));
-        }
-
-        // Filter for unassigned
-        if filters.unassigned {
-            sql.push_str(" AND assignee IS NULL");
-        }
-
-        // Filter by labels (AND logic)
-        for label in &filters.labels_and {
-            sql.push_str(" AND EXISTS (SELECT 1 FROM labels WHERE labels.issue_id = issues.id AND labels.label = ?)");
-            params.push(Box::new(label.clone()));
-        }
-
-        // Filter by labels (OR logic)
-        if !filters.labels_or.is_empty() {
-            let placeholders: Vec<String> =
-                filters.labels_or.iter().map(|_| "?".to_string()).collect();
-            let _ = write!(
-                sql,
-                " AND id IN (SELECT issue_id FROM labels WHERE label IN ({}))",
-                placeholders.join(",")
-            );
-            for l in &filters.labels_or {
-                params.push(Box::new(l.clone()));
-            }
-        }
-
-        // Filter by parent (--parent flag)
-        if let Some(ref parent_id) = filters.parent {
-            if filters.recursive {
-                // Recursive: include all descendants using CTE.
-                // UNION (not UNION ALL) is required here for cycle safety:
-                // if parent-child dependencies form a cycle, UNION ALL would
-                // loop infinitely, while UNION deduplicates and terminates.
-                sql.push_str(
-                    " AND id IN (
-                        WITH RECURSIVE descendants AS (
-                            SELECT issue_id FROM dependencies
-                            WHERE depends_on_id = ? AND type = 'parent-child'
-                            UNION
-                            SELECT d.issue_id FROM dependencies d
-                            INNER JOIN descendants desc ON d.depends_on_id = desc.issue_id
-                            WHERE d.type = 'parent-child'
-                        )
-                        SELECT issue_id FROM descendants
-                    )",
-                );
-            } else {
-                // Non-recursive: only direct children
-                sql.push_str(
-                    " AND id IN (
-                        SELECT issue_id FROM dependencies
-                        WHERE depends_on_id = ? AND type = 'parent-child'
-                    )",
-                );
-            }
-            params.push(Box::new(parent_id.clone()));
-        }
+        push_ready_conditions(&mut sql, &mut params, filters);
 
         // Sorting
-        match sort {
-            ReadySortPolicy::Hybrid => {
-                // P0/P1 first by `created_at` ASC, then others by `created_at` ASC
-                sql.push_str(" ORDER BY CASE WHEN priority <= 1 THEN 0 ELSE 1 END, created_at ASC");
-            }
-            ReadySortPolicy::Priority => {
-                sql.push_str(" ORDER BY priority ASC, created_at ASC");
-            }
-            ReadySortPolicy::Oldest => {
-                sql.push_str(" ORDER BY created_at ASC");
-            }
-        }
+        sql.push_str(ready_order_by(sort));
 
         // Apply limit in SQL to avoid fetching extra rows.
         if let Some(limit) = filters.limit {
@@ -1214,85 +1238,528 @@ impl SqliteStorage {
         let mut stmt = self.conn.prepare(&sql)?;
         let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(AsRef::as_ref).collect();
         let issues: Vec<Issue> = stmt
-            .query_map(params_refs.as_slice(), |row| self.issue_from_row(row))?
+            .query_map(params_refs.as_slice(), Self::issue_from_row)?
             .collect::<std::result::Result<Vec<_>, _>>()?;
 
         Ok(issues)
     }
 
-    /// Get IDs of blocked issues from cache.
+    /// Atomically claim an issue for `actor` and attach a lease.
     ///
-    /// # Errors
-    ///
-    /// Returns an error if the database query fails.
-    pub fn get_blocked_ids(&self) -> Result<HashSet<String>> {
-        let mut stmt = self
-            .conn
-            .prepare_cached("SELECT issue_id FROM blocked_issues_cache")?;
-        let ids = stmt
-            .query_map([], |row| row.get(0))?
-            .collect::<std::result::Result<HashSet<String>, _>>()?;
-        Ok(ids)
-    }
-
-    /// Get issue IDs blocked by `blocks` dependency type only (not full cache).
+    /// Without `options.issue_id`, the highest-priority unassigned ready issue
+    /// matching `options.filters` is picked. Selection, the assignee check, the
+    /// `in_progress` transition and the lease insert all happen inside one
+    /// IMMEDIATE transaction, so two agents claiming at the same moment can
+    /// never receive the same issue. Expired leases are reaped first so their
+    /// issues become claimable again.
     ///
-    /// This is used for stats computation where blocked count should be based
-    /// only on `blocks` deps per classic bd semantics.
+    /// Returns `None` when nothing is ready to claim.
     ///
     /// # Errors
     ///
-    /// Returns an error if the database query fails.
-    pub fn get_blocked_by_blocks_deps_only(&self) -> Result<HashSet<String>> {
-        // Returns issues that:
-        // 1. Have a 'blocks' type dependency
-        // 2. Where the blocker is not closed/tombstone
-        // 3. AND the blocked issue itself is not closed/tombstone
-        let mut stmt = self.conn.prepare_cached(
-            r"SELECT DISTINCT d.issue_id
-              FROM dependencies d
-              LEFT JOIN issues blocker ON d.depends_on_id = blocker.id
-              LEFT JOIN issues blocked ON d.issue_id = blocked.id
-              WHERE d.type = 'blocks'
-                AND blocker.status NOT IN ('closed', 'tombstone')
-                AND blocked.status NOT IN ('closed', 'tombstone')",
-        )?;
-        let ids = stmt
-            .query_map([], |row| row.get(0))?
-            .collect::<std::result::Result<HashSet<String>, _>>()?;
-        Ok(ids)
+    /// Returns an error if the named issue does not exist, is not ready
+    /// (closed, blocked, deferred, pinned, ephemeral or a template), is held by
+    /// another actor, or if the transaction fails.
+    pub fn claim_issue(
+        &mut self,
+        options: &ClaimOptions,
+        actor: &str,
+    ) -> Result<Option<(Issue, ClaimLease)>> {
+        let now = Utc::now();
+        self.mutate("claim_issue", actor, |tx, ctx| {
+            Self::expire_leases_in_tx(tx, ctx, now)?;
+
+            let issue = if let Some(id) = options.issue_id.as_deref() {
+                let issue = Self::fetch_issue(tx, id)?
+                    .ok_or_else(|| BeadsError::IssueNotFound { id: id.to_string() })?;
+                Self::check_claimable(tx, &issue, actor, options.exclusive)?;
+                issue
+            } else {
+                match Self::next_claimable(tx, options)? {
+                    Some(issue) => issue,
+                    None => return Ok(None),
+                }
+            };
+
+            let lease = ClaimLease {
+                issue_id: issue.id.clone(),
+                actor: actor.to_string(),
+                claimed_at: now,
+                expires_at: lease_expiry(now, options.ttl)?,
+            };
+
+            let mut claimed = issue.clone();
+            claimed.status = Status::InProgress;
+            claimed.assignee = Some(actor.to_string());
+            claimed.updated_at = now;
+            tx.execute(
+                "UPDATE issues SET status = ?, assignee = ?, updated_at = ?, content_hash = ?
+                 WHERE id = ?",
+                rusqlite::params![
+                    claimed.status.as_str(),
+                    actor,
+                    now.to_rfc3339(),
+                    claimed.compute_content_hash(),
+                    claimed.id
+                ],
+            )?;
+            tx.execute(
+                "INSERT OR REPLACE INTO claim_leases (issue_id, actor, claimed_at, expires_at)
+                 VALUES (?, ?, ?, ?)",
+                rusqlite::params![
+                    lease.issue_id,
+                    lease.actor,
+                    lease.claimed_at.to_rfc3339(),
+                    lease.expires_at.to_rfc3339()
+                ],
+            )?;
+
+            if issue.status != Status::InProgress {
+                ctx.record_field_change(
+                    EventType::StatusChanged,
+                    &issue.id,
+                    Some(issue.status.as_str().to_string()),
+                    Some(Status::InProgress.as_str().to_string()),
+                    None,
+                );
+                ctx.invalidate_cache();
+            }
+            if issue.assignee.as_deref() != Some(actor) {
+                ctx.record_field_change(
+                    EventType::AssigneeChanged,
+                    &issue.id,
+                    issue.assignee.clone(),
+                    Some(actor.to_string()),
+                    None,
+                );
+            }
+            ctx.record_field_change(
+                EventType::Claimed,
+                &issue.id,
+                None,
+                Some(lease.expires_at.to_rfc3339()),
+                Some(format!("Lease held by {actor}")),
+            );
+            ctx.mark_dirty(&issue.id);
+
+            Ok(Some((claimed, lease)))
+        })
     }
 
-    /// Check if an issue is blocked (in the blocked cache).
+    /// Extend the lease `actor` holds on `issue_id` to `ttl` from now.
     ///
     /// # Errors
     ///
-    /// Returns an error if the database query fails.
-    pub fn is_blocked(&self, issue_id: &str) -> Result<bool> {
-        // Use EXISTS for efficiency (stops at first match)
-        let exists: bool = self
-            .conn
-            .prepare_cached("SELECT EXISTS(SELECT 1 FROM blocked_issues_cache WHERE issue_id = ?)")?
-            .query_row([issue_id], |row| row.get(0))?;
-        Ok(exists)
+    /// Returns an error if there is no live lease on the issue or it is held
+    /// by a different actor.
+    pub fn renew_lease(
+        &mut self,
+        issue_id: &str,
+        actor: &str,
+        ttl: chrono::Duration,
+    ) -> Result<ClaimLease> {
+        let now = Utc::now();
+        self.mutate("renew_lease", actor, |tx, ctx| {
+            Self::expire_leases_in_tx(tx, ctx, now)?;
+
+            let lease = Self::fetch_lease(tx, issue_id)?.ok_or_else(|| {
+                BeadsError::validation("lease", format!("no active lease on {issue_id}"))
+            })?;
+            if lease.actor != actor {
+                return Err(BeadsError::validation(
+                    "lease",
+                    format!("lease on {issue_id} is held by {}", lease.actor),
+                ));
+            }
+
+            let renewed = ClaimLease {
+                expires_at: lease_expiry(now, ttl)?,
+                ..lease.clone()
+            };
+            tx.execute(
+                "UPDATE claim_leases SET expires_at = ? WHERE issue_id = ?",
+                rusqlite::params![renewed.expires_at.to_rfc3339(), issue_id],
+            )?;
+            ctx.record_field_change(
+                EventType::LeaseRenewed,
+                issue_id,
+                Some(lease.expires_at.to_rfc3339()),
+                Some(renewed.expires_at.to_rfc3339()),
+                None,
+            );
+
+            Ok(renewed)
+        })
     }
 
-    /// Get the actual blockers for an issue from the blocked issues cache.
+    /// Release a lease and return the issue to the ready pool.
     ///
-    /// Returns the issue IDs that are blocking this issue. The format includes
-    /// status annotations like "bd-123:open" or "bd-456:parent-blocked".
-    /// Returns an empty vec if the issue is not blocked.
+    /// The issue goes back to `open` and unassigned only if it is still
+    /// `in_progress` under the lease holder; otherwise only the lease is dropped.
     ///
     /// # Errors
     ///
-    /// Returns an error if the database query fails.
-    pub fn get_blockers(&self, issue_id: &str) -> Result<Vec<String>> {
-        let json_opt: Option<String> = self
-            .conn
-            .prepare_cached("SELECT blocked_by FROM blocked_issues_cache WHERE issue_id = ?")?
-            .query_row([issue_id], |row| row.get(0))
-            .optional()?;
-
+    /// Returns an error if there is no lease on the issue, or it is held by a
+    /// different actor and `force` is false.
+    pub fn release_lease(
+        &mut self,
+        issue_id: &str,
+        actor: &str,
+        force: bool,
+    ) -> Result<ClaimLease> {
+        let now = Utc::now();
+        self.mutate("release_lease", actor, |tx, ctx| {
+            let lease = Self::fetch_lease(tx, issue_id)?.ok_or_else(|| {
+                BeadsError::validation("lease", format!("no active lease on {issue_id}"))
+            })?;
+            if lease.actor != actor && !force {
+                return Err(BeadsError::validation(
+                    "lease",
+                    format!(
+                        "lease on {issue_id} is held by {} (use --force to release it)",
+                        lease.actor
+                    ),
+                ));
+            }
+
+            tx.execute("DELETE FROM claim_leases WHERE issue_id = ?", [issue_id])?;
+            ctx.record_field_change(
+                EventType::LeaseReleased,
+                issue_id,
+                Some(lease.actor.clone()),
+                None,
+                None,
+            );
+            Self::return_to_ready(tx, ctx, issue_id, &lease.actor, now)?;
+
+            Ok(lease)
+        })
+    }
+
+    /// Reap expired leases, returning their issues to the ready pool.
+    ///
+    /// Cheap when nothing has expired: no write transaction is opened.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query or update fails.
+    pub fn expire_leases(&mut self, actor: &str) -> Result<Vec<ClaimLease>> {
+        let now = Utc::now();
+        if !self.list_leases()?.iter().any(|l| l.is_expired_at(now)) {
+            return Ok(Vec::new());
+        }
+        self.mutate("expire_leases", actor, |tx, ctx| {
+            Self::expire_leases_in_tx(tx, ctx, now)
+        })
+    }
+
+    /// Get the lease on an issue, if any (expired leases included until reaped).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_lease(&self, issue_id: &str) -> Result<Option<ClaimLease>> {
+        Self::fetch_lease(&self.conn, issue_id)
+    }
+
+    /// List all leases, soonest expiry first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn list_leases(&self) -> Result<Vec<ClaimLease>> {
+        Self::fetch_leases(&self.conn)
+    }
+
+    fn fetch_lease(conn: &Connection, issue_id: &str) -> Result<Option<ClaimLease>> {
+        Ok(conn
+            .query_row(
+                "SELECT issue_id, actor, claimed_at, expires_at FROM claim_leases WHERE issue_id = ?",
+                [issue_id],
+                lease_from_row,
+            )
+            .optional()?)
+    }
+
+    fn fetch_leases(conn: &Connection) -> Result<Vec<ClaimLease>> {
+        let mut stmt = conn.prepare(
+            "SELECT issue_id, actor, claimed_at, expires_at FROM claim_leases
+             ORDER BY expires_at ASC, issue_id ASC",
+        )?;
+        let leases = stmt
+            .query_map([], lease_from_row)?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(leases)
+    }
+
+    fn expire_leases_in_tx(
+        tx: &Transaction,
+        ctx: &mut MutationContext,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<ClaimLease>> {
+        let expired: Vec<ClaimLease> = Self::fetch_leases(tx)?
+            .into_iter()
+            .filter(|lease| lease.is_expired_at(now))
+            .collect();
+
+        for lease in &expired {
+            tx.execute(
+                "DELETE FROM claim_leases WHERE issue_id = ?",
+                [&lease.issue_id],
+            )?;
+            ctx.record_field_change(
+                EventType::LeaseExpired,
+                &lease.issue_id,
+                Some(lease.actor.clone()),
+                None,
+                Some(format!(
+                    "Lease expired at {}",
+                    lease.expires_at.to_rfc3339()
+                )),
+            );
+            Self::return_to_ready(tx, ctx, &lease.issue_id, &lease.actor, now)?;
+        }
+
+        Ok(expired)
+    }
+
+    /// Put an issue claimed by `holder` back to `open` and unassigned.
+    ///
+    /// Leaves the issue alone if someone already moved it on (closed it,
+    /// reassigned it, ...) since the lease no longer describes its state.
+    fn return_to_ready(
+        tx: &Transaction,
+        ctx: &mut MutationContext,
+        issue_id: &str,
+        holder: &str,
+        now: DateTime<Utc>,
+    ) -> Result<bool> {
+        let Some(issue) = Self::fetch_issue(tx, issue_id)? else {
+            return Ok(false);
+        };
+        if issue.status != Status::InProgress || issue.assignee.as_deref() != Some(holder) {
+            return Ok(false);
+        }
+
+        let mut reopened = issue;
+        reopened.status = Status::Open;
+        reopened.assignee = None;
+        reopened.updated_at = now;
+        tx.execute(
+            "UPDATE issues SET status = ?, assignee = NULL, updated_at = ?, content_hash = ?
+             WHERE id = ?",
+            rusqlite::params![
+                reopened.status.as_str(),
+                now.to_rfc3339(),
+                reopened.compute_content_hash(),
+                issue_id
+            ],
+        )?;
+
+        ctx.record_field_change(
+            EventType::StatusChanged,
+            issue_id,
+            Some(Status::InProgress.as_str().to_string()),
+            Some(Status::Open.as_str().to_string()),
+            None,
+        );
+        ctx.record_field_change(
+            EventType::AssigneeChanged,
+            issue_id,
+            Some(holder.to_string()),
+            None,
+            None,
+        );
+        ctx.mark_dirty(issue_id);
+        ctx.invalidate_cache();
+
+        Ok(true)
+    }
+
+    /// Verify a named issue may be claimed by `actor`.
+    fn check_claimable(
+        conn: &Connection,
+        issue: &Issue,
+        actor: &str,
+        exclusive: bool,
+    ) -> Result<()> {
+        let id = &issue.id;
+        if !issue.status.is_active() {
+            return Err(BeadsError::validation(
+                "claim",
+                format!("cannot claim {} issue {id}", issue.status.as_str()),
+            ));
+        }
+        // The same exclusions `push_ready_conditions` applies to `br ready`.
+        if let Some(defer_until) = issue.defer_until.filter(|until| *until > Utc::now()) {
+            return Err(BeadsError::validation(
+                "claim",
+                format!(
+                    "cannot claim {id}: deferred until {}",
+                    defer_until.to_rfc3339()
+                ),
+            ));
+        }
+        let excluded = if issue.pinned {
+            Some("pinned")
+        } else if issue.ephemeral || id.contains("-wisp-") {
+            Some("ephemeral")
+        } else if issue.is_template {
+            Some("template")
+        } else {
+            None
+        };
+        if let Some(kind) = excluded {
+            return Err(BeadsError::validation(
+                "claim",
+                format!("cannot claim {kind} issue {id}"),
+            ));
+        }
+
+        let blocked_by: Option<String> = conn
+            .query_row(
+                "SELECT blocked_by FROM blocked_issues_cache WHERE issue_id = ?",
+                [id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        if let Some(blocked_by) = blocked_by {
+            let blockers: Vec<String> = serde_json::from_str(&blocked_by).unwrap_or_default();
+            return Err(BeadsError::validation(
+                "claim",
+                format!("cannot claim blocked issue: {}", blockers.join(", ")),
+            ));
+        }
+
+        if let Some(lease) = Self::fetch_lease(conn, id)? {
+            if lease.actor != actor || exclusive {
+                return Err(BeadsError::validation(
+                    "claim",
+                    format!(
+                        "issue {id} already claimed by {} until {}",
+                        lease.actor,
+                        lease.expires_at.to_rfc3339()
+                    ),
+                ));
+            }
+        }
+
+        match issue.assignee.as_deref().map(str::trim) {
+            None | Some("") => Ok(()),
+            Some(current) if current == actor && !exclusive => Ok(()),
+            Some(current) => Err(BeadsError::validation(
+                "claim",
+                format!("issue {id} already assigned to {current}"),
+            )),
+        }
+    }
+
+    /// Pick the highest-priority unassigned, unleased ready issue.
+    fn next_claimable(conn: &Connection, options: &ClaimOptions) -> Result<Option<Issue>> {
+        let mut sql = String::from(
+            r"SELECT id, content_hash, title, description, design, acceptance_criteria, notes,
+                     status, priority, issue_type, assignee, owner, estimated_minutes,
+                     created_at, created_by, updated_at, closed_at, close_reason, closed_by_session,
+                     due_at, defer_until, external_ref, source_system, source_repo,
+                     deleted_at, deleted_by, delete_reason, original_type,
+                     compaction_level, compacted_at, compacted_at_commit, original_size,
+                     sender, ephemeral, pinned, is_template
+              FROM issues WHERE 1=1",
+        );
+        let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
+        push_ready_conditions(&mut sql, &mut params, &options.filters);
+
+        sql.push_str(" AND (assignee IS NULL OR assignee = '')");
+        sql.push_str(" AND NOT EXISTS (SELECT 1 FROM claim_leases WHERE issue_id = issues.id)");
+        if !options.exclude_ids.is_empty() {
+            let placeholders: Vec<&str> = options.exclude_ids.iter().map(|_| "?").collect();
+            let _ = write!(sql, " AND id NOT IN ({})", placeholders.join(","));
+            for id in &options.exclude_ids {
+                params.push(Box::new(id.clone()));
+            }
+        }
+        sql.push_str(ready_order_by(ReadySortPolicy::Priority));
+        sql.push_str(" LIMIT 1");
+
+        let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(AsRef::as_ref).collect();
+        Ok(conn
+            .query_row(&sql, params_refs.as_slice(), Self::issue_from_row)
+            .optional()?)
+    }
+
+    /// Get IDs of blocked issues from cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_blocked_ids(&self) -> Result<HashSet<String>> {
+        let mut stmt = self
+            .conn
+            .prepare_cached("SELECT issue_id FROM blocked_issues_cache")?;
+        let ids = stmt
+            .query_map([], |row| row.get(0))?
+            .collect::<std::result::Result<HashSet<String>, _>>()?;
+        Ok(ids)
+    }
+
+    /// Get issue IDs blocked by `blocks` dependency type only (not full cache).
+    ///
+    /// This is used for stats computation where blocked count should be based
+    /// only on `blocks` deps per classic bd semantics.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_blocked_by_blocks_deps_only(&self) -> Result<HashSet<String>> {
+        // Returns issues that:
+        // 1. Have a 'blocks' type dependency
+        // 2. Where the blocker is not closed/tombstone
+        // 3. AND the blocked issue itself is not closed/tombstone
+        let mut stmt = self.conn.prepare_cached(
+            r"SELECT DISTINCT d.issue_id
+              FROM dependencies d
+              LEFT JOIN issues blocker ON d.depends_on_id = blocker.id
+              LEFT JOIN issues blocked ON d.issue_id = blocked.id
+              WHERE d.type = 'blocks'
+                AND blocker.status NOT IN ('closed', 'tombstone')
+                AND blocked.status NOT IN ('closed', 'tombstone')",
+        )?;
+        let ids = stmt
+            .query_map([], |row| row.get(0))?
+            .collect::<std::result::Result<HashSet<String>, _>>()?;
+        Ok(ids)
+    }
+
+    /// Check if an issue is blocked (in the blocked cache).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn is_blocked(&self, issue_id: &str) -> Result<bool> {
+        // Use EXISTS for efficiency (stops at first match)
+        let exists: bool = self
+            .conn
+            .prepare_cached("SELECT EXISTS(SELECT 1 FROM blocked_issues_cache WHERE issue_id = ?)")?
+            .query_row([issue_id], |row| row.get(0))?;
+        Ok(exists)
+    }
+
+    /// Get the actual blockers for an issue from the blocked issues cache.
+    ///
+    /// Returns the issue IDs that are blocking this issue. The format includes
+    /// status annotations like "bd-123:open" or "bd-456:parent-blocked".
+    /// Returns an empty vec if the issue is not blocked.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_blockers(&self, issue_id: &str) -> Result<Vec<String>> {
+        let json_opt: Option<String> = self
+            .conn
+            .prepare_cached("SELECT blocked_by FROM blocked_issues_cache WHERE issue_id = ?")?
+            .query_row([issue_id], |row| row.get(0))
+            .optional()?;
+
         match json_opt {
             Some(json) => {
                 let blockers: Vec<String> = match serde_json::from_str(&json) {
@@ -1312,6 +1779,18 @@ impl SqliteStorage {
         }
     }
 
+    /// Rebuild the full-text search index from scratch.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails.
+    pub fn rebuild_search_index(&mut self) -> Result<usize> {
+        let tx = self.conn.transaction()?;
+        let count = crate::storage::schema::rebuild_search_index(&tx)?;
+        tx.commit()?;
+        Ok(count)
+    }
+
     /// Rebuild the blocked issues cache from scratch.
     ///
     /// This computes which issues are blocked based on their dependencies
@@ -1485,7 +1964,7 @@ impl SqliteStorage {
 
         let results = stmt
             .query_map([], |row| {
-                let issue = self.issue_from_row(row)?;
+                let issue = Self::issue_from_row(row)?;
                 let blockers_json: String = row.get(36)?;
                 Ok((issue, blockers_json))
             })?
@@ -1656,6 +2135,33 @@ impl SqliteStorage {
             .collect())
     }
 
+    /// Find the external project that holds `issue_id`.
+    ///
+    /// Projects whose database cannot be read are skipped. Returns the first
+    /// match in project-name order.
+    #[must_use]
+    pub fn locate_external_issue(
+        external_db_paths: &HashMap<String, PathBuf>,
+        issue_id: &str,
+    ) -> Option<String> {
+        let mut projects: Vec<(&String, &PathBuf)> = external_db_paths.iter().collect();
+        projects.sort();
+        projects
+            .into_iter()
+            .find(|(project, db_path)| {
+                db_path.is_file()
+                    && external_issue_exists(db_path, issue_id).unwrap_or_else(|err| {
+                        tracing::warn!(
+                            project = %project,
+                            error = %err,
+                            "Failed to query external project"
+                        );
+                        false
+                    })
+            })
+            .map(|(project, _)| project.clone())
+    }
+
     fn list_external_dependency_ids(&self, blocking_only: bool) -> Result<HashSet<String>> {
         let mut ids = HashSet::new();
         let sql = if blocking_only {
@@ -2207,89 +2713,513 @@ impl SqliteStorage {
         Ok(map)
     }
 
-    /// Get all unique labels with their issue counts.
+    /// Get all unique labels with their issue counts.
+    ///
+    /// Returns a vector of (label, count) pairs sorted alphabetically by label.
+    /// Excludes labels on tombstoned (deleted) issues.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_unique_labels_with_counts(&self) -> Result<Vec<(String, i64)>> {
+        let mut stmt = self.conn.prepare_cached(
+            r"SELECT l.label, COUNT(*) as count
+              FROM labels l
+              JOIN issues i ON l.issue_id = i.id
+              WHERE i.status != 'tombstone'
+              GROUP BY l.label
+              ORDER BY l.label",
+        )?;
+        let results = stmt
+            .query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(results)
+    }
+
+    /// Rename a label across all issues.
+    ///
+    /// Returns the number of issues affected.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub fn rename_label(&mut self, old_name: &str, new_name: &str, actor: &str) -> Result<usize> {
+        self.mutate("rename_label", actor, |tx, ctx| {
+            // Get all issue IDs that have the old label
+            let mut stmt = tx.prepare("SELECT issue_id FROM labels WHERE label = ?")?;
+            let issue_ids: Vec<String> = stmt
+                .query_map([old_name], |row| row.get(0))?
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+            drop(stmt);
+
+            // Check if any issues already have the new label (would cause duplicates)
+            let mut check_stmt =
+                tx.prepare("SELECT issue_id FROM labels WHERE label = ? AND issue_id IN (SELECT issue_id FROM labels WHERE label = ?)")?;
+            let conflicts: Vec<String> = check_stmt
+                .query_map(rusqlite::params![new_name, old_name], |row| row.get(0))?
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+            drop(check_stmt);
+
+            // For issues that already have the new label, just remove the old one
+            for conflict_id in &conflicts {
+                tx.execute(
+                    "DELETE FROM labels WHERE issue_id = ? AND label = ?",
+                    rusqlite::params![conflict_id, old_name],
+                )?;
+                ctx.mark_dirty(conflict_id);
+            }
+
+            // Rename the label for issues that don't have conflicts
+            let renamed = tx.execute(
+                "UPDATE labels SET label = ? WHERE label = ?",
+                rusqlite::params![new_name, old_name],
+            )?;
+
+            // Mark all affected issues as dirty and record events
+            let now = Utc::now().to_rfc3339();
+            for issue_id in &issue_ids {
+                ctx.record_event(
+                    EventType::LabelRemoved,
+                    issue_id,
+                    Some(format!("Renamed label {old_name} to {new_name}")),
+                );
+                ctx.mark_dirty(issue_id);
+
+                // Update timestamp
+                tx.execute(
+                    "UPDATE issues SET updated_at = ? WHERE id = ?",
+                    rusqlite::params![now, issue_id],
+                )?;
+            }
+
+            Ok(renamed + conflicts.len())
+        })
+    }
+
+    /// Start a timer for `actor` on `issue_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the issue does not exist, `actor` already has a
+    /// timer running on it, or the database update fails.
+    pub fn start_timer(
+        &mut self,
+        issue_id: &str,
+        actor: &str,
+        note: Option<&str>,
+    ) -> Result<TimeEntry> {
+        let now = Utc::now();
+        self.mutate("start_timer", actor, |tx, ctx| {
+            if Self::fetch_issue(tx, issue_id)?.is_none() {
+                return Err(BeadsError::IssueNotFound {
+                    id: issue_id.to_string(),
+                });
+            }
+            if let Some(running) = fetch_running_timers(tx, Some(actor))?
+                .into_iter()
+                .find(|entry| entry.issue_id == issue_id)
+            {
+                return Err(BeadsError::validation(
+                    "timer",
+                    format!(
+                        "{actor} already has a timer running on {issue_id} since {}",
+                        running.started_at.to_rfc3339()
+                    ),
+                ));
+            }
+
+            let entry_id = insert_time_entry_row(tx, issue_id, actor, now, None, 0, note)?;
+            tx.execute(
+                "UPDATE issues SET updated_at = ? WHERE id = ?",
+                rusqlite::params![now.to_rfc3339(), issue_id],
+            )?;
+            ctx.record_event(EventType::TimerStarted, issue_id, note.map(str::to_string));
+            ctx.mark_dirty(issue_id);
+
+            fetch_time_entry(tx, entry_id)
+        })
+    }
+
+    /// Stop the timer `actor` has running on `issue_id`.
+    ///
+    /// Without an issue ID, stops the actor's only running timer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no matching timer is running, `issue_id` is `None`
+    /// and the actor has timers on several issues, or the update fails.
+    pub fn stop_timer(&mut self, issue_id: Option<&str>, actor: &str) -> Result<TimeEntry> {
+        let now = Utc::now();
+        self.mutate("stop_timer", actor, |tx, ctx| {
+            let mut running: Vec<TimeEntry> = fetch_running_timers(tx, Some(actor))?
+                .into_iter()
+                .filter(|entry| issue_id.is_none_or(|id| entry.issue_id == id))
+                .collect();
+            let entry = match running.len() {
+                0 => {
+                    let scope = issue_id.map(|id| format!(" on {id}")).unwrap_or_default();
+                    return Err(BeadsError::validation(
+                        "timer",
+                        format!("{actor} has no timer running{scope}"),
+                    ));
+                }
+                1 => running.remove(0),
+                _ => {
+                    let ids: Vec<&str> = running.iter().map(|e| e.issue_id.as_str()).collect();
+                    return Err(BeadsError::validation(
+                        "timer",
+                        format!(
+                            "{actor} has timers running on {}; name the issue to stop",
+                            ids.join(", ")
+                        ),
+                    ));
+                }
+            };
+
+            let minutes = clamp_minutes(elapsed_minutes(entry.started_at, now));
+            tx.execute(
+                "UPDATE time_entries SET ended_at = ?, minutes = ? WHERE id = ?",
+                rusqlite::params![now.to_rfc3339(), minutes, entry.id],
+            )?;
+            tx.execute(
+                "UPDATE issues SET updated_at = ? WHERE id = ?",
+                rusqlite::params![now.to_rfc3339(), entry.issue_id],
+            )?;
+            ctx.record_field_change(
+                EventType::TimeLogged,
+                &entry.issue_id,
+                None,
+                Some(minutes.to_string()),
+                entry.note.clone(),
+            );
+            ctx.mark_dirty(&entry.issue_id);
+
+            fetch_time_entry(tx, entry.id)
+        })
+    }
+
+    /// Record `minutes` of work on `issue_id` that finished at `ended_at`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the issue does not exist, `minutes` is not
+    /// positive, or the database update fails.
+    pub fn log_time(
+        &mut self,
+        issue_id: &str,
+        actor: &str,
+        minutes: i32,
+        ended_at: DateTime<Utc>,
+        note: Option<&str>,
+    ) -> Result<TimeEntry> {
+        if minutes <= 0 {
+            return Err(BeadsError::validation(
+                "duration",
+                "logged time must be positive",
+            ));
+        }
+        let started_at = ended_at - chrono::Duration::minutes(i64::from(minutes));
+        self.mutate("log_time", actor, |tx, ctx| {
+            if Self::fetch_issue(tx, issue_id)?.is_none() {
+                return Err(BeadsError::IssueNotFound {
+                    id: issue_id.to_string(),
+                });
+            }
+
+            let entry_id = insert_time_entry_row(
+                tx,
+                issue_id,
+                actor,
+                started_at,
+                Some(ended_at),
+                minutes,
+                note,
+            )?;
+            tx.execute(
+                "UPDATE issues SET updated_at = ? WHERE id = ?",
+                rusqlite::params![Utc::now().to_rfc3339(), issue_id],
+            )?;
+            ctx.record_field_change(
+                EventType::TimeLogged,
+                issue_id,
+                None,
+                Some(minutes.to_string()),
+                note.map(str::to_string),
+            );
+            ctx.mark_dirty(issue_id);
+
+            fetch_time_entry(tx, entry_id)
+        })
+    }
+
+    /// Get time entries for an issue, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_time_entries(&self, issue_id: &str) -> Result<Vec<TimeEntry>> {
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT id, issue_id, actor, started_at, ended_at, minutes, note
+             FROM time_entries
+             WHERE issue_id = ?
+             ORDER BY started_at ASC, id ASC",
+        )?;
+        let entries = stmt
+            .query_map([issue_id], time_entry_from_row)?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(entries)
+    }
+
+    /// Get all time entries, keyed by issue ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_all_time_entries(&self) -> Result<HashMap<String, Vec<TimeEntry>>> {
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT id, issue_id, actor, started_at, ended_at, minutes, note
+             FROM time_entries
+             ORDER BY issue_id, started_at ASC, id ASC",
+        )?;
+        let rows = stmt.query_map([], time_entry_from_row)?;
+
+        let mut map: HashMap<String, Vec<TimeEntry>> = HashMap::new();
+        for row in rows {
+            let entry = row?;
+            map.entry(entry.issue_id.clone()).or_default().push(entry);
+        }
+        Ok(map)
+    }
+
+    /// List running timers, optionally only those of `actor`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_running_timers(&self, actor: Option<&str>) -> Result<Vec<TimeEntry>> {
+        fetch_running_timers(&self.conn, actor)
+    }
+
+    /// Attach a recurrence rule to `issue_id`, replacing any previous rule.
+    ///
+    /// The issue becomes a template, so it stays out of ready work and lists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the issue does not exist or the update fails.
+    pub fn set_recurrence(
+        &mut self,
+        issue_id: &str,
+        recurrence: &Recurrence,
+        actor: &str,
+    ) -> Result<()> {
+        self.mutate("set_recurrence", actor, |tx, ctx| {
+            if Self::fetch_issue(tx, issue_id)?.is_none() {
+                return Err(BeadsError::IssueNotFound {
+                    id: issue_id.to_string(),
+                });
+            }
+            let previous = fetch_recurrence(tx, issue_id)?;
+            upsert_recurrence_row(tx, issue_id, recurrence)?;
+            tx.execute(
+                "UPDATE issues SET is_template = 1, updated_at = ? WHERE id = ?",
+                rusqlite::params![Utc::now().to_rfc3339(), issue_id],
+            )?;
+            ctx.record_field_change(
+                EventType::Updated,
+                issue_id,
+                previous.map(|rule| rule.rule),
+                Some(recurrence.rule.clone()),
+                Some("recurrence".to_string()),
+            );
+            ctx.mark_dirty(issue_id);
+            Ok(())
+        })
+    }
+
+    /// Remove the recurrence rule from `issue_id`. The issue stays a template.
+    ///
+    /// Returns `false` if the issue had no rule.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub fn clear_recurrence(&mut self, issue_id: &str, actor: &str) -> Result<bool> {
+        self.mutate("clear_recurrence", actor, |tx, ctx| {
+            let Some(previous) = fetch_recurrence(tx, issue_id)? else {
+                return Ok(false);
+            };
+            tx.execute(
+                "DELETE FROM recurrence_rules WHERE issue_id = ?",
+                [issue_id],
+            )?;
+            tx.execute(
+                "UPDATE issues SET updated_at = ? WHERE id = ?",
+                rusqlite::params![Utc::now().to_rfc3339(), issue_id],
+            )?;
+            ctx.record_field_change(
+                EventType::Updated,
+                issue_id,
+                Some(previous.rule),
+                None,
+                Some("recurrence".to_string()),
+            );
+            ctx.mark_dirty(issue_id);
+            Ok(true)
+        })
+    }
+
+    /// Get the recurrence rule attached to an issue.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_recurrence(&self, issue_id: &str) -> Result<Option<Recurrence>> {
+        fetch_recurrence(&self.conn, issue_id)
+    }
+
+    /// Get all recurrence rules, keyed by template issue ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_all_recurrences(&self) -> Result<HashMap<String, Recurrence>> {
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT issue_id, rule, starts_at, lead_minutes, due_minutes
+             FROM recurrence_rules
+             ORDER BY issue_id",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, recurrence_from_row(row)?))
+        })?;
+        let mut map = HashMap::new();
+        for row in rows {
+            let (issue_id, recurrence) = row?;
+            map.insert(issue_id, recurrence);
+        }
+        Ok(map)
+    }
+
+    /// Record `attachment` on `issue_id`, replacing any attachment with the
+    /// same name. The blob must already be in the blob store.
+    ///
+    /// Returns the replaced attachment, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the issue does not exist or the update fails.
+    pub fn add_attachment(
+        &mut self,
+        issue_id: &str,
+        attachment: &Attachment,
+        actor: &str,
+    ) -> Result<Option<Attachment>> {
+        self.mutate("add_attachment", actor, |tx, ctx| {
+            if Self::fetch_issue(tx, issue_id)?.is_none() {
+                return Err(BeadsError::IssueNotFound {
+                    id: issue_id.to_string(),
+                });
+            }
+            let previous = fetch_attachment(tx, issue_id, &attachment.name)?;
+            tx.execute(
+                "DELETE FROM attachments WHERE issue_id = ? AND name = ?",
+                rusqlite::params![issue_id, attachment.name],
+            )?;
+            insert_attachment_row(tx, issue_id, attachment)?;
+            tx.execute(
+                "UPDATE issues SET updated_at = ? WHERE id = ?",
+                rusqlite::params![Utc::now().to_rfc3339(), issue_id],
+            )?;
+            ctx.record_field_change(
+                EventType::Attached,
+                issue_id,
+                previous.as_ref().map(|prev| prev.sha256.clone()),
+                Some(attachment.sha256.clone()),
+                Some(attachment.name.clone()),
+            );
+            ctx.mark_dirty(issue_id);
+            Ok(previous)
+        })
+    }
+
+    /// Remove the attachment called `name` from `issue_id`. The blob is left
+    /// in place; `br doctor --fix` removes blobs nothing refers to.
     ///
-    /// Returns a vector of (label, count) pairs sorted alphabetically by label.
-    /// Excludes labels on tombstoned (deleted) issues.
+    /// Returns the removed attachment, or `None` if there was none.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub fn remove_attachment(
+        &mut self,
+        issue_id: &str,
+        name: &str,
+        actor: &str,
+    ) -> Result<Option<Attachment>> {
+        self.mutate("remove_attachment", actor, |tx, ctx| {
+            let Some(previous) = fetch_attachment(tx, issue_id, name)? else {
+                return Ok(None);
+            };
+            tx.execute(
+                "DELETE FROM attachments WHERE issue_id = ? AND name = ?",
+                rusqlite::params![issue_id, name],
+            )?;
+            tx.execute(
+                "UPDATE issues SET updated_at = ? WHERE id = ?",
+                rusqlite::params![Utc::now().to_rfc3339(), issue_id],
+            )?;
+            ctx.record_field_change(
+                EventType::Detached,
+                issue_id,
+                Some(previous.sha256.clone()),
+                None,
+                Some(previous.name.clone()),
+            );
+            ctx.mark_dirty(issue_id);
+            Ok(Some(previous))
+        })
+    }
+
+    /// Get attachments for an issue, oldest first.
     ///
     /// # Errors
     ///
     /// Returns an error if the database query fails.
-    pub fn get_unique_labels_with_counts(&self) -> Result<Vec<(String, i64)>> {
+    pub fn get_attachments(&self, issue_id: &str) -> Result<Vec<Attachment>> {
         let mut stmt = self.conn.prepare_cached(
-            r"SELECT l.label, COUNT(*) as count
-              FROM labels l
-              JOIN issues i ON l.issue_id = i.id
-              WHERE i.status != 'tombstone'
-              GROUP BY l.label
-              ORDER BY l.label",
+            "SELECT name, sha256, size, media_type, created_at, created_by, local_only
+             FROM attachments
+             WHERE issue_id = ?
+             ORDER BY created_at ASC, name ASC",
         )?;
-        let results = stmt
-            .query_map([], |row| {
-                Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
-            })?
+        let attachments = stmt
+            .query_map([issue_id], attachment_from_row)?
             .collect::<std::result::Result<Vec<_>, _>>()?;
-        Ok(results)
+        Ok(attachments)
     }
 
-    /// Rename a label across all issues.
-    ///
-    /// Returns the number of issues affected.
+    /// Get all attachments, keyed by issue ID.
     ///
     /// # Errors
     ///
-    /// Returns an error if the database update fails.
-    pub fn rename_label(&mut self, old_name: &str, new_name: &str, actor: &str) -> Result<usize> {
-        self.mutate("rename_label", actor, |tx, ctx| {
-            // Get all issue IDs that have the old label
-            let mut stmt = tx.prepare("SELECT issue_id FROM labels WHERE label = ?")?;
-            let issue_ids: Vec<String> = stmt
-                .query_map([old_name], |row| row.get(0))?
-                .collect::<std::result::Result<Vec<_>, _>>()?;
-            drop(stmt);
-
-            // Check if any issues already have the new label (would cause duplicates)
-            let mut check_stmt =
-                tx.prepare("SELECT issue_id FROM labels WHERE label = ? AND issue_id IN (SELECT issue_id FROM labels WHERE label = ?)")?;
-            let conflicts: Vec<String> = check_stmt
-                .query_map(rusqlite::params![new_name, old_name], |row| row.get(0))?
-                .collect::<std::result::Result<Vec<_>, _>>()?;
-            drop(check_stmt);
-
-            // For issues that already have the new label, just remove the old one
-            for conflict_id in &conflicts {
-                tx.execute(
-                    "DELETE FROM labels WHERE issue_id = ? AND label = ?",
-                    rusqlite::params![conflict_id, old_name],
-                )?;
-                ctx.mark_dirty(conflict_id);
-            }
-
-            // Rename the label for issues that don't have conflicts
-            let renamed = tx.execute(
-                "UPDATE labels SET label = ? WHERE label = ?",
-                rusqlite::params![new_name, old_name],
-            )?;
-
-            // Mark all affected issues as dirty and record events
-            let now = Utc::now().to_rfc3339();
-            for issue_id in &issue_ids {
-                ctx.record_event(
-                    EventType::LabelRemoved,
-                    issue_id,
-                    Some(format!("Renamed label {old_name} to {new_name}")),
-                );
-                ctx.mark_dirty(issue_id);
-
-                // Update timestamp
-                tx.execute(
-                    "UPDATE issues SET updated_at = ? WHERE id = ?",
-                    rusqlite::params![now, issue_id],
-                )?;
-            }
-
-            Ok(renamed + conflicts.len())
-        })
+    /// Returns an error if the database query fails.
+    pub fn get_all_attachments(&self) -> Result<HashMap<String, Vec<Attachment>>> {
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT name, sha256, size, media_type, created_at, created_by, local_only, issue_id
+             FROM attachments
+             ORDER BY issue_id, created_at ASC, name ASC",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok((row.get::<_, String>(7)?, attachment_from_row(row)?))
+        })?;
+        let mut map: HashMap<String, Vec<Attachment>> = HashMap::new();
+        for row in rows {
+            let (issue_id, attachment) = row?;
+            map.entry(issue_id).or_default().push(attachment);
+        }
+        Ok(map)
     }
 
     /// Get comments for an issue.
@@ -2699,7 +3629,7 @@ impl SqliteStorage {
 
         let mut stmt = self.conn.prepare_cached(sql)?;
         let issues = stmt
-            .query_map([], |row| self.issue_from_row(row))?
+            .query_map([], Self::issue_from_row)?
             .collect::<std::result::Result<Vec<_>, _>>()?;
 
         Ok(issues)
@@ -3030,9 +3960,10 @@ impl SqliteStorage {
         include_events: bool,
         event_limit: usize,
     ) -> Result<Option<IssueDetails>> {
-        let Some(issue) = self.get_issue(id)? else {
+        let Some(mut issue) = self.get_issue(id)? else {
             return Ok(None);
         };
+        issue.attachments = self.get_attachments(id)?;
 
         let labels = self.get_labels(id)?;
         let dependencies = self.get_dependencies_with_metadata(id)?;
@@ -3068,8 +3999,7 @@ impl SqliteStorage {
         s.filter(|v| !v.is_empty())
     }
 
-    #[allow(clippy::unused_self)]
-    fn issue_from_row(&self, row: &rusqlite::Row) -> rusqlite::Result<Issue> {
+    fn issue_from_row(row: &rusqlite::Row) -> rusqlite::Result<Issue> {
         Ok(Issue {
             id: row.get(0)?,
             content_hash: row.get::<_, Option<String>>(1)?,
@@ -3125,6 +4055,9 @@ impl SqliteStorage {
             labels: vec![],       // Loaded separately if needed
             dependencies: vec![], // Loaded separately if needed
             comments: vec![],     // Loaded separately if needed
+            time_entries: vec![],
+            recurrence: None,
+            attachments: vec![],
         })
     }
 
@@ -3178,6 +4111,18 @@ pub struct ListFilters {
     pub updated_before: Option<DateTime<Utc>>,
     /// Filter by `updated_at` >= timestamp
     pub updated_after: Option<DateTime<Utc>>,
+    /// Parsed `--where` expression, AND-ed with the other filters
+    pub expr: Option<FilterExpr>,
+}
+
+/// A single full-text search result.
+#[derive(Debug, Clone)]
+pub struct SearchHit {
+    pub issue: Issue,
+    /// BM25 relevance (higher is better); `None` for ID-only matches.
+    pub score: Option<f64>,
+    /// Best-matching excerpt with matched terms wrapped in `**`.
+    pub snippet: Option<String>,
 }
 
 /// Fields to update on an issue.
@@ -3270,6 +4215,169 @@ pub enum ReadySortPolicy {
     Oldest,
 }
 
+/// Options for claiming an issue with a lease.
+#[derive(Debug, Clone)]
+pub struct ClaimOptions {
+    /// Claim this issue instead of picking the next ready one.
+    pub issue_id: Option<String>,
+    /// Ready filters used when picking automatically.
+    pub filters: ReadyFilters,
+    /// Issues to skip when picking automatically (e.g. external blockers).
+    pub exclude_ids: HashSet<String>,
+    /// How long the lease lasts before the issue returns to ready.
+    pub ttl: chrono::Duration,
+    /// Reject re-claims even by the current holder.
+    pub exclusive: bool,
+}
+
+/// Compute when a lease taken at `now` with `ttl` runs out.
+fn lease_expiry(now: DateTime<Utc>, ttl: chrono::Duration) -> Result<DateTime<Utc>> {
+    now.checked_add_signed(ttl)
+        .ok_or_else(|| BeadsError::validation("ttl", "lease TTL is too large"))
+}
+
+/// Append the ready-work conditions for `filters` to a query over `issues`.
+///
+/// Shared by `get_ready_issues` and `claim_issue` so both agree on what
+/// "ready" means.
+#[allow(clippy::too_many_lines)]
+fn push_ready_conditions(
+    sql: &mut String,
+    params: &mut Vec<Box<dyn rusqlite::ToSql>>,
+    filters: &ReadyFilters,
+) {
+    // Ready condition 1: status is `open` OR `in_progress`
+    if filters.include_deferred {
+        sql.push_str(" AND status IN ('open', 'in_progress', 'deferred')");
+    } else {
+        sql.push_str(" AND status IN ('open', 'in_progress')");
+    }
+
+    // Ready condition 2: NOT in blocked_issues_cache (NOT EXISTS is faster than NOT IN)
+    sql.push_str(" AND NOT EXISTS (SELECT 1 FROM blocked_issues_cache WHERE issue_id = issues.id)");
+
+    // Ready condition 3: `defer_until` is NULL or <= now (unless `include_deferred`)
+    if !filters.include_deferred {
+        // Compare using SQLite datetime parsing to handle RFC3339 timestamps consistently.
+        sql.push_str(" AND (defer_until IS NULL OR datetime(defer_until) <= datetime('now'))");
+    }
+
+    // Ready condition 4: not pinned
+    sql.push_str(" AND (pinned = 0 OR pinned IS NULL)");
+
+    // Ready condition 5: not ephemeral and not wisp
+    sql.push_str(" AND (ephemeral = 0 OR ephemeral IS NULL)");
+    sql.push_str(" AND id NOT LIKE '%-wisp-%'");
+
+    // Exclude templates
+    sql.push_str(" AND (is_template = 0 OR is_template IS NULL)");
+
+    // Filter by types
+    if let Some(ref types) = filters.types {
+        if !types.is_empty() {
+            let placeholders: Vec<String> = types.iter().map(|_| "?".to_string()).collect();
+            let _ = write!(sql, " AND issue_type IN ({}) ", placeholders.join(","));
+            for t in types {
+                params.push(Box::new(t.as_str().to_string()));
+            }
+        }
+    }
+
+    // Filter by priorities
+    if let Some(ref priorities) = filters.priorities {
+        if !priorities.is_empty() {
+            let placeholders: Vec<String> = priorities.iter().map(|_| "?".to_string()).collect();
+            let _ = write!(sql, " AND priority IN ({})", placeholders.join(","));
+            for p in priorities {
+                params.push(Box::new(p.0));
+            }
+        }
+    }
+
+    // Filter by assignee
+    if let Some(ref assignee) = filters.assignee {
+        sql.push_str(" AND assignee = ?");
+        params.push(Box::new(assignee.clone()));
+    }
+
+    // Filter for unassigned
+    if filters.unassigned {
+        sql.push_str(" AND assignee IS NULL");
+    }
+
+    // Filter by labels (AND logic)
+    for label in &filters.labels_and {
+        sql.push_str(" AND EXISTS (SELECT 1 FROM labels WHERE labels.issue_id = issues.id AND labels.label = ?)");
+        params.push(Box::new(label.clone()));
+    }
+
+    // Filter by labels (OR logic)
+    if !filters.labels_or.is_empty() {
+        let placeholders: Vec<String> = filters.labels_or.iter().map(|_| "?".to_string()).collect();
+        let _ = write!(
+            sql,
+            " AND id IN (SELECT issue_id FROM labels WHERE label IN ({}))",
+            placeholders.join(",")
+        );
+        for l in &filters.labels_or {
+            params.push(Box::new(l.clone()));
+        }
+    }
+
+    // Filter by parent (--parent flag)
+    if let Some(ref parent_id) = filters.parent {
+        if filters.recursive {
+            // Recursive: include all descendants using CTE.
+            // UNION (not UNION ALL) is required here for cycle safety:
+            // if parent-child dependencies form a cycle, UNION ALL would
+            // loop infinitely, while UNION deduplicates and terminates.
+            sql.push_str(
+                " AND id IN (
+                    WITH RECURSIVE descendants AS (
+                        SELECT issue_id FROM dependencies
+                        WHERE depends_on_id = ? AND type = 'parent-child'
+                        UNION
+                        SELECT d.issue_id FROM dependencies d
+                        INNER JOIN descendants desc ON d.depends_on_id = desc.issue_id
+                        WHERE d.type = 'parent-child'
+                    )
+                    SELECT issue_id FROM descendants
+                )",
+            );
+        } else {
+            // Non-recursive: only direct children
+            sql.push_str(
+                " AND id IN (
+                    SELECT issue_id FROM dependencies
+                    WHERE depends_on_id = ? AND type = 'parent-child'
+                )",
+            );
+        }
+        params.push(Box::new(parent_id.clone()));
+    }
+}
+
+/// ORDER BY clause for a ready sort policy.
+const fn ready_order_by(sort: ReadySortPolicy) -> &'static str {
+    match sort {
+        // P0/P1 first by `created_at` ASC, then others by `created_at` ASC
+        ReadySortPolicy::Hybrid => {
+            " ORDER BY CASE WHEN priority <= 1 THEN 0 ELSE 1 END, created_at ASC"
+        }
+        ReadySortPolicy::Priority => " ORDER BY priority ASC, created_at ASC",
+        ReadySortPolicy::Oldest => " ORDER BY created_at ASC",
+    }
+}
+
+fn lease_from_row(row: &rusqlite::Row) -> rusqlite::Result<ClaimLease> {
+    Ok(ClaimLease {
+        issue_id: row.get(0)?,
+        actor: row.get(1)?,
+        claimed_at: parse_datetime(&row.get::<_, String>(2)?),
+        expires_at: parse_datetime(&row.get::<_, String>(3)?),
+    })
+}
+
 fn parse_status(s: Option<&str>) -> Status {
     s.map_or_else(Status::default, |val| {
         val.parse()
@@ -3337,9 +4445,38 @@ fn query_external_project_capabilities(
         }
     }
 
+    // A capability may also name an issue in that project (cross-project
+    // `blocks`); it is satisfied once the issue is closed.
+    let ids: Vec<&String> = capabilities.iter().collect();
+    for chunk in ids.chunks(SQLITE_VAR_LIMIT) {
+        let placeholders: Vec<&str> = chunk.iter().map(|_| "?").collect();
+        let sql = format!(
+            "SELECT id FROM issues WHERE status IN ('closed', 'tombstone') AND id IN ({})",
+            placeholders.join(",")
+        );
+        let params: Vec<&dyn rusqlite::ToSql> =
+            chunk.iter().map(|id| *id as &dyn rusqlite::ToSql).collect();
+        let mut stmt = conn.prepare(&sql)?;
+        let rows = stmt.query_map(params.as_slice(), |row| row.get::<_, String>(0))?;
+        for row in rows {
+            satisfied.insert(row?);
+        }
+    }
+
     Ok(satisfied)
 }
 
+/// Whether an external project's database contains the issue.
+fn external_issue_exists(db_path: &Path, issue_id: &str) -> Result<bool> {
+    let conn = Connection::open_with_flags(db_path, OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+    let exists = conn.query_row(
+        "SELECT EXISTS(SELECT 1 FROM issues WHERE id = ?)",
+        [issue_id],
+        |row| row.get(0),
+    )?;
+    Ok(exists)
+}
+
 fn parse_datetime(s: &str) -> DateTime<Utc> {
     if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(s) {
         return dt.with_timezone(&Utc);
@@ -3373,7 +4510,8 @@ fn escape_like_pattern(s: &str) -> String {
 impl SqliteStorage {
     /// Get issue with all relations populated for export.
     ///
-    /// Includes labels, dependencies, and comments.
+    /// Includes labels, dependencies, comments, time entries, recurrence and
+    /// attachments.
     ///
     /// # Errors
     ///
@@ -3387,6 +4525,9 @@ impl SqliteStorage {
         issue.labels = self.get_labels(id)?;
         issue.dependencies = self.get_dependencies_full(id)?;
         issue.comments = self.get_comments(id)?;
+        issue.time_entries = self.get_time_entries(id)?;
+        issue.recurrence = self.get_recurrence(id)?;
+        issue.attachments = self.get_attachments(id)?;
 
         Ok(Some(issue))
     }
@@ -3604,7 +4745,7 @@ impl SqliteStorage {
                      pinned, is_template
                FROM issues WHERE external_ref = ?",
             [external_ref],
-            |row| self.issue_from_row(row),
+            Self::issue_from_row,
         );
         match result {
             Ok(issue) => Ok(Some(issue)),
@@ -3629,7 +4770,7 @@ impl SqliteStorage {
                      pinned, is_template
                FROM issues WHERE content_hash = ?",
             [content_hash],
-            |row| self.issue_from_row(row),
+            Self::issue_from_row,
         );
         match result {
             Ok(issue) => Ok(Some(issue)),
@@ -3807,6 +4948,72 @@ impl SqliteStorage {
 
         Ok(())
     }
+
+    /// Sync time entries for an issue (remove existing, add new).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails.
+    pub fn sync_time_entries_for_import(
+        &mut self,
+        issue_id: &str,
+        entries: &[TimeEntry],
+    ) -> Result<()> {
+        let tx = self.conn.transaction()?;
+        tx.execute("DELETE FROM time_entries WHERE issue_id = ?", [issue_id])?;
+        for entry in entries {
+            insert_time_entry_row(
+                &tx,
+                issue_id,
+                &entry.actor,
+                entry.started_at,
+                entry.ended_at,
+                entry.minutes.max(0),
+                entry.note.as_deref(),
+            )?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Sync attachments for an issue (remove existing, add new).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails.
+    pub fn sync_attachments_for_import(
+        &mut self,
+        issue_id: &str,
+        attachments: &[Attachment],
+    ) -> Result<()> {
+        let tx = self.conn.transaction()?;
+        tx.execute("DELETE FROM attachments WHERE issue_id = ?", [issue_id])?;
+        for attachment in attachments {
+            insert_attachment_row(&tx, issue_id, attachment)?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Sync the recurrence rule for an issue (replace or remove).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails.
+    pub fn sync_recurrence_for_import(
+        &mut self,
+        issue_id: &str,
+        recurrence: Option<&Recurrence>,
+    ) -> Result<()> {
+        if let Some(recurrence) = recurrence {
+            return upsert_recurrence_row(&self.conn, issue_id, recurrence);
+        }
+        self.conn.execute(
+            "DELETE FROM recurrence_rules WHERE issue_id = ?",
+            [issue_id],
+        )?;
+        Ok(())
+    }
 }
 
 /// Implement the `DependencyStore` trait for `SqliteStorage`.
@@ -3863,6 +5070,154 @@ fn fetch_comment(tx: &Transaction<'_>, comment_id: i64) -> Result<Comment> {
     .map_err(BeadsError::from)
 }
 
+fn insert_time_entry_row(
+    conn: &Connection,
+    issue_id: &str,
+    actor: &str,
+    started_at: DateTime<Utc>,
+    ended_at: Option<DateTime<Utc>>,
+    minutes: i32,
+    note: Option<&str>,
+) -> Result<i64> {
+    conn.execute(
+        "INSERT INTO time_entries (issue_id, actor, started_at, ended_at, minutes, note)
+         VALUES (?, ?, ?, ?, ?, ?)",
+        rusqlite::params![
+            issue_id,
+            actor,
+            started_at.to_rfc3339(),
+            ended_at.map(|t| t.to_rfc3339()),
+            minutes,
+            note
+        ],
+    )?;
+    Ok(conn.last_insert_rowid())
+}
+
+fn fetch_time_entry(conn: &Connection, entry_id: i64) -> Result<TimeEntry> {
+    conn.query_row(
+        "SELECT id, issue_id, actor, started_at, ended_at, minutes, note
+         FROM time_entries WHERE id = ?",
+        [entry_id],
+        time_entry_from_row,
+    )
+    .map_err(BeadsError::from)
+}
+
+fn fetch_running_timers(conn: &Connection, actor: Option<&str>) -> Result<Vec<TimeEntry>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, issue_id, actor, started_at, ended_at, minutes, note
+         FROM time_entries
+         WHERE ended_at IS NULL AND (?1 IS NULL OR actor = ?1)
+         ORDER BY started_at ASC, id ASC",
+    )?;
+    let entries = stmt
+        .query_map([actor], time_entry_from_row)?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    Ok(entries)
+}
+
+fn time_entry_from_row(row: &rusqlite::Row) -> rusqlite::Result<TimeEntry> {
+    Ok(TimeEntry {
+        id: row.get(0)?,
+        issue_id: row.get(1)?,
+        actor: row.get(2)?,
+        started_at: parse_datetime(&row.get::<_, String>(3)?),
+        ended_at: row
+            .get::<_, Option<String>>(4)?
+            .map(|value| parse_datetime(&value)),
+        minutes: row.get(5)?,
+        note: row.get(6)?,
+    })
+}
+
+fn insert_attachment_row(conn: &Connection, issue_id: &str, attachment: &Attachment) -> Result<()> {
+    conn.execute(
+        "INSERT OR REPLACE INTO attachments
+         (issue_id, name, sha256, size, media_type, created_at, created_by, local_only)
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+        rusqlite::params![
+            issue_id,
+            attachment.name,
+            attachment.sha256,
+            i64::try_from(attachment.size).unwrap_or(i64::MAX),
+            attachment.media_type,
+            attachment.created_at.to_rfc3339(),
+            attachment.created_by,
+            attachment.local_only
+        ],
+    )?;
+    Ok(())
+}
+
+fn fetch_attachment(conn: &Connection, issue_id: &str, name: &str) -> Result<Option<Attachment>> {
+    conn.query_row(
+        "SELECT name, sha256, size, media_type, created_at, created_by, local_only
+         FROM attachments WHERE issue_id = ? AND name = ?",
+        rusqlite::params![issue_id, name],
+        attachment_from_row,
+    )
+    .optional()
+    .map_err(BeadsError::from)
+}
+
+fn attachment_from_row(row: &rusqlite::Row) -> rusqlite::Result<Attachment> {
+    Ok(Attachment {
+        name: row.get(0)?,
+        sha256: row.get(1)?,
+        size: u64::try_from(row.get::<_, i64>(2)?).unwrap_or(0),
+        media_type: row.get(3)?,
+        created_at: parse_datetime(&row.get::<_, String>(4)?),
+        created_by: row.get(5)?,
+        local_only: row.get(6)?,
+    })
+}
+
+fn upsert_recurrence_row(conn: &Connection, issue_id: &str, recurrence: &Recurrence) -> Result<()> {
+    conn.execute(
+        "INSERT INTO recurrence_rules (issue_id, rule, starts_at, lead_minutes, due_minutes)
+         VALUES (?, ?, ?, ?, ?)
+         ON CONFLICT(issue_id) DO UPDATE SET
+            rule = excluded.rule,
+            starts_at = excluded.starts_at,
+            lead_minutes = excluded.lead_minutes,
+            due_minutes = excluded.due_minutes",
+        rusqlite::params![
+            issue_id,
+            recurrence.rule,
+            recurrence.starts_at.to_rfc3339(),
+            recurrence.lead_minutes,
+            recurrence.due_minutes
+        ],
+    )?;
+    Ok(())
+}
+
+fn fetch_recurrence(conn: &Connection, issue_id: &str) -> Result<Option<Recurrence>> {
+    conn.query_row(
+        "SELECT issue_id, rule, starts_at, lead_minutes, due_minutes
+         FROM recurrence_rules WHERE issue_id = ?",
+        [issue_id],
+        recurrence_from_row,
+    )
+    .optional()
+    .map_err(BeadsError::from)
+}
+
+fn recurrence_from_row(row: &rusqlite::Row) -> rusqlite::Result<Recurrence> {
+    Ok(Recurrence {
+        rule: row.get(1)?,
+        starts_at: parse_datetime(&row.get::<_, String>(2)?),
+        lead_minutes: row.get(3)?,
+        due_minutes: row.get(4)?,
+    })
+}
+
+/// Fit a minute count into the `minutes` column.
+fn clamp_minutes(minutes: i64) -> i32 {
+    i32::try_from(minutes).unwrap_or(i32::MAX)
+}
+
 #[cfg(test)]
 impl SqliteStorage {
     /// Execute raw SQL for tests.
@@ -3934,6 +5289,9 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            time_entries: vec![],
+            recurrence: None,
+            attachments: vec![],
         }
     }
 
@@ -3986,6 +5344,9 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            time_entries: vec![],
+            recurrence: None,
+            attachments: vec![],
         };
 
         storage.create_issue(&issue, "tester").unwrap();
@@ -4284,6 +5645,9 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            time_entries: vec![],
+            recurrence: None,
+            attachments: vec![],
         };
         storage.create_issue(&issue, "tester").unwrap();
 
@@ -4353,6 +5717,9 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            time_entries: vec![],
+            recurrence: None,
+            attachments: vec![],
         };
         storage.create_issue(&issue, "tester").unwrap();
 
@@ -4414,6 +5781,9 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            time_entries: vec![],
+            recurrence: None,
+            attachments: vec![],
         };
         storage.create_issue(&issue, "tester").unwrap();
 
@@ -4890,6 +6260,61 @@ mod tests {
         assert_eq!(issues[0].id, "bd-l1");
     }
 
+    #[test]
+    fn test_list_issues_filter_by_expression() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let now = Utc::now();
+        let stale = now - chrono::Duration::days(30);
+
+        let backend = make_issue("bd-e1", "Backend work", Status::Open, 2, None, now, None);
+        let urgent = make_issue("bd-e2", "Urgent fix", Status::Open, 1, None, now, None);
+        let blocked = make_issue("bd-e3", "Blocked task", Status::Open, 3, None, now, None);
+        let old = make_issue("bd-e4", "Old backend", Status::Open, 0, None, stale, None);
+        let done = Issue {
+            closed_at: Some(now),
+            ..make_issue("bd-e5", "Done", Status::Closed, 0, None, now, None)
+        };
+        for issue in [&backend, &urgent, &blocked, &old, &done] {
+            storage.create_issue(issue, "tester").unwrap();
+        }
+        storage.add_label("bd-e1", "backend", "tester").unwrap();
+        storage.add_label("bd-e4", "backend", "tester").unwrap();
+        storage
+            .add_dependency("bd-e3", "bd-e2", "blocks", "tester")
+            .unwrap();
+
+        let list = |storage: &SqliteStorage, expr: &str| -> Vec<String> {
+            let filters = ListFilters {
+                include_closed: true,
+                expr: Some(FilterExpr::parse(expr).unwrap()),
+                ..Default::default()
+            };
+            let mut ids: Vec<String> = storage
+                .list_issues(&filters)
+                .unwrap()
+                .into_iter()
+                .map(|issue| issue.id)
+                .collect();
+            ids.sort();
+            ids
+        };
+
+        assert_eq!(
+            list(
+                &storage,
+                "status:open AND (label:backend OR priority<=1) AND created>-7d"
+            ),
+            vec!["bd-e1", "bd-e2"]
+        );
+        assert_eq!(list(&storage, "blocked_by:bd-e2"), vec!["bd-e3"]);
+        assert_eq!(list(&storage, "blocks:bd-e3"), vec!["bd-e2"]);
+        assert_eq!(
+            list(&storage, "NOT label:backend status!=closed"),
+            vec!["bd-e2", "bd-e3"]
+        );
+        assert_eq!(list(&storage, "title:backend"), vec!["bd-e1", "bd-e4"]);
+    }
+
     #[test]
     fn test_blocked_cache_handles_quotes_in_ids() {
         let mut storage = SqliteStorage::open_memory().unwrap();
@@ -105,6 +105,9 @@ pub enum ExportEntityType {
     Dependency,
     Label,
     Comment,
+    TimeEntry,
+    Recurrence,
+    Attachment,
 }
 
 /// Export error record.
@@ -146,6 +149,7 @@ pub struct ExportReport {
     pub dependencies_exported: usize,
     pub labels_exported: usize,
     pub comments_exported: usize,
+    pub time_entries_exported: usize,
     pub errors: Vec<ExportError>,
     pub policy_used: ExportErrorPolicy,
 }
@@ -157,6 +161,7 @@ impl ExportReport {
             dependencies_exported: 0,
             labels_exported: 0,
             comments_exported: 0,
+            time_entries_exported: 0,
             errors: Vec::new(),
             policy_used: policy,
         }
@@ -175,7 +180,8 @@ impl ExportReport {
         let total = self.issues_exported
             + self.dependencies_exported
             + self.labels_exported
-            + self.comments_exported;
+            + self.comments_exported
+            + self.time_entries_exported;
         let failed = self.errors.len();
         if total + failed == 0 {
             1.0
@@ -1207,6 +1213,39 @@ pub fn export_to_jsonl_with_policy(
             None
         }
     };
+    let all_time_entries = match storage.get_all_time_entries() {
+        Ok(map) => Some(map),
+        Err(err) => {
+            ctx.handle_error(ExportError::new(
+                ExportEntityType::TimeEntry,
+                "all",
+                err.to_string(),
+            ))?;
+            None
+        }
+    };
+    let all_recurrences = match storage.get_all_recurrences() {
+        Ok(map) => Some(map),
+        Err(err) => {
+            ctx.handle_error(ExportError::new(
+                ExportEntityType::Recurrence,
+                "all",
+                err.to_string(),
+            ))?;
+            None
+        }
+    };
+    let all_attachments = match storage.get_all_attachments() {
+        Ok(map) => Some(map),
+        Err(err) => {
+            ctx.handle_error(ExportError::new(
+                ExportEntityType::Attachment,
+                "all",
+                err.to_string(),
+            ))?;
+            None
+        }
+    };
 
     for issue in &mut issues {
         if let Some(deps) = all_deps.as_ref().and_then(|map| map.get(&issue.id)) {
@@ -1229,6 +1268,20 @@ pub fn export_to_jsonl_with_policy(
         } else {
             issue.comments.clear();
         }
+        if let Some(entries) = all_time_entries.as_ref().and_then(|map| map.get(&issue.id)) {
+            issue.time_entries = entries.clone();
+        } else {
+            issue.time_entries.clear();
+        }
+        issue.recurrence = all_recurrences
+            .as_ref()
+            .and_then(|map| map.get(&issue.id))
+            .cloned();
+        if let Some(attachments) = all_attachments.as_ref().and_then(|map| map.get(&issue.id)) {
+            issue.attachments = attachments.clone();
+        } else {
+            issue.attachments.clear();
+        }
     }
 
     // Write to temp file for atomic rename
@@ -1311,6 +1364,7 @@ pub fn export_to_jsonl_with_policy(
         report.dependencies_exported += issue.dependencies.len();
         report.labels_exported += issue.labels.len();
         report.comments_exported += issue.comments.len();
+        report.time_entries_exported += issue.time_entries.len();
         progress.inc(1);
     }
 
@@ -1436,6 +1490,39 @@ pub fn export_to_writer_with_policy<W: Write>(
             None
         }
     };
+    let all_time_entries = match storage.get_all_time_entries() {
+        Ok(map) => Some(map),
+        Err(err) => {
+            ctx.handle_error(ExportError::new(
+                ExportEntityType::TimeEntry,
+                "all",
+                err.to_string(),
+            ))?;
+            None
+        }
+    };
+    let all_recurrences = match storage.get_all_recurrences() {
+        Ok(map) => Some(map),
+        Err(err) => {
+            ctx.handle_error(ExportError::new(
+                ExportEntityType::Recurrence,
+                "all",
+                err.to_string(),
+            ))?;
+            None
+        }
+    };
+    let all_attachments = match storage.get_all_attachments() {
+        Ok(map) => Some(map),
+        Err(err) => {
+            ctx.handle_error(ExportError::new(
+                ExportEntityType::Attachment,
+                "all",
+                err.to_string(),
+            ))?;
+            None
+        }
+    };
 
     for issue in &mut issues {
         if let Some(deps) = all_deps.as_ref().and_then(|map| map.get(&issue.id)) {
@@ -1453,6 +1540,20 @@ pub fn export_to_writer_with_policy<W: Write>(
         } else {
             issue.comments.clear();
         }
+        if let Some(entries) = all_time_entries.as_ref().and_then(|map| map.get(&issue.id)) {
+            issue.time_entries = entries.clone();
+        } else {
+            issue.time_entries.clear();
+        }
+        issue.recurrence = all_recurrences
+            .as_ref()
+            .and_then(|map| map.get(&issue.id))
+            .cloned();
+        if let Some(attachments) = all_attachments.as_ref().and_then(|map| map.get(&issue.id)) {
+            issue.attachments = attachments.clone();
+        } else {
+            issue.attachments.clear();
+        }
     }
 
     let mut hasher = Sha256::new();
@@ -1495,6 +1596,7 @@ pub fn export_to_writer_with_policy<W: Write>(
         report.dependencies_exported += issue.dependencies.len();
         report.labels_exported += issue.labels.len();
         report.comments_exported += issue.comments.len();
+        report.time_entries_exported += issue.time_entries.len();
     }
 
     let content_hash = format!("{:x}", hasher.finalize());
@@ -2287,7 +2389,8 @@ fn process_import_action(
     Ok(())
 }
 
-/// Sync labels, dependencies, and comments for an imported issue.
+/// Sync labels, dependencies, comments, time entries, recurrence, and attachments for
+/// an imported issue.
 fn sync_issue_relations(storage: &mut SqliteStorage, issue: &Issue) -> Result<()> {
     // Sync labels
     storage.sync_labels_for_import(&issue.id, &issue.labels)?;
@@ -2298,6 +2401,15 @@ fn sync_issue_relations(storage: &mut SqliteStorage, issue: &Issue) -> Result<()
     // Sync comments
     storage.sync_comments_for_import(&issue.id, &issue.comments)?;
 
+    // Sync time entries
+    storage.sync_time_entries_for_import(&issue.id, &issue.time_entries)?;
+
+    // Sync recurrence rule
+    storage.sync_recurrence_for_import(&issue.id, issue.recurrence.as_ref())?;
+
+    // Sync attachments
+    storage.sync_attachments_for_import(&issue.id, &issue.attachments)?;
+
     Ok(())
 }
 
@@ -2339,6 +2451,36 @@ pub enum ConflictType {
     ConvergentCreation,
 }
 
+/// Which side of a merge a value was taken from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MergeSide {
+    Local,
+    External,
+}
+
+impl std::fmt::Display for MergeSide {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Local => write!(f, "local"),
+            Self::External => write!(f, "external"),
+        }
+    }
+}
+
+/// A field that both sides changed to different values since the base.
+///
+/// Values are rendered as JSON so any field type can be listed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FieldConflict {
+    pub issue_id: String,
+    pub field: &'static str,
+    pub local: String,
+    pub external: String,
+    /// The side whose value the merged issue keeps.
+    pub kept: MergeSide,
+}
+
 /// Result of merging a single issue across base, left (local), and right (external).
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum MergeResult {
@@ -2401,6 +2543,8 @@ pub struct MergeReport {
     pub tombstone_protected: Vec<String>,
     /// Notes about merge decisions.
     pub notes: Vec<(String, String)>,
+    /// Fields both sides changed to different values, with the side kept.
+    pub field_conflicts: Vec<FieldConflict>,
 }
 
 impl MergeReport {
@@ -2437,7 +2581,8 @@ pub enum ConflictResolution {
 /// - New local issues are kept
 /// - New external issues are imported
 /// - Deletions are handled based on whether the other side modified
-/// - Both-modified uses `updated_at` as tiebreaker (or specified strategy)
+/// - Both-modified merges field by field against the base (see `merge_fields`);
+///   only fields changed on both sides fall back to `updated_at` (or the strategy)
 ///
 /// # Arguments
 /// * `base` - The issue in the base (common ancestor) state, if it existed
@@ -2520,57 +2665,7 @@ pub fn merge_issue(
         }
 
         // Case 6: In all three (potentially modified in one or both)
-        (Some(b), Some(l), Some(r)) => {
-            let left_changed = l.content_hash != b.content_hash;
-            let right_changed = r.content_hash != b.content_hash;
-
-            match (left_changed, right_changed) {
-                // Neither changed OR only left changed - keep left
-                (false | true, false) => MergeResult::Keep(l.clone()),
-                // Only right changed - keep right
-                (false, true) => MergeResult::Keep(r.clone()),
-                // Both changed - use strategy
-                (true, true) => match strategy {
-                    ConflictResolution::PreferLocal => MergeResult::KeepWithNote(
-                        l.clone(),
-                        "Both modified - kept local".to_string(),
-                    ),
-                    ConflictResolution::PreferExternal => MergeResult::KeepWithNote(
-                        r.clone(),
-                        "Both modified - kept external".to_string(),
-                    ),
-                    ConflictResolution::PreferNewer => {
-                        if l.updated_at >= r.updated_at {
-                            MergeResult::KeepWithNote(
-                                l.clone(),
-                                "Both modified - kept local (newer)".to_string(),
-                            )
-                        } else {
-                            MergeResult::KeepWithNote(
-                                r.clone(),
-                                "Both modified - kept external (newer)".to_string(),
-                            )
-                        }
-                    }
-                    ConflictResolution::Manual => {
-                        // For manual, we still need to pick one, so use newer but mark as note
-                        if l.updated_at >= r.updated_at {
-                            MergeResult::KeepWithNote(
-                                l.clone(),
-                                "Both modified - kept local (newer), review recommended"
-                                    .to_string(),
-                            )
-                        } else {
-                            MergeResult::KeepWithNote(
-                                r.clone(),
-                                "Both modified - kept external (newer), review recommended"
-                                    .to_string(),
-                            )
-                        }
-                    }
-                },
-            }
-        }
+        (Some(b), Some(l), Some(r)) => merge_fields(b, l, r, strategy).0,
 
         // Case 7: In left and right but not base (convergent creation)
         (None, Some(l), Some(r)) => {
@@ -2610,6 +2705,307 @@ pub fn merge_issue(
     }
 }
 
+/// Merge an issue present in base, left (local) and right (external) field by field.
+///
+/// A field changed on one side only takes that side's value. A field changed on
+/// both sides to different values is a conflict: it is resolved by `strategy`
+/// (`updated_at` for `PreferNewer` and `Manual`) and returned so callers can
+/// report it. Labels, dependencies, comments and time entries merge as sets:
+/// additions from either side are kept, and removals from either side apply.
+/// Status, close and deletion fields move together so they stay consistent.
+#[must_use]
+#[allow(clippy::too_many_lines)]
+pub fn merge_fields(
+    base: &Issue,
+    left: &Issue,
+    right: &Issue,
+    strategy: ConflictResolution,
+) -> (MergeResult, Vec<FieldConflict>) {
+    let keep_local = match strategy {
+        ConflictResolution::PreferLocal => true,
+        ConflictResolution::PreferExternal => false,
+        ConflictResolution::PreferNewer | ConflictResolution::Manual => {
+            left.updated_at >= right.updated_at
+        }
+    };
+    let mut tracker = FieldMerger {
+        issue_id: &left.id,
+        keep_local,
+        took_left: false,
+        took_right: false,
+        conflicts: Vec::new(),
+    };
+
+    let (b, l, r) = (base, left, right);
+    let mut merged = left.clone();
+    merged.title = tracker.field("title", &b.title, &l.title, &r.title);
+    merged.description = tracker.field(
+        "description",
+        &b.description,
+        &l.description,
+        &r.description,
+    );
+    merged.design = tracker.field("design", &b.design, &l.design, &r.design);
+    merged.acceptance_criteria = tracker.field(
+        "acceptance_criteria",
+        &b.acceptance_criteria,
+        &l.acceptance_criteria,
+        &r.acceptance_criteria,
+    );
+    merged.notes = tracker.field("notes", &b.notes, &l.notes, &r.notes);
+    (
+        merged.status,
+        merged.closed_at,
+        merged.close_reason,
+        merged.closed_by_session,
+        merged.deleted_at,
+        merged.deleted_by,
+        merged.delete_reason,
+        merged.original_type,
+    ) = tracker.field(
+        "status",
+        &status_fields(b),
+        &status_fields(l),
+        &status_fields(r),
+    );
+    merged.priority = tracker.field("priority", &b.priority, &l.priority, &r.priority);
+    merged.issue_type = tracker.field("issue_type", &b.issue_type, &l.issue_type, &r.issue_type);
+    merged.assignee = tracker.field("assignee", &b.assignee, &l.assignee, &r.assignee);
+    merged.owner = tracker.field("owner", &b.owner, &l.owner, &r.owner);
+    merged.estimated_minutes = tracker.field(
+        "estimated_minutes",
+        &b.estimated_minutes,
+        &l.estimated_minutes,
+        &r.estimated_minutes,
+    );
+    merged.due_at = tracker.field("due_at", &b.due_at, &l.due_at, &r.due_at);
+    merged.defer_until = tracker.field(
+        "defer_until",
+        &b.defer_until,
+        &l.defer_until,
+        &r.defer_until,
+    );
+    merged.external_ref = tracker.field(
+        "external_ref",
+        &b.external_ref,
+        &l.external_ref,
+        &r.external_ref,
+    );
+    merged.source_system = tracker.field(
+        "source_system",
+        &b.source_system,
+        &l.source_system,
+        &r.source_system,
+    );
+    merged.source_repo = tracker.field(
+        "source_repo",
+        &b.source_repo,
+        &l.source_repo,
+        &r.source_repo,
+    );
+    (
+        merged.compaction_level,
+        merged.compacted_at,
+        merged.compacted_at_commit,
+        merged.original_size,
+    ) = tracker.field(
+        "compaction",
+        &compaction_fields(b),
+        &compaction_fields(l),
+        &compaction_fields(r),
+    );
+    merged.sender = tracker.field("sender", &b.sender, &l.sender, &r.sender);
+    merged.ephemeral = tracker.field("ephemeral", &b.ephemeral, &l.ephemeral, &r.ephemeral);
+    merged.pinned = tracker.field("pinned", &b.pinned, &l.pinned, &r.pinned);
+    merged.is_template = tracker.field(
+        "is_template",
+        &b.is_template,
+        &l.is_template,
+        &r.is_template,
+    );
+    merged.recurrence = tracker.field("recurrence", &b.recurrence, &l.recurrence, &r.recurrence);
+
+    merged.labels = tracker.set(&b.labels, &l.labels, &r.labels, Clone::clone);
+    merged.labels.sort();
+    merged.dependencies = tracker.set(&b.dependencies, &l.dependencies, &r.dependencies, |dep| {
+        (dep.depends_on_id.clone(), dep.dep_type.as_str().to_string())
+    });
+    merged.comments = tracker.set(&b.comments, &l.comments, &r.comments, |comment| {
+        (
+            comment.author.clone(),
+            comment.body.clone(),
+            comment.created_at,
+        )
+    });
+    merged.time_entries = tracker.set(&b.time_entries, &l.time_entries, &r.time_entries, |entry| {
+        (entry.actor.clone(), entry.started_at, entry.ended_at)
+    });
+    merged.attachments = tracker.set(&b.attachments, &l.attachments, &r.attachments, |a| {
+        (a.name.clone(), a.sha256.clone())
+    });
+
+    let FieldMerger {
+        took_left,
+        took_right,
+        conflicts,
+        ..
+    } = tracker;
+
+    let kept = if !took_right {
+        left.clone()
+    } else if !took_left {
+        right.clone()
+    } else {
+        merged.updated_at = left.updated_at.max(right.updated_at);
+        merged.content_hash = Some(merged.compute_content_hash());
+        merged
+    };
+
+    if conflicts.is_empty() {
+        let result = if took_left && took_right {
+            MergeResult::KeepWithNote(kept, "Merged local and external changes".to_string())
+        } else {
+            MergeResult::Keep(kept)
+        };
+        return (result, conflicts);
+    }
+
+    let fields: Vec<&str> = conflicts.iter().map(|conflict| conflict.field).collect();
+    let side = if keep_local {
+        MergeSide::Local
+    } else {
+        MergeSide::External
+    };
+    let reason = match strategy {
+        ConflictResolution::PreferNewer => " (newer)",
+        ConflictResolution::Manual => " (newer), review recommended",
+        ConflictResolution::PreferLocal | ConflictResolution::PreferExternal => "",
+    };
+    let note = format!("Both modified {} - kept {side}{reason}", fields.join(", "));
+    (MergeResult::KeepWithNote(kept, note), conflicts)
+}
+
+type StatusFields = (
+    crate::model::Status,
+    Option<chrono::DateTime<chrono::Utc>>,
+    Option<String>,
+    Option<String>,
+    Option<chrono::DateTime<chrono::Utc>>,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+);
+
+fn status_fields(issue: &Issue) -> StatusFields {
+    (
+        issue.status.clone(),
+        issue.closed_at,
+        issue.close_reason.clone(),
+        issue.closed_by_session.clone(),
+        issue.deleted_at,
+        issue.deleted_by.clone(),
+        issue.delete_reason.clone(),
+        issue.original_type.clone(),
+    )
+}
+
+type CompactionFields = (
+    Option<i32>,
+    Option<chrono::DateTime<chrono::Utc>>,
+    Option<String>,
+    Option<i32>,
+);
+
+fn compaction_fields(issue: &Issue) -> CompactionFields {
+    (
+        issue.compaction_level,
+        issue.compacted_at,
+        issue.compacted_at_commit.clone(),
+        issue.original_size,
+    )
+}
+
+/// Per-issue state for `merge_fields`.
+struct FieldMerger<'a> {
+    issue_id: &'a str,
+    /// How true conflicts are resolved.
+    keep_local: bool,
+    /// Some merged value differs from the external side.
+    took_left: bool,
+    /// Some merged value differs from the local side.
+    took_right: bool,
+    conflicts: Vec<FieldConflict>,
+}
+
+impl FieldMerger<'_> {
+    fn field<T: Clone + PartialEq + Serialize>(
+        &mut self,
+        name: &'static str,
+        base: &T,
+        left: &T,
+        right: &T,
+    ) -> T {
+        if left == right {
+            return left.clone();
+        }
+        let take_left = if right == base {
+            true
+        } else if left == base {
+            false
+        } else {
+            let render = |value: &T| serde_json::to_string(value).unwrap_or_default();
+            self.conflicts.push(FieldConflict {
+                issue_id: self.issue_id.to_string(),
+                field: name,
+                local: render(left),
+                external: render(right),
+                kept: if self.keep_local {
+                    MergeSide::Local
+                } else {
+                    MergeSide::External
+                },
+            });
+            self.keep_local
+        };
+        if take_left {
+            self.took_left = true;
+            left.clone()
+        } else {
+            self.took_right = true;
+            right.clone()
+        }
+    }
+
+    /// Merge sets keyed by `key`: keep items on both sides or added by one,
+    /// drop items that one side removed.
+    fn set<T: Clone, K: Eq + std::hash::Hash>(
+        &mut self,
+        base: &[T],
+        left: &[T],
+        right: &[T],
+        key: impl Fn(&T) -> K,
+    ) -> Vec<T> {
+        let base_keys: HashSet<K> = base.iter().map(&key).collect();
+        let left_keys: HashSet<K> = left.iter().map(&key).collect();
+        let right_keys: HashSet<K> = right.iter().map(&key).collect();
+
+        let mut merged = Vec::new();
+        let mut merged_keys = HashSet::new();
+        for item in left.iter().chain(right) {
+            let item_key = key(item);
+            let keep = (left_keys.contains(&item_key) && right_keys.contains(&item_key))
+                || !base_keys.contains(&item_key);
+            if keep && !merged_keys.contains(&item_key) {
+                merged.push(item.clone());
+                merged_keys.insert(item_key);
+            }
+        }
+        self.took_left |= merged_keys != right_keys;
+        self.took_right |= merged_keys != left_keys;
+        merged
+    }
+}
+
 /// Perform a 3-way merge across all issues in the context.
 ///
 /// This iterates through all unique issue IDs across base, left, and right,
@@ -2647,7 +3043,14 @@ pub fn three_way_merge(
             }
         }
 
-        let result = merge_issue(base, left, right, strategy);
+        let result = match (base, left, right) {
+            (Some(b), Some(l), Some(r)) => {
+                let (result, conflicts) = merge_fields(b, l, r, strategy);
+                report.field_conflicts.extend(conflicts);
+                result
+            }
+            _ => merge_issue(base, left, right, strategy),
+        };
 
         match result {
             MergeResult::NoAction => {}
@@ -2740,6 +3143,64 @@ pub fn load_base_snapshot(jsonl_dir: &Path) -> Result<std::collections::HashMap<
     Ok(base)
 }
 
+/// Merge three versions of an issues JSONL file (git merge driver `%O %A %B`).
+///
+/// `base` may be missing or empty when both branches added the file. The
+/// report's `kept` issues are sorted by ID with normalized labels, so the
+/// same inputs always produce the same output.
+///
+/// # Errors
+///
+/// Returns an error if any version cannot be read or parsed (for example
+/// when it still contains conflict markers).
+pub fn merge_jsonl_versions(
+    base: &Path,
+    ours: &Path,
+    theirs: &Path,
+    strategy: ConflictResolution,
+) -> Result<MergeReport> {
+    let load = |path: &Path| -> Result<std::collections::HashMap<String, Issue>> {
+        if !path.exists() {
+            return Ok(std::collections::HashMap::new());
+        }
+        Ok(read_issues_from_jsonl(path)?
+            .into_iter()
+            .map(|issue| (issue.id.clone(), issue))
+            .collect())
+    };
+
+    let context = MergeContext::new(load(base)?, load(ours)?, load(theirs)?);
+    let mut report = three_way_merge(&context, strategy, None);
+    for issue in &mut report.kept {
+        issue.labels.sort();
+        issue.labels.dedup();
+    }
+    report.kept.sort_by(|a, b| a.id.cmp(&b.id));
+    report.deleted.sort();
+    Ok(report)
+}
+
+/// Write issues to a JSONL file, one per line, via a temp file and rename.
+///
+/// # Errors
+///
+/// Returns an error if an issue cannot be serialized or the file cannot be
+/// written.
+pub fn write_issues_jsonl(path: &Path, issues: &[Issue]) -> Result<()> {
+    let temp_path = path.with_extension("jsonl.tmp");
+    let mut writer = BufWriter::new(File::create(&temp_path)?);
+    for issue in issues {
+        let json = serde_json::to_string(issue).map_err(|e| {
+            BeadsError::Config(format!("Failed to serialize issue {}: {}", issue.id, e))
+        })?;
+        writeln!(writer, "{json}")?;
+    }
+    writer.flush()?;
+    drop(writer);
+    fs::rename(&temp_path, path)?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2790,6 +3251,9 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            time_entries: vec![],
+            recurrence: None,
+            attachments: vec![],
         }
     }
 
@@ -2835,6 +3299,9 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            time_entries: vec![],
+            recurrence: None,
+            attachments: vec![],
         }
     }
 
@@ -4006,6 +4473,9 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            time_entries: vec![],
+            recurrence: None,
+            attachments: vec![],
         }
     }
 
@@ -4260,6 +4730,149 @@ mod tests {
         assert_eq!(report.total_actions(), 3);
     }
 
+    #[test]
+    fn test_merge_fields_combines_disjoint_edits() {
+        let mut base = make_issue_with_hash("bd-020", "Base", fixed_time_merge(100), Some("h"));
+        base.labels = vec!["backend".to_string(), "old".to_string()];
+
+        let mut local = base.clone();
+        local.description = Some("Local description".to_string());
+        local.labels = vec!["backend".to_string(), "docs".to_string()];
+        local.updated_at = fixed_time_merge(300);
+
+        let mut external = base.clone();
+        external.status = Status::Closed;
+        external.closed_at = Some(fixed_time_merge(200));
+        external.labels.push("urgent".to_string());
+        external.updated_at = fixed_time_merge(200);
+
+        let (result, conflicts) =
+            merge_fields(&base, &local, &external, ConflictResolution::PreferNewer);
+        assert!(conflicts.is_empty());
+        let MergeResult::KeepWithNote(merged, _) = result else {
+            panic!("expected a merged issue, got {result:?}");
+        };
+        assert_eq!(merged.description.as_deref(), Some("Local description"));
+        assert_eq!(merged.status, Status::Closed);
+        assert_eq!(merged.closed_at, Some(fixed_time_merge(200)));
+        // "old" was removed locally; "docs" and "urgent" were added on each side.
+        assert_eq!(merged.labels, vec!["backend", "docs", "urgent"]);
+        assert_eq!(merged.updated_at, fixed_time_merge(300));
+    }
+
+    #[test]
+    fn test_merge_fields_unions_comments_and_dependencies() {
+        let base = make_issue_with_hash("bd-021", "Base", fixed_time_merge(100), Some("h"));
+        let comment = |id: i64, body: &str| crate::model::Comment {
+            id,
+            issue_id: "bd-021".to_string(),
+            author: "alice".to_string(),
+            body: body.to_string(),
+            created_at: fixed_time_merge(150),
+        };
+        let mut local = base.clone();
+        local.comments = vec![comment(1, "from local")];
+        let mut external = base.clone();
+        external.comments = vec![comment(1, "from external")];
+        external.dependencies = vec![crate::model::Dependency {
+            issue_id: "bd-021".to_string(),
+            depends_on_id: "bd-022".to_string(),
+            dep_type: crate::model::DependencyType::Blocks,
+            created_at: fixed_time_merge(150),
+            created_by: None,
+            metadata: None,
+            thread_id: None,
+        }];
+
+        let (result, conflicts) =
+            merge_fields(&base, &local, &external, ConflictResolution::PreferLocal);
+        assert!(conflicts.is_empty());
+        let MergeResult::KeepWithNote(merged, _) = result else {
+            panic!("expected a merged issue, got {result:?}");
+        };
+        let bodies: Vec<&str> = merged.comments.iter().map(|c| c.body.as_str()).collect();
+        assert_eq!(bodies, vec!["from local", "from external"]);
+        assert_eq!(merged.dependencies.len(), 1);
+    }
+
+    #[test]
+    fn test_three_way_merge_records_field_conflicts() {
+        let base = make_issue_with_hash("bd-023", "Base", fixed_time_merge(100), Some("h"));
+        let mut local = base.clone();
+        local.title = "Local title".to_string();
+        local.priority = Priority::HIGH;
+        local.updated_at = fixed_time_merge(200);
+        let mut external = base.clone();
+        external.title = "External title".to_string();
+        external.updated_at = fixed_time_merge(300);
+
+        let context = MergeContext::new(
+            std::collections::HashMap::from([("bd-023".to_string(), base)]),
+            std::collections::HashMap::from([("bd-023".to_string(), local)]),
+            std::collections::HashMap::from([("bd-023".to_string(), external)]),
+        );
+        let report = three_way_merge(&context, ConflictResolution::PreferNewer, None);
+
+        assert_eq!(report.field_conflicts.len(), 1);
+        let conflict = &report.field_conflicts[0];
+        assert_eq!(conflict.field, "title");
+        assert_eq!(conflict.local, "\"Local title\"");
+        assert_eq!(conflict.kept, MergeSide::External);
+        // The non-conflicting local priority change survives.
+        assert_eq!(report.kept[0].title, "External title");
+        assert_eq!(report.kept[0].priority, Priority::HIGH);
+        assert!(report.notes[0].1.contains("title"));
+    }
+
+    #[test]
+    fn test_merge_jsonl_versions_is_sorted_and_deterministic() {
+        let temp = TempDir::new().unwrap();
+        let write = |name: &str, issues: &[Issue]| {
+            let path = temp.path().join(name);
+            write_issues_jsonl(&path, issues).unwrap();
+            path
+        };
+        let shared = make_issue_with_hash("bd-010", "Shared", fixed_time_merge(100), Some("h"));
+        let mut ours_edit = shared.clone();
+        ours_edit.priority = Priority::HIGH;
+        ours_edit.updated_at = fixed_time_merge(200);
+        let mut theirs_edit = shared.clone();
+        theirs_edit.labels = vec!["ui".to_string(), "backend".to_string()];
+        theirs_edit.updated_at = fixed_time_merge(300);
+        let ours_new = make_issue_with_hash("bd-030", "Ours", fixed_time_merge(150), Some("o"));
+        let theirs_new = make_issue_with_hash("bd-020", "Theirs", fixed_time_merge(150), Some("t"));
+
+        let base = write("base.jsonl", std::slice::from_ref(&shared));
+        let ours = write("ours.jsonl", &[ours_edit, ours_new]);
+        let theirs = write("theirs.jsonl", &[theirs_new, theirs_edit]);
+
+        let report =
+            merge_jsonl_versions(&base, &ours, &theirs, ConflictResolution::PreferNewer).unwrap();
+        let ids: Vec<_> = report.kept.iter().map(|issue| issue.id.as_str()).collect();
+        assert_eq!(ids, ["bd-010", "bd-020", "bd-030"]);
+        assert_eq!(report.kept[0].priority, Priority::HIGH);
+        assert_eq!(report.kept[0].labels, ["backend", "ui"]);
+
+        // Swapping the sides yields the same merged file.
+        let swapped =
+            merge_jsonl_versions(&base, &theirs, &ours, ConflictResolution::PreferNewer).unwrap();
+        let out_a = temp.path().join("a.jsonl");
+        let out_b = temp.path().join("b.jsonl");
+        write_issues_jsonl(&out_a, &report.kept).unwrap();
+        write_issues_jsonl(&out_b, &swapped.kept).unwrap();
+        assert_eq!(
+            fs::read_to_string(out_a).unwrap(),
+            fs::read_to_string(out_b).unwrap()
+        );
+
+        // A missing ancestor is treated as empty.
+        let missing = temp.path().join("missing.jsonl");
+        let report =
+            merge_jsonl_versions(&missing, &ours, &theirs, ConflictResolution::PreferNewer)
+                .unwrap();
+        assert_eq!(report.kept.len(), 3);
+    }
+
     // ========================================================================
     // three_way_merge orchestration tests
     // ========================================================================
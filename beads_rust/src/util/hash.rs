@@ -2,8 +2,10 @@
 //!
 //! Uses SHA256 over stable ordered fields with null separators.
 //! Matches classic bd behavior for export/import compatibility.
+//! Also hashes raw content for the attachment blob store.
 
 use sha2::{Digest, Sha256};
+use std::io::{self, Read};
 
 use crate::model::{Issue, IssueType, Priority, Status};
 
@@ -98,6 +100,38 @@ pub fn content_hash_from_parts(
     writer.finalize()
 }
 
+/// Stream `reader` through SHA256, returning the lowercase hex digest and the
+/// number of bytes read.
+///
+/// # Errors
+///
+/// Returns any error from reading.
+pub fn sha256_reader<R: Read>(mut reader: R) -> io::Result<(String, u64)> {
+    let mut hasher = Sha256::new();
+    let mut buf = [0u8; 8 * 1024];
+    let mut size = 0u64;
+    loop {
+        let read = match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        };
+        hasher.update(&buf[..read]);
+        size += read as u64;
+    }
+    Ok((format!("{:x}", hasher.finalize()), size))
+}
+
+/// Whether `value` looks like a lowercase hex SHA256 digest.
+#[must_use]
+pub fn is_sha256_hex(value: &str) -> bool {
+    value.len() == 64
+        && value
+            .bytes()
+            .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b))
+}
+
 struct HashFieldWriter {
     hasher: Sha256,
 }
@@ -176,6 +210,9 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            time_entries: vec![],
+            recurrence: None,
+            attachments: vec![],
         }
     }
 
@@ -273,4 +310,16 @@ mod tests {
         );
         assert_eq!(direct, from_parts);
     }
+
+    #[test]
+    fn test_sha256_reader() {
+        let (digest, size) = sha256_reader(&b"hello"[..]).unwrap();
+        assert_eq!(
+            digest,
+            "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+        );
+        assert_eq!(size, 5);
+        assert!(is_sha256_hex(&digest));
+        assert!(!is_sha256_hex("2CF24DBA"));
+    }
 }
@@ -7,14 +7,18 @@
 //! - ID generation (base36 adaptive)
 //! - Last-touched tracking
 //! - Progress indicators (for long-running operations)
+//! - Recurrence schedules (cron and RRULE)
 
 mod hash;
 pub mod id;
 pub mod markdown_import;
 pub mod progress;
+pub mod schedule;
 pub mod time;
 
-pub use hash::{ContentHashable, content_hash, content_hash_from_parts};
+pub use hash::{
+    ContentHashable, content_hash, content_hash_from_parts, is_sha256_hex, sha256_reader,
+};
 pub use id::{
     IdConfig, IdGenerator, IdResolver, MatchType, ParsedId, ResolvedId, ResolverConfig, child_id,
     find_matching_ids, generate_id, id_depth, is_child_id, is_valid_id_format, normalize_id,
@@ -0,0 +1,462 @@
+//! Recurrence schedules for `br recur`.
+//!
+//! Two rule syntaxes are accepted, both evaluated in UTC:
+//! - cron: five fields `minute hour day-of-month month day-of-week` with `*`,
+//!   lists, ranges, steps, month/day names, and the `@hourly`, `@daily`,
+//!   `@weekly`, `@monthly` and `@yearly` shortcuts;
+//! - RRULE (an RFC 5545 subset): `FREQ=DAILY|WEEKLY|MONTHLY|YEARLY` with
+//!   `INTERVAL`, `BYDAY`, `BYMONTHDAY`, `BYMONTH`, `BYHOUR` and `BYMINUTE`.
+//!   Parts left out default to the rule's start, as DTSTART does.
+
+use crate::error::{BeadsError, Result};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, Timelike, Utc};
+
+/// How many days `next_after` and `latest_at_or_before` scan before giving up.
+const SCAN_LIMIT_DAYS: u32 = 366 * 8;
+
+const ALL_MONTH_DAYS: u32 = u32::MAX << 1;
+const ALL_MONTHS: u16 = 0x1FFE;
+const ALL_WEEKDAYS: u8 = 0x7F;
+
+const MONTH_NAMES: &[&str] = &[
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+const CRON_DAY_NAMES: &[&str] = &["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+const RRULE_DAY_NAMES: &[&str] = &["SU", "MO", "TU", "WE", "TH", "FR", "SA"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+/// A parsed recurrence rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schedule {
+    minutes: u64,
+    hours: u32,
+    /// Bits 1..=31.
+    month_days: u32,
+    /// Bits 1..=12.
+    months: u16,
+    /// Bits 0..=6, Sunday first.
+    weekdays: u8,
+    /// Cron semantics: when both day fields are restricted, either may match.
+    either_day: bool,
+    /// RRULE `INTERVAL` above 1: only every n-th period from the start date.
+    interval: Option<(Frequency, u32, NaiveDate)>,
+}
+
+impl Schedule {
+    /// Parse a cron expression or RRULE. `start` supplies the RRULE defaults.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error naming the offending part of the rule.
+    pub fn parse(rule: &str, start: DateTime<Utc>) -> Result<Self> {
+        let rule = rule.trim();
+        let upper = rule.to_ascii_uppercase();
+        let body = upper.strip_prefix("RRULE:").unwrap_or(&upper);
+        if body.contains('=') {
+            parse_rrule(body, start)
+        } else {
+            parse_cron(rule)
+        }
+    }
+
+    /// First occurrence strictly after `after`.
+    #[must_use]
+    pub fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let times = self.times();
+        let mut date = after.date_naive();
+        for _ in 0..SCAN_LIMIT_DAYS {
+            if self.day_matches(date) {
+                if let Some(found) = times
+                    .iter()
+                    .map(|time| date.and_time(*time).and_utc())
+                    .find(|candidate| *candidate > after)
+                {
+                    return Some(found);
+                }
+            }
+            date = date.succ_opt()?;
+        }
+        None
+    }
+
+    /// Last occurrence at or before `at`.
+    #[must_use]
+    pub fn latest_at_or_before(&self, at: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let times = self.times();
+        let mut date = at.date_naive();
+        for _ in 0..SCAN_LIMIT_DAYS {
+            if self.day_matches(date) {
+                if let Some(found) = times
+                    .iter()
+                    .rev()
+                    .map(|time| date.and_time(*time).and_utc())
+                    .find(|candidate| *candidate <= at)
+                {
+                    return Some(found);
+                }
+            }
+            date = date.pred_opt()?;
+        }
+        None
+    }
+
+    fn times(&self) -> Vec<NaiveTime> {
+        let mut times = Vec::new();
+        for hour in 0..24 {
+            if self.hours & (1 << hour) == 0 {
+                continue;
+            }
+            for minute in 0..60 {
+                if self.minutes & (1 << minute) != 0 {
+                    times.extend(NaiveTime::from_hms_opt(hour, minute, 0));
+                }
+            }
+        }
+        times
+    }
+
+    fn day_matches(&self, date: NaiveDate) -> bool {
+        if self.months & (1 << date.month()) == 0 {
+            return false;
+        }
+        let day_of_month = self.month_days & (1 << date.day()) != 0;
+        let day_of_week = self.weekdays & (1 << date.weekday().num_days_from_sunday()) != 0;
+        let day_ok = if self.either_day {
+            day_of_month || day_of_week
+        } else {
+            day_of_month && day_of_week
+        };
+        day_ok
+            && self.interval.is_none_or(|(frequency, every, anchor)| {
+                period_index(frequency, anchor, date) % i64::from(every) == 0
+            })
+    }
+}
+
+/// Number of whole periods between the anchor's period and `date`'s.
+fn period_index(frequency: Frequency, anchor: NaiveDate, date: NaiveDate) -> i64 {
+    match frequency {
+        Frequency::Daily => (date - anchor).num_days(),
+        Frequency::Weekly => (week_start(date) - week_start(anchor)).num_days() / 7,
+        Frequency::Monthly => {
+            i64::from(date.year() - anchor.year()) * 12 + i64::from(date.month())
+                - i64::from(anchor.month())
+        }
+        Frequency::Yearly => i64::from(date.year() - anchor.year()),
+    }
+}
+
+/// Monday of the week containing `date` (RRULE's default `WKST=MO`).
+fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(i64::from(date.weekday().num_days_from_monday()))
+}
+
+fn rule_error(message: impl Into<String>) -> BeadsError {
+    BeadsError::validation("rule", message)
+}
+
+fn parse_cron(rule: &str) -> Result<Schedule> {
+    let expanded = match rule.to_ascii_lowercase().as_str() {
+        "@hourly" => "0 * * * *",
+        "@daily" | "@midnight" => "0 0 * * *",
+        "@weekly" => "0 0 * * 0",
+        "@monthly" => "0 0 1 * *",
+        "@yearly" | "@annually" => "0 0 1 1 *",
+        _ => rule,
+    };
+    let fields: Vec<&str> = expanded.split_whitespace().collect();
+    let [minute, hour, day, month, weekday] = fields.as_slice() else {
+        return Err(rule_error(format!(
+            "cron rule '{rule}' needs 5 fields: minute hour day month weekday"
+        )));
+    };
+
+    let mut weekdays = parse_cron_field(weekday, 0, 7, CRON_DAY_NAMES)?;
+    if weekdays & (1 << 7) != 0 {
+        weekdays = (weekdays | 1) & !(1 << 7);
+    }
+    let to_u32 = |bits: u64| u32::try_from(bits).unwrap_or(u32::MAX);
+    Ok(Schedule {
+        minutes: parse_cron_field(minute, 0, 59, &[])?,
+        hours: to_u32(parse_cron_field(hour, 0, 23, &[])?),
+        month_days: to_u32(parse_cron_field(day, 1, 31, &[])?),
+        months: u16::try_from(parse_cron_field(month, 1, 12, MONTH_NAMES)?).unwrap_or(ALL_MONTHS),
+        weekdays: u8::try_from(weekdays).unwrap_or(ALL_WEEKDAYS),
+        either_day: !day.starts_with('*') && !weekday.starts_with('*'),
+        interval: None,
+    })
+}
+
+/// Parse one cron field into a bit set of the values it allows.
+fn parse_cron_field(field: &str, min: u32, max: u32, names: &[&str]) -> Result<u64> {
+    let mut bits = 0u64;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step = step
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|step| *step > 0)
+                    .ok_or_else(|| rule_error(format!("invalid step in '{part}'")))?;
+                (range, Some(step))
+            }
+            None => (part, None),
+        };
+        let (low, high) = if range == "*" {
+            (min, max)
+        } else if let Some((low, high)) = range.split_once('-') {
+            (
+                parse_cron_value(low, min, max, names)?,
+                parse_cron_value(high, min, max, names)?,
+            )
+        } else {
+            let value = parse_cron_value(range, min, max, names)?;
+            (value, if step.is_some() { max } else { value })
+        };
+        if low > high {
+            return Err(rule_error(format!("invalid range '{range}'")));
+        }
+        let step = step.unwrap_or(1) as usize;
+        for value in (low..=high).step_by(step) {
+            bits |= 1 << value;
+        }
+    }
+    Ok(bits)
+}
+
+fn parse_cron_value(value: &str, min: u32, max: u32, names: &[&str]) -> Result<u32> {
+    let parsed = value.parse::<u32>().ok().or_else(|| {
+        names
+            .iter()
+            .position(|name| name.eq_ignore_ascii_case(value))
+            .and_then(|index| u32::try_from(index).ok())
+            .map(|index| index + min)
+    });
+    parsed
+        .filter(|parsed| (min..=max).contains(parsed))
+        .ok_or_else(|| rule_error(format!("'{value}' is outside {min}-{max}")))
+}
+
+fn parse_rrule(body: &str, start: DateTime<Utc>) -> Result<Schedule> {
+    let mut frequency = None;
+    let mut interval = 1u32;
+    let mut minutes = None;
+    let mut hours = None;
+    let mut month_days = None;
+    let mut months = None;
+    let mut weekdays = None;
+
+    for part in body.split(';').filter(|part| !part.trim().is_empty()) {
+        let (key, value) = part
+            .split_once('=')
+            .ok_or_else(|| rule_error(format!("invalid RRULE part '{part}'")))?;
+        let value = value.trim();
+        match key.trim() {
+            "FREQ" => {
+                frequency = Some(match value {
+                    "DAILY" => Frequency::Daily,
+                    "WEEKLY" => Frequency::Weekly,
+                    "MONTHLY" => Frequency::Monthly,
+                    "YEARLY" => Frequency::Yearly,
+                    other => {
+                        return Err(rule_error(format!(
+                            "unsupported FREQ '{other}' (use DAILY, WEEKLY, MONTHLY or YEARLY)"
+                        )));
+                    }
+                });
+            }
+            "INTERVAL" => {
+                interval = value
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|n| *n > 0)
+                    .ok_or_else(|| rule_error(format!("invalid INTERVAL '{value}'")))?;
+            }
+            "BYMINUTE" => minutes = Some(parse_rrule_list(value, 0, 59)?),
+            "BYHOUR" => hours = Some(parse_rrule_list(value, 0, 23)?),
+            "BYMONTHDAY" => month_days = Some(parse_rrule_list(value, 1, 31)?),
+            "BYMONTH" => months = Some(parse_rrule_list(value, 1, 12)?),
+            "BYDAY" => {
+                let mut bits = 0u64;
+                for day in value.split(',') {
+                    let index = RRULE_DAY_NAMES
+                        .iter()
+                        .position(|name| *name == day.trim())
+                        .ok_or_else(|| rule_error(format!("unsupported BYDAY value '{day}'")))?;
+                    bits |= 1 << index;
+                }
+                weekdays = Some(bits);
+            }
+            other => return Err(rule_error(format!("unsupported RRULE part '{other}'"))),
+        }
+    }
+
+    let frequency = frequency.ok_or_else(|| rule_error("RRULE needs FREQ"))?;
+    let start_day = 1u64 << start.day();
+    let start_weekday = 1u64 << start.weekday().num_days_from_sunday();
+    let no_day = month_days.is_none() && weekdays.is_none();
+    let (month_days, weekdays, months) = match frequency {
+        Frequency::Weekly => (month_days, weekdays.or(Some(start_weekday)), months),
+        Frequency::Monthly if no_day => (Some(start_day), None, months),
+        Frequency::Yearly if no_day => (
+            Some(start_day),
+            None,
+            months.or_else(|| Some(1 << start.month())),
+        ),
+        Frequency::Daily | Frequency::Monthly | Frequency::Yearly => (month_days, weekdays, months),
+    };
+
+    let to_u32 = |bits: u64| u32::try_from(bits).unwrap_or(u32::MAX);
+    Ok(Schedule {
+        minutes: minutes.unwrap_or_else(|| 1 << start.minute()),
+        hours: to_u32(hours.unwrap_or_else(|| 1 << start.hour())),
+        month_days: month_days.map_or(ALL_MONTH_DAYS, to_u32),
+        months: months.map_or(ALL_MONTHS, |bits| u16::try_from(bits).unwrap_or(ALL_MONTHS)),
+        weekdays: weekdays.map_or(ALL_WEEKDAYS, |bits| {
+            u8::try_from(bits).unwrap_or(ALL_WEEKDAYS)
+        }),
+        either_day: false,
+        interval: (interval > 1).then(|| (frequency, interval, start.date_naive())),
+    })
+}
+
+fn parse_rrule_list(value: &str, min: u32, max: u32) -> Result<u64> {
+    let mut bits = 0u64;
+    for item in value.split(',') {
+        let parsed = item
+            .trim()
+            .parse::<u32>()
+            .ok()
+            .filter(|parsed| (min..=max).contains(parsed))
+            .ok_or_else(|| rule_error(format!("'{item}' is outside {min}-{max}")))?;
+        bits |= 1 << parsed;
+    }
+    Ok(bits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(y: i32, mo: u32, d: u32, h: u32, mi: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, mo, d, h, mi, 0).unwrap()
+    }
+
+    #[test]
+    fn test_cron_weekly_next_and_latest() {
+        // 2026-10-17 is a Saturday.
+        let schedule = Schedule::parse("0 9 * * MON", at(2026, 1, 1, 0, 0)).unwrap();
+        assert_eq!(
+            schedule.next_after(at(2026, 10, 17, 12, 0)),
+            Some(at(2026, 10, 19, 9, 0))
+        );
+        assert_eq!(
+            schedule.latest_at_or_before(at(2026, 10, 17, 12, 0)),
+            Some(at(2026, 10, 12, 9, 0))
+        );
+        assert_eq!(
+            schedule.latest_at_or_before(at(2026, 10, 12, 9, 0)),
+            Some(at(2026, 10, 12, 9, 0))
+        );
+    }
+
+    #[test]
+    fn test_cron_steps_lists_and_shortcuts() {
+        let schedule = Schedule::parse("*/15 8-9 * * *", at(2026, 1, 1, 0, 0)).unwrap();
+        assert_eq!(
+            schedule.next_after(at(2026, 10, 17, 8, 15)),
+            Some(at(2026, 10, 17, 8, 30))
+        );
+        assert_eq!(
+            schedule.next_after(at(2026, 10, 17, 9, 45)),
+            Some(at(2026, 10, 18, 8, 0))
+        );
+
+        let monthly = Schedule::parse("@monthly", at(2026, 1, 1, 0, 0)).unwrap();
+        assert_eq!(
+            monthly.next_after(at(2026, 10, 17, 0, 0)),
+            Some(at(2026, 11, 1, 0, 0))
+        );
+
+        // Sunday may be written as 0 or 7.
+        let sunday = Schedule::parse("0 0 * * 7", at(2026, 1, 1, 0, 0)).unwrap();
+        assert_eq!(
+            sunday.next_after(at(2026, 10, 17, 0, 0)),
+            Some(at(2026, 10, 18, 0, 0))
+        );
+    }
+
+    #[test]
+    fn test_cron_restricted_day_fields_match_either() {
+        // The 20th, or any Monday.
+        let schedule = Schedule::parse("0 0 20 * 1", at(2026, 1, 1, 0, 0)).unwrap();
+        assert_eq!(
+            schedule.next_after(at(2026, 10, 17, 0, 0)),
+            Some(at(2026, 10, 19, 0, 0))
+        );
+        assert_eq!(
+            schedule.next_after(at(2026, 10, 19, 0, 0)),
+            Some(at(2026, 10, 20, 0, 0))
+        );
+    }
+
+    #[test]
+    fn test_rrule_defaults_from_start() {
+        // Starts Wednesday 2026-10-14 at 10:30.
+        let start = at(2026, 10, 14, 10, 30);
+        let weekly = Schedule::parse("FREQ=WEEKLY", start).unwrap();
+        assert_eq!(weekly.next_after(start), Some(at(2026, 10, 21, 10, 30)));
+
+        let monthly = Schedule::parse("RRULE:FREQ=MONTHLY", start).unwrap();
+        assert_eq!(monthly.next_after(start), Some(at(2026, 11, 14, 10, 30)));
+
+        let yearly = Schedule::parse("freq=yearly", start).unwrap();
+        assert_eq!(yearly.next_after(start), Some(at(2027, 10, 14, 10, 30)));
+    }
+
+    #[test]
+    fn test_rrule_interval_counts_from_start() {
+        let start = at(2026, 10, 5, 9, 0);
+        let schedule = Schedule::parse(
+            "FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,TH;BYHOUR=9;BYMINUTE=0",
+            start,
+        )
+        .unwrap();
+        assert_eq!(schedule.next_after(start), Some(at(2026, 10, 8, 9, 0)));
+        assert_eq!(
+            schedule.next_after(at(2026, 10, 8, 9, 0)),
+            Some(at(2026, 10, 19, 9, 0))
+        );
+    }
+
+    #[test]
+    fn test_invalid_rules_rejected() {
+        let start = at(2026, 1, 1, 0, 0);
+        for rule in [
+            "0 9 * *",
+            "61 * * * *",
+            "0 9 * * FUNDAY",
+            "*/0 * * * *",
+            "FREQ=HOURLY",
+            "FREQ=WEEKLY;COUNT=3",
+            "FREQ=MONTHLY;BYDAY=1MO",
+            "INTERVAL=2",
+        ] {
+            assert!(Schedule::parse(rule, start).is_err(), "{rule} should fail");
+        }
+    }
+
+    #[test]
+    fn test_impossible_date_never_fires() {
+        let schedule = Schedule::parse("0 0 30 2 *", at(2026, 1, 1, 0, 0)).unwrap();
+        assert_eq!(schedule.next_after(at(2026, 1, 1, 0, 0)), None);
+    }
+}
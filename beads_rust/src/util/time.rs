@@ -150,6 +150,47 @@ pub fn parse_relative_time(s: &str) -> Option<DateTime<Utc>> {
     }
 }
 
+/// Parse a positive duration like `90s`, `30m`, `2h`, `1d`, or `1w`.
+///
+/// A bare number is interpreted as minutes.
+///
+/// # Errors
+///
+/// Returns a validation error if the amount is missing, non-positive, out of
+/// range, or the unit is not one of s, m, h, d, w.
+pub fn parse_duration(s: &str, field_name: &str) -> Result<Duration> {
+    let s = s.trim();
+    let (amount_str, unit) = match s.chars().last() {
+        Some(c) if c.is_ascii_alphabetic() => (&s[..s.len() - c.len_utf8()], c),
+        _ => (s, 'm'),
+    };
+    let amount = amount_str
+        .trim()
+        .parse::<i64>()
+        .map_err(|_| BeadsError::validation(field_name, format!("invalid duration '{s}'")))?;
+    if amount <= 0 {
+        return Err(BeadsError::validation(
+            field_name,
+            "duration must be positive",
+        ));
+    }
+    let duration = match unit.to_ascii_lowercase() {
+        's' => Duration::try_seconds(amount),
+        'm' => Duration::try_minutes(amount),
+        'h' => Duration::try_hours(amount),
+        'd' => Duration::try_days(amount),
+        'w' => Duration::try_weeks(amount),
+        _ => {
+            return Err(BeadsError::validation(
+                field_name,
+                "invalid unit (use s, m, h, d, w)",
+            ));
+        }
+    };
+    duration
+        .ok_or_else(|| BeadsError::validation(field_name, format!("duration '{s}' is too large")))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -193,6 +234,26 @@ mod tests {
         assert!(result < Utc::now());
     }
 
+    #[test]
+    fn test_parse_duration_units() {
+        assert_eq!(parse_duration("90s", "ttl").unwrap(), Duration::seconds(90));
+        assert_eq!(parse_duration("30m", "ttl").unwrap(), Duration::minutes(30));
+        assert_eq!(parse_duration("2h", "ttl").unwrap(), Duration::hours(2));
+        assert_eq!(parse_duration("1d", "ttl").unwrap(), Duration::days(1));
+        assert_eq!(parse_duration("45", "ttl").unwrap(), Duration::minutes(45));
+    }
+
+    #[test]
+    fn test_parse_duration_rejects_invalid() {
+        assert!(parse_duration("", "ttl").is_err());
+        assert!(parse_duration("0m", "ttl").is_err());
+        assert!(parse_duration("-5m", "ttl").is_err());
+        assert!(parse_duration("3y", "ttl").is_err());
+        assert!(parse_duration("abc", "ttl").is_err());
+        assert!(parse_duration("9223372036854775807w", "ttl").is_err());
+        assert!(parse_duration("9223372036854775807s", "ttl").is_err());
+    }
+
     #[test]
     fn test_parse_relative_time_invalid() {
         assert!(parse_relative_time("invalid").is_none());
@@ -465,6 +465,9 @@ mod tests {
             labels: Vec::new(),
             dependencies: Vec::new(),
             comments: Vec::new(),
+            time_entries: Vec::new(),
+            recurrence: None,
+            attachments: vec![],
         }
     }
 
@@ -52,6 +52,9 @@ pub fn issue(title: &str) -> Issue {
         is_template: false,
         dependencies: vec![],
         comments: vec![],
+        time_entries: vec![],
+        recurrence: None,
+        attachments: vec![],
     }
 }
 
@@ -0,0 +1,135 @@
+//! `br analytics`: burndown, throughput, lead/cycle time and aging WIP from
+//! the events table.
+
+mod common;
+
+use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use serde_json::Value;
+
+fn setup() -> BrWorkspace {
+    let workspace = BrWorkspace::new();
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    workspace
+}
+
+fn create(workspace: &BrWorkspace, args: &[&str]) -> String {
+    let mut full = vec!["create"];
+    full.extend_from_slice(args);
+    full.push("--json");
+    let run = run_br(workspace, &full, "create");
+    assert!(run.status.success(), "create failed: {}", run.stderr);
+    let created: Value = serde_json::from_str(&extract_json_payload(&run.stdout)).unwrap();
+    created["id"].as_str().unwrap().to_string()
+}
+
+fn analytics(workspace: &BrWorkspace, args: &[&str]) -> Value {
+    let mut full = vec!["analytics"];
+    full.extend_from_slice(args);
+    full.push("--json");
+    let run = run_br(workspace, &full, "analytics");
+    assert!(run.status.success(), "analytics failed: {}", run.stderr);
+    serde_json::from_str(&extract_json_payload(&run.stdout)).unwrap()
+}
+
+#[test]
+fn analytics_reports_flow_and_wip() {
+    let workspace = setup();
+    let done = create(&workspace, &["Ship login", "--labels", "backend"]);
+    let wip = create(&workspace, &["Write docs", "--assignee", "alice"]);
+    create(&workspace, &["Backlog item", "--labels", "backend"]);
+
+    for (id, status) in [(&done, "in_progress"), (&wip, "in_progress")] {
+        let run = run_br(&workspace, ["update", id, "--status", status], "update");
+        assert!(run.status.success(), "update failed: {}", run.stderr);
+    }
+    let close = run_br(&workspace, ["close", &done], "close");
+    assert!(close.status.success(), "close failed: {}", close.stderr);
+
+    let report = analytics(&workspace, &[]);
+    assert_eq!(report["scope"]["issues"], 3);
+
+    let today = report["burndown"].as_array().unwrap().last().unwrap();
+    assert_eq!(today["total"], 3);
+    assert_eq!(today["closed"], 1);
+    assert_eq!(today["remaining"], 2);
+    assert_eq!(report["burndown"].as_array().unwrap().len(), 30);
+
+    let weeks = report["throughput"].as_array().unwrap();
+    assert_eq!(weeks.len(), 12);
+    assert_eq!(weeks.last().unwrap()["closed"], 1);
+
+    assert_eq!(report["lead_time"]["count"], 1);
+    assert_eq!(report["cycle_time"]["count"], 1);
+    assert_eq!(report["flow"][0]["id"], done.as_str());
+    assert!(report["flow"][0]["started_at"].is_string());
+
+    let aging = report["aging"].as_array().unwrap();
+    assert_eq!(aging.len(), 1);
+    assert_eq!(aging[0]["id"], wip.as_str());
+    assert_eq!(aging[0]["assignee"], "alice");
+
+    // Scoping by label keeps only the labelled issues.
+    let backend = analytics(
+        &workspace,
+        &["burndown", "--label", "backend", "--days", "7"],
+    );
+    assert_eq!(backend["scope"]["issues"], 2);
+    assert_eq!(backend["burndown"].as_array().unwrap().len(), 7);
+    assert!(backend.get("throughput").is_none());
+}
+
+#[test]
+fn analytics_scopes_to_epic_children() {
+    let workspace = setup();
+    let epic = create(&workspace, &["Auth", "--type", "epic"]);
+    let child = create(&workspace, &["Login form", "--parent", &epic]);
+    create(&workspace, &["Unrelated"]);
+
+    let report = analytics(&workspace, &["burndown", "--epic", &epic]);
+    assert_eq!(report["scope"]["issues"], 1);
+    assert_eq!(report["scope"]["epic"], epic.as_str());
+
+    let close = run_br(&workspace, ["close", &child], "close");
+    assert!(close.status.success(), "close failed: {}", close.stderr);
+    let report = analytics(&workspace, &["burndown", "--epic", &epic]);
+    let today = report["burndown"].as_array().unwrap().last().unwrap();
+    assert_eq!(today["remaining"], 0);
+}
+
+#[test]
+fn analytics_csv_and_text_output() {
+    let workspace = setup();
+    let id = create(&workspace, &["Fix crash"]);
+    let close = run_br(&workspace, ["close", &id], "close");
+    assert!(close.status.success(), "close failed: {}", close.stderr);
+
+    let csv = run_br(
+        &workspace,
+        ["analytics", "throughput", "--weeks", "2", "--format", "csv"],
+        "csv",
+    );
+    assert!(csv.status.success(), "csv failed: {}", csv.stderr);
+    let lines: Vec<&str> = csv.stdout.lines().collect();
+    assert_eq!(lines[0], "week_start,closed");
+    assert_eq!(lines.len(), 3);
+    assert!(lines[2].ends_with(",1"), "{}", csv.stdout);
+
+    let all_csv = run_br(&workspace, ["analytics", "--format", "csv"], "all_csv");
+    assert!(!all_csv.status.success());
+    assert!(
+        all_csv.stderr.contains("csv renders one report"),
+        "{}",
+        all_csv.stderr
+    );
+
+    let text = run_br(&workspace, ["analytics"], "text");
+    assert!(text.status.success(), "text failed: {}", text.stderr);
+    assert!(
+        text.stdout.contains("Burndown (last 30 days)"),
+        "{}",
+        text.stdout
+    );
+    assert!(text.stdout.contains("1 closed"), "{}", text.stdout);
+    assert!(text.stdout.contains('\u{2588}'), "{}", text.stdout);
+}
@@ -0,0 +1,191 @@
+//! `br attach`: content-addressed blobs in `.beads/blobs/`, attachment
+//! metadata in JSONL, the git size policy and `br doctor` blob checks.
+
+mod common;
+
+use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use serde_json::Value;
+use std::fs;
+
+fn setup() -> (BrWorkspace, String) {
+    let workspace = BrWorkspace::new();
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    let create = run_br(&workspace, ["create", "Flaky build", "--json"], "create");
+    assert!(create.status.success(), "create failed: {}", create.stderr);
+    let created: Value = serde_json::from_str(&extract_json_payload(&create.stdout)).unwrap();
+    (workspace, created["id"].as_str().unwrap().to_string())
+}
+
+fn json(workspace: &BrWorkspace, args: &[&str]) -> Value {
+    let run = run_br(workspace, args, "json");
+    assert!(run.status.success(), "{args:?} failed: {}", run.stderr);
+    serde_json::from_str(&extract_json_payload(&run.stdout)).unwrap()
+}
+
+fn doctor_check(report: &Value, name: &str) -> Value {
+    report["checks"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|check| check["name"] == name)
+        .cloned()
+        .unwrap_or_else(|| panic!("no {name} check in {report}"))
+}
+
+#[test]
+fn attach_stores_blobs_and_exports_metadata() {
+    let (workspace, id) = setup();
+    let log = workspace.root.join("build.log");
+    fs::write(&log, "error: linker failed\n").unwrap();
+    let log_path = log.to_str().unwrap();
+
+    let added = json(&workspace, &["attach", &id, log_path, "--json"]);
+    let sha = added[0]["sha256"].as_str().unwrap().to_string();
+    assert_eq!(added[0]["name"], "build.log");
+    assert_eq!(added[0]["size"], 21);
+    assert_eq!(added[0]["media_type"], "text/plain");
+    let blob = workspace
+        .root
+        .join(".beads/blobs")
+        .join(&sha[..2])
+        .join(&sha);
+    assert_eq!(fs::read_to_string(&blob).unwrap(), "error: linker failed\n");
+
+    // Same content under another name shares the blob.
+    json(
+        &workspace,
+        &["attach", &id, log_path, "--name", "ci.log", "--json"],
+    );
+    let listed = json(&workspace, &["attach", &id, "--json"]);
+    assert_eq!(listed.as_array().unwrap().len(), 2);
+    assert_eq!(listed[0]["present"], true);
+
+    let shown = json(&workspace, &["show", &id, "--json"]);
+    assert_eq!(shown[0]["attachments"].as_array().unwrap().len(), 2);
+    let text = run_br(&workspace, ["show", &id], "show_text");
+    assert!(text.stdout.contains("Attachments:"), "{}", text.stdout);
+    assert!(text.stdout.contains("build.log"), "{}", text.stdout);
+
+    let out = workspace.root.join("copy.log");
+    let get = run_br(
+        &workspace,
+        ["attach", &id, "--get", "ci.log", "-o", out.to_str().unwrap()],
+        "get",
+    );
+    assert!(get.status.success(), "get failed: {}", get.stderr);
+    assert_eq!(fs::read_to_string(&out).unwrap(), "error: linker failed\n");
+
+    let flush = run_br(&workspace, ["sync", "--flush-only"], "flush");
+    assert!(flush.status.success(), "flush failed: {}", flush.stderr);
+    let jsonl = fs::read_to_string(workspace.root.join(".beads/issues.jsonl")).unwrap();
+    let line: Value = serde_json::from_str(jsonl.lines().next().unwrap()).unwrap();
+    assert_eq!(line["attachments"][0]["sha256"], sha.as_str());
+
+    let missing = run_br(&workspace, ["attach", &id, "--get", "nope.log"], "get_missing");
+    assert!(!missing.status.success());
+    assert!(
+        missing.stderr.contains("have: build.log, ci.log"),
+        "{}",
+        missing.stderr
+    );
+}
+
+#[test]
+fn size_policy_keeps_large_blobs_out_of_git() {
+    let (workspace, id) = setup();
+    let config_path = workspace.root.join(".beads/config.yaml");
+    let mut config = fs::read_to_string(&config_path).unwrap_or_default();
+    config.push_str("\nattachments:\n  git_max_size: 8\n  max_size: 64\n");
+    fs::write(&config_path, config).unwrap();
+
+    let small = workspace.root.join("small.txt");
+    fs::write(&small, "tiny").unwrap();
+    let large = workspace.root.join("trace.txt");
+    fs::write(&large, "x".repeat(32)).unwrap();
+    let huge = workspace.root.join("core.dump");
+    fs::write(&huge, vec![0u8; 128]).unwrap();
+
+    let added = json(
+        &workspace,
+        &[
+            "attach",
+            &id,
+            small.to_str().unwrap(),
+            large.to_str().unwrap(),
+            "--json",
+        ],
+    );
+    assert!(added[0].get("local_only").is_none());
+    assert_eq!(added[1]["local_only"], true);
+    let gitignore = fs::read_to_string(workspace.root.join(".beads/blobs/.gitignore")).unwrap();
+    let large_sha = added[1]["sha256"].as_str().unwrap();
+    assert!(gitignore.contains(large_sha), "{gitignore}");
+    assert!(
+        !gitignore.contains(added[0]["sha256"].as_str().unwrap()),
+        "{gitignore}"
+    );
+
+    let rejected = run_br(
+        &workspace,
+        ["attach", &id, huge.to_str().unwrap()],
+        "too_large",
+    );
+    assert!(!rejected.status.success());
+    assert!(
+        rejected.stderr.contains("attachments.max_size"),
+        "{}",
+        rejected.stderr
+    );
+}
+
+#[test]
+fn doctor_reports_missing_and_orphaned_blobs() {
+    let (workspace, id) = setup();
+    for (name, content) in [("a.log", "first"), ("b.log", "second")] {
+        let path = workspace.root.join(name);
+        fs::write(&path, content).unwrap();
+        json(&workspace, &["attach", &id, path.to_str().unwrap(), "--json"]);
+    }
+    let listed = json(&workspace, &["attach", &id, "--json"]);
+    let a_sha = listed[0]["sha256"].as_str().unwrap().to_string();
+    let b_path = listed[1]["path"].as_str().unwrap().to_string();
+
+    let report = json(&workspace, &["doctor", "--json"]);
+    assert_eq!(
+        doctor_check(&report, "attachments.orphaned_blobs")["status"],
+        "ok"
+    );
+
+    // Dropping the record orphans the blob; deleting a blob leaves a record
+    // without content.
+    let remove = run_br(&workspace, ["attach", &id, "--remove", "a.log"], "remove");
+    assert!(remove.status.success(), "remove failed: {}", remove.stderr);
+    fs::remove_file(&b_path).unwrap();
+
+    let report = json(&workspace, &["doctor", "--json"]);
+    let orphaned = doctor_check(&report, "attachments.orphaned_blobs");
+    assert_eq!(orphaned["status"], "warn");
+    assert_eq!(orphaned["details"]["orphaned"][0], a_sha.as_str());
+    let missing = doctor_check(&report, "attachments.missing_blobs");
+    assert_eq!(missing["status"], "warn");
+    assert_eq!(missing["details"]["missing"][0]["name"], "b.log");
+
+    let get = run_br(&workspace, ["attach", &id, "--get", "b.log"], "get_gone");
+    assert!(!get.status.success());
+    assert!(get.stderr.contains("missing"), "{}", get.stderr);
+
+    let fixed = json(&workspace, &["doctor", "--fix", "--json"]);
+    assert_eq!(
+        doctor_check(&fixed, "attachments.orphaned_blobs")["status"],
+        "ok"
+    );
+    assert!(
+        !workspace
+            .root
+            .join(".beads/blobs")
+            .join(&a_sha[..2])
+            .join(&a_sha)
+            .exists()
+    );
+}
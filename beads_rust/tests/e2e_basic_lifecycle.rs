@@ -63,6 +63,9 @@ fn make_issue(id: &str, title: &str, now: chrono::DateTime<Utc>) -> Issue {
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        time_entries: vec![],
+        recurrence: None,
+        attachments: vec![],
     }
 }
 
@@ -0,0 +1,341 @@
+//! `br bridge` tests against a minimal in-process GitHub API mock: pulling
+//! tracker issues, linking local ones, and syncing edits in both directions.
+
+mod common;
+
+use common::cli::{BrWorkspace, extract_json_payload, run_br, run_br_with_env};
+use serde_json::{Value, json};
+use std::fs;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+const TOKEN: &str = "test-token";
+
+/// Issues, comments and received auth headers of the mock tracker.
+#[derive(Default)]
+struct MockState {
+    issues: Vec<Value>,
+    comments: Vec<(u64, Value)>,
+    auth_headers: Vec<String>,
+    clock: u32,
+}
+
+impl MockState {
+    /// Strictly increasing `updated_at` values, far ahead of local edits.
+    fn tick(&mut self) -> String {
+        self.clock += 1;
+        format!("2099-01-01T00:{:02}:00Z", self.clock)
+    }
+
+    fn issue(&mut self, number: u64) -> &mut Value {
+        self.issues
+            .iter_mut()
+            .find(|issue| issue["number"] == number)
+            .expect("mock issue")
+    }
+
+    fn add_issue(&mut self, title: &str, labels: &[&str], pull_request: bool) -> u64 {
+        let number = self.issues.len() as u64 + 1;
+        let now = self.tick();
+        let mut issue = json!({
+            "number": number,
+            "title": title,
+            "body": "",
+            "state": "open",
+            "labels": labels.iter().map(|name| json!({ "name": name })).collect::<Vec<_>>(),
+            "comments": 0,
+            "created_at": now,
+            "updated_at": now,
+        });
+        if pull_request {
+            issue["pull_request"] = json!({});
+        }
+        self.issues.push(issue);
+        number
+    }
+
+    fn add_comment(&mut self, number: u64, author: &str, body: &str) {
+        self.comments
+            .push((number, json!({ "user": { "login": author }, "body": body })));
+        let now = self.tick();
+        let issue = self.issue(number);
+        issue["comments"] = json!(issue["comments"].as_u64().unwrap() + 1);
+        issue["updated_at"] = json!(now);
+    }
+
+    fn apply_edit(&mut self, number: u64, edit: &Value) {
+        let now = self.tick();
+        let issue = self.issue(number);
+        for field in ["title", "body", "state"] {
+            if let Some(value) = edit.get(field) {
+                issue[field] = value.clone();
+            }
+        }
+        if let Some(labels) = edit["labels"].as_array() {
+            issue["labels"] = labels.iter().map(|name| json!({ "name": name })).collect();
+        }
+        issue["updated_at"] = json!(now);
+    }
+
+    fn respond(&mut self, method: &str, target: &str, body: &str) -> (u16, Value) {
+        let (path, query) = target.split_once('?').unwrap_or((target, ""));
+        let Some(rest) = path.strip_prefix("/repos/acme/app/issues") else {
+            return (404, json!({ "message": "Not Found" }));
+        };
+        let segments: Vec<&str> = rest.split('/').filter(|s| !s.is_empty()).collect();
+        let input: Value = serde_json::from_str(body).unwrap_or(Value::Null);
+        match (method, segments.as_slice()) {
+            ("GET", []) => {
+                let since = query
+                    .split('&')
+                    .find_map(|pair| pair.strip_prefix("since="))
+                    .unwrap_or("");
+                let page = query
+                    .split('&')
+                    .find_map(|pair| pair.strip_prefix("page="))
+                    .unwrap_or("1");
+                if page != "1" {
+                    return (200, json!([]));
+                }
+                let issues: Vec<Value> = self
+                    .issues
+                    .iter()
+                    .filter(|issue| issue["updated_at"].as_str().unwrap() >= since)
+                    .cloned()
+                    .collect();
+                (200, Value::Array(issues))
+            }
+            ("POST", []) => {
+                let labels: Vec<&str> = input["labels"]
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .map(|label| label.as_str().unwrap())
+                    .collect();
+                let number = self.add_issue(input["title"].as_str().unwrap(), &labels, false);
+                self.issue(number)["body"] = input["body"].clone();
+                (201, self.issue(number).clone())
+            }
+            ("GET", [number]) => (200, self.issue(number.parse().unwrap()).clone()),
+            ("PATCH", [number]) => {
+                let number = number.parse().unwrap();
+                self.apply_edit(number, &input);
+                (200, self.issue(number).clone())
+            }
+            ("GET", [number, "comments"]) => {
+                let number: u64 = number.parse().unwrap();
+                let comments: Vec<Value> = self
+                    .comments
+                    .iter()
+                    .filter(|(issue, _)| *issue == number)
+                    .map(|(_, comment)| comment.clone())
+                    .collect();
+                (200, Value::Array(comments))
+            }
+            ("POST", [number, "comments"]) => {
+                let body = input["body"].as_str().unwrap().to_string();
+                self.add_comment(number.parse().unwrap(), "bridge-bot", &body);
+                (201, json!({ "body": body }))
+            }
+            _ => (405, json!({ "message": "Method Not Allowed" })),
+        }
+    }
+}
+
+/// Serve the mock API on an ephemeral port until the test process exits.
+fn start_mock(state: Arc<Mutex<MockState>>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind mock");
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(stream) = stream else { continue };
+            handle(stream, &state);
+        }
+    });
+    url
+}
+
+fn handle(mut stream: TcpStream, state: &Mutex<MockState>) {
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).unwrap();
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let target = parts.next().unwrap_or_default().to_string();
+
+    let mut content_length = 0;
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        let (name, value) = line.split_once(':').unwrap();
+        let value = value.trim();
+        match name.to_ascii_lowercase().as_str() {
+            "content-length" => content_length = value.parse().unwrap(),
+            "authorization" => state.lock().unwrap().auth_headers.push(value.to_string()),
+            "expect" => stream.write_all(b"HTTP/1.1 100 Continue\r\n\r\n").unwrap(),
+            _ => {}
+        }
+    }
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).unwrap();
+
+    let (status, response) =
+        state
+            .lock()
+            .unwrap()
+            .respond(&method, &target, &String::from_utf8_lossy(&body));
+    let response = response.to_string();
+    write!(
+        stream,
+        "HTTP/1.1 {status} X\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{response}",
+        response.len()
+    )
+    .unwrap();
+}
+
+fn bridge_json(workspace: &BrWorkspace, args: &[&str], label: &str) -> Value {
+    let mut full = vec!["bridge"];
+    full.extend_from_slice(args);
+    full.push("--json");
+    let run = run_br_with_env(
+        workspace,
+        &full,
+        [("GITHUB_TOKEN", TOKEN), ("NO_PROXY", "127.0.0.1")],
+        label,
+    );
+    assert!(run.status.success(), "{label} failed: {}", run.stderr);
+    serde_json::from_str(&extract_json_payload(&run.stdout)).expect("bridge json")
+}
+
+fn show(workspace: &BrWorkspace, id: &str) -> Value {
+    let run = run_br(workspace, ["show", id, "--json"], "show");
+    assert!(run.status.success(), "show failed: {}", run.stderr);
+    let value: Value = serde_json::from_str(&extract_json_payload(&run.stdout)).unwrap();
+    match value {
+        Value::Array(mut items) => items.remove(0),
+        other => other,
+    }
+}
+
+#[test]
+fn bridge_syncs_issues_with_github() {
+    let state = Arc::new(Mutex::new(MockState::default()));
+    {
+        let mut mock = state.lock().unwrap();
+        mock.add_issue("Login fails", &["bug"], false);
+        mock.add_comment(1, "octocat", "Repro: click login twice");
+        mock.add_issue("Bump deps", &[], true);
+    }
+    let url = start_mock(Arc::clone(&state));
+
+    let workspace = BrWorkspace::new();
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    let config_path = workspace.root.join(".beads/config.yaml");
+    let mut config = fs::read_to_string(&config_path).unwrap_or_default();
+    config.push_str(&format!(
+        "\nbridge:\n  provider: github\n  repo: acme/app\n  api-url: {url}\n"
+    ));
+    fs::write(&config_path, config).unwrap();
+
+    // Pull: the issue comes over with its label and comment; the PR does not.
+    let report = bridge_json(&workspace, &["sync"], "sync_pull");
+    let pulled = report["pulled"].as_array().unwrap();
+    assert_eq!(pulled.len(), 1, "{report}");
+    assert_eq!(pulled[0]["external_ref"], "gh-1");
+    let pulled_id = pulled[0]["id"].as_str().unwrap().to_string();
+    let issue = show(&workspace, &pulled_id);
+    assert_eq!(issue["title"], "Login fails");
+    assert_eq!(issue["external_ref"], "gh-1");
+    assert_eq!(issue["labels"], json!(["bug"]));
+    assert_eq!(issue["comments"][0]["author"], "octocat");
+    assert!(
+        state
+            .lock()
+            .unwrap()
+            .auth_headers
+            .iter()
+            .all(|header| header == &format!("Bearer {TOKEN}"))
+    );
+
+    // Link: a local issue becomes a new GitHub issue.
+    let created = run_br(&workspace, ["create", "Agent task", "--json"], "create");
+    let created: Value = serde_json::from_str(&extract_json_payload(&created.stdout)).unwrap();
+    let local_id = created["id"].as_str().unwrap().to_string();
+    let link = bridge_json(&workspace, &["link", &local_id], "link");
+    assert_eq!(link["external_ref"], "gh-3");
+    assert_eq!(state.lock().unwrap().issue(3)["title"], "Agent task");
+
+    // Both sides edit: local retitles and comments, GitHub closes.
+    let update = run_br(
+        &workspace,
+        [
+            "update",
+            &pulled_id,
+            "--title",
+            "Login fails on double click",
+        ],
+        "update",
+    );
+    assert!(update.status.success(), "update failed: {}", update.stderr);
+    let comment = run_br(
+        &workspace,
+        ["comments", "add", &pulled_id, "Fixed in the auth handler"],
+        "comment",
+    );
+    assert!(
+        comment.status.success(),
+        "comment failed: {}",
+        comment.stderr
+    );
+    state
+        .lock()
+        .unwrap()
+        .apply_edit(1, &json!({ "state": "closed" }));
+
+    let report = bridge_json(&workspace, &["sync"], "sync_both");
+    assert!(report["field_conflicts"].as_array().unwrap().is_empty());
+    assert_eq!(report["comments_pushed"], 1, "{report}");
+
+    let issue = show(&workspace, &pulled_id);
+    assert_eq!(issue["status"], "closed");
+    let remote = state.lock().unwrap().issue(1).clone();
+    assert_eq!(remote["title"], "Login fails on double click");
+    assert_eq!(remote["state"], "closed");
+    let pushed = state.lock().unwrap().comments.clone();
+    assert!(pushed.iter().any(
+        |(number, comment)| *number == 1 && comment["body"] == "Fixed in the auth handler"
+    ));
+
+    // Nothing changed since: the next sync is a no-op.
+    let report = bridge_json(&workspace, &["sync"], "sync_noop");
+    assert!(report["pulled"].as_array().unwrap().is_empty(), "{report}");
+    assert!(
+        report["updated_local"].as_array().unwrap().is_empty(),
+        "{report}"
+    );
+    assert!(
+        report["updated_remote"].as_array().unwrap().is_empty(),
+        "{report}"
+    );
+
+    let status = bridge_json(&workspace, &["status"], "status");
+    assert_eq!(status["linked"], 2);
+    assert_eq!(status["token_set"], true);
+}
+
+#[test]
+fn bridge_requires_configuration() {
+    let workspace = BrWorkspace::new();
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    let run = run_br(&workspace, ["bridge", "status"], "status");
+    assert!(!run.status.success());
+    assert!(run.stderr.contains("bridge.provider"), "{}", run.stderr);
+}
@@ -50,6 +50,9 @@ fn seed_issue(storage: &mut SqliteStorage, id: &str, assignee: Option<&str>) {
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        time_entries: vec![],
+        recurrence: None,
+        attachments: vec![],
     };
     storage.create_issue(&issue, "seed").unwrap();
 }
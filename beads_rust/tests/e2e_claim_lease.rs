@@ -0,0 +1,370 @@
+//! Leased claim tests — verifies `br claim` assignment, renewal, release and expiry.
+
+mod common;
+
+use beads_rust::model::{EventType, Issue, Priority, Status};
+use beads_rust::storage::{ClaimOptions, IssueUpdate, SqliteStorage};
+use chrono::{Duration, Utc};
+use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use common::fixtures::IssueBuilder;
+use serde_json::Value;
+use std::path::Path;
+use std::sync::{Arc, Barrier};
+use std::thread;
+
+/// Helper to create a minimal open issue for testing.
+fn seed_issue(storage: &mut SqliteStorage, id: &str, priority: i32) {
+    seed(storage, &test_issue(id, priority));
+}
+
+fn test_issue(id: &str, priority: i32) -> Issue {
+    IssueBuilder::new(&format!("Test issue {id}"))
+        .with_id(id)
+        .with_priority(Priority(priority))
+        .build()
+}
+
+fn seed(storage: &mut SqliteStorage, issue: &Issue) {
+    storage.create_issue(issue, "seed").unwrap();
+}
+
+fn claim_options(issue_id: Option<&str>, ttl: Duration) -> ClaimOptions {
+    ClaimOptions {
+        issue_id: issue_id.map(str::to_string),
+        filters: beads_rust::storage::ReadyFilters::default(),
+        exclude_ids: std::collections::HashSet::new(),
+        ttl,
+        exclusive: false,
+    }
+}
+
+#[test]
+fn claim_picks_highest_priority_ready_issue() {
+    let mut storage = SqliteStorage::open_memory().unwrap();
+    seed_issue(&mut storage, "low-1", 3);
+    seed_issue(&mut storage, "high-1", 0);
+
+    let (issue, lease) = storage
+        .claim_issue(&claim_options(None, Duration::minutes(30)), "alice")
+        .unwrap()
+        .expect("a ready issue should be claimed");
+
+    assert_eq!(issue.id, "high-1");
+    assert_eq!(issue.status, Status::InProgress);
+    assert_eq!(issue.assignee.as_deref(), Some("alice"));
+    assert_eq!(lease.actor, "alice");
+    assert!(lease.expires_at > lease.claimed_at);
+
+    // The next claim skips the leased issue.
+    let (next, _) = storage
+        .claim_issue(&claim_options(None, Duration::minutes(30)), "bob")
+        .unwrap()
+        .expect("second issue should be claimable");
+    assert_eq!(next.id, "low-1");
+
+    assert!(
+        storage
+            .claim_issue(&claim_options(None, Duration::minutes(30)), "carol")
+            .unwrap()
+            .is_none(),
+        "nothing left to claim"
+    );
+}
+
+#[test]
+fn claim_named_issue_rejects_other_holder() {
+    let mut storage = SqliteStorage::open_memory().unwrap();
+    seed_issue(&mut storage, "named-1", 2);
+
+    storage
+        .claim_issue(
+            &claim_options(Some("named-1"), Duration::minutes(30)),
+            "alice",
+        )
+        .unwrap()
+        .expect("claim should succeed");
+
+    let err = storage
+        .claim_issue(
+            &claim_options(Some("named-1"), Duration::minutes(30)),
+            "bob",
+        )
+        .unwrap_err();
+    assert!(
+        err.to_string().contains("already"),
+        "unexpected error: {err}"
+    );
+
+    // Re-claiming your own issue extends the lease.
+    assert!(
+        storage
+            .claim_issue(
+                &claim_options(Some("named-1"), Duration::minutes(60)),
+                "alice"
+            )
+            .unwrap()
+            .is_some()
+    );
+}
+
+#[test]
+fn claim_named_issue_applies_ready_exclusions() {
+    let mut storage = SqliteStorage::open_memory().unwrap();
+    let mut deferred = test_issue("deferred-1", 2);
+    deferred.defer_until = Some(Utc::now() + Duration::days(1));
+    seed(&mut storage, &deferred);
+    let mut pinned = test_issue("pinned-1", 2);
+    pinned.pinned = true;
+    seed(&mut storage, &pinned);
+    let mut ephemeral = test_issue("eph-1", 2);
+    ephemeral.ephemeral = true;
+    seed(&mut storage, &ephemeral);
+    seed(
+        &mut storage,
+        &IssueBuilder::new("Template")
+            .with_id("tmpl-1")
+            .with_template()
+            .build(),
+    );
+
+    for (id, reason) in [
+        ("deferred-1", "deferred until"),
+        ("pinned-1", "pinned"),
+        ("eph-1", "ephemeral"),
+        ("tmpl-1", "template"),
+    ] {
+        let err = storage
+            .claim_issue(&claim_options(Some(id), Duration::minutes(30)), "alice")
+            .unwrap_err();
+        assert!(err.to_string().contains(reason), "{id}: {err}");
+    }
+    assert!(storage.list_leases().unwrap().is_empty());
+}
+
+#[test]
+fn claim_rejects_ttl_past_the_end_of_time() {
+    let mut storage = SqliteStorage::open_memory().unwrap();
+    seed_issue(&mut storage, "ttl-1", 2);
+
+    let err = storage
+        .claim_issue(
+            &claim_options(Some("ttl-1"), Duration::weeks(100_000_000)),
+            "alice",
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("too large"), "{err}");
+    assert_eq!(
+        storage.get_issue("ttl-1").unwrap().unwrap().status,
+        Status::Open
+    );
+}
+
+#[test]
+fn renew_only_by_holder() {
+    let mut storage = SqliteStorage::open_memory().unwrap();
+    seed_issue(&mut storage, "renew-1", 2);
+    let (_, lease) = storage
+        .claim_issue(
+            &claim_options(Some("renew-1"), Duration::minutes(5)),
+            "alice",
+        )
+        .unwrap()
+        .unwrap();
+
+    assert!(
+        storage
+            .renew_lease("renew-1", "bob", Duration::minutes(30))
+            .is_err()
+    );
+
+    let renewed = storage
+        .renew_lease("renew-1", "alice", Duration::minutes(30))
+        .unwrap();
+    assert!(renewed.expires_at > lease.expires_at);
+}
+
+#[test]
+fn release_returns_issue_to_ready() {
+    let mut storage = SqliteStorage::open_memory().unwrap();
+    seed_issue(&mut storage, "rel-1", 2);
+    storage
+        .claim_issue(
+            &claim_options(Some("rel-1"), Duration::minutes(30)),
+            "alice",
+        )
+        .unwrap()
+        .unwrap();
+
+    assert!(storage.release_lease("rel-1", "bob", false).is_err());
+    storage.release_lease("rel-1", "alice", false).unwrap();
+
+    let issue = storage.get_issue("rel-1").unwrap().unwrap();
+    assert_eq!(issue.status, Status::Open);
+    assert!(issue.assignee.is_none());
+    assert!(storage.get_lease("rel-1").unwrap().is_none());
+}
+
+#[test]
+fn expired_lease_returns_issue_to_ready() {
+    let mut storage = SqliteStorage::open_memory().unwrap();
+    seed_issue(&mut storage, "exp-1", 2);
+    storage
+        .claim_issue(&claim_options(Some("exp-1"), Duration::zero()), "alice")
+        .unwrap()
+        .unwrap();
+
+    let expired = storage.expire_leases("sweeper").unwrap();
+    assert_eq!(expired.len(), 1);
+    assert_eq!(expired[0].issue_id, "exp-1");
+
+    let issue = storage.get_issue("exp-1").unwrap().unwrap();
+    assert_eq!(issue.status, Status::Open);
+    assert!(issue.assignee.is_none());
+
+    let events = storage.get_events("exp-1", 0).unwrap();
+    assert!(events.iter().any(|e| e.event_type == EventType::Claimed));
+    assert!(
+        events
+            .iter()
+            .any(|e| e.event_type == EventType::LeaseExpired)
+    );
+
+    // Another agent can now pick it up.
+    let (issue, _) = storage
+        .claim_issue(&claim_options(None, Duration::minutes(30)), "bob")
+        .unwrap()
+        .unwrap();
+    assert_eq!(issue.id, "exp-1");
+}
+
+#[test]
+fn closing_issue_drops_lease() {
+    let mut storage = SqliteStorage::open_memory().unwrap();
+    seed_issue(&mut storage, "close-1", 2);
+    storage
+        .claim_issue(
+            &claim_options(Some("close-1"), Duration::minutes(30)),
+            "alice",
+        )
+        .unwrap()
+        .unwrap();
+
+    let update = IssueUpdate {
+        status: Some(Status::Closed),
+        ..IssueUpdate::default()
+    };
+    storage.update_issue("close-1", &update, "alice").unwrap();
+    assert!(storage.list_leases().unwrap().is_empty());
+}
+
+#[test]
+fn concurrent_claims_hand_out_distinct_issues() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let db_path = temp.path().join("beads.db");
+    {
+        let mut storage = SqliteStorage::open(&db_path).unwrap();
+        seed_issue(&mut storage, "race-1", 1);
+        seed_issue(&mut storage, "race-2", 1);
+    }
+
+    let barrier = Arc::new(Barrier::new(2));
+    // Both threads must be spawned before joining, or the barrier never opens.
+    #[allow(clippy::needless_collect)]
+    let handles: Vec<_> = ["alice", "bob"]
+        .iter()
+        .map(|actor| {
+            let barrier = Arc::clone(&barrier);
+            let path = db_path.clone();
+            let actor = (*actor).to_string();
+            thread::spawn(move || {
+                let mut storage = SqliteStorage::open(Path::new(&path)).unwrap();
+                barrier.wait();
+                storage
+                    .claim_issue(&claim_options(None, Duration::minutes(30)), &actor)
+                    .unwrap()
+                    .map(|(issue, _)| issue.id)
+            })
+        })
+        .collect();
+
+    let mut claimed: Vec<String> = handles
+        .into_iter()
+        .filter_map(|h| h.join().unwrap())
+        .collect();
+    claimed.sort();
+    assert_eq!(claimed, vec!["race-1".to_string(), "race-2".to_string()]);
+}
+
+#[test]
+fn cli_claim_json_round_trip() {
+    let workspace = BrWorkspace::new();
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let create = run_br(
+        &workspace,
+        ["create", "Claim me", "-p", "1", "--json"],
+        "create",
+    );
+    assert!(create.status.success(), "create failed: {}", create.stderr);
+    let created: Value = serde_json::from_str(&extract_json_payload(&create.stdout)).unwrap();
+    let id = created["id"].as_str().unwrap().to_string();
+
+    let claim = run_br(&workspace, ["claim", "--ttl", "10m", "--json"], "claim");
+    assert!(claim.status.success(), "claim failed: {}", claim.stderr);
+    let payload: Value = serde_json::from_str(&extract_json_payload(&claim.stdout)).unwrap();
+    assert_eq!(payload["issue"]["id"], id.as_str());
+    assert_eq!(payload["issue"]["status"], "in_progress");
+    assert_eq!(payload["lease"]["issue_id"], id.as_str());
+
+    let list = run_br(&workspace, ["claim", "--list", "--json"], "list");
+    assert!(list.status.success(), "list failed: {}", list.stderr);
+    let leases: Value = serde_json::from_str(&extract_json_payload(&list.stdout)).unwrap();
+    assert_eq!(leases.as_array().map(Vec::len), Some(1));
+
+    let release = run_br(&workspace, ["claim", &id, "--release"], "release");
+    assert!(
+        release.status.success(),
+        "release failed: {}",
+        release.stderr
+    );
+
+    let ready = run_br(&workspace, ["ready", "--json"], "ready");
+    assert!(ready.status.success(), "ready failed: {}", ready.stderr);
+    assert!(ready.stdout.contains(&id));
+}
+
+#[test]
+fn cli_read_commands_reap_and_persist_expired_leases() {
+    let workspace = BrWorkspace::new();
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let create = run_br(&workspace, ["create", "Short lease", "--json"], "create");
+    assert!(create.status.success(), "create failed: {}", create.stderr);
+    let created: Value = serde_json::from_str(&extract_json_payload(&create.stdout)).unwrap();
+    let id = created["id"].as_str().unwrap().to_string();
+
+    let claim = run_br(&workspace, ["claim", &id, "--ttl", "1s"], "claim");
+    assert!(claim.status.success(), "claim failed: {}", claim.stderr);
+    thread::sleep(std::time::Duration::from_millis(1500));
+
+    let show = run_br(&workspace, ["show", &id, "--json"], "show");
+    assert!(show.status.success(), "show failed: {}", show.stderr);
+    let shown: Value = serde_json::from_str(&extract_json_payload(&show.stdout)).unwrap();
+    assert_eq!(shown[0]["status"], "open");
+
+    // The reaped state reaches JSONL without a mutating command.
+    let jsonl = std::fs::read_to_string(workspace.root.join(".beads/issues.jsonl")).unwrap();
+    let line = jsonl.lines().find(|line| line.contains(&id)).unwrap();
+    let exported: Value = serde_json::from_str(line).unwrap();
+    assert_eq!(exported["status"], "open");
+
+    let huge = run_br(
+        &workspace,
+        ["claim", &id, "--ttl", "9223372036854775807w"],
+        "claim_huge_ttl",
+    );
+    assert!(!huge.status.success());
+    assert!(huge.stderr.contains("too large"), "{}", huge.stderr);
+}
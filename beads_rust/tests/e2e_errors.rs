@@ -624,6 +624,37 @@ fn e2e_structured_error_invalid_status() {
     );
 }
 
+#[test]
+fn e2e_structured_error_query_parse() {
+    let _log = common::test_log("e2e_structured_error_query_parse");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success());
+
+    let result = run_br(
+        &workspace,
+        ["list", "--where", "status:open AND bogus:1", "--json"],
+        "list_where_bad_field_json",
+    );
+    assert!(!result.status.success());
+    assert_eq!(result.status.code(), Some(4), "exit code should be 4");
+
+    let json = parse_error_json(&result.stderr).expect("should be valid JSON");
+    assert!(verify_error_structure(&json), "missing required fields");
+
+    let error = &json["error"];
+    assert_eq!(error["code"], "QUERY_PARSE_ERROR");
+    assert!(error["retryable"].as_bool().unwrap());
+    assert_eq!(error["context"]["position"], 17);
+    assert!(
+        error["context"]["reason"]
+            .as_str()
+            .unwrap()
+            .contains("bogus")
+    );
+}
+
 #[test]
 fn e2e_structured_error_cycle_detected() {
     let _log = common::test_log("e2e_structured_error_cycle_detected");
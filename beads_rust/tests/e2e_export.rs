@@ -0,0 +1,96 @@
+//! `br export --format html`: the static site has an index, issue pages with
+//! comments and history, epic progress and the dependency graph.
+
+mod common;
+
+use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use serde_json::Value;
+use std::fs;
+
+fn create(workspace: &BrWorkspace, args: &[&str]) -> String {
+    let mut full = vec!["create"];
+    full.extend_from_slice(args);
+    full.push("--json");
+    let run = run_br(workspace, &full, "create");
+    assert!(run.status.success(), "create failed: {}", run.stderr);
+    let created: Value = serde_json::from_str(&extract_json_payload(&run.stdout)).unwrap();
+    created["id"].as_str().unwrap().to_string()
+}
+
+fn br_ok(workspace: &BrWorkspace, args: &[&str], label: &str) -> String {
+    let run = run_br(workspace, args, label);
+    assert!(run.status.success(), "{label} failed: {}", run.stderr);
+    run.stdout
+}
+
+#[test]
+fn export_html_writes_static_site() {
+    let workspace = BrWorkspace::new();
+    br_ok(&workspace, &["init"], "init");
+
+    let epic = create(&workspace, &["Launch <v2>", "--type", "epic"]);
+    let task = create(&workspace, &["Write docs", "--labels", "docs"]);
+    let bug = create(&workspace, &["Fix login", "--type", "bug"]);
+    br_ok(
+        &workspace,
+        &["dep", "add", &task, &epic, "--type", "parent-child"],
+        "parent",
+    );
+    br_ok(&workspace, &["dep", "add", &bug, &task], "dep");
+    br_ok(
+        &workspace,
+        &["comments", "add", &task, "Draft is up & ready"],
+        "comment",
+    );
+    br_ok(&workspace, &["close", &task], "close");
+
+    let out = workspace.root.join("site");
+    let stdout = br_ok(
+        &workspace,
+        &["export", "--out", out.to_str().unwrap(), "--json"],
+        "export",
+    );
+    let report: Value = serde_json::from_str(&extract_json_payload(&stdout)).unwrap();
+    assert_eq!(report["issues"], 3);
+    assert_eq!(report["epics"], 1);
+    assert_eq!(report["files"], 6);
+
+    let index = fs::read_to_string(out.join("index.html")).unwrap();
+    assert!(index.contains("Launch &lt;v2&gt;"));
+    assert!(index.contains(&format!("href=\"issues/{bug}.html\"")));
+    assert!(index.contains("data-labels=\"docs\""));
+    assert!(index.contains("id=\"q\""));
+
+    let task_page = fs::read_to_string(out.join(format!("issues/{task}.html"))).unwrap();
+    assert!(task_page.contains("Draft is up &amp; ready"));
+    assert!(task_page.contains(&format!("<a href=\"{epic}.html\">{epic}</a>")));
+    assert!(task_page.contains("<h2>History</h2>"));
+    assert!(task_page.contains("<td>closed"));
+
+    let epics = fs::read_to_string(out.join("epics.html")).unwrap();
+    assert!(epics.contains("1/1 closed (100%)"));
+    assert!(epics.contains(&format!("href=\"issues/{task}.html\"")));
+
+    let graph = fs::read_to_string(out.join("graph.html")).unwrap();
+    assert!(graph.contains("<svg"));
+    assert!(graph.contains(&format!("href=\"issues/{bug}.html\"")));
+
+    // Re-export after deleting an issue drops its page.
+    br_ok(&workspace, &["delete", &bug, "--force"], "delete");
+    br_ok(
+        &workspace,
+        &["export", "--out", out.to_str().unwrap()],
+        "re-export",
+    );
+    assert!(!out.join(format!("issues/{bug}.html")).exists());
+    assert!(out.join(format!("issues/{task}.html")).exists());
+}
+
+#[test]
+fn export_rejects_unknown_format() {
+    let workspace = BrWorkspace::new();
+    br_ok(&workspace, &["init"], "init");
+    let run = run_br(&workspace, ["export", "--format", "pdf"], "export");
+    assert!(!run.status.success());
+    assert!(run.stderr.contains("html"), "{}", run.stderr);
+}
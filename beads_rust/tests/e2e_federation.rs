@@ -0,0 +1,125 @@
+//! Cross-project queries: `--all-projects`/`--projects` on list, ready,
+//! search and stats, and cross-project `blocks` dependencies.
+
+mod common;
+
+use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use serde_json::Value;
+use std::fs;
+
+/// A `bd` workspace that knows the `fe` workspace as external project `web`.
+fn setup() -> (BrWorkspace, BrWorkspace) {
+    let workspace = BrWorkspace::new();
+    let web = BrWorkspace::new();
+    for (ws, prefix) in [(&workspace, "bd"), (&web, "fe")] {
+        let init = run_br(ws, ["init", "--prefix", prefix], "init");
+        assert!(init.status.success(), "init failed: {}", init.stderr);
+    }
+    let config_path = workspace.root.join(".beads/config.yaml");
+    let mut config = fs::read_to_string(&config_path).unwrap_or_default();
+    config.push_str(&format!(
+        "\nexternal_projects:\n  web: \"{}\"\n",
+        web.root.display()
+    ));
+    fs::write(&config_path, config).unwrap();
+    (workspace, web)
+}
+
+fn create(workspace: &BrWorkspace, title: &str) -> String {
+    let run = run_br(workspace, ["create", title, "--json"], "create");
+    assert!(run.status.success(), "create failed: {}", run.stderr);
+    let created: Value = serde_json::from_str(&extract_json_payload(&run.stdout)).unwrap();
+    created["id"].as_str().unwrap().to_string()
+}
+
+fn json(workspace: &BrWorkspace, args: &[&str]) -> Value {
+    let run = run_br(workspace, args, "json");
+    assert!(run.status.success(), "{args:?} failed: {}", run.stderr);
+    serde_json::from_str(&extract_json_payload(&run.stdout)).unwrap()
+}
+
+fn projects_of(items: &Value) -> Vec<(String, String)> {
+    items
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| {
+            (
+                item["project"].as_str().unwrap().to_string(),
+                item["id"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn federated_list_search_and_stats() {
+    let (workspace, web) = setup();
+    let local = create(&workspace, "Login API");
+    let remote = create(&web, "Login page");
+
+    // Without a flag, only the local database is read.
+    let list = json(&workspace, &["list", "--json"]);
+    assert_eq!(list.as_array().unwrap().len(), 1);
+    assert!(list[0].get("project").is_none());
+
+    let list = json(&workspace, &["list", "--all-projects", "--json"]);
+    assert_eq!(
+        projects_of(&list),
+        [
+            ("bd".to_string(), local),
+            ("web".to_string(), remote.clone())
+        ]
+    );
+
+    let search = json(
+        &workspace,
+        &["search", "login", "--projects", "web", "--json"],
+    );
+    assert_eq!(projects_of(&search).len(), 2);
+
+    let stats = json(
+        &workspace,
+        &["stats", "--all-projects", "--no-activity", "--json"],
+    );
+    assert_eq!(stats[1]["project"], "web");
+    assert_eq!(stats[1]["summary"]["total_issues"], 1);
+
+    let csv = run_br(
+        &workspace,
+        ["list", "--all-projects", "--format", "csv"],
+        "csv",
+    );
+    assert!(csv.status.success(), "csv failed: {}", csv.stderr);
+    assert!(csv.stdout.starts_with("project,id,"), "{}", csv.stdout);
+    assert!(
+        csv.stdout.contains(&format!("web,{remote}")),
+        "{}",
+        csv.stdout
+    );
+
+    let unknown = run_br(&workspace, ["list", "--projects", "api"], "unknown");
+    assert!(!unknown.status.success());
+    assert!(unknown.stderr.contains("known: web"), "{}", unknown.stderr);
+}
+
+#[test]
+fn ready_honors_cross_project_blocks() {
+    let (workspace, web) = setup();
+    let local = create(&workspace, "Ship login");
+    let remote = create(&web, "Login page");
+
+    let dep = run_br(&workspace, ["dep", "add", &local, &remote, "--json"], "dep");
+    assert!(dep.status.success(), "dep failed: {}", dep.stderr);
+    let added: Value = serde_json::from_str(&extract_json_payload(&dep.stdout)).unwrap();
+    assert_eq!(added["depends_on_id"], format!("external:web:{remote}"));
+
+    let ready = json(&workspace, &["ready", "--all-projects", "--json"]);
+    assert_eq!(projects_of(&ready), [("web".to_string(), remote.clone())]);
+
+    let close = run_br(&web, ["close", &remote], "close");
+    assert!(close.status.success(), "close failed: {}", close.stderr);
+
+    let ready = json(&workspace, &["ready", "--json"]);
+    assert_eq!(ready[0]["id"], local.as_str());
+}
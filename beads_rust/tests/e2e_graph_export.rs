@@ -0,0 +1,153 @@
+//! `br graph --format` export tests — DOT, Mermaid, GraphML and Cytoscape
+//! JSON, with epic clusters and focus/depth subgraphs.
+
+mod common;
+
+use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use serde_json::Value;
+
+fn init_workspace() -> BrWorkspace {
+    let workspace = BrWorkspace::new();
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    workspace
+}
+
+fn create(workspace: &BrWorkspace, args: &[&str], label: &str) -> String {
+    let mut full = vec!["create"];
+    full.extend_from_slice(args);
+    full.push("--json");
+    let run = run_br(workspace, full, label);
+    assert!(run.status.success(), "create failed: {}", run.stderr);
+    let created: Value = serde_json::from_str(&extract_json_payload(&run.stdout)).unwrap();
+    created["id"].as_str().unwrap().to_string()
+}
+
+fn depend(workspace: &BrWorkspace, args: &[&str]) {
+    let mut full = vec!["dep", "add"];
+    full.extend_from_slice(args);
+    let run = run_br(workspace, full, "dep");
+    assert!(run.status.success(), "dep add failed: {}", run.stderr);
+}
+
+fn export(workspace: &BrWorkspace, args: &[&str], label: &str) -> String {
+    let mut full = vec!["graph"];
+    full.extend_from_slice(args);
+    let run = run_br(workspace, full, label);
+    assert!(run.status.success(), "graph failed: {}", run.stderr);
+    run.stdout
+}
+
+/// epic <- api (parent-child) <- ui (blocks); notes ~ ui (related);
+/// unrelated stands alone.
+struct Project {
+    epic: String,
+    api: String,
+    ui: String,
+    notes: String,
+    unrelated: String,
+}
+
+fn seed_project(workspace: &BrWorkspace) -> Project {
+    let epic = create(workspace, &["Launch", "-t", "epic"], "epic");
+    let api = create(workspace, &["API", "--parent", &epic, "-p", "0"], "api");
+    let ui = create(workspace, &["UI"], "ui");
+    let notes = create(workspace, &["Notes"], "notes");
+    let unrelated = create(workspace, &["Unrelated"], "unrelated");
+    depend(workspace, &[&ui, &api]);
+    depend(workspace, &[&notes, &ui, "--type", "related"]);
+    Project {
+        epic,
+        api,
+        ui,
+        notes,
+        unrelated,
+    }
+}
+
+#[test]
+fn graph_exports_dot_and_mermaid_with_epic_clusters() {
+    let workspace = init_workspace();
+    let project = seed_project(&workspace);
+
+    let dot = export(&workspace, &["--all", "--format", "dot"], "dot");
+    assert!(dot.starts_with("digraph beads {"), "{dot}");
+    assert!(
+        dot.contains(&format!("subgraph \"cluster_{}\"", project.epic)),
+        "{dot}"
+    );
+    assert!(dot.contains(&format!(
+        "\"{}\" -> \"{}\" [label=\"blocks\", style=bold",
+        project.ui, project.api
+    )));
+    assert!(dot.contains(&format!(
+        "\"{}\" -> \"{}\" [label=\"parent-child\"",
+        project.api, project.epic
+    )));
+    assert!(dot.contains("label=\"related\", style=dotted"), "{dot}");
+    assert!(dot.contains(&project.unrelated));
+
+    let mermaid = export(&workspace, &["--all", "--format", "mermaid"], "mermaid");
+    assert!(mermaid.starts_with("graph LR\n"), "{mermaid}");
+    assert!(mermaid.contains("subgraph cluster_"), "{mermaid}");
+    assert!(mermaid.contains("==>|blocks|"), "{mermaid}");
+    assert!(mermaid.contains("-.-|related|"), "{mermaid}");
+
+    let bad = run_br(&workspace, ["graph", "--all", "--format", "svg"], "bad");
+    assert!(!bad.status.success());
+}
+
+#[test]
+fn graph_export_focus_and_depth_select_a_subgraph() {
+    let workspace = init_workspace();
+    let project = seed_project(&workspace);
+
+    let json = export(
+        &workspace,
+        &[&project.api, "--format", "cytoscape-json", "--depth", "1"],
+        "cytoscape_depth",
+    );
+    let elements: Value = serde_json::from_str(&json).unwrap();
+    let mut ids: Vec<&str> = elements["elements"]["nodes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|n| n["data"]["id"].as_str().unwrap())
+        .collect();
+    ids.sort_unstable();
+    let mut expected = vec![
+        project.epic.as_str(),
+        project.api.as_str(),
+        project.ui.as_str(),
+    ];
+    expected.sort_unstable();
+    assert_eq!(ids, expected, "{json}");
+    let api_node = elements["elements"]["nodes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|n| n["data"]["id"] == project.api)
+        .unwrap();
+    assert_eq!(api_node["data"]["parent"], project.epic.as_str());
+    assert_eq!(api_node["data"]["border_color"], "#d62728");
+
+    let graphml = export(
+        &workspace,
+        &[&project.api, "--format", "graphml"],
+        "graphml",
+    );
+    assert!(graphml.contains("<graphml"), "{graphml}");
+    assert!(graphml.contains(&format!("<node id=\"{}\">", project.notes)));
+    assert!(!graphml.contains(&project.unrelated));
+    assert!(graphml.contains("<data key=\"dep_type\">related</data>"));
+
+    // Depth also limits the regular text traversal.
+    let text = run_br(
+        &workspace,
+        ["graph", &project.api, "--depth", "0", "--json"],
+        "text_depth",
+    );
+    assert!(text.status.success(), "{}", text.stderr);
+    let single: Value = serde_json::from_str(&extract_json_payload(&text.stdout)).unwrap();
+    assert_eq!(single["count"], 1, "{single}");
+}
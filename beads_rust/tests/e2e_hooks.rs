@@ -0,0 +1,147 @@
+//! Lifecycle hooks in `.beads/hooks/`: pre-hooks veto mutations, post-hooks
+//! observe them with the issue JSON on stdin.
+#![cfg(unix)]
+
+mod common;
+
+use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use serde_json::Value;
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+
+fn setup() -> BrWorkspace {
+    let workspace = BrWorkspace::new();
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    workspace
+}
+
+fn write_hook(workspace: &BrWorkspace, name: &str, body: &str) {
+    let dir = workspace.root.join(".beads/hooks");
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(name);
+    fs::write(&path, format!("#!/bin/sh\n{body}\n")).unwrap();
+    fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+}
+
+fn create(workspace: &BrWorkspace, title: &str) -> String {
+    let run = run_br(workspace, ["create", title, "--json"], "create");
+    assert!(run.status.success(), "create failed: {}", run.stderr);
+    let created: Value = serde_json::from_str(&extract_json_payload(&run.stdout)).unwrap();
+    created["id"].as_str().unwrap().to_string()
+}
+
+/// Payloads appended by the logging hook, one JSON object per line.
+fn logged(workspace: &BrWorkspace) -> Vec<Value> {
+    fs::read_to_string(workspace.root.join("hooks.log"))
+        .unwrap_or_default()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+#[test]
+fn post_hooks_receive_issue_json() {
+    let workspace = setup();
+    for name in ["on_create", "on_status_change", "on_close", "on_dep_added"] {
+        write_hook(
+            &workspace,
+            name,
+            "cat >> hooks.log\necho >> hooks.log\necho \"$BR_HOOK_EVENT $BR_ISSUE_ID\" >> env.log",
+        );
+    }
+
+    let id = create(&workspace, "Ship hooks");
+    let other = create(&workspace, "Write docs");
+    let dep = run_br(&workspace, ["dep", "add", &other, &id], "dep");
+    assert!(dep.status.success(), "dep failed: {}", dep.stderr);
+    let close = run_br(&workspace, ["close", &id, "--reason", "shipped"], "close");
+    assert!(close.status.success(), "close failed: {}", close.stderr);
+
+    let events = logged(&workspace);
+    let names: Vec<&str> = events
+        .iter()
+        .map(|event| event["event"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        names,
+        ["create", "create", "dep_added", "status_change", "close"]
+    );
+    assert_eq!(events[0]["phase"], "post");
+    assert_eq!(events[0]["issue"]["title"], "Ship hooks");
+    assert_eq!(events[2]["issue"]["id"], other.as_str());
+    assert_eq!(events[2]["dependency"]["depends_on_id"], id.as_str());
+    assert_eq!(events[3]["from"], "open");
+    assert_eq!(events[3]["to"], "closed");
+    assert_eq!(events[4]["reason"], "shipped");
+    assert_eq!(events[4]["issue"]["status"], "closed");
+
+    let env = fs::read_to_string(workspace.root.join("env.log")).unwrap();
+    assert!(env.starts_with(&format!("create {id}\n")), "{env}");
+}
+
+#[test]
+fn pre_hooks_veto_changes() {
+    let workspace = setup();
+    let id = create(&workspace, "Needs review");
+    write_hook(
+        &workspace,
+        "pre_close.sh",
+        "echo 'closing requires a review' >&2\nexit 1",
+    );
+    write_hook(
+        &workspace,
+        "pre_status_change",
+        "grep -q '\"to\":\"in_progress\"' || exit 0\necho 'claims are frozen' >&2\nexit 1",
+    );
+
+    let update = run_br(
+        &workspace,
+        ["update", &id, "--status", "in_progress"],
+        "update",
+    );
+    assert!(!update.status.success());
+    assert!(
+        update.stderr.contains("claims are frozen"),
+        "{}",
+        update.stderr
+    );
+
+    // close skips vetoed issues instead of failing the batch.
+    let close = run_br(&workspace, ["close", &id], "close");
+    assert!(close.status.success(), "close failed: {}", close.stderr);
+    let output = format!("{}{}", close.stdout, close.stderr);
+    assert!(output.contains("closing requires a review"), "{output}");
+
+    let show = run_br(&workspace, ["show", &id, "--json"], "show");
+    let shown: Value = serde_json::from_str(&extract_json_payload(&show.stdout)).unwrap();
+    let issue = shown.as_array().map_or(&shown, |items| &items[0]);
+    assert_eq!(issue["status"], "open");
+
+    // Disabling hooks lets the change through.
+    let config_path = workspace.root.join(".beads/config.yaml");
+    let mut config = fs::read_to_string(&config_path).unwrap_or_default();
+    config.push_str("\nhooks:\n  enabled: false\n");
+    fs::write(&config_path, config).unwrap();
+    let close = run_br(&workspace, ["close", &id], "close_disabled");
+    assert!(close.status.success(), "close failed: {}", close.stderr);
+}
+
+#[test]
+fn pre_hook_timeout_is_reported() {
+    let workspace = setup();
+    write_hook(&workspace, "pre_create", "sleep 10");
+    let config_path = workspace.root.join(".beads/config.yaml");
+    let mut config = fs::read_to_string(&config_path).unwrap_or_default();
+    config.push_str("\nhooks:\n  timeout: 1\n");
+    fs::write(&config_path, config).unwrap();
+
+    let run = run_br(&workspace, ["create", "Slow", "--json"], "create");
+    assert!(!run.status.success());
+    assert_eq!(run.status.code(), Some(9));
+    assert!(run.stderr.contains("HOOK_FAILED"), "{}", run.stderr);
+    assert!(run.stderr.contains("timed out"), "{}", run.stderr);
+
+    let list = run_br(&workspace, ["list", "--json"], "list");
+    assert!(!list.stdout.contains("Slow"), "{}", list.stdout);
+}
@@ -11,6 +11,7 @@
 //! - Sorting (--sort, --reverse)
 //! - Limiting (--limit)
 //! - Deferred and overdue filtering (--deferred, --overdue)
+//! - Filter expressions (--where)
 //! - Output format variations (--long, --pretty)
 
 mod common;
@@ -1057,6 +1058,104 @@ fn e2e_list_empty_result() {
     assert!(issues.is_empty(), "expected no matching issues");
 }
 
+// =============================================================================
+// FILTER EXPRESSION TESTS
+// =============================================================================
+
+fn list_where_ids(workspace: &BrWorkspace, expr: &str, label: &str) -> Vec<String> {
+    let list = run_br(workspace, ["list", "--where", expr, "--json"], label);
+    assert!(
+        list.status.success(),
+        "list --where failed: {}",
+        list.stderr
+    );
+    let payload = extract_json_payload(&list.stdout);
+    let issues: Vec<Value> = serde_json::from_str(&payload).expect("json parse");
+    let mut ids: Vec<String> = issues
+        .iter()
+        .map(|issue| issue["id"].as_str().unwrap().to_string())
+        .collect();
+    ids.sort();
+    ids
+}
+
+fn sorted(ids: &[&String]) -> Vec<String> {
+    let mut ids: Vec<String> = ids.iter().map(|id| (*id).clone()).collect();
+    ids.sort();
+    ids
+}
+
+#[test]
+fn e2e_list_where_expression() {
+    let _log = common::test_log("e2e_list_where_expression");
+    let (workspace, ids) = setup_diverse_workspace();
+
+    assert_eq!(
+        list_where_ids(&workspace, "label:core OR priority<=0", "where_or"),
+        sorted(&[&ids[0], &ids[1], &ids[2]])
+    );
+
+    // Mentioning status lifts the default closed/deferred scoping.
+    assert_eq!(
+        list_where_ids(&workspace, "status:closed OR type:epic", "where_status"),
+        sorted(&[&ids[3], &ids[4]])
+    );
+
+    assert_eq!(
+        list_where_ids(
+            &workspace,
+            "assignee:none AND (type:task OR type:epic) AND NOT priority<2",
+            "where_not"
+        ),
+        sorted(&[&ids[4], &ids[5]])
+    );
+}
+
+#[test]
+fn e2e_list_where_not_includes_missing_timestamps() {
+    let _log = common::test_log("e2e_list_where_not_includes_missing_timestamps");
+    let workspace = BrWorkspace::new();
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let dated = run_br(&workspace, ["create", "Dated", "--due", "+2d"], "dated");
+    assert!(dated.status.success(), "create failed: {}", dated.stderr);
+    let dated = parse_created_id(&dated.stdout);
+    let undated = run_br(&workspace, ["create", "Undated"], "undated");
+    assert!(
+        undated.status.success(),
+        "create failed: {}",
+        undated.stderr
+    );
+    let undated = parse_created_id(&undated.stdout);
+
+    assert_eq!(
+        list_where_ids(&workspace, "due<+1w", "where_due"),
+        vec![dated]
+    );
+    assert_eq!(
+        list_where_ids(&workspace, "NOT due<+1w", "where_not_due"),
+        vec![undated]
+    );
+}
+
+#[test]
+fn e2e_list_where_combines_with_flags() {
+    let _log = common::test_log("e2e_list_where_combines_with_flags");
+    let (workspace, ids) = setup_diverse_workspace();
+
+    let list = run_br(
+        &workspace,
+        ["list", "--type", "bug", "--where", "label:urgent", "--json"],
+        "where_with_flags",
+    );
+    assert!(list.status.success(), "list failed: {}", list.stderr);
+    let payload = extract_json_payload(&list.stdout);
+    let issues: Vec<Value> = serde_json::from_str(&payload).expect("json parse");
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0]["id"], ids[1].as_str());
+}
+
 // =============================================================================
 // ERROR CASE TESTS
 // =============================================================================
@@ -0,0 +1,162 @@
+//! `br merge-driver` tests — installation via `br init --merge-driver` and a
+//! real `git merge` of branches that both edited `.beads/issues.jsonl`.
+
+mod common;
+
+use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use serde_json::Value;
+use std::ffi::OsString;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// Run git in the workspace with the `br` binary first on PATH, as the merge
+/// driver command invokes plain `br`.
+fn git(workspace: &BrWorkspace, args: &[&str]) -> String {
+    let br_dir = Path::new(assert_cmd::cargo::cargo_bin!("br"))
+        .parent()
+        .unwrap()
+        .to_path_buf();
+    let mut paths = vec![br_dir];
+    paths.extend(std::env::split_paths(
+        &std::env::var_os("PATH").unwrap_or_default(),
+    ));
+    let path: OsString = std::env::join_paths(paths).unwrap();
+
+    let output = Command::new("git")
+        .current_dir(&workspace.root)
+        .env("PATH", path)
+        .env("HOME", &workspace.root)
+        .args(args)
+        .output()
+        .expect("run git");
+    assert!(
+        output.status.success(),
+        "git {args:?} failed: {}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+fn br_ok(workspace: &BrWorkspace, args: &[&str], label: &str) -> String {
+    let run = run_br(workspace, args, label);
+    assert!(run.status.success(), "{label} failed: {}", run.stderr);
+    run.stdout
+}
+
+fn read_jsonl(path: &Path) -> Vec<Value> {
+    fs::read_to_string(path)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+fn write_jsonl(path: &Path, issues: &[Value]) {
+    let lines: Vec<String> = issues.iter().map(Value::to_string).collect();
+    fs::write(path, lines.join("\n") + "\n").unwrap();
+}
+
+fn edit_issue(path: &Path, id: &str, field: &str, value: Value, updated_at: &str) {
+    let mut issues = read_jsonl(path);
+    let issue = issues.iter_mut().find(|issue| issue["id"] == id).unwrap();
+    issue[field] = value;
+    issue["updated_at"] = updated_at.into();
+    write_jsonl(path, &issues);
+}
+
+#[test]
+fn init_installs_merge_driver_once() {
+    let workspace = BrWorkspace::new();
+    let outside = run_br(&workspace, ["init", "--merge-driver"], "init_no_git");
+    assert!(!outside.status.success());
+    assert!(
+        outside.stderr.contains("git repository"),
+        "{}",
+        outside.stderr
+    );
+
+    git(&workspace, &["init", "-q"]);
+    let stdout = br_ok(&workspace, &["init", "--merge-driver"], "init");
+    assert!(stdout.contains("Installed git merge driver"), "{stdout}");
+    assert_eq!(
+        git(&workspace, &["config", "--get", "merge.beads.driver"]),
+        "br merge-driver %O %A %B"
+    );
+
+    // Running it again on the initialized workspace changes nothing.
+    let stdout = br_ok(&workspace, &["init", "--merge-driver"], "init_again");
+    assert!(stdout.contains("already installed"), "{stdout}");
+    let attributes = fs::read_to_string(workspace.root.join(".gitattributes")).unwrap();
+    assert_eq!(attributes, ".beads/issues.jsonl merge=beads\n");
+}
+
+#[test]
+fn git_merge_combines_concurrent_jsonl_edits() {
+    let workspace = BrWorkspace::new();
+    git(&workspace, &["init", "-q"]);
+    git(&workspace, &["config", "user.email", "test@example.com"]);
+    git(&workspace, &["config", "user.name", "Test User"]);
+    fs::write(workspace.root.join(".gitignore"), "logs/\n").unwrap();
+    br_ok(&workspace, &["init", "--merge-driver"], "init");
+
+    let mut ids = Vec::new();
+    for title in ["Parser", "Lexer"] {
+        let created = br_ok(&workspace, &["create", title, "--json"], title);
+        let created: Value = serde_json::from_str(&extract_json_payload(&created)).unwrap();
+        ids.push(created["id"].as_str().unwrap().to_string());
+    }
+    git(&workspace, &["add", "-A"]);
+    git(&workspace, &["commit", "-qm", "base"]);
+    let main_branch = git(&workspace, &["rev-parse", "--abbrev-ref", "HEAD"]);
+
+    // Both branches edit neighbouring lines, which a textual merge rejects.
+    let jsonl = workspace.root.join(".beads/issues.jsonl");
+    git(&workspace, &["checkout", "-qb", "feature"]);
+    edit_issue(
+        &jsonl,
+        &ids[0],
+        "priority",
+        0.into(),
+        "2030-01-01T00:00:00Z",
+    );
+    git(&workspace, &["commit", "-qam", "feature"]);
+
+    git(&workspace, &["checkout", "-q", &main_branch]);
+    edit_issue(
+        &jsonl,
+        &ids[1],
+        "title",
+        "Tokenizer".into(),
+        "2030-01-02T00:00:00Z",
+    );
+    edit_issue(
+        &jsonl,
+        &ids[0],
+        "assignee",
+        "alice".into(),
+        "2030-01-02T00:00:00Z",
+    );
+    git(&workspace, &["commit", "-qam", "main"]);
+
+    git(&workspace, &["merge", "-q", "--no-edit", "feature"]);
+
+    let merged = read_jsonl(&jsonl);
+    let merged_ids: Vec<&str> = merged
+        .iter()
+        .map(|issue| issue["id"].as_str().unwrap())
+        .collect();
+    let mut sorted = merged_ids.clone();
+    sorted.sort_unstable();
+    assert_eq!(merged_ids, sorted);
+
+    let parser = merged.iter().find(|issue| issue["id"] == ids[0]).unwrap();
+    assert_eq!(parser["priority"], 0);
+    assert_eq!(parser["assignee"], "alice");
+    let lexer = merged.iter().find(|issue| issue["id"] == ids[1]).unwrap();
+    assert_eq!(lexer["title"], "Tokenizer");
+
+    // The merged file imports cleanly.
+    br_ok(&workspace, &["sync", "--import-only", "--force"], "import");
+}
@@ -0,0 +1,143 @@
+//! `br plan` tests — critical path, worker schedule, due-date risk and
+//! graph output formats.
+
+mod common;
+
+use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use serde_json::Value;
+
+fn init_workspace() -> BrWorkspace {
+    let workspace = BrWorkspace::new();
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    workspace
+}
+
+fn create(workspace: &BrWorkspace, args: &[&str], label: &str) -> String {
+    let mut full = vec!["create"];
+    full.extend_from_slice(args);
+    full.push("--json");
+    let run = run_br(workspace, full, label);
+    assert!(run.status.success(), "create failed: {}", run.stderr);
+    let created: Value = serde_json::from_str(&extract_json_payload(&run.stdout)).unwrap();
+    created["id"].as_str().unwrap().to_string()
+}
+
+fn depend(workspace: &BrWorkspace, dependent: &str, prerequisite: &str) {
+    let run = run_br(workspace, ["dep", "add", dependent, prerequisite], "dep");
+    assert!(run.status.success(), "dep add failed: {}", run.stderr);
+}
+
+fn plan_json(workspace: &BrWorkspace, args: &[&str], label: &str) -> Value {
+    let mut full = vec!["plan", "--json"];
+    full.extend_from_slice(args);
+    let run = run_br(workspace, full, label);
+    assert!(run.status.success(), "plan failed: {}", run.stderr);
+    serde_json::from_str(&extract_json_payload(&run.stdout)).unwrap()
+}
+
+fn entry<'a>(plan: &'a Value, id: &str) -> &'a Value {
+    plan["issues"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|e| e["id"] == id)
+        .unwrap_or_else(|| panic!("{id} missing from plan: {plan}"))
+}
+
+/// schema(120) -> api(60) -> ui(60); docs(30) is independent.
+fn seed_project(workspace: &BrWorkspace) -> (String, String, String, String) {
+    let schema = create(workspace, &["Schema", "-e", "120"], "schema");
+    let api = create(workspace, &["API", "-e", "60"], "api");
+    let ui = create(workspace, &["UI", "-e", "60", "--due", "+1h"], "ui");
+    let docs = create(workspace, &["Docs", "-e", "30"], "docs");
+    depend(workspace, &api, &schema);
+    depend(workspace, &ui, &api);
+    (schema, api, ui, docs)
+}
+
+#[test]
+fn plan_reports_critical_path_and_slack() {
+    let workspace = init_workspace();
+    let (schema, api, ui, docs) = seed_project(&workspace);
+    let closed = create(&workspace, &["Done already", "-e", "600"], "closed");
+    depend(&workspace, &schema, &closed);
+    let close = run_br(&workspace, ["close", &closed], "close");
+    assert!(close.status.success(), "close failed: {}", close.stderr);
+
+    let plan = plan_json(&workspace, &["--workers", "2"], "plan_two");
+    assert_eq!(
+        plan["critical_path"],
+        serde_json::json!([schema, api, ui]),
+        "{plan}"
+    );
+    assert_eq!(plan["critical_path_minutes"], 240);
+    assert_eq!(plan["total_minutes"], 240);
+    assert_eq!(plan["issues"].as_array().unwrap().len(), 4);
+
+    assert_eq!(entry(&plan, &schema)["critical"], true);
+    assert_eq!(entry(&plan, &docs)["slack_minutes"], 210);
+    assert_eq!(entry(&plan, &docs)["earliest_start"], 0);
+    assert_eq!(entry(&plan, &api)["earliest_start"], 120);
+    assert_eq!(entry(&plan, &ui)["earliest_finish"], 240);
+
+    // One worker does everything in sequence, docs last (most slack).
+    let plan = plan_json(&workspace, &[], "plan_one");
+    assert_eq!(plan["total_minutes"], 270);
+    assert_eq!(entry(&plan, &docs)["earliest_start"], 240);
+}
+
+#[test]
+fn plan_flags_due_dates_that_cannot_be_met() {
+    let workspace = init_workspace();
+    let (_, _, ui, _) = seed_project(&workspace);
+    let unestimated = create(&workspace, &["Someday"], "unestimated");
+
+    let plan = plan_json(&workspace, &["--workers", "4"], "plan_due");
+    assert_eq!(plan["at_risk"], serde_json::json!([ui]));
+    let ui_entry = entry(&plan, &ui);
+    assert!(ui_entry["late_by_minutes"].as_i64().unwrap() > 0);
+    assert_eq!(ui_entry["due_unreachable"], true);
+    assert_eq!(plan["unestimated"], serde_json::json!([unestimated]));
+
+    let plan = plan_json(
+        &workspace,
+        &["--default-estimate", "500"],
+        "plan_default_estimate",
+    );
+    assert_eq!(entry(&plan, &unestimated)["estimate_minutes"], 500);
+}
+
+#[test]
+fn plan_renders_mermaid_dot_and_text() {
+    let workspace = init_workspace();
+    let (schema, api, _, _) = seed_project(&workspace);
+
+    let mermaid = run_br(&workspace, ["plan", "--format", "mermaid"], "mermaid");
+    assert!(mermaid.status.success(), "{}", mermaid.stderr);
+    assert!(mermaid.stdout.starts_with("graph LR"), "{}", mermaid.stdout);
+    assert!(mermaid.stdout.contains(&format!("{schema} ==> {api}")));
+    assert!(mermaid.stdout.contains("classDef critical"));
+
+    let dot = run_br(&workspace, ["plan", "--format", "dot"], "dot");
+    assert!(dot.status.success(), "{}", dot.stderr);
+    assert!(dot.stdout.starts_with("digraph plan {"), "{}", dot.stdout);
+    assert!(
+        dot.stdout
+            .contains(&format!("\"{schema}\" -> \"{api}\" [color=red"))
+    );
+
+    let text = run_br(&workspace, ["plan"], "text");
+    assert!(text.status.success(), "{}", text.stderr);
+    assert!(
+        text.stdout.contains("Critical path (4h)"),
+        "{}",
+        text.stdout
+    );
+    assert!(text.stdout.contains("At risk (1)"), "{}", text.stdout);
+
+    let bad = run_br(&workspace, ["plan", "--format", "svg"], "bad_format");
+    assert!(!bad.status.success());
+    let bad = run_br(&workspace, ["plan", "--workers", "0"], "bad_workers");
+    assert!(!bad.status.success());
+}
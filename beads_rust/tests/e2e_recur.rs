@@ -0,0 +1,156 @@
+//! `br recur` tests — rules on template issues, idempotent materialization,
+//! and identical instance IDs across clones.
+
+mod common;
+
+use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use serde_json::Value;
+use std::fs;
+
+fn init_workspace() -> BrWorkspace {
+    let workspace = BrWorkspace::new();
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    workspace
+}
+
+fn br_json(workspace: &BrWorkspace, args: &[&str], label: &str) -> Value {
+    let mut full = args.to_vec();
+    full.push("--json");
+    let run = run_br(workspace, full, label);
+    assert!(run.status.success(), "{label} failed: {}", run.stderr);
+    serde_json::from_str(&extract_json_payload(&run.stdout)).unwrap()
+}
+
+fn copy_jsonl(from: &BrWorkspace, to: &BrWorkspace) {
+    let flush = run_br(from, ["sync", "--flush-only"], "flush");
+    assert!(flush.status.success(), "flush failed: {}", flush.stderr);
+    fs::copy(
+        from.root.join(".beads/issues.jsonl"),
+        to.root.join(".beads/issues.jsonl"),
+    )
+    .unwrap();
+    let import = run_br(to, ["sync", "--import-only", "--force"], "import");
+    assert!(import.status.success(), "import failed: {}", import.stderr);
+}
+
+fn run_ids(workspace: &BrWorkspace, label: &str) -> Vec<String> {
+    let report = br_json(workspace, &["recur", "run"], label);
+    report["created"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|instance| instance["id"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[test]
+fn recur_run_materializes_due_instances_once() {
+    let workspace = init_workspace();
+    let created = br_json(
+        &workspace,
+        &["create", "Dependency audit", "-l", "maintenance"],
+        "create",
+    );
+    let template = created["id"].as_str().unwrap().to_string();
+
+    let bad = run_br(
+        &workspace,
+        ["recur", "set", &template, "0 9 * *"],
+        "bad_rule",
+    );
+    assert!(!bad.status.success());
+    assert!(bad.stderr.contains("needs 5 fields"), "{}", bad.stderr);
+
+    let rule = br_json(
+        &workspace,
+        &[
+            "recur",
+            "set",
+            &template,
+            "@daily",
+            "--start=-3d",
+            "--due",
+            "2d",
+        ],
+        "set",
+    );
+    assert_eq!(rule["rule"], "@daily");
+    assert_eq!(rule["due_minutes"], 2 * 24 * 60);
+    assert!(rule["next_occurrence"].is_string(), "{rule}");
+
+    let preview = br_json(&workspace, &["recur", "run", "--dry-run"], "dry_run");
+    assert_eq!(preview["created"].as_array().unwrap().len(), 1);
+
+    let ids = run_ids(&workspace, "run");
+    assert_eq!(ids.len(), 1);
+    assert!(run_ids(&workspace, "run_again").is_empty());
+
+    let show = br_json(&workspace, &["show", &ids[0]], "show");
+    let instance = &show[0];
+    assert!(
+        instance["title"]
+            .as_str()
+            .unwrap()
+            .starts_with("Dependency audit ("),
+        "{instance}"
+    );
+    assert!(instance["defer_until"].is_string(), "{instance}");
+    assert!(instance["due_at"].is_string(), "{instance}");
+    assert_eq!(instance["labels"][0], "maintenance");
+
+    // The template itself is not ready work; its instance is.
+    let ready = br_json(&workspace, &["ready"], "ready");
+    let ready_ids: Vec<&str> = ready
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|issue| issue["id"].as_str().unwrap())
+        .collect();
+    assert!(ready_ids.contains(&ids[0].as_str()), "{ready}");
+    assert!(!ready_ids.contains(&template.as_str()), "{ready}");
+
+    let removed = br_json(&workspace, &["recur", "remove", &template], "remove");
+    assert_eq!(removed["removed"], true);
+    let rules = br_json(&workspace, &["recur", "list"], "list");
+    assert!(rules.as_array().unwrap().is_empty());
+}
+
+#[test]
+fn clones_derive_the_same_instance_ids() {
+    let origin = init_workspace();
+    let created = br_json(&origin, &["create", "Flaky test triage"], "create");
+    let template = created["id"].as_str().unwrap().to_string();
+    br_json(
+        &origin,
+        &["recur", "set", &template, "FREQ=WEEKLY", "--start=-10d"],
+        "set",
+    );
+
+    // The rule travels with the template.
+    let clone = init_workspace();
+    copy_jsonl(&origin, &clone);
+    let rules = br_json(&clone, &["recur", "list"], "list_clone");
+    assert_eq!(rules[0]["template_id"], template.as_str());
+    assert_eq!(rules[0]["rule"], "FREQ=WEEKLY");
+
+    // Both clones run independently, then merge: one instance, not two.
+    let origin_ids = run_ids(&origin, "run_origin");
+    let clone_ids = run_ids(&clone, "run_clone");
+    assert_eq!(origin_ids.len(), 1);
+    assert_eq!(origin_ids, clone_ids);
+
+    copy_jsonl(&clone, &origin);
+    let all = br_json(&origin, &["list", "--all"], "list_all");
+    let instances = all
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|issue| {
+            issue["title"]
+                .as_str()
+                .is_some_and(|title| title.starts_with("Flaky test triage ("))
+        })
+        .count();
+    assert_eq!(instances, 1, "{all}");
+}
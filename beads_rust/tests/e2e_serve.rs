@@ -0,0 +1,256 @@
+//! `br serve` tests — JSON-RPC over stdio, MCP tool calls and batched flush.
+
+mod common;
+
+use common::cli::{BrWorkspace, run_br, run_br_with_stdin};
+use serde_json::{Value, json};
+use std::fs;
+
+fn init_workspace() -> BrWorkspace {
+    let workspace = BrWorkspace::new();
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    workspace
+}
+
+/// Run `br serve` with one request per line and return the parsed responses.
+fn serve(workspace: &BrWorkspace, requests: &[Value], label: &str) -> Vec<Value> {
+    let input: String = requests.iter().map(|r| r.to_string() + "\n").collect();
+    let run = run_br_with_stdin(workspace, ["serve"], &input, label);
+    assert!(run.status.success(), "serve failed: {}", run.stderr);
+    run.stdout
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("response is one JSON line"))
+        .collect()
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn request(id: i64, method: &str, params: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params })
+}
+
+#[test]
+fn serve_runs_issue_workflow_in_one_session() {
+    let workspace = init_workspace();
+
+    let responses = serve(
+        &workspace,
+        &[
+            request(1, "create", json!({ "title": "Schema", "priority": 1 })),
+            request(2, "create", json!({ "title": "API", "type": "feature" })),
+            request(3, "ready", json!({})),
+        ],
+        "serve_create",
+    );
+    assert_eq!(responses.len(), 3);
+    assert_eq!(responses[0]["id"], 1);
+    let schema = responses[0]["result"]["id"].as_str().unwrap().to_string();
+    let api = responses[1]["result"]["id"].as_str().unwrap().to_string();
+    assert_eq!(responses[0]["result"]["priority"], 1);
+    assert_eq!(responses[1]["result"]["issue_type"], "feature");
+    assert_eq!(responses[2]["result"].as_array().unwrap().len(), 2);
+
+    let responses = serve(
+        &workspace,
+        &[
+            request(1, "dep_add", json!({ "issue": api, "depends_on": schema })),
+            request(2, "ready", json!({})),
+            request(3, "close", json!({ "id": api })),
+            request(
+                4,
+                "update",
+                json!({ "id": schema, "status": "in_progress", "add_labels": ["db"] }),
+            ),
+            request(
+                5,
+                "comment",
+                json!({ "id": schema, "text": "migrations drafted" }),
+            ),
+            request(6, "close", json!({ "id": schema, "reason": "shipped" })),
+            request(7, "search", json!({ "query": "API" })),
+            request(8, "show", json!({ "id": schema })),
+        ],
+        "serve_workflow",
+    );
+
+    assert_eq!(responses[0]["result"]["action"], "added");
+    let ready_ids: Vec<&str> = responses[1]["result"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|issue| issue["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(ready_ids, vec![schema.as_str()]);
+    assert_eq!(responses[2]["result"]["closed"], json!([]));
+    assert_eq!(responses[2]["result"]["skipped"][0]["id"], api.as_str());
+    assert_eq!(responses[3]["result"]["status"], "in_progress");
+    assert_eq!(responses[3]["result"]["labels"], json!(["db"]));
+    assert_eq!(responses[4]["result"]["text"], "migrations drafted");
+    assert_eq!(
+        responses[5]["result"]["closed"][0]["close_reason"],
+        "shipped"
+    );
+    assert_eq!(responses[6]["result"][0]["id"], api.as_str());
+    assert_eq!(responses[7]["result"]["status"], "closed");
+    assert_eq!(
+        responses[7]["result"]["comments"][0]["text"],
+        "migrations drafted"
+    );
+
+    // Pending writes are flushed to JSONL when stdin closes.
+    let jsonl = fs::read_to_string(workspace.root.join(".beads/issues.jsonl")).unwrap();
+    assert!(jsonl.contains("migrations drafted"), "jsonl: {jsonl}");
+}
+
+#[test]
+fn serve_returns_structured_errors_and_keeps_going() {
+    let workspace = init_workspace();
+
+    let responses = serve(
+        &workspace,
+        &[
+            json!("not a request"),
+            request(1, "show", json!({ "id": "bd-missing" })),
+            request(2, "launch", json!({})),
+            request(3, "close", json!({})),
+            request(4, "create", json!({ "title": "Still alive" })),
+        ],
+        "serve_errors",
+    );
+
+    assert_eq!(responses[0]["error"]["code"], -32600);
+    assert_eq!(responses[1]["id"], 1);
+    assert_eq!(responses[1]["error"]["code"], -32000);
+    assert_eq!(responses[1]["error"]["data"]["code"], "ISSUE_NOT_FOUND");
+    assert_eq!(responses[2]["error"]["code"], -32601);
+    assert_eq!(responses[3]["error"]["code"], -32602);
+    assert_eq!(responses[4]["result"]["title"], "Still alive");
+
+    let run = run_br_with_stdin(&workspace, ["serve"], "{oops\n", "serve_parse_error");
+    let response: Value = serde_json::from_str(run.stdout.trim()).unwrap();
+    assert_eq!(response["id"], Value::Null);
+    assert_eq!(response["error"]["code"], -32700);
+}
+
+#[test]
+fn serve_answers_invalid_utf8_and_still_flushes() {
+    let workspace = init_workspace();
+
+    let mut input = b"\xff\xfe\n".to_vec();
+    input.extend_from_slice(
+        format!(
+            "{}\n",
+            request(1, "create", json!({ "title": "After garbage" }))
+        )
+        .as_bytes(),
+    );
+    let output = assert_cmd::Command::new(assert_cmd::cargo::cargo_bin!("br"))
+        .current_dir(&workspace.root)
+        .env("HOME", &workspace.root)
+        .arg("serve")
+        .write_stdin(input)
+        .output()
+        .expect("run br serve");
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let responses: Vec<Value> = stdout
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(responses[0]["error"]["code"], -32700);
+    assert_eq!(responses[1]["result"]["title"], "After garbage");
+
+    let jsonl = fs::read_to_string(workspace.root.join(".beads/issues.jsonl")).unwrap();
+    assert!(jsonl.contains("After garbage"));
+}
+
+#[test]
+fn serve_speaks_mcp_tools() {
+    let workspace = init_workspace();
+
+    let responses = serve(
+        &workspace,
+        &[
+            request(1, "initialize", json!({ "protocolVersion": "2024-11-05" })),
+            json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }),
+            request(2, "tools/list", json!({})),
+            request(
+                3,
+                "tools/call",
+                json!({ "name": "create", "arguments": { "title": "From MCP" } }),
+            ),
+            request(
+                4,
+                "tools/call",
+                json!({ "name": "show", "arguments": { "id": "bd-missing" } }),
+            ),
+            request(5, "tools/call", json!({ "name": "nope", "arguments": {} })),
+        ],
+        "serve_mcp",
+    );
+
+    // The notification gets no response.
+    assert_eq!(responses.len(), 5);
+    assert_eq!(responses[0]["result"]["serverInfo"]["name"], "br");
+    assert!(responses[0]["result"]["capabilities"]["tools"].is_object());
+
+    let tools: Vec<&str> = responses[1]["result"]["tools"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|tool| tool["name"].as_str().unwrap())
+        .collect();
+    for name in [
+        "create", "update", "close", "ready", "dep_add", "comment", "search",
+    ] {
+        assert!(tools.contains(&name), "missing tool {name}: {tools:?}");
+    }
+
+    let created = &responses[2]["result"];
+    assert_eq!(created["isError"], false);
+    let issue: Value =
+        serde_json::from_str(created["content"][0]["text"].as_str().unwrap()).unwrap();
+    assert_eq!(issue["title"], "From MCP");
+
+    let failed = &responses[3]["result"];
+    assert_eq!(failed["isError"], true);
+    let error: Value =
+        serde_json::from_str(failed["content"][0]["text"].as_str().unwrap()).unwrap();
+    assert_eq!(error["error"]["code"], "ISSUE_NOT_FOUND");
+
+    assert_eq!(responses[4]["error"]["code"], -32602);
+}
+
+#[test]
+fn serve_flush_method_and_batches() {
+    let workspace = init_workspace();
+    let jsonl_path = workspace.root.join(".beads/issues.jsonl");
+
+    let input = format!(
+        "{}\n{}\n",
+        json!([
+            request(1, "create", json!({ "title": "Batched one" })),
+            request(2, "create", json!({ "title": "Batched two" })),
+        ]),
+        request(3, "flush", json!({})),
+    );
+    let run = run_br_with_stdin(
+        &workspace,
+        ["serve", "--flush-every", "0", "--flush-interval", "0"],
+        &input,
+        "serve_flush",
+    );
+    assert!(run.status.success(), "serve failed: {}", run.stderr);
+
+    let lines: Vec<Value> = run
+        .stdout
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines[0].as_array().unwrap().len(), 2);
+    assert_eq!(lines[1]["result"]["flushed"], true);
+
+    let jsonl = fs::read_to_string(jsonl_path).unwrap();
+    assert!(jsonl.contains("Batched one") && jsonl.contains("Batched two"));
+}
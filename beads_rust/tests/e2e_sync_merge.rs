@@ -0,0 +1,83 @@
+//! `br sync --merge` tests — field-level three-way merge against the base
+//! snapshot, with true conflicts listed in the report.
+
+mod common;
+
+use chrono::{Duration, Utc};
+use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use serde_json::Value;
+use std::fs;
+
+fn br_json(workspace: &BrWorkspace, args: &[&str], label: &str) -> Value {
+    let mut full = args.to_vec();
+    full.push("--json");
+    let run = run_br(workspace, full, label);
+    assert!(run.status.success(), "{label} failed: {}", run.stderr);
+    serde_json::from_str(&extract_json_payload(&run.stdout)).unwrap()
+}
+
+fn br_ok(workspace: &BrWorkspace, args: &[&str], label: &str) {
+    let run = run_br(workspace, args, label);
+    assert!(run.status.success(), "{label} failed: {}", run.stderr);
+}
+
+#[test]
+fn merge_keeps_edits_from_both_sides_and_lists_conflicts() {
+    let workspace = BrWorkspace::new();
+    br_ok(&workspace, &["init"], "init");
+    let created = br_json(
+        &workspace,
+        &["create", "Parser", "-l", "backend", "-p", "2"],
+        "create",
+    );
+    let id = created["id"].as_str().unwrap().to_string();
+    br_ok(&workspace, &["sync", "--flush-only"], "flush");
+    br_ok(&workspace, &["sync", "--merge"], "merge_base");
+
+    // Local edits stay in the database only.
+    br_ok(
+        &workspace,
+        &[
+            "update",
+            &id,
+            "--title",
+            "Local title",
+            "--description",
+            "Local description",
+            "--no-auto-flush",
+        ],
+        "update_local",
+    );
+    br_ok(
+        &workspace,
+        &["label", "add", &id, "docs", "--no-auto-flush"],
+        "label_local",
+    );
+
+    // External edits land in the JSONL, newer than the local ones.
+    let jsonl_path = workspace.root.join(".beads/issues.jsonl");
+    let mut external: Value =
+        serde_json::from_str(fs::read_to_string(&jsonl_path).unwrap().trim()).unwrap();
+    external["title"] = "External title".into();
+    external["priority"] = 0.into();
+    external["labels"] = serde_json::json!(["backend", "urgent"]);
+    external["updated_at"] = (Utc::now() + Duration::hours(1)).to_rfc3339().into();
+    fs::write(&jsonl_path, format!("{external}\n")).unwrap();
+
+    let report = br_json(&workspace, &["sync", "--merge"], "merge");
+    let conflicts = report["field_conflicts"].as_array().unwrap();
+    assert_eq!(conflicts.len(), 1, "{report}");
+    assert_eq!(conflicts[0]["issue_id"], id.as_str());
+    assert_eq!(conflicts[0]["field"], "title");
+    assert_eq!(conflicts[0]["kept"], "external");
+
+    let show = br_json(&workspace, &["show", &id], "show");
+    let merged = &show[0];
+    assert_eq!(merged["title"], "External title");
+    assert_eq!(merged["description"], "Local description");
+    assert_eq!(merged["priority"], 0);
+    assert_eq!(
+        merged["labels"],
+        serde_json::json!(["backend", "docs", "urgent"])
+    );
+}
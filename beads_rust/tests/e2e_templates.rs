@@ -0,0 +1,175 @@
+//! Issue template tests — `br create --template` scaffolding and required
+//! fields, and `br lint` sharing the same template definitions.
+
+mod common;
+
+use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use serde_json::Value;
+use std::fs;
+
+fn init_workspace() -> BrWorkspace {
+    let workspace = BrWorkspace::new();
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    workspace
+}
+
+fn write_template(workspace: &BrWorkspace, name: &str, contents: &str) {
+    let dir = workspace.root.join(".beads/templates");
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join(format!("{name}.md")), contents).unwrap();
+}
+
+fn create_json(workspace: &BrWorkspace, args: &[&str], label: &str) -> Value {
+    let mut full = vec!["create"];
+    full.extend_from_slice(args);
+    full.push("--json");
+    let run = run_br(workspace, full, label);
+    assert!(run.status.success(), "create failed: {}", run.stderr);
+    serde_json::from_str(&extract_json_payload(&run.stdout)).unwrap()
+}
+
+fn lint_json(workspace: &BrWorkspace, label: &str) -> Value {
+    let run = run_br(workspace, ["lint", "--json"], label);
+    assert!(run.status.success(), "lint failed: {}", run.stderr);
+    serde_json::from_str(&extract_json_payload(&run.stdout)).unwrap()
+}
+
+#[test]
+fn create_with_builtin_template_scaffolds_lint_sections() {
+    let workspace = init_workspace();
+
+    let issue = create_json(
+        &workspace,
+        &["Crash on save", "--template", "bug"],
+        "create",
+    );
+    assert_eq!(issue["issue_type"], "bug");
+    let description = issue["description"].as_str().unwrap();
+    assert!(
+        description.contains("## Steps to Reproduce"),
+        "{description}"
+    );
+    assert!(
+        description.contains("## Acceptance Criteria"),
+        "{description}"
+    );
+
+    // The scaffolded issue satisfies lint's bug checks.
+    let lint = lint_json(&workspace, "lint");
+    assert_eq!(lint["total"], 0, "{lint}");
+
+    let unknown = run_br(
+        &workspace,
+        ["create", "X", "--template", "nope"],
+        "create_unknown",
+    );
+    assert!(!unknown.status.success());
+    assert!(
+        unknown.stderr.contains("bug, epic, feature, task"),
+        "{}",
+        unknown.stderr
+    );
+}
+
+#[test]
+fn file_template_sets_defaults_and_requires_fields() {
+    let workspace = init_workspace();
+    write_template(
+        &workspace,
+        "incident",
+        "---\ntype: bug\npriority: P0\nlabels: [incident]\nrequired: [description, assignee]\n---\n## Impact\n\n<!-- who is affected? -->\n\n## Timeline\n",
+    );
+
+    let missing = run_br(
+        &workspace,
+        ["create", "Outage", "--template", "incident"],
+        "create_missing",
+    );
+    assert!(!missing.status.success());
+    assert!(
+        missing.stderr.contains("requires: description, assignee"),
+        "{}",
+        missing.stderr
+    );
+
+    let issue = create_json(
+        &workspace,
+        &[
+            "Outage",
+            "--template",
+            "incident",
+            "--assignee",
+            "alice",
+            "--description",
+            "API returned 500s for 20 minutes.",
+            "-l",
+            "api",
+        ],
+        "create_ok",
+    );
+    assert_eq!(issue["issue_type"], "bug");
+    assert_eq!(issue["priority"], 0);
+    assert_eq!(issue["labels"], serde_json::json!(["api", "incident"]));
+    let description = issue["description"].as_str().unwrap();
+    assert!(
+        description.starts_with("API returned 500s"),
+        "{description}"
+    );
+    assert!(description.contains("## Impact") && description.contains("## Timeline"));
+}
+
+#[test]
+fn lint_uses_overridden_and_config_templates() {
+    let workspace = init_workspace();
+    write_template(
+        &workspace,
+        "bug",
+        "---\nrequired: [assignee]\n---\n## Repro\n",
+    );
+    let config_path = workspace.root.join(".beads/config.yaml");
+    let mut config = fs::read_to_string(&config_path).unwrap_or_default();
+    config.push_str(
+        "\ntemplates:\n  chore:\n    type: chore\n    required: [estimate]\n    sections: [Checklist]\n",
+    );
+    fs::write(&config_path, config).unwrap();
+
+    let bug = create_json(
+        &workspace,
+        &["Old style bug", "-t", "bug", "-d", "## Repro\n1. click"],
+        "create_bug",
+    );
+    let chore = create_json(&workspace, &["Rotate keys", "-t", "chore"], "create_chore");
+
+    let lint = lint_json(&workspace, "lint");
+    let results = lint["results"].as_array().unwrap();
+    let bug_result = results.iter().find(|r| r["id"] == bug["id"]).unwrap();
+    // The built-in "Steps to Reproduce" check is replaced by the file.
+    assert!(bug_result.get("missing").is_none(), "{bug_result}");
+    assert_eq!(
+        bug_result["missing_fields"],
+        serde_json::json!(["assignee"])
+    );
+
+    let chore_result = results.iter().find(|r| r["id"] == chore["id"]).unwrap();
+    assert_eq!(chore_result["missing"], serde_json::json!(["## Checklist"]));
+    assert_eq!(
+        chore_result["missing_fields"],
+        serde_json::json!(["estimate"])
+    );
+
+    // create --template chore scaffolds from the same config definition.
+    let scaffolded = create_json(
+        &workspace,
+        &["Renew certs", "--template", "chore", "-e", "15"],
+        "create_chore_template",
+    );
+    assert_eq!(scaffolded["issue_type"], "chore");
+    assert_eq!(scaffolded["description"], "## Checklist\n");
+    let lint = lint_json(&workspace, "lint_after");
+    let results = lint["results"].as_array().unwrap();
+    assert!(
+        results.iter().all(|r| r["id"] != scaffolded["id"]),
+        "{lint}"
+    );
+}
@@ -0,0 +1,167 @@
+//! `br time` tests — timers, logged sessions, JSONL round-trip, and
+//! `br stats --accuracy` estimate-vs-actual reports.
+
+mod common;
+
+use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use serde_json::Value;
+use std::fs;
+
+fn init_workspace() -> BrWorkspace {
+    let workspace = BrWorkspace::new();
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    workspace
+}
+
+fn create(workspace: &BrWorkspace, args: &[&str], label: &str) -> String {
+    let mut full = vec!["create"];
+    full.extend_from_slice(args);
+    full.push("--json");
+    let run = run_br(workspace, full, label);
+    assert!(run.status.success(), "create failed: {}", run.stderr);
+    let created: Value = serde_json::from_str(&extract_json_payload(&run.stdout)).unwrap();
+    created["id"].as_str().unwrap().to_string()
+}
+
+fn br_json(workspace: &BrWorkspace, args: &[&str], label: &str) -> Value {
+    let mut full = args.to_vec();
+    full.push("--json");
+    let run = run_br(workspace, full, label);
+    assert!(run.status.success(), "{label} failed: {}", run.stderr);
+    serde_json::from_str(&extract_json_payload(&run.stdout)).unwrap()
+}
+
+#[test]
+fn timers_and_logged_sessions_are_tracked_and_exported() {
+    let workspace = init_workspace();
+    let id = create(&workspace, &["Write parser", "-e", "120"], "create");
+
+    let started = br_json(&workspace, &["time", "start", &id, "-n", "spike"], "start");
+    assert_eq!(started["issue_id"], id.as_str());
+    assert!(started.get("ended_at").is_none(), "{started}");
+
+    let again = run_br(&workspace, ["time", "start", &id], "start_again");
+    assert!(!again.status.success());
+    assert!(
+        again.stderr.contains("already has a timer running"),
+        "{}",
+        again.stderr
+    );
+
+    let running = br_json(&workspace, &["time", "list", "--running"], "running");
+    assert_eq!(running["entries"].as_array().unwrap().len(), 1);
+
+    // Stop without naming the issue: the actor's only running timer.
+    let stopped = br_json(&workspace, &["time", "stop"], "stop");
+    assert_eq!(stopped["issue_id"], id.as_str());
+    assert!(stopped["ended_at"].is_string());
+    let stop_again = run_br(&workspace, ["time", "stop"], "stop_again");
+    assert!(!stop_again.status.success());
+
+    let logged = br_json(
+        &workspace,
+        &["time", "log", &id, "1h", "--note", "review"],
+        "log",
+    );
+    assert_eq!(logged["minutes"], 60);
+    let bad = run_br(&workspace, ["time", "log", &id, "0"], "log_zero");
+    assert!(!bad.status.success());
+
+    let report = br_json(&workspace, &["time", "list", &id], "list");
+    assert_eq!(report["entries"].as_array().unwrap().len(), 2);
+    assert_eq!(report["estimated_minutes"], 120);
+    assert_eq!(report["total_minutes"], 60);
+
+    let text = run_br(&workspace, ["time", "list", &id], "list_text");
+    assert!(
+        text.stdout.contains("Total: 1h of 2h estimated (50%)"),
+        "{}",
+        text.stdout
+    );
+
+    // Sessions travel with the issue through JSONL.
+    let flush = run_br(&workspace, ["sync", "--flush-only"], "flush");
+    assert!(flush.status.success(), "flush failed: {}", flush.stderr);
+    let jsonl = fs::read_to_string(workspace.root.join(".beads/issues.jsonl")).unwrap();
+    let line: Value = serde_json::from_str(jsonl.lines().next().unwrap()).unwrap();
+    let entries = line["time_entries"].as_array().unwrap();
+    assert_eq!(entries.len(), 2);
+    assert!(entries.iter().any(|e| e["note"] == "review"), "{line}");
+
+    let clone = init_workspace();
+    fs::copy(
+        workspace.root.join(".beads/issues.jsonl"),
+        clone.root.join(".beads/issues.jsonl"),
+    )
+    .unwrap();
+    let import = run_br(&clone, ["sync", "--import-only", "--force"], "import");
+    assert!(import.status.success(), "import failed: {}", import.stderr);
+    let imported = br_json(&clone, &["time", "list", &id], "list_imported");
+    assert_eq!(imported["entries"].as_array().unwrap().len(), 2);
+    assert_eq!(imported["total_minutes"], 60);
+}
+
+#[test]
+fn stats_accuracy_compares_tracked_time_with_estimates() {
+    let workspace = init_workspace();
+    let bug = create(
+        &workspace,
+        &[
+            "Crash",
+            "-t",
+            "bug",
+            "-e",
+            "60",
+            "-l",
+            "backend",
+            "--assignee",
+            "alice",
+        ],
+        "bug",
+    );
+    let task = create(&workspace, &["Docs", "-t", "task", "-e", "60"], "task");
+    let open = create(
+        &workspace,
+        &["Still going", "-t", "bug", "-e", "60"],
+        "open",
+    );
+    for (id, duration) in [(&bug, "2h"), (&task, "30m"), (&open, "5h")] {
+        let log = run_br(&workspace, ["time", "log", id.as_str(), duration], "log");
+        assert!(log.status.success(), "log failed: {}", log.stderr);
+    }
+    for id in [&bug, &task] {
+        let close = run_br(&workspace, ["close", id.as_str()], "close");
+        assert!(close.status.success(), "close failed: {}", close.stderr);
+    }
+
+    let stats = br_json(
+        &workspace,
+        &["stats", "--accuracy", "--no-activity"],
+        "stats",
+    );
+    let accuracy = stats["estimate_accuracy"].as_array().unwrap();
+    let by_type = accuracy.iter().find(|b| b["dimension"] == "type").unwrap();
+    let bug_entry = by_type["entries"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|e| e["key"] == "bug")
+        .unwrap();
+    // The open bug's 5h is not counted.
+    assert_eq!(bug_entry["issues"], 1);
+    assert_eq!(bug_entry["actual_minutes"], 120);
+    assert_eq!(bug_entry["ratio"], 2.0);
+
+    let by_label = accuracy.iter().find(|b| b["dimension"] == "label").unwrap();
+    let keys: Vec<&str> = by_label["entries"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["key"].as_str().unwrap())
+        .collect();
+    assert_eq!(keys, vec!["(no labels)", "backend"]);
+
+    let plain = br_json(&workspace, &["stats", "--no-activity"], "stats_plain");
+    assert!(plain.get("estimate_accuracy").is_none());
+}
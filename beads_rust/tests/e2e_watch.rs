@@ -0,0 +1,208 @@
+//! `br watch` tests — event replay with cursors, filters, JSONL sync events
+//! and live streaming.
+
+mod common;
+
+use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use serde_json::Value;
+use std::fs;
+use std::io::{BufRead, BufReader};
+use std::process::Stdio;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+fn init_workspace() -> BrWorkspace {
+    let workspace = BrWorkspace::new();
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    workspace
+}
+
+fn create(workspace: &BrWorkspace, args: &[&str], label: &str) -> String {
+    let mut full = vec!["create"];
+    full.extend_from_slice(args);
+    full.push("--json");
+    let run = run_br(workspace, full, label);
+    assert!(run.status.success(), "create failed: {}", run.stderr);
+    let created: Value = serde_json::from_str(&extract_json_payload(&run.stdout)).unwrap();
+    created["id"].as_str().unwrap().to_string()
+}
+
+fn watch_once(workspace: &BrWorkspace, args: &[&str], label: &str) -> Vec<Value> {
+    let mut full = vec!["watch", "--once"];
+    full.extend_from_slice(args);
+    let run = run_br(workspace, full, label);
+    assert!(run.status.success(), "watch failed: {}", run.stderr);
+    run.stdout
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("one JSON event per line"))
+        .collect()
+}
+
+fn event_types(events: &[Value]) -> Vec<&str> {
+    events
+        .iter()
+        .map(|e| e["event_type"].as_str().unwrap())
+        .collect()
+}
+
+#[test]
+fn watch_replays_events_from_cursor() {
+    let workspace = init_workspace();
+    let a = create(&workspace, &["Schema", "-l", "backend"], "create_a");
+    let b = create(&workspace, &["Docs"], "create_b");
+    let dep = run_br(&workspace, ["dep", "add", &b, &a], "dep");
+    assert!(dep.status.success(), "dep failed: {}", dep.stderr);
+    let close = run_br(&workspace, ["close", &a], "close");
+    assert!(close.status.success(), "close failed: {}", close.stderr);
+
+    // Without --since only new changes are reported.
+    assert!(watch_once(&workspace, &[], "watch_new").is_empty());
+
+    let events = watch_once(&workspace, &["--since", "0"], "watch_all");
+    let types = event_types(&events);
+    assert!(types.contains(&"created"));
+    assert!(types.contains(&"dependency_added"));
+    assert!(types.contains(&"closed"));
+
+    let cursors: Vec<i64> = events
+        .iter()
+        .map(|e| e["cursor"].as_i64().unwrap())
+        .collect();
+    assert!(cursors.windows(2).all(|w| w[0] < w[1]), "{cursors:?}");
+    assert!(events.iter().all(|e| e["source"] == "event"));
+
+    let closed = events.iter().find(|e| e["event_type"] == "closed").unwrap();
+    assert_eq!(closed["issue_id"], a.as_str());
+    assert_eq!(closed["issue"]["status"], "closed");
+    assert_eq!(closed["issue"]["labels"][0], "backend");
+
+    // Resuming after the dependency event only yields what came later.
+    let dep_event = events
+        .iter()
+        .find(|e| e["event_type"] == "dependency_added")
+        .unwrap();
+    let resume = dep_event["cursor"].to_string();
+    let later = watch_once(&workspace, &["--since", &resume], "watch_resume");
+    assert!(
+        later
+            .iter()
+            .all(|e| e["cursor"].as_i64() > dep_event["cursor"].as_i64())
+    );
+    assert!(event_types(&later).contains(&"closed"));
+}
+
+#[test]
+fn watch_filters_by_current_issue_state() {
+    let workspace = init_workspace();
+    let backend = create(&workspace, &["API", "-l", "backend"], "create_backend");
+    create(&workspace, &["UI", "-l", "frontend"], "create_frontend");
+    let assign = run_br(
+        &workspace,
+        ["update", &backend, "--assignee", "alice"],
+        "assign",
+    );
+    assert!(assign.status.success(), "update failed: {}", assign.stderr);
+
+    let events = watch_once(
+        &workspace,
+        &["--since", "0", "--label", "backend"],
+        "watch_label",
+    );
+    assert!(!events.is_empty());
+    assert!(events.iter().all(|e| e["issue_id"] == backend.as_str()));
+
+    let events = watch_once(
+        &workspace,
+        &["--since", "0", "--assignee", "bob"],
+        "watch_assignee",
+    );
+    assert!(events.is_empty());
+
+    let events = watch_once(
+        &workspace,
+        &["--since", "0", "--status", "closed"],
+        "watch_status",
+    );
+    assert!(events.is_empty());
+
+    let bad = run_br(&workspace, ["watch", "--once", "--status", "nope"], "bad");
+    assert!(!bad.status.success());
+}
+
+#[test]
+fn watch_reports_jsonl_changes_as_sync_events() {
+    let workspace = init_workspace();
+    let id = create(&workspace, &["Before pull"], "create");
+
+    // Simulate a `git pull` that edits one issue and adds another.
+    let jsonl_path = workspace.root.join(".beads/issues.jsonl");
+    let jsonl = fs::read_to_string(&jsonl_path).unwrap();
+    let mut issue: Value = serde_json::from_str(jsonl.lines().next().unwrap()).unwrap();
+    issue["title"] = Value::from("After pull");
+    issue["updated_at"] = Value::from("2030-01-01T00:00:00Z");
+    let mut added = issue.clone();
+    let prefix = id.split('-').next().unwrap();
+    let added_id = format!("{prefix}-pulled");
+    added["id"] = Value::from(added_id.as_str());
+    added["title"] = Value::from("From teammate");
+    thread::sleep(Duration::from_millis(50));
+    fs::write(&jsonl_path, format!("{issue}\n{added}\n")).unwrap();
+
+    let events = watch_once(&workspace, &[], "watch_sync");
+    assert_eq!(events.len(), 2, "events: {events:?}");
+    assert!(events.iter().all(|e| e["source"] == "sync"));
+
+    let updated = events
+        .iter()
+        .find(|e| e["issue_id"] == id.as_str())
+        .unwrap();
+    assert_eq!(updated["event_type"], "updated");
+    assert_eq!(updated["issue"]["title"], "After pull");
+    let created = events
+        .iter()
+        .find(|e| e["issue_id"] == added_id.as_str())
+        .unwrap();
+    assert_eq!(created["event_type"], "created");
+
+    // Once imported, the same JSONL produces nothing new.
+    assert!(watch_once(&workspace, &[], "watch_sync_again").is_empty());
+}
+
+#[test]
+fn watch_streams_changes_from_other_processes() {
+    let workspace = init_workspace();
+
+    let mut child = std::process::Command::new(assert_cmd::cargo::cargo_bin!("br"))
+        .current_dir(&workspace.root)
+        .env("HOME", &workspace.root)
+        .env("NO_COLOR", "1")
+        .args(["watch", "--interval", "50"])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("spawn br watch");
+    let stdout = child.stdout.take().unwrap();
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        for line in BufReader::new(stdout).lines() {
+            if tx.send(line.unwrap()).is_err() {
+                break;
+            }
+        }
+    });
+
+    // Give the watcher time to take its starting snapshot.
+    thread::sleep(Duration::from_millis(500));
+    let id = create(&workspace, &["Live one"], "create_live");
+
+    let line = rx.recv_timeout(Duration::from_secs(10));
+    child.kill().ok();
+    child.wait().ok();
+
+    let event: Value = serde_json::from_str(&line.expect("an event within 10s")).unwrap();
+    assert_eq!(event["event_type"], "created");
+    assert_eq!(event["issue_id"], id.as_str());
+    assert_eq!(event["issue"]["title"], "Live one");
+}
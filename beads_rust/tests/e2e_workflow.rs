@@ -0,0 +1,197 @@
+//! Custom workflows: `update`, `close` and `reopen` follow the transitions and
+//! guards in `workflow.*`, and `br workflow show` renders them.
+
+mod common;
+
+use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use serde_json::Value;
+use std::fs;
+
+const WORKFLOW: &str = "
+workflow:
+  default:
+    states: [open, in_progress, review, closed]
+    transitions:
+      open: [in_progress]
+      in_progress: [review, open]
+      review: [closed, in_progress]
+      closed: [open]
+    guards:
+      in_progress: [assignee]
+      closed: [acceptance_criteria]
+  chore:
+    states: [open, closed]
+    transitions:
+      open: [closed]
+";
+
+fn setup() -> BrWorkspace {
+    let workspace = BrWorkspace::new();
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    let config_path = workspace.root.join(".beads/config.yaml");
+    let mut config = fs::read_to_string(&config_path).unwrap_or_default();
+    config.push_str(WORKFLOW);
+    fs::write(&config_path, config).unwrap();
+    workspace
+}
+
+fn create(workspace: &BrWorkspace, args: &[&str]) -> String {
+    let mut full = vec!["create"];
+    full.extend_from_slice(args);
+    full.push("--json");
+    let run = run_br(workspace, &full, "create");
+    assert!(run.status.success(), "create failed: {}", run.stderr);
+    let created: Value = serde_json::from_str(&extract_json_payload(&run.stdout)).unwrap();
+    created["id"].as_str().unwrap().to_string()
+}
+
+fn status(workspace: &BrWorkspace, id: &str) -> String {
+    let run = run_br(workspace, ["show", id, "--json"], "show");
+    let value: Value = serde_json::from_str(&extract_json_payload(&run.stdout)).unwrap();
+    let issue = value.as_array().map_or(&value, |items| &items[0]);
+    issue["status"].as_str().unwrap().to_string()
+}
+
+#[test]
+fn workflow_enforces_transitions_and_guards() {
+    let workspace = setup();
+    let id = create(&workspace, &["Add OAuth login"]);
+
+    let skip = run_br(&workspace, ["update", &id, "--status", "closed"], "skip");
+    assert!(!skip.status.success());
+    assert!(
+        skip.stderr.contains("does not allow open -> closed"),
+        "{}",
+        skip.stderr
+    );
+
+    let unassigned = run_br(
+        &workspace,
+        ["update", &id, "--status", "in_progress"],
+        "unassigned",
+    );
+    assert!(!unassigned.status.success());
+    assert!(
+        unassigned.stderr.contains("requires assignee"),
+        "{}",
+        unassigned.stderr
+    );
+
+    // The guard sees the assignee set in the same update.
+    let start = run_br(
+        &workspace,
+        [
+            "update",
+            &id,
+            "--status",
+            "in_progress",
+            "--assignee",
+            "alice",
+        ],
+        "start",
+    );
+    assert!(start.status.success(), "start failed: {}", start.stderr);
+
+    // `review` is a custom status declared by the workflow.
+    let review = run_br(&workspace, ["update", &id, "--status", "review"], "review");
+    assert!(review.status.success(), "review failed: {}", review.stderr);
+    assert_eq!(status(&workspace, &id), "review");
+
+    // Closing needs acceptance criteria; --force does not bypass the workflow.
+    let close = run_br(&workspace, ["close", &id, "--force", "--json"], "close");
+    assert!(close.status.success(), "close failed: {}", close.stderr);
+    assert_eq!(status(&workspace, &id), "review");
+
+    let criteria = run_br(
+        &workspace,
+        [
+            "update",
+            &id,
+            "--acceptance-criteria",
+            "Login via GitHub works",
+        ],
+        "criteria",
+    );
+    assert!(criteria.status.success(), "{}", criteria.stderr);
+    let close = run_br(&workspace, ["close", &id], "close_ok");
+    assert!(close.status.success(), "close failed: {}", close.stderr);
+    assert_eq!(status(&workspace, &id), "closed");
+
+    let reopen = run_br(&workspace, ["reopen", &id], "reopen");
+    assert!(reopen.status.success(), "reopen failed: {}", reopen.stderr);
+    assert_eq!(status(&workspace, &id), "open");
+}
+
+#[test]
+fn workflow_applies_per_issue_type() {
+    let workspace = setup();
+    let chore = create(&workspace, &["Rotate keys", "--type", "chore"]);
+
+    let review = run_br(
+        &workspace,
+        ["update", &chore, "--status", "review"],
+        "chore_review",
+    );
+    assert!(!review.status.success());
+    assert!(
+        review.stderr.contains("not a state of the chore workflow"),
+        "{}",
+        review.stderr
+    );
+
+    let close = run_br(&workspace, ["close", &chore], "chore_close");
+    assert!(close.status.success(), "close failed: {}", close.stderr);
+    assert_eq!(status(&workspace, &chore), "closed");
+
+    // The chore workflow lists no way out of closed.
+    let reopen = run_br(&workspace, ["reopen", &chore, "--json"], "chore_reopen");
+    assert!(reopen.status.success(), "reopen failed: {}", reopen.stderr);
+    let result: Value = serde_json::from_str(&extract_json_payload(&reopen.stdout)).unwrap();
+    assert!(result["reopened"].as_array().unwrap().is_empty());
+    assert!(
+        result["skipped"][0]["reason"]
+            .as_str()
+            .unwrap()
+            .contains("closed -> open"),
+        "{result}"
+    );
+    assert_eq!(status(&workspace, &chore), "closed");
+}
+
+#[test]
+fn workflow_show_renders_config() {
+    let workspace = setup();
+
+    let show = run_br(&workspace, ["workflow", "show", "--type", "bug"], "show");
+    assert!(show.status.success(), "show failed: {}", show.stderr);
+    assert!(
+        show.stdout
+            .contains("default (types without their own workflow)")
+    );
+    assert!(show.stdout.contains("review      -> closed, in_progress"));
+    assert!(show.stdout.contains("closed requires acceptance_criteria"));
+    assert!(!show.stdout.contains("chore"));
+
+    let json = run_br(&workspace, ["workflow", "show", "--json"], "show_json");
+    assert!(json.status.success(), "show failed: {}", json.stderr);
+    let report: Value = serde_json::from_str(&extract_json_payload(&json.stdout)).unwrap();
+    let workflows = report["workflows"].as_array().unwrap();
+    assert_eq!(workflows.len(), 2);
+    assert_eq!(workflows[0]["name"], "default");
+    assert_eq!(workflows[1]["name"], "chore");
+    assert_eq!(workflows[1]["transitions"]["open"][0], "closed");
+
+    let mermaid = run_br(
+        &workspace,
+        ["workflow", "show", "--type", "chore", "--format", "mermaid"],
+        "mermaid",
+    );
+    assert!(
+        mermaid.status.success(),
+        "mermaid failed: {}",
+        mermaid.stderr
+    );
+    assert!(mermaid.stdout.contains("stateDiagram-v2"));
+    assert!(mermaid.stdout.contains("open --> closed"));
+}
@@ -64,6 +64,9 @@ fn make_issue(title: &str, description: Option<&str>) -> Issue {
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        time_entries: vec![],
+        recurrence: None,
+        attachments: vec![],
     }
 }
 
@@ -64,6 +64,9 @@ fn make_valid_issue(title: &str) -> Issue {
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        time_entries: vec![],
+        recurrence: None,
+        attachments: vec![],
     }
 }
 
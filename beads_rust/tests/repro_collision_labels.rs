@@ -47,6 +47,9 @@ fn make_issue(id: &str, title: &str) -> Issue {
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        time_entries: vec![],
+        recurrence: None,
+        attachments: vec![],
     }
 }
 
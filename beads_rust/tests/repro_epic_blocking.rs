@@ -43,6 +43,9 @@ fn create_issue(id: &str, title: &str, issue_type: IssueType) -> Issue {
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        time_entries: vec![],
+        recurrence: None,
+        attachments: vec![],
     }
 }
 
@@ -63,6 +63,9 @@ fn create_issue_all_fields_populated() {
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        time_entries: vec![],
+        recurrence: None,
+        attachments: vec![],
         // Other optional fields
         content_hash: None,
         closed_at: None,
@@ -443,6 +446,9 @@ fn update_issue_clear_optional_fields() {
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        time_entries: vec![],
+        recurrence: None,
+        attachments: vec![],
     };
 
     storage.create_issue(&issue, "tester").unwrap();
@@ -702,6 +708,9 @@ fn upsert_issue_stores_all_fields() {
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        time_entries: vec![],
+        recurrence: None,
+        attachments: vec![],
         content_hash: Some("abc123".to_string()),
         closed_at: None,
         close_reason: None,
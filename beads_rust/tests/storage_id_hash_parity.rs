@@ -589,6 +589,9 @@ fn content_hash_trait_implementation() {
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        time_entries: vec![],
+        recurrence: None,
+        attachments: vec![],
     };
 
     let hash_trait = issue.content_hash();